@@ -9,7 +9,7 @@ use coerce::actor::context::ActorContext;
 use coerce::actor::message::{Handler, Message};
 use coerce::actor::scheduler::ActorType::Anonymous;
 use coerce::actor::system::ActorSystem;
-use coerce::actor::{Actor, IntoActorId, LocalActorRef, ToActorId};
+use coerce::actor::{Actor, IntoActorId, LocalActorRef};
 use tokio::runtime::Runtime;
 
 struct BenchmarkActor;
@@ -29,7 +29,7 @@ impl Handler<Msg> for BenchmarkActor {
 
 async fn actor_1000_send_and_wait(actor: &LocalActorRef<BenchmarkActor>) {
     for _ in 0..1000 {
-        let _ = actor.send(Msg).await.unwrap();
+        actor.send(Msg).await.unwrap();
     }
 }
 
@@ -38,7 +38,7 @@ async fn actor_999_notify_1_send_and_wait(actor: &LocalActorRef<BenchmarkActor>)
         let _ = actor.notify(Msg);
     }
 
-    let _ = actor.send(Msg).await.unwrap();
+    actor.send(Msg).await.unwrap();
 }
 
 fn actor_send_1000_benchmark(bench: &mut Bencher) {
@@ -72,4 +72,148 @@ benchmark_group!(
     actor_send_1000_benchmark,
     actor_notify_1000_benchmark
 );
+
+#[cfg(feature = "remote")]
+mod remote {
+    use super::BenchmarkActor;
+    use bencher::Bencher;
+    use coerce::actor::context::ActorContext;
+    use coerce::actor::message::{Handler, Message, MessageUnwrapErr, MessageWrapErr};
+    use coerce::actor::scheduler::ActorType::Tracked;
+    use coerce::actor::system::ActorSystem;
+    use coerce::actor::{ActorRef, IntoActorId};
+    use coerce::remote::net::config::{BatchingConfig, NetworkConfig};
+    use coerce::remote::net::transport::MemoryTransport;
+    use coerce::remote::system::{NodeId, RemoteActorSystem};
+    use coerce::remote::RemoteActorRef;
+    use futures::future::join_all;
+    use tokio::runtime::Runtime;
+
+    struct RemoteMsg;
+
+    impl Message for RemoteMsg {
+        type Result = ();
+
+        fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+            Ok(vec![])
+        }
+
+        fn from_bytes(_: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+            Ok(RemoteMsg)
+        }
+
+        fn read_remote_result(_: Vec<u8>) -> Result<(), MessageUnwrapErr> {
+            Ok(())
+        }
+
+        fn write_remote_result(_: ()) -> Result<Vec<u8>, MessageWrapErr> {
+            Ok(vec![])
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Handler<RemoteMsg> for BenchmarkActor {
+        async fn handle(&mut self, _message: RemoteMsg, _ctx: &mut ActorContext) {}
+    }
+
+    async fn remote_1000_concurrent_send_and_wait(actor: &ActorRef<BenchmarkActor>) {
+        for res in join_all((0..1000).map(|_| actor.send(RemoteMsg))).await {
+            res.unwrap();
+        }
+    }
+
+    pub fn remote_send_1000_benchmark(bench: &mut Bencher) {
+        remote_benchmark(bench, BatchingConfig::default());
+    }
+
+    pub fn remote_send_1000_batched_benchmark(bench: &mut Bencher) {
+        remote_benchmark(
+            bench,
+            BatchingConfig {
+                enabled: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn remote_benchmark(bench: &mut Bencher, batching: BatchingConfig) {
+        let runtime = rt();
+        let actor = runtime.block_on(async { remote_actor(batching).await });
+
+        bench.iter(|| runtime.block_on(remote_1000_concurrent_send_and_wait(&actor)));
+    }
+
+    fn rt() -> Runtime {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    async fn remote_actor(batching: BatchingConfig) -> ActorRef<BenchmarkActor> {
+        let transport = MemoryTransport::new();
+        let node_1 = node(1, &transport, batching.clone()).await;
+        let node_2 = node(2, &transport, batching).await;
+
+        let _ = node_1
+            .actor_system()
+            .new_actor("actor".into_actor_id(), BenchmarkActor, Tracked)
+            .await
+            .expect("unable to create actor");
+
+        RemoteActorRef::<BenchmarkActor>::new("actor".into_actor_id(), 1, node_2).into()
+    }
+
+    async fn node(
+        node_id: NodeId,
+        transport: &MemoryTransport,
+        batching: BatchingConfig,
+    ) -> RemoteActorSystem {
+        let remote = RemoteActorSystem::builder()
+            .with_tag(format!("node-{}", node_id))
+            .with_id(node_id)
+            .with_actor_system(ActorSystem::new())
+            .with_transport(transport.clone())
+            .with_handlers(|handlers| {
+                handlers.with_handler::<BenchmarkActor, RemoteMsg>("BenchmarkActor.RemoteMsg")
+            })
+            .configure(|config| {
+                config.network(NetworkConfig {
+                    batching,
+                    ..Default::default()
+                })
+            })
+            .build()
+            .await;
+
+        let worker = remote
+            .clone()
+            .cluster_worker()
+            .listen_addr(format!("node-{}", node_id));
+
+        let worker = if node_id == 1 {
+            worker
+        } else {
+            worker.with_seed_addr("node-1")
+        };
+
+        worker.start().await;
+        remote
+    }
+}
+
+#[cfg(feature = "remote")]
+use remote::{remote_send_1000_batched_benchmark, remote_send_1000_benchmark};
+
+#[cfg(feature = "remote")]
+benchmark_group!(
+    remote_messaging,
+    remote_send_1000_benchmark,
+    remote_send_1000_batched_benchmark
+);
+
+#[cfg(feature = "remote")]
+benchmark_main!(actor_messaging, remote_messaging);
+
+#[cfg(not(feature = "remote"))]
 benchmark_main!(actor_messaging);
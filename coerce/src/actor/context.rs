@@ -3,9 +3,10 @@
 use crate::actor::message::{Handler, Message};
 use crate::actor::metrics::ActorMetrics;
 use crate::actor::system::ActorSystem;
+use crate::actor::watch::{ActorTerminated, TerminationReason, Unwatch, Watch, Watcher, Watchers};
 use crate::actor::{
-    Actor, ActorId, ActorPath, ActorRefErr, ActorTags, BoxedActorRef, CoreActorRef, IntoActorPath,
-    LocalActorRef,
+    Actor, ActorId, ActorPath, ActorRef, ActorRefErr, ActorTags, BoxedActorRef, CoreActorRef,
+    IntoActorPath, LocalActorRef, Ref,
 };
 use futures::{Stream, StreamExt};
 use std::any::Any;
@@ -32,6 +33,7 @@ pub struct ActorContext {
     boxed_ref: BoxedActorRef,
     boxed_parent_ref: Option<BoxedActorRef>,
     supervised: Option<Supervised>,
+    watchers: Watchers,
     system: Option<ActorSystem>,
     on_actor_stopped: Option<Vec<Sender<()>>>,
     tags: Option<ActorTags>,
@@ -63,6 +65,7 @@ impl ActorContext {
             context_id,
             full_path,
             supervised: None,
+            watchers: Watchers::default(),
             boxed_parent_ref: None,
             on_actor_stopped: None,
             tags: None,
@@ -255,6 +258,54 @@ impl ActorContext {
         supervised.spawn_deferred(id, actor, system, parent_ref)
     }
 
+    /// Watches the actor, once the watched actor has stopped, or the node hosting it has been
+    /// terminated, an [`ActorTerminated`] message is delivered to the current actor.
+    pub fn watch<A: Actor>(&self, actor_ref: &ActorRef<A>) {
+        let watcher = Watcher::Local(self.boxed_ref.clone());
+
+        match &actor_ref.inner_ref {
+            Ref::Local(local_ref) => {
+                // if the watched actor has already stopped, dropping the undelivered `Watch`
+                // notifies the watcher
+                let _ = local_ref.notify(Watch::new(
+                    watcher,
+                    local_ref.actor_id().clone(),
+                    self.system.clone(),
+                ));
+            }
+
+            #[cfg(feature = "remote")]
+            Ref::Remote(remote_ref) => self.system().remote().watch_remote_actor(
+                remote_ref.node_id(),
+                remote_ref.actor_id().clone(),
+                self.boxed_ref.clone(),
+            ),
+        }
+    }
+
+    pub fn unwatch<A: Actor>(&self, actor_ref: &ActorRef<A>) {
+        match &actor_ref.inner_ref {
+            Ref::Local(local_ref) => {
+                let _ = local_ref.notify(Unwatch(Watcher::Local(self.boxed_ref.clone())));
+            }
+
+            #[cfg(feature = "remote")]
+            Ref::Remote(remote_ref) => self.system().remote().unwatch_remote_actor(
+                remote_ref.node_id(),
+                remote_ref.actor_id(),
+                self.id(),
+            ),
+        }
+    }
+
+    pub fn watchers(&self) -> &Watchers {
+        &self.watchers
+    }
+
+    pub fn watchers_mut(&mut self) -> &mut Watchers {
+        &mut self.watchers
+    }
+
     pub fn supervised_count(&self) -> usize {
         self.supervised.as_ref().map_or(0, |s| s.count())
     }
@@ -324,6 +375,7 @@ impl Valuable for LogContext {
 impl Drop for ActorContext {
     fn drop(&mut self) {
         let parent_ref = self.boxed_parent_ref.take();
+        let watchers = std::mem::take(&mut self.watchers);
 
         if let Some(mut supervised) = self.supervised.take() {
             let parent_ref = parent_ref.clone();
@@ -334,10 +386,16 @@ impl Drop for ActorContext {
             tokio::spawn(async move {
                 supervised.stop_all().await;
 
-                on_context_dropped(&boxed_ref, &parent_ref, &status, &system);
+                on_context_dropped(&boxed_ref, &parent_ref, watchers, &status, &system);
             });
         } else {
-            on_context_dropped(&self.boxed_ref, &parent_ref, &self.status, &self.system);
+            on_context_dropped(
+                &self.boxed_ref,
+                &parent_ref,
+                watchers,
+                &self.status,
+                &self.system,
+            );
         }
     }
 }
@@ -345,6 +403,7 @@ impl Drop for ActorContext {
 fn on_context_dropped(
     actor: &BoxedActorRef,
     parent_ref: &Option<BoxedActorRef>,
+    watchers: Watchers,
     status: &ActorStatus,
     system: &Option<ActorSystem>,
 ) {
@@ -389,6 +448,16 @@ fn on_context_dropped(
     if let Some(boxed_parent_ref) = parent_ref {
         let _ = boxed_parent_ref.notify_child_terminated(actor_id.clone());
     }
+
+    for watcher in watchers.into_inner() {
+        watcher.notify_terminated(
+            ActorTerminated {
+                actor_id: actor_id.clone(),
+                reason: TerminationReason::Stopped,
+            },
+            system.as_ref(),
+        );
+    }
 }
 
 pub fn attach_stream<S, T, R, E, A, M>(
@@ -147,6 +147,7 @@ use crate::actor::metrics::ActorMetrics;
 use crate::actor::scheduler::ActorType::{Anonymous, Tracked};
 use crate::actor::supervised::Terminated;
 use crate::actor::system::ActorSystem;
+use crate::actor::watch::{ActorTerminated, Unwatch, Watch, Watcher};
 use std::any::Any;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -176,6 +177,7 @@ pub mod metrics;
 pub mod scheduler;
 pub mod supervised;
 pub mod system;
+pub mod watch;
 pub mod worker;
 
 /// A reference to a string-based `ActorId`
@@ -209,6 +211,16 @@ pub trait Actor: 'static + Send + Sync {
     /// Called when a supervised actor has stopped
     async fn on_child_stopped(&mut self, _id: &ActorId, _ctx: &mut ActorContext) {}
 
+    /// Called when an actor watched via [`ActorContext::watch`] has stopped
+    ///
+    /// [`ActorContext::watch`]: context::ActorContext::watch
+    async fn on_actor_terminated(
+        &mut self,
+        _terminated: &ActorTerminated,
+        _ctx: &mut ActorContext,
+    ) {
+    }
+
     /// Returns a [`LocalActorRef<Self>`] instance of the current actor,
     /// automatically casting from the [`ActorContext`][context::ActorContext]'s [`BoxedActorRef`][BoxedActorRef].
     ///
@@ -569,6 +581,12 @@ pub trait CoreActorRef: Any {
 
     fn notify_child_terminated(&self, id: ActorId) -> Result<(), ActorRefErr>;
 
+    fn notify_watch(&self, watcher: Watcher, system: &ActorSystem) -> Result<(), ActorRefErr>;
+
+    fn notify_unwatch(&self, watcher: Watcher) -> Result<(), ActorRefErr>;
+
+    fn notify_actor_terminated(&self, terminated: ActorTerminated) -> Result<(), ActorRefErr>;
+
     fn is_valid(&self) -> bool;
 
     fn as_any(&self) -> &dyn Any;
@@ -827,6 +845,15 @@ impl<A: Actor> LocalActorRef<A> {
         }
     }
 
+    /// Enqueues a message that has already been wrapped as a [`MessageHandler`], allowing messages
+    /// sent via one reference to be intercepted and forwarded to the actor, for example by a testkit.
+    pub fn enqueue(&self, message: MessageHandler<A>) -> Result<(), ActorRefErr> {
+        self.inner
+            .sender
+            .send(message)
+            .map_err(|_e| ActorRefErr::InvalidRef)
+    }
+
     pub async fn exec<F, R>(&self, f: F) -> Result<R, ActorRefErr>
     where
         F: (FnMut(&mut A) -> R) + 'static + Send + Sync,
@@ -906,6 +933,22 @@ impl<A: Actor> CoreActorRef for LocalActorRef<A> {
         self.notify(Terminated(id))
     }
 
+    fn notify_watch(&self, watcher: Watcher, system: &ActorSystem) -> Result<(), ActorRefErr> {
+        self.notify(Watch::new(
+            watcher,
+            self.actor_id().clone(),
+            Some(system.clone()),
+        ))
+    }
+
+    fn notify_unwatch(&self, watcher: Watcher) -> Result<(), ActorRefErr> {
+        self.notify(Unwatch(watcher))
+    }
+
+    fn notify_actor_terminated(&self, terminated: ActorTerminated) -> Result<(), ActorRefErr> {
+        self.notify(terminated)
+    }
+
     fn is_valid(&self) -> bool {
         self.is_valid()
     }
@@ -949,6 +992,18 @@ impl CoreActorRef for BoxedActorRef {
         self.0.notify_child_terminated(id)
     }
 
+    fn notify_watch(&self, watcher: Watcher, system: &ActorSystem) -> Result<(), ActorRefErr> {
+        self.0.notify_watch(watcher, system)
+    }
+
+    fn notify_unwatch(&self, watcher: Watcher) -> Result<(), ActorRefErr> {
+        self.0.notify_unwatch(watcher)
+    }
+
+    fn notify_actor_terminated(&self, terminated: ActorTerminated) -> Result<(), ActorRefErr> {
+        self.0.notify_actor_terminated(terminated)
+    }
+
     fn is_valid(&self) -> bool {
         self.0.is_valid()
     }
@@ -174,6 +174,12 @@ where
     }
 }
 
+pub struct GetBoxedActor(pub ActorId);
+
+impl Message for GetBoxedActor {
+    type Result = Option<BoxedActorRef>;
+}
+
 #[cfg(feature = "remote")]
 #[async_trait]
 impl Handler<SetRemote> for ActorScheduler {
@@ -225,6 +231,17 @@ impl Handler<DeregisterActor> for ActorScheduler {
     }
 }
 
+#[async_trait]
+impl Handler<GetBoxedActor> for ActorScheduler {
+    async fn handle(
+        &mut self,
+        message: GetBoxedActor,
+        _ctx: &mut ActorContext,
+    ) -> Option<BoxedActorRef> {
+        self.actors.get(&message.0).cloned()
+    }
+}
+
 #[async_trait]
 impl<A: Actor> Handler<GetActor<A>> for ActorScheduler
 where
@@ -1,6 +1,8 @@
 //! Actor System
 //!
-use crate::actor::scheduler::{start_actor, ActorScheduler, ActorType, GetActor, RegisterActor};
+use crate::actor::scheduler::{
+    start_actor, ActorCount, ActorScheduler, ActorType, GetActor, GetBoxedActor, RegisterActor,
+};
 use crate::actor::{
     new_actor_id, Actor, ActorId, ActorPath, ActorRefErr, BoxedActorRef, IntoActorId,
     LocalActorRef, ToActorId,
@@ -242,6 +244,23 @@ impl ActorSystem {
         info!("shutdown complete");
     }
 
+    /// The number of actors tracked by the system's scheduler.
+    pub async fn actor_count(&self) -> usize {
+        self.core
+            .scheduler
+            .send(ActorCount)
+            .await
+            .unwrap_or_default()
+    }
+
+    pub async fn get_tracked_boxed_actor(&self, id: ActorId) -> Option<BoxedActorRef> {
+        self.core
+            .scheduler
+            .send(GetBoxedActor(id))
+            .await
+            .unwrap_or_default()
+    }
+
     pub async fn get_tracked_actor<A: Actor>(&self, id: ActorId) -> Option<LocalActorRef<A>> {
         let _actor_type_name = A::type_name();
         // let span = tracing::trace_span!(
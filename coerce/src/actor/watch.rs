@@ -0,0 +1,181 @@
+//! Actor death watch
+//!
+//! Any actor can watch another actor, local or remote, via [`ActorContext::watch`]. Once the
+//! watched actor stops, or the node hosting it is terminated, the watcher receives an
+//! [`ActorTerminated`] message, which is forwarded to [`Actor::on_actor_terminated`].
+//!
+//! [`ActorContext::watch`]: crate::actor::context::ActorContext::watch
+//! [`Actor::on_actor_terminated`]: crate::actor::Actor::on_actor_terminated
+
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::system::ActorSystem;
+use crate::actor::{Actor, ActorId, BoxedActorRef, CoreActorRef};
+
+#[cfg(feature = "remote")]
+use crate::remote::system::NodeId;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TerminationReason {
+    /// The watched actor has stopped
+    Stopped,
+
+    /// The watched actor did not exist, or stopped before the watch could be registered
+    NotFound,
+
+    /// The node hosting the watched actor was terminated
+    NodeTerminated,
+
+    /// The watch couldn't be registered because there is no connection to the node
+    /// hosting the watched actor
+    Unreachable,
+}
+
+#[derive(Debug, Clone)]
+pub struct ActorTerminated {
+    pub actor_id: ActorId,
+    pub reason: TerminationReason,
+}
+
+#[derive(Debug, Clone)]
+pub enum Watcher {
+    Local(BoxedActorRef),
+
+    #[cfg(feature = "remote")]
+    Remote(NodeId),
+}
+
+/// Registers a watcher with the receiving actor.
+///
+/// If the receiving actor stops before the message is handled, the queued message is dropped
+/// along with the rest of the mailbox, and the watcher is notified that the actor was not found,
+/// rather than waiting for a notification that would never arrive.
+pub struct Watch {
+    watcher: Option<Watcher>,
+    actor_id: ActorId,
+    system: Option<ActorSystem>,
+}
+
+pub struct Unwatch(pub Watcher);
+
+impl Watch {
+    pub fn new(watcher: Watcher, actor_id: ActorId, system: Option<ActorSystem>) -> Watch {
+        Watch {
+            watcher: Some(watcher),
+            actor_id,
+            system,
+        }
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            watcher.notify_terminated(
+                ActorTerminated {
+                    actor_id: self.actor_id.clone(),
+                    reason: TerminationReason::NotFound,
+                },
+                self.system.as_ref(),
+            );
+        }
+    }
+}
+
+impl Watcher {
+    fn is_same(&self, other: &Watcher) -> bool {
+        match (self, other) {
+            (Watcher::Local(a), Watcher::Local(b)) => a.actor_id() == b.actor_id(),
+
+            #[cfg(feature = "remote")]
+            (Watcher::Remote(a), Watcher::Remote(b)) => a == b,
+
+            #[cfg(feature = "remote")]
+            _ => false,
+        }
+    }
+
+    #[cfg_attr(not(feature = "remote"), allow(unused_variables))]
+    pub(crate) fn notify_terminated(
+        &self,
+        terminated: ActorTerminated,
+        system: Option<&ActorSystem>,
+    ) {
+        match self {
+            Watcher::Local(actor_ref) => {
+                let _ = actor_ref.notify_actor_terminated(terminated);
+            }
+
+            #[cfg(feature = "remote")]
+            Watcher::Remote(node_id) => {
+                if let Some(system) = system.filter(|s| s.is_remote()) {
+                    system
+                        .remote()
+                        .notify_watcher_terminated(*node_id, terminated);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Watchers(Vec<Watcher>);
+
+impl Watchers {
+    pub fn add(&mut self, watcher: Watcher) {
+        if !self.0.iter().any(|w| w.is_same(&watcher)) {
+            self.0.push(watcher);
+        }
+    }
+
+    pub fn remove(&mut self, watcher: &Watcher) {
+        self.0.retain(|w| !w.is_same(watcher));
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn into_inner(self) -> Vec<Watcher> {
+        self.0
+    }
+}
+
+impl Message for ActorTerminated {
+    type Result = ();
+}
+
+impl Message for Watch {
+    type Result = ();
+}
+
+impl Message for Unwatch {
+    type Result = ();
+}
+
+#[async_trait]
+impl<A: Actor> Handler<ActorTerminated> for A {
+    async fn handle(&mut self, message: ActorTerminated, ctx: &mut ActorContext) {
+        self.on_actor_terminated(&message, ctx).await;
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<Watch> for A {
+    async fn handle(&mut self, mut message: Watch, ctx: &mut ActorContext) {
+        if let Some(watcher) = message.watcher.take() {
+            ctx.watchers_mut().add(watcher);
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Actor> Handler<Unwatch> for A {
+    async fn handle(&mut self, message: Unwatch, ctx: &mut ActorContext) {
+        ctx.watchers_mut().remove(&message.0);
+    }
+}
@@ -63,6 +63,7 @@
 //! - `actor-tracing` - Enables actor tracing
 //! - `api` - Enables HTTP API server
 //! - `client-auth-jwt` - Enables JWT authentication between Coerce cluster nodes
+//! - `remote-tls` - Enables TLS (and mutual TLS) between Coerce cluster nodes
 //!
 //! # Getting Started
 //! The entry point into the Coerce runtime is an [`ActorSystem`]. Every [`ActorSystem`] has an ID
@@ -179,5 +180,3 @@ pub mod remote;
 
 #[cfg(feature = "sharding")]
 pub mod sharding;
-
-pub(crate) const CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,432 @@
+//! Journal export, import and migration between [`JournalStorage`] providers.
+//!
+//! Journals are exported to a portable format, consisting of a sequence of length-delimited
+//! `ExportedJournalEntry` protobuf messages (see `journal.proto`). Each record carries the
+//! persistence ID, whether the entry is a snapshot or a message, and the original [`JournalEntry`].
+//! All records for a single persistence ID are written contiguously, snapshot first.
+//!
+//! [`JournalStorage`]: crate::persistent::journal::storage::JournalStorage
+
+use crate::persistent::journal::proto::journal::{
+    ExportedJournalEntry, JournalEntry as ProtoJournalEntry, JournalEntryKind,
+};
+use crate::persistent::journal::storage::{JournalEntry, JournalStorageRef};
+use protobuf::{CodedInputStream, EnumOrUnknown, Message, MessageField};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+/// All snapshots and messages stored for a single persistence ID.
+#[derive(Clone, Debug, Default)]
+pub struct ExportedJournal {
+    pub persistence_id: String,
+    pub snapshot: Option<JournalEntry>,
+    pub messages: Vec<JournalEntry>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ImportOptions {
+    /// Read every imported journal back from the target storage and compare it with the source.
+    pub verify: bool,
+
+    /// Delete any existing journal in the target storage before importing.
+    pub replace_existing: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        ImportOptions {
+            verify: true,
+            replace_existing: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MigrationSummary {
+    pub journals: usize,
+    pub snapshots: usize,
+    pub messages: usize,
+}
+
+#[derive(Debug)]
+pub enum MigrationErr {
+    Storage(anyhow::Error),
+    Io(std::io::Error),
+    Decode(protobuf::Error),
+    Encode(protobuf::Error),
+    InvalidEntry(String),
+    Verification {
+        persistence_id: String,
+        reason: String,
+    },
+}
+
+impl Display for MigrationErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            MigrationErr::Storage(e) => write!(f, "journal storage error: {}", e),
+            MigrationErr::Io(e) => write!(f, "io error: {}", e),
+            MigrationErr::Decode(e) => write!(f, "failed to decode exported entry: {}", e),
+            MigrationErr::Encode(e) => write!(f, "failed to encode exported entry: {}", e),
+            MigrationErr::InvalidEntry(reason) => write!(f, "invalid exported entry: {}", reason),
+            MigrationErr::Verification {
+                persistence_id,
+                reason,
+            } => write!(
+                f,
+                "verification failed (persistence_id={}): {}",
+                persistence_id, reason
+            ),
+        }
+    }
+}
+
+impl Error for MigrationErr {}
+
+impl From<std::io::Error> for MigrationErr {
+    fn from(value: std::io::Error) -> Self {
+        MigrationErr::Io(value)
+    }
+}
+
+impl MigrationSummary {
+    fn add(&mut self, journal: &ExportedJournal) {
+        self.journals += 1;
+        self.snapshots += journal.snapshot.is_some() as usize;
+        self.messages += journal.messages.len();
+    }
+}
+
+/// Writes exported journals to any [`Write`] implementation.
+pub struct JournalExportWriter<W: Write> {
+    writer: W,
+    summary: MigrationSummary,
+}
+
+impl<W: Write> JournalExportWriter<W> {
+    pub fn new(writer: W) -> Self {
+        JournalExportWriter {
+            writer,
+            summary: MigrationSummary::default(),
+        }
+    }
+
+    pub fn write_journal(&mut self, journal: &ExportedJournal) -> Result<(), MigrationErr> {
+        if let Some(snapshot) = &journal.snapshot {
+            self.write_entry(
+                &journal.persistence_id,
+                JournalEntryKind::SNAPSHOT,
+                snapshot,
+            )?;
+        }
+
+        for message in &journal.messages {
+            self.write_entry(&journal.persistence_id, JournalEntryKind::MESSAGE, message)?;
+        }
+
+        self.summary.add(journal);
+        Ok(())
+    }
+
+    fn write_entry(
+        &mut self,
+        persistence_id: &str,
+        kind: JournalEntryKind,
+        entry: &JournalEntry,
+    ) -> Result<(), MigrationErr> {
+        let record = ExportedJournalEntry {
+            persistence_id: persistence_id.to_string(),
+            kind: EnumOrUnknown::new(kind),
+            entry: MessageField::some(ProtoJournalEntry {
+                sequence: entry.sequence,
+                payload_type: entry.payload_type.to_string(),
+                bytes: entry.bytes.as_ref().clone(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        record
+            .write_length_delimited_to_writer(&mut self.writer)
+            .map_err(MigrationErr::Encode)
+    }
+
+    pub fn summary(&self) -> MigrationSummary {
+        self.summary
+    }
+
+    pub fn finish(mut self) -> Result<MigrationSummary, MigrationErr> {
+        self.writer.flush()?;
+        Ok(self.summary)
+    }
+}
+
+/// Reads journals previously written by [`JournalExportWriter`].
+pub struct JournalExportReader<'a> {
+    input: CodedInputStream<'a>,
+    pending: Option<(String, JournalEntryKind, JournalEntry)>,
+}
+
+impl<'a> JournalExportReader<'a> {
+    pub fn new(reader: &'a mut dyn Read) -> Self {
+        JournalExportReader {
+            input: CodedInputStream::new(reader),
+            pending: None,
+        }
+    }
+
+    /// Reads all records belonging to the next persistence ID, returns `None` once the
+    /// end of the input has been reached.
+    pub fn next_journal(&mut self) -> Result<Option<ExportedJournal>, MigrationErr> {
+        let mut journal: Option<ExportedJournal> = None;
+
+        loop {
+            let record = match self.pending.take() {
+                Some(record) => record,
+                None => match self.read_entry()? {
+                    Some(record) => record,
+                    None => return Ok(journal),
+                },
+            };
+
+            let (persistence_id, kind, entry) = record;
+            let journal = match &mut journal {
+                Some(journal) if journal.persistence_id != persistence_id => {
+                    self.pending = Some((persistence_id, kind, entry));
+                    break;
+                }
+                Some(journal) => journal,
+                None => journal.insert(ExportedJournal {
+                    persistence_id,
+                    ..Default::default()
+                }),
+            };
+
+            match kind {
+                JournalEntryKind::SNAPSHOT => journal.snapshot = Some(entry),
+                JournalEntryKind::MESSAGE => journal.messages.push(entry),
+            }
+        }
+
+        Ok(journal)
+    }
+
+    fn read_entry(
+        &mut self,
+    ) -> Result<Option<(String, JournalEntryKind, JournalEntry)>, MigrationErr> {
+        if self.input.eof().map_err(MigrationErr::Decode)? {
+            return Ok(None);
+        }
+
+        let record: ExportedJournalEntry =
+            self.input.read_message().map_err(MigrationErr::Decode)?;
+
+        let kind = record.kind.enum_value().map_err(|kind| {
+            MigrationErr::InvalidEntry(format!(
+                "unknown entry kind {} (persistence_id={})",
+                kind, &record.persistence_id
+            ))
+        })?;
+
+        let entry = record.entry.into_option().ok_or_else(|| {
+            MigrationErr::InvalidEntry(format!(
+                "missing journal entry (persistence_id={})",
+                &record.persistence_id
+            ))
+        })?;
+
+        Ok(Some((
+            record.persistence_id,
+            kind,
+            JournalEntry {
+                sequence: entry.sequence,
+                payload_type: entry.payload_type.into(),
+                bytes: Arc::new(entry.bytes),
+            },
+        )))
+    }
+}
+
+/// Reads the latest snapshot and all messages stored for `persistence_id`.
+pub async fn read_journal(
+    storage: &JournalStorageRef,
+    persistence_id: &str,
+) -> Result<ExportedJournal, MigrationErr> {
+    let snapshot = storage
+        .read_latest_snapshot(persistence_id)
+        .await
+        .map_err(MigrationErr::Storage)?;
+
+    let messages = storage
+        .read_latest_messages(persistence_id, 0)
+        .await
+        .map_err(MigrationErr::Storage)?
+        .unwrap_or_default();
+
+    Ok(ExportedJournal {
+        persistence_id: persistence_id.to_string(),
+        snapshot,
+        messages,
+    })
+}
+
+/// Exports every journal in `storage` to `writer`.
+pub async fn export_journals<W: Write>(
+    storage: &JournalStorageRef,
+    writer: W,
+) -> Result<MigrationSummary, MigrationErr> {
+    let persistence_ids = storage
+        .read_persistence_ids()
+        .await
+        .map_err(MigrationErr::Storage)?;
+
+    let mut writer = JournalExportWriter::new(writer);
+    for persistence_id in persistence_ids {
+        let journal = read_journal(storage, &persistence_id).await?;
+        writer.write_journal(&journal)?;
+
+        trace!(
+            "exported journal (persistence_id={}, snapshot={}, messages={})",
+            &persistence_id,
+            journal.snapshot.is_some(),
+            journal.messages.len()
+        );
+    }
+
+    writer.finish()
+}
+
+/// Imports every journal read from `reader` into `storage`.
+pub async fn import_journals(
+    reader: &mut dyn Read,
+    storage: &JournalStorageRef,
+    options: ImportOptions,
+) -> Result<MigrationSummary, MigrationErr> {
+    let mut reader = JournalExportReader::new(reader);
+    let mut summary = MigrationSummary::default();
+
+    while let Some(journal) = reader.next_journal()? {
+        import_journal(&journal, storage, options).await?;
+        summary.add(&journal);
+    }
+
+    Ok(summary)
+}
+
+/// Copies every journal from `source` into `target`, without an intermediate file.
+pub async fn migrate_journals(
+    source: &JournalStorageRef,
+    target: &JournalStorageRef,
+    options: ImportOptions,
+) -> Result<MigrationSummary, MigrationErr> {
+    let persistence_ids = source
+        .read_persistence_ids()
+        .await
+        .map_err(MigrationErr::Storage)?;
+
+    let mut summary = MigrationSummary::default();
+    for persistence_id in persistence_ids {
+        let journal = read_journal(source, &persistence_id).await?;
+        import_journal(&journal, target, options).await?;
+        summary.add(&journal);
+    }
+
+    Ok(summary)
+}
+
+pub async fn import_journal(
+    journal: &ExportedJournal,
+    storage: &JournalStorageRef,
+    options: ImportOptions,
+) -> Result<(), MigrationErr> {
+    let persistence_id = journal.persistence_id.as_str();
+    if options.replace_existing {
+        storage
+            .delete_all(persistence_id)
+            .await
+            .map_err(MigrationErr::Storage)?;
+    }
+
+    if let Some(snapshot) = &journal.snapshot {
+        storage
+            .write_snapshot(persistence_id, snapshot.clone())
+            .await
+            .map_err(MigrationErr::Storage)?;
+    }
+
+    if !journal.messages.is_empty() {
+        storage
+            .write_message_batch(persistence_id, journal.messages.clone())
+            .await
+            .map_err(MigrationErr::Storage)?;
+    }
+
+    if options.verify {
+        verify_journal(journal, storage).await?;
+    }
+
+    trace!(
+        "imported journal (persistence_id={}, snapshot={}, messages={})",
+        persistence_id,
+        journal.snapshot.is_some(),
+        journal.messages.len()
+    );
+
+    Ok(())
+}
+
+/// Checks that `storage` contains exactly the snapshot and messages held by `journal`.
+pub async fn verify_journal(
+    journal: &ExportedJournal,
+    storage: &JournalStorageRef,
+) -> Result<(), MigrationErr> {
+    let stored = read_journal(storage, &journal.persistence_id).await?;
+    let verification_err = |reason: String| MigrationErr::Verification {
+        persistence_id: journal.persistence_id.clone(),
+        reason,
+    };
+
+    match (&journal.snapshot, &stored.snapshot) {
+        (None, None) => {}
+        (Some(expected), Some(actual)) => {
+            if !entries_eq(expected, actual) {
+                return Err(verification_err(format!(
+                    "snapshot mismatch (expected_sequence={}, actual_sequence={})",
+                    expected.sequence, actual.sequence
+                )));
+            }
+        }
+        (expected, actual) => {
+            return Err(verification_err(format!(
+                "snapshot mismatch (expected_snapshot={}, actual_snapshot={})",
+                expected.is_some(),
+                actual.is_some()
+            )));
+        }
+    }
+
+    if journal.messages.len() != stored.messages.len() {
+        return Err(verification_err(format!(
+            "message count mismatch (expected={}, actual={})",
+            journal.messages.len(),
+            stored.messages.len()
+        )));
+    }
+
+    for (expected, actual) in journal.messages.iter().zip(stored.messages.iter()) {
+        if !entries_eq(expected, actual) {
+            return Err(verification_err(format!(
+                "message mismatch (expected_sequence={}, actual_sequence={})",
+                expected.sequence, actual.sequence
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn entries_eq(a: &JournalEntry, b: &JournalEntry) -> bool {
+    a.sequence == b.sequence && a.payload_type == b.payload_type && a.bytes == b.bytes
+}
@@ -1,3 +1,4 @@
+pub mod migration;
 pub mod provider;
 pub mod snapshot;
 pub mod storage;
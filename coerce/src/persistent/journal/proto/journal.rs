@@ -183,11 +183,229 @@ impl ::protobuf::reflect::ProtobufValue for JournalEntry {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.persistent.journal.ExportedJournalEntry)
+pub struct ExportedJournalEntry {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.persistent.journal.ExportedJournalEntry.persistence_id)
+    pub persistence_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.persistent.journal.ExportedJournalEntry.kind)
+    pub kind: ::protobuf::EnumOrUnknown<JournalEntryKind>,
+    // @@protoc_insertion_point(field:coerce.persistent.journal.ExportedJournalEntry.entry)
+    pub entry: ::protobuf::MessageField<JournalEntry>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.persistent.journal.ExportedJournalEntry.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ExportedJournalEntry {
+    fn default() -> &'a ExportedJournalEntry {
+        <ExportedJournalEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportedJournalEntry {
+    pub fn new() -> ExportedJournalEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "persistence_id",
+            |m: &ExportedJournalEntry| { &m.persistence_id },
+            |m: &mut ExportedJournalEntry| { &mut m.persistence_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &ExportedJournalEntry| { &m.kind },
+            |m: &mut ExportedJournalEntry| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, JournalEntry>(
+            "entry",
+            |m: &ExportedJournalEntry| { &m.entry },
+            |m: &mut ExportedJournalEntry| { &mut m.entry },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ExportedJournalEntry>(
+            "ExportedJournalEntry",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ExportedJournalEntry {
+    const NAME: &'static str = "ExportedJournalEntry";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.persistence_id = is.read_string()?;
+                },
+                16 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.entry)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.persistence_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.persistence_id);
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(JournalEntryKind::MESSAGE) {
+            my_size += ::protobuf::rt::int32_size(2, self.kind.value());
+        }
+        if let Some(v) = self.entry.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.persistence_id.is_empty() {
+            os.write_string(1, &self.persistence_id)?;
+        }
+        if self.kind != ::protobuf::EnumOrUnknown::new(JournalEntryKind::MESSAGE) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if let Some(v) = self.entry.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ExportedJournalEntry {
+        ExportedJournalEntry::new()
+    }
+
+    fn clear(&mut self) {
+        self.persistence_id.clear();
+        self.kind = ::protobuf::EnumOrUnknown::new(JournalEntryKind::MESSAGE);
+        self.entry.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ExportedJournalEntry {
+        static instance: ExportedJournalEntry = ExportedJournalEntry {
+            persistence_id: ::std::string::String::new(),
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            entry: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ExportedJournalEntry {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ExportedJournalEntry").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ExportedJournalEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportedJournalEntry {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:coerce.persistent.journal.JournalEntryKind)
+pub enum JournalEntryKind {
+    // @@protoc_insertion_point(enum_value:coerce.persistent.journal.JournalEntryKind.MESSAGE)
+    MESSAGE = 0,
+    // @@protoc_insertion_point(enum_value:coerce.persistent.journal.JournalEntryKind.SNAPSHOT)
+    SNAPSHOT = 1,
+}
+
+impl ::protobuf::Enum for JournalEntryKind {
+    const NAME: &'static str = "JournalEntryKind";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JournalEntryKind> {
+        match value {
+            0 => ::std::option::Option::Some(JournalEntryKind::MESSAGE),
+            1 => ::std::option::Option::Some(JournalEntryKind::SNAPSHOT),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [JournalEntryKind] = &[
+        JournalEntryKind::MESSAGE,
+        JournalEntryKind::SNAPSHOT,
+    ];
+}
+
+impl ::protobuf::EnumFull for JournalEntryKind {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("JournalEntryKind").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for JournalEntryKind {
+    fn default() -> Self {
+        JournalEntryKind::MESSAGE
+    }
+}
+
+impl JournalEntryKind {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<JournalEntryKind>("JournalEntryKind")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x18persistent/journal.proto\x12\x19coerce.persistent.journal\"c\n\x0c\
     JournalEntry\x12\x1a\n\x08sequence\x18\x01\x20\x01(\x03R\x08sequence\x12\
     !\n\x0cpayload_type\x18\x02\x20\x01(\tR\x0bpayloadType\x12\x14\n\x05byte\
-    s\x18\x03\x20\x01(\x0cR\x05bytesb\x06proto3\
+    s\x18\x03\x20\x01(\x0cR\x05bytes\"\xbd\x01\n\x14ExportedJournalEntry\x12\
+    %\n\x0epersistence_id\x18\x01\x20\x01(\tR\rpersistenceId\x12?\n\x04kind\
+    \x18\x02\x20\x01(\x0e2+.coerce.persistent.journal.JournalEntryKindR\x04k\
+    ind\x12=\n\x05entry\x18\x03\x20\x01(\x0b2'.coerce.persistent.journal.Jou\
+    rnalEntryR\x05entry*-\n\x10JournalEntryKind\x12\x0b\n\x07MESSAGE\x10\0\
+    \x12\x0c\n\x08SNAPSHOT\x10\x01b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -205,9 +423,11 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(1);
+            let mut messages = ::std::vec::Vec::with_capacity(2);
             messages.push(JournalEntry::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            messages.push(ExportedJournalEntry::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(JournalEntryKind::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -297,5 +297,12 @@ pub mod inmemory {
             store.remove(persistence_id);
             Ok(())
         }
+
+        async fn read_persistence_ids(&self) -> anyhow::Result<Vec<String>> {
+            let store = self.store.read();
+            let mut persistence_ids: Vec<String> = store.keys().cloned().collect();
+            persistence_ids.sort();
+            Ok(persistence_ids)
+        }
     }
 }
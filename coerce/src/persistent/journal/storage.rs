@@ -51,6 +51,14 @@ pub trait JournalStorage: Send + Sync {
     async fn delete_messages_to(&self, persistence_id: &str, to_sequence: i64) -> Result<()>;
 
     async fn delete_all(&self, persistence_id: &str) -> Result<()>;
+
+    /// Returns every persistence ID that has at least one snapshot or message stored,
+    /// used by [`migration`](crate::persistent::journal::migration) to enumerate journals.
+    async fn read_persistence_ids(&self) -> Result<Vec<String>> {
+        Err(anyhow::anyhow!(
+            "journal storage does not support listing persistence ids"
+        ))
+    }
 }
 
 pub type JournalStorageRef = Arc<dyn JournalStorage>;
@@ -2,24 +2,40 @@ use crate::actor::context::ActorContext;
 use crate::actor::message::Handler;
 use crate::actor::scheduler::ActorType;
 use crate::actor::system::ActorSystem;
-use crate::actor::{Actor, LocalActorRef};
-use crate::remote::actor::message::{ClientConnected, ClientWrite, NewClient};
+use crate::actor::{Actor, IntoActorId, LocalActorRef};
+use crate::remote::actor::message::{
+    ClientConnected, ClientSessionClosed, ClientSessionConnected, ClientWrite, NewClient, SetRemote,
+};
+use crate::remote::net::client::lane::{ConnectionLane, LaneClients};
 use crate::remote::net::client::send::Write;
 use crate::remote::net::client::RemoteClient;
-use crate::remote::system::NodeId;
+use crate::remote::net::message::{ClientEvent, SessionEvent};
+use crate::remote::net::server::session::store::SessionWrite;
+use crate::remote::net::server::session::RemoteSession;
+use crate::remote::system::{NodeId, RemoteActorSystem};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use uuid::Uuid;
 
 pub struct RemoteClientRegistry {
     node_addr_registry: HashMap<String, LocalActorRef<RemoteClient>>,
-    node_id_registry: HashMap<NodeId, LocalActorRef<RemoteClient>>,
+    node_id_registry: HashMap<NodeId, LaneClients>,
+    client_sessions: HashMap<NodeId, ClientSession>,
+    remote: Option<RemoteActorSystem>,
+}
+
+struct ClientSession {
+    session_id: Uuid,
+    session: LocalActorRef<RemoteSession>,
 }
 
 #[async_trait]
 impl Actor for RemoteClientRegistry {
     async fn stopped(&mut self, _ctx: &mut ActorContext) {
-        for client in &self.node_id_registry {
-            let _ = client.1.stop().await;
+        for clients in self.node_id_registry.values() {
+            for client in clients.clients() {
+                let _ = client.stop().await;
+            }
         }
     }
 }
@@ -31,6 +47,8 @@ impl RemoteClientRegistry {
             RemoteClientRegistry {
                 node_addr_registry: HashMap::new(),
                 node_id_registry: HashMap::new(),
+                client_sessions: HashMap::new(),
+                remote: None,
             },
             ActorType::Tracked,
         )
@@ -39,6 +57,13 @@ impl RemoteClientRegistry {
     }
 }
 
+#[async_trait]
+impl Handler<SetRemote> for RemoteClientRegistry {
+    async fn handle(&mut self, message: SetRemote, _ctx: &mut ActorContext) {
+        self.remote = Some(message.0);
+    }
+}
+
 #[async_trait]
 impl Handler<NewClient> for RemoteClientRegistry {
     async fn handle(
@@ -73,8 +98,58 @@ impl Handler<NewClient> for RemoteClientRegistry {
 #[async_trait]
 impl Handler<ClientConnected> for RemoteClientRegistry {
     async fn handle(&mut self, message: ClientConnected, _ctx: &mut ActorContext) {
-        self.node_id_registry
-            .insert(message.remote_node_id, message.client_actor_ref);
+        let clients = match self.node_id_registry.entry(message.remote_node_id) {
+            Entry::Occupied(mut entry) => {
+                if entry.get().control() == &message.client_actor_ref {
+                    // reconnected, the lane clients reconnect independently
+                    return;
+                }
+
+                let previous = entry.insert(LaneClients::new(message.client_actor_ref));
+                for client in previous.clients().skip(1) {
+                    let _ = client.notify_stop();
+                }
+
+                entry.into_mut()
+            }
+            Entry::Vacant(entry) => entry.insert(LaneClients::new(message.client_actor_ref)),
+        };
+
+        let remote = match &self.remote {
+            Some(remote) => remote,
+            None => return,
+        };
+
+        let lanes_config = &remote.config().network_config().lanes;
+        if !lanes_config.enabled || clients.has_lanes() {
+            return;
+        }
+
+        for lane in [ConnectionLane::Request, ConnectionLane::Streaming] {
+            let mut lane_clients = vec![];
+            for index in 0..lane.connections(lanes_config) {
+                lane_clients.push(
+                    RemoteClient::new_lane(
+                        message.addr.clone(),
+                        remote.clone(),
+                        message.client_type,
+                        lane,
+                        index,
+                    )
+                    .await,
+                );
+            }
+
+            debug!(
+                "opened {} {} connection(s) to node_id={} (addr={})",
+                lane_clients.len(),
+                &lane,
+                message.remote_node_id,
+                &message.addr
+            );
+
+            clients.set_lane(lane, lane_clients);
+        }
     }
 }
 
@@ -84,17 +159,69 @@ impl Handler<ClientWrite> for RemoteClientRegistry {
         let node_id = message.0;
         let message = message.1;
 
-        // TODO: we could open multiple clients per node and use some routing mechanism
-        //       to potentially improve throughput, whilst still maintainingnStore
-        //       message ordering
-
-        if let Some(client) = self.node_id_registry.get(&node_id) {
+        if let Some(clients) = self.node_id_registry.get(&node_id) {
             trace!("emitting message ({:?}) to node_id={}", &message, &node_id);
-            client.notify(Write(message)).expect("send client msg");
+            clients
+                .route(&message)
+                .notify(Write(message))
+                .expect("send client msg");
             trace!("written data to client");
+        } else if let Some(client_session) = self.client_sessions.get(&node_id) {
+            let event = match message {
+                SessionEvent::Result(result) => ClientEvent::Result(result),
+                SessionEvent::Err(err) => ClientEvent::Err(err),
+                message => {
+                    debug!("cluster client (node_id={}) only receives results, ignoring message ({:?})", &node_id, &message);
+                    self.on_write_failed(node_id, &message);
+                    return;
+                }
+            };
+
+            trace!("writing result to cluster client (node_id={})", &node_id);
+            let _ = client_session
+                .session
+                .notify(SessionWrite(client_session.session_id, event));
         } else {
             // TODO: should we buffer the message incase the client will eventually exist
             warn!("attempted to write message to node_id={} but no client was registered (message={:?})", &node_id, &message);
+            self.on_write_failed(node_id, &message);
+        }
+    }
+}
+
+impl RemoteClientRegistry {
+    fn on_write_failed(&self, node_id: NodeId, message: &SessionEvent) {
+        if let (Some(remote), SessionEvent::WatchActor(watch)) = (&self.remote, message) {
+            if !watch.unwatch {
+                remote.on_watched_node_unreachable(node_id, watch.actor_id.clone().into_actor_id());
+            }
         }
     }
 }
+
+#[async_trait]
+impl Handler<ClientSessionConnected> for RemoteClientRegistry {
+    async fn handle(&mut self, message: ClientSessionConnected, _ctx: &mut ActorContext) {
+        debug!(
+            "cluster client connected (node_id={}, session_id={})",
+            message.node_id, &message.session_id
+        );
+
+        self.client_sessions.insert(
+            message.node_id,
+            ClientSession {
+                session_id: message.session_id,
+                session: message.session,
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<ClientSessionClosed> for RemoteClientRegistry {
+    async fn handle(&mut self, message: ClientSessionClosed, _ctx: &mut ActorContext) {
+        let session_id = message.0;
+        self.client_sessions
+            .retain(|_, client_session| client_session.session_id != session_id);
+    }
+}
@@ -1,13 +1,16 @@
 use crate::remote::actor::RemoteRequest;
+use crate::remote::cluster::events::MemberEvent;
 use crate::remote::cluster::node::{RemoteNode, RemoteNodeState};
 use crate::remote::system::{NodeId, RemoteActorSystem};
 
 use crate::actor::message::Message;
 use crate::remote::net::client::{ClientType, RemoteClient};
 use crate::remote::net::message::SessionEvent;
+use crate::remote::net::server::session::RemoteSession;
 
 use crate::actor::{ActorId, LocalActorRef};
 
+use tokio::sync::broadcast;
 use uuid::Uuid;
 
 pub struct SetRemote(pub RemoteActorSystem);
@@ -47,6 +50,7 @@ impl Message for NewClient {
 pub struct ClientConnected {
     pub addr: String,
     pub remote_node_id: NodeId,
+    pub client_type: ClientType,
     pub client_actor_ref: LocalActorRef<RemoteClient>,
 }
 
@@ -78,12 +82,52 @@ impl Message for NodeTerminated {
     type Result = ();
 }
 
+/// A node has announced that it's gracefully leaving the cluster.
+pub struct NodeLeaving(pub NodeId);
+
+impl Message for NodeLeaving {
+    type Result = ();
+}
+
+/// Subscribes to the node's membership events, resulting in a snapshot of the current members,
+/// and a receiver of every membership event published after the snapshot was taken.
+pub struct SubscribeMembership;
+
+impl Message for SubscribeMembership {
+    type Result = (MemberEvent, broadcast::Receiver<MemberEvent>);
+}
+
+/// Removes a node that has been downed from the cluster's membership.
+pub struct RemoveNode(pub NodeId);
+
+impl Message for RemoveNode {
+    type Result = ();
+}
+
 pub struct ClientWrite(pub NodeId, pub SessionEvent);
 
 impl Message for ClientWrite {
     type Result = ();
 }
 
+/// A cluster client (a node which doesn't join the cluster) has handshaken via an incoming
+/// session, results destined for the client's node are written back via the session.
+pub struct ClientSessionConnected {
+    pub node_id: NodeId,
+    pub session_id: Uuid,
+    pub session: LocalActorRef<RemoteSession>,
+}
+
+impl Message for ClientSessionConnected {
+    type Result = ();
+}
+
+pub struct ClientSessionClosed(pub Uuid);
+
+impl Message for ClientSessionClosed {
+    type Result = ();
+}
+
 #[derive(Debug)]
 pub struct RegisterActor {
     pub actor_id: ActorId,
@@ -4,18 +4,23 @@ use crate::actor::scheduler::ActorType;
 use crate::actor::system::ActorSystem;
 use crate::actor::{Actor, ActorId, LocalActorRef};
 use crate::remote::actor::message::{
-    GetActorNode, GetNodes, NodeTerminated, RegisterActor, RegisterNode, SetRemote, UpdateNodes,
+    GetActorNode, GetNodes, NodeLeaving, NodeTerminated, RegisterActor, RegisterNode, RemoveNode,
+    SetRemote, SubscribeMembership, UpdateNodes,
 };
 use crate::remote::actor::RemoteResponse;
-use crate::remote::cluster::node::{RemoteNode, RemoteNodeState, RemoteNodeStore};
+use crate::remote::cluster::events::{MemberEvent, MemberEvents};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState, RemoteNodeStore};
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network::{ActorAddress, FindActorEvent};
 use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
 use crate::remote::stream::system::{SystemEvent, SystemTopic};
 use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::tracing::extract_trace_identifier;
 use protobuf::well_known_types::wrappers::UInt64Value;
 use protobuf::Message;
 use std::collections::HashMap;
+use tokio::sync::broadcast;
+use tracing::Span;
 use uuid::Uuid;
 
 pub struct RemoteRegistry {
@@ -23,6 +28,7 @@ pub struct RemoteRegistry {
     actors: HashMap<ActorId, NodeId>,
     system: Option<RemoteActorSystem>,
     system_event_subscription: Option<Subscription>,
+    member_events: MemberEvents,
 }
 
 impl RemoteRegistry {
@@ -34,6 +40,7 @@ impl RemoteRegistry {
                 nodes: RemoteNodeStore::new(vec![]),
                 system: None,
                 system_event_subscription: None,
+                member_events: MemberEvents::new(),
             },
             ActorType::Tracked,
         )
@@ -79,14 +86,27 @@ impl Handler<RegisterNode> for RemoteRegistry {
 
 impl RemoteRegistry {
     pub fn register_node(&mut self, node: RemoteNode) {
+        let node_id = node.id;
         self.nodes.add(node);
+        self.publish_member_events(node_id);
+    }
+
+    fn publish_member_events(&mut self, node_id: NodeId) {
+        if let Some(node) = self.nodes.get(&node_id) {
+            self.member_events.update(node);
+        }
     }
 }
 
 #[async_trait]
 impl Handler<UpdateNodes> for RemoteRegistry {
     async fn handle(&mut self, message: UpdateNodes, _ctx: &mut ActorContext) {
+        let node_ids: Vec<NodeId> = message.0.iter().map(|n| n.id).collect();
         self.nodes.update_nodes(message.0);
+
+        for node_id in node_ids {
+            self.publish_member_events(node_id);
+        }
     }
 }
 
@@ -94,9 +114,48 @@ impl Handler<UpdateNodes> for RemoteRegistry {
 impl Handler<NodeTerminated> for RemoteRegistry {
     async fn handle(&mut self, message: NodeTerminated, _ctx: &mut ActorContext) {
         self.nodes.node_terminated(message.0);
+        self.publish_member_events(message.0);
         debug!("node_id={} marked as terminated", message.0);
+    }
+}
+
+#[async_trait]
+impl Handler<NodeLeaving> for RemoteRegistry {
+    async fn handle(&mut self, message: NodeLeaving, _ctx: &mut ActorContext) {
+        self.nodes.node_leaving(message.0);
+        self.publish_member_events(message.0);
+        debug!("node_id={} marked as leaving", message.0);
+    }
+}
 
-        // TODO: should this be published to clusterevent subscribers?
+#[async_trait]
+impl Handler<RemoveNode> for RemoteRegistry {
+    async fn handle(&mut self, message: RemoveNode, _ctx: &mut ActorContext) {
+        if let Some(node) = self.nodes.remove(&message.0) {
+            self.member_events.removed(node);
+            debug!("node_id={} removed", message.0);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<SubscribeMembership> for RemoteRegistry {
+    async fn handle(
+        &mut self,
+        _message: SubscribeMembership,
+        _ctx: &mut ActorContext,
+    ) -> (MemberEvent, broadcast::Receiver<MemberEvent>) {
+        let members = self
+            .nodes
+            .get_all()
+            .into_iter()
+            .filter(|n| n.status != NodeStatus::Terminated)
+            .collect();
+
+        (
+            MemberEvent::CurrentMembers(members),
+            self.member_events.subscribe(),
+        )
     }
 }
 
@@ -140,6 +199,7 @@ impl Handler<GetActorNode> for RemoteRegistry {
                 current_system,
                 assigned_registry_node
             );
+            let trace_id = extract_trace_identifier(&Span::current());
             tokio::spawn(async move {
                 // let span = tracing::trace_span!("RemoteRegistry::GetActorNode::Remote");
                 // let _enter = span.enter();
@@ -152,7 +212,6 @@ impl Handler<GetActorNode> for RemoteRegistry {
                 system.push_request(message_id, res_tx);
 
                 trace!("sending actor lookup request to={}", assigned_registry_node);
-                let trace_id = String::new(); //extract_trace_identifier(&span);
                 system
                     .notify_node(
                         assigned_registry_node,
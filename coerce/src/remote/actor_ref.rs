@@ -5,12 +5,14 @@ use crate::remote::actor::RemoteResponse;
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network::MessageRequest;
 use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::tracing::extract_trace_identifier;
 
 use std::fmt::{Debug, Formatter};
 
 use std::marker::PhantomData;
 
 use tokio::sync::oneshot;
+use tracing::Span;
 
 use uuid::Uuid;
 
@@ -62,11 +64,13 @@ where
 
         let id = Uuid::new_v4();
 
-        let request = self.create_request(msg, String::new(), id, false);
+        let trace_id = extract_trace_identifier(&Span::current());
+        let request = self.create_request(msg, trace_id, id, false);
 
         // TODO: `notify` could propagate errors?
 
         match request {
+            Some(request) if !self.node_supports(&request) => Err(self.not_supported::<Msg>()),
             Some(request) => {
                 self.system.notify_node(self.node_id, request).await;
                 Ok(())
@@ -88,7 +92,17 @@ where
         // let _enter = span.enter();
 
         let id = Uuid::new_v4();
-        let event = self.create_request(msg, String::new(), id, true);
+        let trace_id = extract_trace_identifier(&Span::current());
+        let event = self.create_request(msg, trace_id, id, true);
+        if let Some(event) = &event {
+            if !self.node_supports(event) {
+                warn!(
+                    "node (node_id={}) has no handler registered for actor_type={}, message_type={}",
+                    self.node_id, &actor_type, message_type
+                );
+                return Err(self.not_supported::<Msg>());
+            }
+        }
 
         let (res_tx, res_rx) = oneshot::channel();
         self.system.push_request(id, res_tx);
@@ -118,15 +132,30 @@ where
                     "no handler registered actor_type={}, message_type={}",
                     &actor_type, message_type
                 );
-                Err(ActorRefErr::NotSupported {
-                    actor_id: self.id.clone(),
-                    message_type: message_type.to_string(),
-                    actor_type: actor_type.to_string(),
-                })
+                Err(self.not_supported::<Msg>())
             }
         }
     }
 
+    /// Whether the target node is known to have a handler registered for the request, based on
+    /// the capabilities exchanged when the nodes were connected.
+    fn node_supports(&self, event: &SessionEvent) -> bool {
+        match event {
+            SessionEvent::NotifyActor(request) => self
+                .system
+                .node_supports_handler(self.node_id, &request.handler_type),
+            _ => true,
+        }
+    }
+
+    fn not_supported<Msg: Message>(&self) -> ActorRefErr {
+        ActorRefErr::NotSupported {
+            actor_id: self.id.clone(),
+            message_type: Msg::type_name().to_string(),
+            actor_type: A::type_name().to_string(),
+        }
+    }
+
     fn create_request<Msg: Message>(
         &self,
         msg: Envelope<Msg>,
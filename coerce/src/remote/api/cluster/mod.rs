@@ -51,6 +51,8 @@ pub enum NodeStatus {
     Joining,
     Healthy,
     Unhealthy,
+    Suspect,
+    Leaving,
     Terminated,
 }
 
@@ -60,6 +62,8 @@ impl From<crate::remote::cluster::node::NodeStatus> for NodeStatus {
             crate::remote::cluster::node::NodeStatus::Joining => Self::Joining,
             crate::remote::cluster::node::NodeStatus::Healthy => Self::Healthy,
             crate::remote::cluster::node::NodeStatus::Unhealthy => Self::Unhealthy,
+            crate::remote::cluster::node::NodeStatus::Suspect => Self::Suspect,
+            crate::remote::cluster::node::NodeStatus::Leaving => Self::Leaving,
             crate::remote::cluster::node::NodeStatus::Terminated => Self::Terminated,
         }
     }
@@ -1,42 +1,55 @@
+use crate::actor::IntoActor;
+use crate::remote::cluster::client::contact::{ClusterContact, RefreshContact};
 use crate::remote::cluster::client::RemoteClusterClient;
 use crate::remote::system::RemoteActorSystem;
+use std::time::Duration;
 
 pub struct ClusterClientBuilder {
     system: RemoteActorSystem,
-    seed_addr: Option<String>,
+    seed_addrs: Vec<String>,
+    refresh_interval: Duration,
 }
 
 impl ClusterClientBuilder {
     pub fn new(system: RemoteActorSystem) -> ClusterClientBuilder {
         ClusterClientBuilder {
             system,
-            seed_addr: None,
+            seed_addrs: vec![],
+            refresh_interval: Duration::from_secs(5),
         }
     }
 
+    /// Adds a seed node, seeds are contacted in the order they were added.
     pub fn with_seed_addr<T: ToString>(mut self, seed_addr: T) -> Self {
-        self.seed_addr = Some(seed_addr.to_string());
+        self.seed_addrs.push(seed_addr.to_string());
+
+        self
+    }
+
+    /// How often the cluster's membership is refreshed from the contact node.
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
 
         self
     }
 
     pub async fn start(self) -> RemoteClusterClient {
-        // let seed_addr = self.seed_addr.expect("no seed addr");
-        // let system = self.system.clone();
-        // let client = RemoteClient::connect(seed_addr.clone(), None, system, None, Client)
-        //     .await
-        //     .expect("failed to connect to seed server");
-        //
-        // self.system
-        //     .register_node(RemoteNode::new(
-        //         client.node_id,
-        //         seed_addr,
-        //         client.node_tag.clone(),
-        //         Some(Utc::now()),
-        //     ))
-        //     .await;
-
-        // self.system.register_client(client.node_id, client).await;
-        RemoteClusterClient::new(self.system)
+        if self.seed_addrs.is_empty() {
+            panic!("no seed addr");
+        }
+
+        self.system.set_cluster_client();
+
+        let contact =
+            ClusterContact::new(self.system.clone(), self.seed_addrs, self.refresh_interval)
+                .into_actor(Some("cluster-client-contact"), self.system.actor_system())
+                .await
+                .expect("create ClusterContact actor");
+
+        if contact.send(RefreshContact).await.ok().flatten().is_none() {
+            warn!("unable to contact any seed node, retrying in the background");
+        }
+
+        RemoteClusterClient::new(self.system, contact)
     }
 }
@@ -1,3 +1,4 @@
+use crate::remote::cluster::discovery::provider::{DiscoveryProvider, ProviderDiscovery};
 use crate::remote::cluster::discovery::{Discover, Seed};
 use crate::remote::cluster::node::RemoteNode;
 use crate::remote::net::server::{RemoteServer, RemoteServerConfig};
@@ -5,15 +6,23 @@ use crate::remote::system::RemoteActorSystem;
 use std::env;
 
 use std::time::Duration;
-use tokio::net::lookup_host;
 use tokio::sync::oneshot;
 use tokio::time::sleep;
 
+#[cfg(feature = "remote-tls")]
+use crate::remote::net::security::tls::TlsConfig;
+
+#[cfg(feature = "remote-dns")]
+use crate::remote::cluster::discovery::dns::DnsClusterSeed;
+
 pub struct ClusterWorkerBuilder {
     server_listen_addr: String,
     server_external_addr: Option<String>,
     seed_addr: Option<String>,
     system: RemoteActorSystem,
+    discovery_providers: Vec<Box<dyn DiscoveryProvider>>,
+    #[cfg(feature = "remote-tls")]
+    tls: Option<TlsConfig>,
 }
 
 impl ClusterWorkerBuilder {
@@ -26,6 +35,9 @@ impl ClusterWorkerBuilder {
             server_external_addr,
             system,
             seed_addr,
+            discovery_providers: vec![],
+            #[cfg(feature = "remote-tls")]
+            tls: None,
         }
     }
 
@@ -35,11 +47,38 @@ impl ClusterWorkerBuilder {
         self
     }
 
+    /// Continuously discovers nodes via the provider, see [`provider`]. Multiple providers can be
+    /// registered.
+    ///
+    /// [`provider`]: crate::remote::cluster::discovery::provider
+    pub fn with_discovery(mut self, provider: impl DiscoveryProvider) -> Self {
+        self.discovery_providers.push(Box::new(provider));
+        self
+    }
+
+    /// Discovers seed nodes by periodically resolving DNS records, see [`dns`].
+    ///
+    /// [`dns`]: crate::remote::cluster::discovery::dns
+    #[cfg(feature = "remote-dns")]
+    pub fn with_dns_seed(self, dns_seed: DnsClusterSeed) -> Self {
+        self.with_discovery(dns_seed)
+    }
+
     pub fn external_addr<T: ToString>(mut self, server_external_addr: T) -> Self {
         self.server_external_addr = Some(server_external_addr.to_string());
         self
     }
 
+    /// Overrides the TLS configuration used by the server, by default the TLS configuration
+    /// provided via [`RemoteActorSystemBuilder::with_tls`] is used.
+    ///
+    /// [`RemoteActorSystemBuilder::with_tls`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_tls
+    #[cfg(feature = "remote-tls")]
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
     pub fn listen_addr<T: ToString>(mut self, listen_addr: T) -> Self {
         self.server_listen_addr = listen_addr.to_string();
 
@@ -57,6 +96,8 @@ impl ClusterWorkerBuilder {
                 self.system.node_tag().to_string(),
                 Some(started_at),
                 self.system.config().get_attributes().clone(),
+                self.system.config().get_roles().clone(),
+                self.system.config().get_datacenter().clone(),
             ))
             .await;
 
@@ -79,6 +120,9 @@ impl ClusterWorkerBuilder {
             override_incoming_node_addr,
         );
 
+        #[cfg(feature = "remote-tls")]
+        let config = config.with_tls(self.tls.take());
+
         server
             .start(config, system)
             .await
@@ -90,6 +134,13 @@ impl ClusterWorkerBuilder {
             }
         }
 
+        for provider in self.discovery_providers.drain(..) {
+            let name = provider.name().to_string();
+            if let Err(e) = ProviderDiscovery::start(provider, &self.system).await {
+                error!("failed to start discovery (provider={}), e={}", name, e);
+            }
+        }
+
         server
     }
 
@@ -114,7 +165,7 @@ async fn discover_peers(seed_addr: String, system: &RemoteActorSystem) {
             return;
         }
 
-        if seed_addr_resolves(&seed_addr).await {
+        if seed_addr_resolves(&seed_addr, system).await {
             break;
         }
 
@@ -143,7 +194,6 @@ async fn discover_peers(seed_addr: String, system: &RemoteActorSystem) {
     info!("discover_peers - discovered peers successfully");
 }
 
-async fn seed_addr_resolves(seed_addr: &str) -> bool {
-    let resolves = lookup_host(seed_addr).await;
-    resolves.is_ok()
+async fn seed_addr_resolves(seed_addr: &str, system: &RemoteActorSystem) -> bool {
+    system.config().transport().resolves(seed_addr).await
 }
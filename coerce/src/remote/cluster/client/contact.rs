@@ -0,0 +1,209 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::{Timer, TimerTick};
+use crate::actor::Actor;
+use crate::remote::cluster::discovery::{Discover, Seed};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+const CLUSTER_NODES_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Keeps a cluster client connected to the cluster, via a single contact node.
+///
+/// Membership is periodically refreshed from the contact node, so the client is able to
+/// locate actors on nodes that joined after the client started. If the contact node becomes
+/// unhealthy, the client fails over to another healthy node, or the next reachable seed.
+pub struct ClusterContact {
+    system: RemoteActorSystem,
+    seed_addrs: Vec<String>,
+    refresh_interval: Duration,
+    contact_node_id: Option<NodeId>,
+    refresh_timer: Option<Timer>,
+}
+
+impl ClusterContact {
+    pub fn new(
+        system: RemoteActorSystem,
+        seed_addrs: Vec<String>,
+        refresh_interval: Duration,
+    ) -> ClusterContact {
+        ClusterContact {
+            system,
+            seed_addrs,
+            refresh_interval,
+            contact_node_id: None,
+            refresh_timer: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for ClusterContact {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.refresh_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.refresh_interval,
+            RefreshContact,
+        ));
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(refresh_timer) = self.refresh_timer.take() {
+            refresh_timer.stop();
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RefreshContact;
+
+pub struct GetContactNode;
+
+impl Message for RefreshContact {
+    type Result = Option<NodeId>;
+}
+
+impl TimerTick for RefreshContact {}
+
+impl Message for GetContactNode {
+    type Result = Option<NodeId>;
+}
+
+#[async_trait]
+impl Handler<RefreshContact> for ClusterContact {
+    async fn handle(
+        &mut self,
+        _message: RefreshContact,
+        _ctx: &mut ActorContext,
+    ) -> Option<NodeId> {
+        if !self.is_contact_healthy().await {
+            if let Some(contact_node_id) = self.contact_node_id.take() {
+                warn!(
+                    "lost contact with node_id={}, failing over to another node",
+                    contact_node_id
+                );
+            }
+
+            self.contact_node_id = self.find_contact().await;
+            match self.contact_node_id {
+                Some(contact_node_id) => {
+                    info!("cluster client contact node_id={}", contact_node_id);
+                }
+                None => {
+                    warn!(
+                        "unable to contact the cluster, seeds: {:?}",
+                        &self.seed_addrs
+                    );
+                    return None;
+                }
+            }
+        }
+
+        let contact_node_id = self.contact_node_id?;
+        let cluster_nodes = tokio::time::timeout(
+            CLUSTER_NODES_TIMEOUT,
+            self.system.cluster_nodes(contact_node_id),
+        )
+        .await;
+
+        match cluster_nodes {
+            Ok(Ok(nodes)) => self.discover(nodes).await,
+            Ok(Err(e)) => {
+                warn!(
+                    "unable to refresh cluster nodes from node_id={} - {}",
+                    contact_node_id, e
+                );
+            }
+            Err(_) => {
+                warn!(
+                    "timed out refreshing cluster nodes from node_id={}",
+                    contact_node_id
+                );
+            }
+        }
+
+        Some(contact_node_id)
+    }
+}
+
+#[async_trait]
+impl Handler<GetContactNode> for ClusterContact {
+    async fn handle(
+        &mut self,
+        _message: GetContactNode,
+        _ctx: &mut ActorContext,
+    ) -> Option<NodeId> {
+        self.contact_node_id
+    }
+}
+
+impl ClusterContact {
+    async fn is_contact_healthy(&self) -> bool {
+        let contact_node_id = match self.contact_node_id {
+            Some(contact_node_id) => contact_node_id,
+            None => return false,
+        };
+
+        self.system
+            .get_nodes()
+            .await
+            .iter()
+            .any(|n| n.id == contact_node_id && is_available(n.status))
+    }
+
+    async fn find_contact(&self) -> Option<NodeId> {
+        let healthy_node = self
+            .system
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| is_available(n.status))
+            .min_by_key(|n| (!n.status.is_healthy(), n.id))
+            .map(|n| n.id);
+
+        if healthy_node.is_some() {
+            return healthy_node;
+        }
+
+        for seed_addr in &self.seed_addrs {
+            let client = match self.system.get_remote_client(seed_addr.clone()).await {
+                Some(client) => client,
+                None => continue,
+            };
+
+            match client.identify().await {
+                Ok(Some(identity)) => {
+                    let contact_node_id = identity.node.id;
+                    let mut nodes = identity.peers;
+                    nodes.push(identity.node);
+
+                    self.discover(nodes).await;
+                    return Some(contact_node_id);
+                }
+                _ => {
+                    debug!("unable to identify seed (addr={})", seed_addr);
+                }
+            }
+        }
+
+        None
+    }
+
+    async fn discover(&self, nodes: Vec<RemoteNode>) {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.system.node_discovery().notify(Discover {
+            seed: Seed::Nodes(nodes),
+            on_discovery_complete: Some(tx),
+        });
+
+        let _ = rx.await;
+    }
+}
+
+/// Nodes that have only just been discovered are yet to receive a heartbeat, but are still
+/// able to act as the client's contact.
+fn is_available(status: NodeStatus) -> bool {
+    matches!(status, NodeStatus::Joining | NodeStatus::Healthy)
+}
@@ -1,14 +1,47 @@
-use crate::remote::system::RemoteActorSystem;
+//! Cluster client, which allows a process to communicate with actors in a cluster
+//! without joining the cluster as a member.
+//!
+//! Clients connect to one of the configured seed nodes, and from there discover the rest of the
+//! cluster. Clients are never assigned actors or shards, and results of requests made by the client
+//! are written back to the client via the session it opened with each node.
+
+use crate::actor::{Actor, ActorId, ActorRef, LocalActorRef};
+use crate::remote::cluster::client::contact::{ClusterContact, GetContactNode, RefreshContact};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+
+pub mod contact;
 
 #[derive(Clone)]
 pub struct RemoteClusterClient {
     system: RemoteActorSystem,
+    contact: LocalActorRef<ClusterContact>,
 }
 
 impl RemoteClusterClient {
-    pub fn new(system: RemoteActorSystem) -> RemoteClusterClient {
-        RemoteClusterClient { system }
+    pub fn new(
+        system: RemoteActorSystem,
+        contact: LocalActorRef<ClusterContact>,
+    ) -> RemoteClusterClient {
+        RemoteClusterClient { system, contact }
     }
-}
 
-impl RemoteClusterClient {}
+    pub fn system(&self) -> &RemoteActorSystem {
+        &self.system
+    }
+
+    /// The node the client is currently using to keep track of the cluster's membership.
+    pub async fn contact_node(&self) -> Option<NodeId> {
+        self.contact.send(GetContactNode).await.ok().flatten()
+    }
+
+    /// Refreshes the cluster's membership, failing over to another node if the
+    /// current contact node is no longer healthy.
+    pub async fn refresh(&self) -> Option<NodeId> {
+        self.contact.send(RefreshContact).await.ok().flatten()
+    }
+
+    /// Locates an actor within the cluster.
+    pub async fn actor_ref<A: Actor>(&self, actor_id: ActorId) -> Option<ActorRef<A>> {
+        self.system.actor_ref(actor_id).await
+    }
+}
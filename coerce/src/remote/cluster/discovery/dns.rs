@@ -1,57 +1,197 @@
-// use crate::remote::cluster::discovery::{ClusterSeed, ClusterSeedErr, DiscoveredWorker};
-//
-// use std::net::{IpAddr, SocketAddr};
-//
-// use std::str::FromStr;
-// use trust_dns_client::client::{AsyncClient, ClientHandle};
-//
-// use trust_dns_client::rr::{DNSClass, Name, RecordType};
-// use trust_dns_client::udp::UdpClientStream;
-// use trust_dns_proto::udp::UdpResponse;
-//
-// use tokio::net::UdpSocket;
-//
-// pub struct DnsClusterSeed {
-//     client: AsyncClient<UdpResponse>,
-//     seed_host: String,
-// }
-//
-// impl DnsClusterSeed {
-//     pub async fn new(upstream: SocketAddr, seed_host: String) -> DnsClusterSeed {
-//         let stream = UdpClientStream::<UdpSocket>::new(upstream);
-//         let (client, bg) = AsyncClient::connect(stream).await.unwrap();
-//
-//         tokio::spawn(bg);
-//
-//         DnsClusterSeed { client, seed_host }
-//     }
-//
-//     pub async fn all_a_records(&mut self, host: &str) -> Result<Vec<IpAddr>, ClusterSeedErr> {
-//         match self
-//             .client
-//             .query(Name::from_str(host).unwrap(), DNSClass::IN, RecordType::A)
-//             .await
-//         {
-//             Ok(res) => Ok(res
-//                 .answers()
-//                 .into_iter()
-//                 .filter_map(|a| a.rdata().to_ip_addr())
-//                 .collect()),
-//
-//             Err(e) => Err(ClusterSeedErr::Err(format!("{:?}", e))),
-//         }
-//     }
-// }
-//
-// #[async_trait]
-// impl ClusterSeed for DnsClusterSeed {
-//     async fn initial_workers(&mut self) -> Result<Vec<DiscoveredWorker>, ClusterSeedErr> {
-//         let seed_host = self.seed_host.clone();
-//         let records = self.all_a_records(&seed_host).await?;
-//
-//         Ok(records
-//             .into_iter()
-//             .map(DiscoveredWorker::from_host)
-//             .collect())
-//     }
-// }
+//! DNS-based seed discovery, typically used with a Kubernetes headless service.
+//!
+//! [`DnsClusterSeed`] is a [`DiscoveryProvider`] which periodically resolves the SRV or A records
+//! of a service name, see the [`provider`] module for how the resolved addresses are discovered.
+//!
+//! [`provider`]: crate::remote::cluster::discovery::provider
+
+use crate::remote::cluster::discovery::provider::{
+    DiscoveryErr, DiscoveryProvider, DEFAULT_REFRESH_INTERVAL,
+};
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use trust_dns_resolver::error::{ResolveError, ResolveErrorKind};
+use trust_dns_resolver::TokioAsyncResolver;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SrvRecord {
+    pub target: String,
+    pub port: u16,
+}
+
+#[derive(Debug)]
+pub enum DnsResolveErr {
+    Err(String),
+}
+
+/// Resolves the records used to discover seed nodes. [`SystemDnsResolver`] is used by default,
+/// tests can provide a stub implementation via [`DnsClusterSeed::with_resolver`].
+#[async_trait]
+pub trait DnsResolver: 'static + Send + Sync {
+    async fn lookup_srv(&self, name: &str) -> Result<Vec<SrvRecord>, DnsResolveErr>;
+
+    async fn lookup_ip(&self, name: &str) -> Result<Vec<IpAddr>, DnsResolveErr>;
+}
+
+/// Resolves records using the system's DNS configuration (`/etc/resolv.conf` on unix).
+pub struct SystemDnsResolver {
+    resolver: TokioAsyncResolver,
+}
+
+impl SystemDnsResolver {
+    pub fn new() -> Result<SystemDnsResolver, DnsResolveErr> {
+        let resolver = TokioAsyncResolver::tokio_from_system_conf()?;
+        Ok(SystemDnsResolver { resolver })
+    }
+}
+
+#[async_trait]
+impl DnsResolver for SystemDnsResolver {
+    async fn lookup_srv(&self, name: &str) -> Result<Vec<SrvRecord>, DnsResolveErr> {
+        match self.resolver.srv_lookup(name).await {
+            Ok(records) => Ok(records
+                .iter()
+                .map(|srv| SrvRecord {
+                    target: srv.target().to_utf8().trim_end_matches('.').to_string(),
+                    port: srv.port(),
+                })
+                .collect()),
+            Err(e) => no_records_found(e),
+        }
+    }
+
+    async fn lookup_ip(&self, name: &str) -> Result<Vec<IpAddr>, DnsResolveErr> {
+        match self.resolver.lookup_ip(name).await {
+            Ok(records) => Ok(records.iter().collect()),
+            Err(e) => no_records_found(e),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DnsLookup {
+    /// Each SRV record's target and port is the address of a node.
+    Srv,
+
+    /// Each A (or AAAA) record is the IP address of a node, with every node listening on `port`.
+    A { port: u16 },
+}
+
+#[derive(Clone)]
+pub struct DnsClusterSeed {
+    name: String,
+    lookup: DnsLookup,
+    refresh_interval: Duration,
+    resolver: Option<Arc<dyn DnsResolver>>,
+}
+
+impl DnsClusterSeed {
+    /// Discovers nodes via the SRV records of `name`, for example
+    /// `_coerce._tcp.my-service.my-namespace.svc.cluster.local`.
+    pub fn srv(name: impl ToString) -> DnsClusterSeed {
+        Self::new(name, DnsLookup::Srv)
+    }
+
+    /// Discovers nodes via the A records of `name`, every node is expected to be listening on
+    /// `port`.
+    pub fn a_records(name: impl ToString, port: u16) -> DnsClusterSeed {
+        Self::new(name, DnsLookup::A { port })
+    }
+
+    fn new(name: impl ToString, lookup: DnsLookup) -> DnsClusterSeed {
+        DnsClusterSeed {
+            name: name.to_string(),
+            lookup,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            resolver: None,
+        }
+    }
+
+    /// How often `name` is resolved, defaults to 10 seconds.
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Overrides the resolver, by default a [`SystemDnsResolver`] is used.
+    pub fn with_resolver(mut self, resolver: impl DnsResolver) -> Self {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Resolves the addresses of every node currently registered with the service.
+    pub async fn resolve(&self, resolver: &dyn DnsResolver) -> Result<Vec<String>, DnsResolveErr> {
+        let addrs = match self.lookup {
+            DnsLookup::Srv => resolver
+                .lookup_srv(&self.name)
+                .await?
+                .into_iter()
+                .map(|srv| format!("{}:{}", srv.target, srv.port))
+                .collect(),
+
+            DnsLookup::A { port } => resolver
+                .lookup_ip(&self.name)
+                .await?
+                .into_iter()
+                .map(|ip| SocketAddr::new(ip, port).to_string())
+                .collect(),
+        };
+
+        Ok(addrs)
+    }
+}
+
+#[async_trait]
+impl DiscoveryProvider for DnsClusterSeed {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn discover(&mut self) -> Result<Vec<String>, DiscoveryErr> {
+        let resolver = match &self.resolver {
+            Some(resolver) => resolver.clone(),
+            None => {
+                let resolver: Arc<dyn DnsResolver> = Arc::new(SystemDnsResolver::new()?);
+                self.resolver = Some(resolver.clone());
+                resolver
+            }
+        };
+
+        Ok(self.resolve(resolver.as_ref()).await?)
+    }
+
+    fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+}
+
+fn no_records_found<T>(e: ResolveError) -> Result<Vec<T>, DnsResolveErr> {
+    match e.kind() {
+        ResolveErrorKind::NoRecordsFound { .. } => Ok(vec![]),
+        _ => Err(e.into()),
+    }
+}
+
+impl From<ResolveError> for DnsResolveErr {
+    fn from(e: ResolveError) -> Self {
+        DnsResolveErr::Err(e.to_string())
+    }
+}
+
+impl Display for DnsResolveErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DnsResolveErr::Err(e) => write!(f, "dns resolution failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DnsResolveErr {}
+
+impl From<DnsResolveErr> for DiscoveryErr {
+    fn from(e: DnsResolveErr) -> Self {
+        DiscoveryErr::Err(e.to_string())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::remote::cluster::discovery::provider::{
+    DiscoveryErr, DiscoveryProvider, DiscoveryWatch, DEFAULT_REFRESH_INTERVAL,
+};
+use futures::stream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Discovers nodes from a file containing one address per line. Blank lines, and lines starting
+/// with `#`, are ignored.
+///
+/// The file is polled for changes, so that addresses added to it are discovered without waiting
+/// for the next refresh.
+pub struct FileDiscovery {
+    name: String,
+    path: PathBuf,
+    refresh_interval: Duration,
+    poll_interval: Duration,
+}
+
+impl FileDiscovery {
+    pub fn new(path: impl Into<PathBuf>) -> FileDiscovery {
+        let path = path.into();
+        FileDiscovery {
+            name: path.display().to_string(),
+            path,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// How often every address in the file is re-identified, defaults to 10 seconds.
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// How often the file is checked for changes, defaults to 1 second.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+#[async_trait]
+impl DiscoveryProvider for FileDiscovery {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn discover(&mut self) -> Result<Vec<String>, DiscoveryErr> {
+        read_addrs(&self.path)
+    }
+
+    fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+
+    fn watch(&mut self) -> Option<DiscoveryWatch> {
+        let path = self.path.clone();
+        let poll_interval = self.poll_interval;
+
+        let changes = stream::unfold(None, move |last_addrs| {
+            let path = path.clone();
+            async move {
+                loop {
+                    tokio::time::sleep(poll_interval).await;
+
+                    match read_addrs(&path) {
+                        Ok(addrs) if Some(&addrs) != last_addrs.as_ref() => {
+                            return Some((addrs.clone(), Some(addrs)));
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            debug!("unable to read file (path={}), e={}", path.display(), e);
+                        }
+                    }
+                }
+            }
+        });
+
+        Some(Box::pin(changes))
+    }
+}
+
+fn read_addrs(path: &Path) -> Result<Vec<String>, DiscoveryErr> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect())
+}
@@ -15,6 +15,12 @@ use std::sync::Arc;
 use tokio::sync::oneshot::Sender;
 use uuid::Uuid;
 
+#[cfg(feature = "remote-dns")]
+pub mod dns;
+pub mod file;
+pub mod provider;
+pub mod seeds;
+
 #[derive(Default)]
 pub struct NodeDiscovery {
     discovering_nodes: HashSet<NodeId>,
@@ -0,0 +1,296 @@
+//! Pluggable, continuously refreshed discovery of the nodes in the cluster.
+//!
+//! A [`DiscoveryProvider`] resolves the addresses of the nodes that should be part of the
+//! cluster, for example from a static list ([`StaticDiscovery`]), a file ([`FileDiscovery`]) or
+//! DNS records. Providers are registered via [`ClusterWorkerBuilder::with_discovery`], and each
+//! one is driven by a [`ProviderDiscovery`] actor, which:
+//!
+//! - resolves the provider's addresses once the node has started, and then on every
+//!   [`DiscoveryProvider::refresh_interval`],
+//! - applies any updates streamed by [`DiscoveryProvider::watch`], as soon as they're received,
+//! - resolves the provider's addresses again straight away when the cluster shrinks to just the
+//!   current node, for example after every other node was restarted.
+//!
+//! Each resolved address that isn't yet part of the cluster is identified and the identified
+//! nodes are fed into [`NodeDiscovery`] via [`Seed::Nodes`].
+//!
+//! [`StaticDiscovery`]: crate::remote::cluster::discovery::seeds::StaticDiscovery
+//! [`FileDiscovery`]: crate::remote::cluster::discovery::file::FileDiscovery
+//! [`ClusterWorkerBuilder::with_discovery`]: crate::remote::cluster::builder::worker::ClusterWorkerBuilder::with_discovery
+//! [`NodeDiscovery`]: crate::remote::cluster::discovery::NodeDiscovery
+
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::{Timer, TimerTick};
+use crate::actor::{Actor, ActorRefErr, IntoActor, LocalActorRef};
+use crate::remote::cluster::discovery::{Discover, Seed};
+use crate::remote::cluster::node::NodeStatus;
+use crate::remote::system::RemoteActorSystem;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Stream of every address known to a provider, emitted each time the addresses change.
+pub type DiscoveryWatch = BoxStream<'static, Vec<String>>;
+
+#[derive(Debug)]
+pub enum DiscoveryErr {
+    Io(std::io::Error),
+    Err(String),
+}
+
+/// Resolves the addresses of the nodes that should be part of the cluster.
+#[async_trait]
+pub trait DiscoveryProvider: 'static + Send + Sync {
+    /// Identifies the provider in logs, for example the DNS name that's being resolved.
+    fn name(&self) -> &str;
+
+    /// Resolves the address of every node currently known to the provider.
+    async fn discover(&mut self) -> Result<Vec<String>, DiscoveryErr>;
+
+    /// How often [`DiscoveryProvider::discover`] is called, defaults to 10 seconds.
+    fn refresh_interval(&self) -> Duration {
+        DEFAULT_REFRESH_INTERVAL
+    }
+
+    /// Providers that are able to watch for changes can return a stream of updated addresses,
+    /// which are applied as soon as they're received, rather than on the next refresh.
+    fn watch(&mut self) -> Option<DiscoveryWatch> {
+        None
+    }
+}
+
+/// Keeps the cluster's membership in sync with the addresses resolved by a [`DiscoveryProvider`].
+pub struct ProviderDiscovery {
+    system: RemoteActorSystem,
+    provider: Box<dyn DiscoveryProvider>,
+    refresh_timer: Option<Timer>,
+    cluster_size_timer: Option<Timer>,
+    watch_task: Option<JoinHandle<()>>,
+    single_node: bool,
+}
+
+impl ProviderDiscovery {
+    /// Starts discovery, waiting for the provider's initial addresses to be discovered.
+    pub async fn start(
+        provider: Box<dyn DiscoveryProvider>,
+        system: &RemoteActorSystem,
+    ) -> Result<LocalActorRef<ProviderDiscovery>, ActorRefErr> {
+        let actor_id = format!("discovery-{}", provider.name());
+        let discovery = ProviderDiscovery {
+            system: system.clone(),
+            provider,
+            refresh_timer: None,
+            cluster_size_timer: None,
+            watch_task: None,
+            single_node: true,
+        }
+        .into_actor(Some(actor_id), system.actor_system())
+        .await?;
+
+        let _ = discovery.send(RefreshDiscovery).await;
+        Ok(discovery)
+    }
+
+    async fn is_single_node(&self) -> bool {
+        let node_id = self.system.node_id();
+        !self
+            .system
+            .get_nodes()
+            .await
+            .iter()
+            .any(|n| n.id != node_id && n.status != NodeStatus::Terminated)
+    }
+
+    /// Identifies each of `addrs` that isn't yet part of the cluster, and waits for the
+    /// identified nodes to be discovered. Returns the number of newly discovered nodes.
+    async fn discover_addrs(&self, addrs: Vec<String>) -> usize {
+        let node_id = self.system.node_id();
+        let nodes: Vec<_> = self
+            .system
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| n.status != NodeStatus::Terminated)
+            .collect();
+
+        let known_addrs: HashSet<&str> = nodes.iter().map(|n| n.addr.as_str()).collect();
+        let known_nodes: HashSet<_> = nodes.iter().map(|n| n.id).collect();
+
+        let mut discovered_nodes = vec![];
+        for addr in addrs {
+            if known_addrs.contains(addr.as_str()) {
+                continue;
+            }
+
+            let client = match self.system.get_remote_client(addr.clone()).await {
+                Some(client) => client,
+                None => continue,
+            };
+
+            match client.identify().await {
+                Ok(Some(identity))
+                    if identity.node.id != node_id && !known_nodes.contains(&identity.node.id) =>
+                {
+                    discovered_nodes.push(identity.node);
+                }
+                Ok(Some(_)) => {}
+                _ => {
+                    debug!("unable to identify node (addr={})", &addr);
+                }
+            }
+        }
+
+        let discovered_count = discovered_nodes.len();
+        if discovered_count == 0 {
+            return 0;
+        }
+
+        info!(
+            "discovered {} nodes (provider={})",
+            discovered_count,
+            self.provider.name()
+        );
+
+        let (tx, rx) = oneshot::channel();
+        let _ = self.system.node_discovery().notify(Discover {
+            seed: Seed::Nodes(discovered_nodes),
+            on_discovery_complete: Some(tx),
+        });
+
+        let _ = rx.await;
+        discovered_count
+    }
+}
+
+#[async_trait]
+impl Actor for ProviderDiscovery {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.refresh_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.provider.refresh_interval(),
+            RefreshDiscovery,
+        ));
+
+        self.cluster_size_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.system.config().heartbeat_config().interval,
+            CheckClusterSize,
+        ));
+
+        if let Some(mut watch) = self.provider.watch() {
+            let actor_ref = self.actor_ref(ctx);
+            self.watch_task = Some(tokio::spawn(async move {
+                while let Some(addrs) = watch.next().await {
+                    if actor_ref.notify(AddrsChanged(addrs)).is_err() {
+                        break;
+                    }
+                }
+            }));
+        }
+    }
+
+    async fn stopped(&mut self, _ctx: &mut ActorContext) {
+        if let Some(refresh_timer) = self.refresh_timer.take() {
+            refresh_timer.stop();
+        }
+
+        if let Some(cluster_size_timer) = self.cluster_size_timer.take() {
+            cluster_size_timer.stop();
+        }
+
+        if let Some(watch_task) = self.watch_task.take() {
+            watch_task.abort();
+        }
+    }
+}
+
+/// Resolves the provider's addresses, discovering any nodes that aren't yet part of the cluster.
+/// Results in the number of newly discovered nodes.
+#[derive(Clone)]
+pub struct RefreshDiscovery;
+
+impl Message for RefreshDiscovery {
+    type Result = usize;
+}
+
+impl TimerTick for RefreshDiscovery {}
+
+struct AddrsChanged(Vec<String>);
+
+impl Message for AddrsChanged {
+    type Result = ();
+}
+
+#[derive(Clone)]
+struct CheckClusterSize;
+
+impl Message for CheckClusterSize {
+    type Result = ();
+}
+
+impl TimerTick for CheckClusterSize {}
+
+#[async_trait]
+impl Handler<RefreshDiscovery> for ProviderDiscovery {
+    async fn handle(&mut self, _message: RefreshDiscovery, _ctx: &mut ActorContext) -> usize {
+        match self.provider.discover().await {
+            Ok(addrs) => self.discover_addrs(addrs).await,
+            Err(e) => {
+                warn!(
+                    "unable to discover nodes (provider={}), e={}",
+                    self.provider.name(),
+                    e
+                );
+                0
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<AddrsChanged> for ProviderDiscovery {
+    async fn handle(&mut self, message: AddrsChanged, _ctx: &mut ActorContext) {
+        self.discover_addrs(message.0).await;
+    }
+}
+
+#[async_trait]
+impl Handler<CheckClusterSize> for ProviderDiscovery {
+    async fn handle(&mut self, _message: CheckClusterSize, ctx: &mut ActorContext) {
+        let single_node = self.is_single_node().await;
+        if single_node && !self.single_node {
+            info!(
+                "cluster shrunk to a single node, re-discovering nodes (provider={})",
+                self.provider.name()
+            );
+
+            let _ = self.actor_ref(ctx).notify(RefreshDiscovery);
+        }
+
+        self.single_node = single_node;
+    }
+}
+
+impl From<std::io::Error> for DiscoveryErr {
+    fn from(e: std::io::Error) -> Self {
+        DiscoveryErr::Io(e)
+    }
+}
+
+impl Display for DiscoveryErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscoveryErr::Io(e) => write!(f, "discovery failed, io error: {}", e),
+            DiscoveryErr::Err(e) => write!(f, "discovery failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DiscoveryErr {}
@@ -0,0 +1,46 @@
+use crate::remote::cluster::discovery::provider::{
+    DiscoveryErr, DiscoveryProvider, DEFAULT_REFRESH_INTERVAL,
+};
+use std::time::Duration;
+
+/// Discovers nodes from a fixed list of addresses.
+///
+/// Unlike [`ClusterWorkerBuilder::with_seed_addr`], every address in the list is periodically
+/// re-identified, so nodes that restart, or that weren't reachable when the current node started,
+/// rejoin the cluster.
+///
+/// [`ClusterWorkerBuilder::with_seed_addr`]: crate::remote::cluster::builder::worker::ClusterWorkerBuilder::with_seed_addr
+pub struct StaticDiscovery {
+    addrs: Vec<String>,
+    refresh_interval: Duration,
+}
+
+impl StaticDiscovery {
+    pub fn new<T: ToString>(addrs: impl IntoIterator<Item = T>) -> StaticDiscovery {
+        StaticDiscovery {
+            addrs: addrs.into_iter().map(|addr| addr.to_string()).collect(),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+        }
+    }
+
+    /// How often the addresses are re-identified, defaults to 10 seconds.
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+}
+
+#[async_trait]
+impl DiscoveryProvider for StaticDiscovery {
+    fn name(&self) -> &str {
+        "static"
+    }
+
+    async fn discover(&mut self) -> Result<Vec<String>, DiscoveryErr> {
+        Ok(self.addrs.clone())
+    }
+
+    fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+}
@@ -0,0 +1,159 @@
+//! Typed cluster membership events.
+//!
+//! Every change to the status of a node in the [`RemoteNodeStore`] is translated into a
+//! [`MemberEvent`]:
+//!
+//! - [`MemberEvent::MemberJoining`]: the node has been discovered, but hasn't been seen as healthy.
+//! - [`MemberEvent::MemberUp`]: the node has been seen as healthy for the first time.
+//! - [`MemberEvent::MemberUnreachable`]: the node is unhealthy, or suspected of having failed.
+//! - [`MemberEvent::MemberReachable`]: a previously unreachable node is healthy again.
+//! - [`MemberEvent::MemberLeaving`]: the node is gracefully leaving the cluster.
+//! - [`MemberEvent::MemberRemoved`]: the node has terminated, or was downed.
+//!
+//! Actors subscribe via [`RemoteActorSystem::subscribe_membership`]. The first event received is
+//! always [`MemberEvent::CurrentMembers`], a snapshot of the membership at the time of
+//! subscribing, followed by every change after the snapshot was taken, so subscribers that
+//! start late still end up with a consistent view of the membership.
+//!
+//! [`RemoteNodeStore`]: crate::remote::cluster::node::RemoteNodeStore
+//! [`RemoteActorSystem::subscribe_membership`]: crate::remote::system::RemoteActorSystem::subscribe_membership
+
+use crate::actor::message::Message;
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState};
+use crate::remote::system::NodeId;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+const EVENT_BUFFER_SIZE: usize = 1024;
+
+#[derive(Clone, Debug)]
+pub enum MemberEvent {
+    /// Every member of the cluster at the time of subscribing, including the current node.
+    CurrentMembers(Vec<RemoteNodeState>),
+    MemberJoining(Arc<RemoteNode>),
+    MemberUp(Arc<RemoteNode>),
+    MemberUnreachable(Arc<RemoteNode>),
+    MemberReachable(Arc<RemoteNode>),
+    MemberLeaving(Arc<RemoteNode>),
+    MemberRemoved(Arc<RemoteNode>),
+}
+
+impl MemberEvent {
+    /// The member the event relates to, `None` for [`MemberEvent::CurrentMembers`].
+    pub fn node(&self) -> Option<&Arc<RemoteNode>> {
+        match self {
+            MemberEvent::CurrentMembers(_) => None,
+            MemberEvent::MemberJoining(node)
+            | MemberEvent::MemberUp(node)
+            | MemberEvent::MemberUnreachable(node)
+            | MemberEvent::MemberReachable(node)
+            | MemberEvent::MemberLeaving(node)
+            | MemberEvent::MemberRemoved(node) => Some(node),
+        }
+    }
+}
+
+impl Message for MemberEvent {
+    type Result = ();
+}
+
+#[derive(Default)]
+struct MemberState {
+    up: bool,
+    reachable: bool,
+    leaving: bool,
+}
+
+/// Tracks the membership state last published for each node, so that events are only published
+/// when the membership of a node actually changes, rather than on every status update.
+pub(crate) struct MemberEvents {
+    members: HashMap<NodeId, MemberState>,
+    sender: broadcast::Sender<MemberEvent>,
+}
+
+impl MemberEvents {
+    pub fn new() -> MemberEvents {
+        let (sender, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+        MemberEvents {
+            members: HashMap::new(),
+            sender,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<MemberEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Publishes the events resulting from the node's current status.
+    pub fn update(&mut self, node: &RemoteNodeState) {
+        if let Entry::Vacant(entry) = self.members.entry(node.id) {
+            if node.status == NodeStatus::Terminated {
+                return;
+            }
+
+            entry.insert(MemberState {
+                reachable: true,
+                ..Default::default()
+            });
+
+            self.publish(MemberEvent::MemberJoining(Arc::new(node.clone().into())));
+        }
+
+        let member = self.members.get_mut(&node.id).unwrap();
+        let event: Option<fn(Arc<RemoteNode>) -> MemberEvent> = match node.status {
+            NodeStatus::Joining => None,
+
+            NodeStatus::Healthy if !member.up => {
+                member.up = true;
+                member.reachable = true;
+                Some(MemberEvent::MemberUp)
+            }
+
+            NodeStatus::Healthy if !member.reachable => {
+                member.reachable = true;
+                Some(MemberEvent::MemberReachable)
+            }
+
+            NodeStatus::Healthy => None,
+
+            NodeStatus::Unhealthy | NodeStatus::Suspect if member.reachable => {
+                member.reachable = false;
+                Some(MemberEvent::MemberUnreachable)
+            }
+
+            NodeStatus::Unhealthy | NodeStatus::Suspect => None,
+
+            NodeStatus::Leaving if !member.leaving => {
+                member.leaving = true;
+                Some(MemberEvent::MemberLeaving)
+            }
+
+            NodeStatus::Leaving => None,
+
+            NodeStatus::Terminated => {
+                self.members.remove(&node.id);
+                Some(MemberEvent::MemberRemoved)
+            }
+        };
+
+        if let Some(event) = event {
+            self.publish(event(Arc::new(node.clone().into())));
+        }
+    }
+
+    /// Publishes [`MemberEvent::MemberRemoved`] for a node that was removed from the store.
+    pub fn removed(&mut self, node: RemoteNode) {
+        if self.members.remove(&node.id).is_some() {
+            self.publish(MemberEvent::MemberRemoved(Arc::new(node)));
+        }
+    }
+
+    fn publish(&self, event: MemberEvent) {
+        trace!("membership event - {:?}", &event);
+
+        // there may not be any subscribers, in which case the event is dropped
+        let _ = self.sender.send(event);
+    }
+}
@@ -0,0 +1,545 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::{Timer, TimerTick};
+use crate::actor::{Actor, LocalActorRef};
+use crate::remote::actor::message::SetRemote;
+use crate::remote::cluster::discovery::{Discover, Seed};
+use crate::remote::cluster::gossip::membership::{
+    MemberStatus, MemberUpdate, Membership, MergeResult,
+};
+use crate::remote::cluster::gossip::rpc::{self, GossipRpcRequest};
+use crate::remote::cluster::gossip::GossipConfig;
+use crate::remote::cluster::node::{NodeStatus, RemoteNode};
+use crate::remote::net::proto::network::{
+    GossipAck, GossipMember, GossipPing, GossipPush, GossipRequest, GossipSync, GossipSyncAck,
+};
+use crate::remote::stream::pubsub::PubSub;
+use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use futures::future::join_all;
+use protobuf::Message as ProtoMessage;
+use rand::seq::SliceRandom;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::time::Instant;
+
+/// Probes members and disseminates membership updates, see [`crate::remote::cluster::gossip`].
+pub struct Gossip {
+    node_id: NodeId,
+    config: GossipConfig,
+    system: Option<RemoteActorSystem>,
+    membership: Membership,
+    probe_timer: Option<Timer>,
+    sync_timer: Option<Timer>,
+    probe_order: Vec<NodeId>,
+    broadcasts: Vec<Broadcast>,
+}
+
+/// A membership update waiting to be piggybacked on outgoing messages.
+struct Broadcast {
+    update: MemberUpdate,
+    transmits: usize,
+}
+
+impl Gossip {
+    pub fn new(node_id: NodeId, config: GossipConfig, membership: Membership) -> Gossip {
+        Gossip {
+            node_id,
+            config,
+            system: None,
+            membership,
+            probe_timer: None,
+            sync_timer: None,
+            probe_order: vec![],
+            broadcasts: vec![],
+        }
+    }
+}
+
+impl Actor for Gossip {}
+
+#[derive(Clone)]
+struct ProbeTick;
+
+impl Message for ProbeTick {
+    type Result = ();
+}
+
+impl TimerTick for ProbeTick {}
+
+#[derive(Clone)]
+struct SyncTick;
+
+impl Message for SyncTick {
+    type Result = ();
+}
+
+impl TimerTick for SyncTick {}
+
+/// A gossip request received from another node, the encoded response is sent via the sender.
+pub(crate) struct GossipRpc(pub GossipRequest, pub oneshot::Sender<Option<Vec<u8>>>);
+
+impl Message for GossipRpc {
+    type Result = ();
+}
+
+struct ProbeResult {
+    target: NodeId,
+    acked: bool,
+
+    /// Only measured for direct probes.
+    rtt: Option<Duration>,
+    updates: Vec<GossipMember>,
+}
+
+impl Message for ProbeResult {
+    type Result = ();
+}
+
+struct UpdatesReceived(Vec<GossipMember>);
+
+impl Message for UpdatesReceived {
+    type Result = ();
+}
+
+#[async_trait]
+impl Handler<SetRemote> for Gossip {
+    async fn handle(&mut self, message: SetRemote, ctx: &mut ActorContext) {
+        debug!(
+            "[node={}] starting gossip (probe_interval={} millis, sync_interval={} millis)",
+            self.node_id,
+            self.config.probe_interval.as_millis(),
+            self.config.sync_interval.as_millis()
+        );
+
+        self.system = Some(message.0);
+        self.probe_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.config.probe_interval,
+            ProbeTick,
+        ));
+
+        self.sync_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.config.sync_interval,
+            SyncTick,
+        ));
+    }
+}
+
+#[async_trait]
+impl Handler<ProbeTick> for Gossip {
+    async fn handle(&mut self, _message: ProbeTick, ctx: &mut ActorContext) {
+        let system = match &self.system {
+            Some(system) => system.clone(),
+            None => return,
+        };
+
+        self.refresh_members(&system).await;
+
+        for update in self
+            .membership
+            .expire_suspects(self.config.suspicion_timeout)
+        {
+            self.on_member_dead(&update.node);
+            self.enqueue(update);
+        }
+
+        let target = match self.next_probe_target() {
+            Some(target) => target,
+            None => return,
+        };
+
+        let mut helpers: Vec<NodeId> = self
+            .membership
+            .member_ids(&[MemberStatus::Alive])
+            .into_iter()
+            .filter(|id| *id != target)
+            .collect();
+
+        helpers.shuffle(&mut rand::thread_rng());
+        helpers.truncate(self.config.indirect_probes);
+
+        let ping = GossipPing {
+            from_node_id: self.node_id,
+            target_node_id: target,
+            updates: self.take_updates(),
+            ..Default::default()
+        };
+
+        let actor_ref: LocalActorRef<Gossip> = self.actor_ref(ctx);
+        let config = self.config.clone();
+        tokio::spawn(async move {
+            let result = probe(&system, target, helpers, ping, &config).await;
+            let _ = actor_ref.notify(result);
+        });
+    }
+}
+
+#[async_trait]
+impl Handler<SyncTick> for Gossip {
+    async fn handle(&mut self, _message: SyncTick, ctx: &mut ActorContext) {
+        let system = match &self.system {
+            Some(system) => system.clone(),
+            None => return,
+        };
+
+        let target = match self
+            .membership
+            .member_ids(&[MemberStatus::Alive])
+            .choose(&mut rand::thread_rng())
+        {
+            Some(target) => *target,
+            None => return,
+        };
+
+        let sync = GossipRpcRequest::Sync(GossipSync {
+            from_node_id: self.node_id,
+            digest: self.membership.digest(),
+            ..Default::default()
+        });
+
+        let actor_ref: LocalActorRef<Gossip> = self.actor_ref(ctx);
+        let membership = self.membership.clone();
+        let timeout = self.config.probe_interval;
+        tokio::spawn(async move {
+            let response = rpc::send::<GossipSyncAck>(&system, target, sync, timeout).await;
+            let response = match response {
+                Some(response) => response,
+                None => return,
+            };
+
+            if !response.members.is_empty() {
+                let _ = actor_ref.notify(UpdatesReceived(response.members));
+            }
+
+            if !response.requested.is_empty() {
+                let push = GossipRpcRequest::Push(GossipPush {
+                    members: membership
+                        .updates_for(&response.requested)
+                        .into_iter()
+                        .map(GossipMember::from)
+                        .collect(),
+                    ..Default::default()
+                });
+
+                let _ = rpc::send::<GossipAck>(&system, target, push, timeout).await;
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl Handler<ProbeResult> for Gossip {
+    async fn handle(&mut self, message: ProbeResult, _ctx: &mut ActorContext) {
+        self.merge(message.updates);
+
+        if message.acked {
+            self.membership.on_ack(message.target, message.rtt);
+        } else if let Some(update) = self.membership.suspect(message.target) {
+            warn!(
+                "[node={}] node_id={} did not acknowledge a direct or indirect probe, suspecting node (incarnation={})",
+                self.node_id, message.target, update.incarnation
+            );
+
+            self.enqueue(update);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<UpdatesReceived> for Gossip {
+    async fn handle(&mut self, message: UpdatesReceived, _ctx: &mut ActorContext) {
+        self.merge(message.0);
+    }
+}
+
+#[async_trait]
+impl Handler<GossipRpc> for Gossip {
+    async fn handle(&mut self, message: GossipRpc, ctx: &mut ActorContext) {
+        let GossipRpc(request, result) = message;
+        let request = match GossipRpcRequest::from_proto(&request) {
+            Some(request) => request,
+            None => {
+                let _ = result.send(None);
+                return;
+            }
+        };
+
+        let response = match request {
+            GossipRpcRequest::Ping(ping) => {
+                self.merge(ping.updates);
+
+                GossipAck {
+                    ack: true,
+                    updates: self.take_updates(),
+                    ..Default::default()
+                }
+                .write_to_bytes()
+            }
+
+            GossipRpcRequest::PingReq(ping) => {
+                self.merge(ping.updates);
+
+                let system = self.system.clone();
+                let actor_ref: LocalActorRef<Gossip> = self.actor_ref(ctx);
+                let timeout = self.config.probe_timeout;
+                let target = ping.target_node_id;
+                let ping = GossipRpcRequest::Ping(GossipPing {
+                    from_node_id: self.node_id,
+                    target_node_id: target,
+                    updates: self.take_updates(),
+                    ..Default::default()
+                });
+
+                tokio::spawn(async move {
+                    let ack = match &system {
+                        Some(system) => rpc::send::<GossipAck>(system, target, ping, timeout).await,
+                        None => None,
+                    };
+
+                    let ack = match ack {
+                        Some(ack) => {
+                            let _ = actor_ref.notify(UpdatesReceived(ack.updates));
+                            ack.ack
+                        }
+                        None => false,
+                    };
+
+                    let response = GossipAck {
+                        ack,
+                        ..Default::default()
+                    };
+
+                    let _ = result.send(response.write_to_bytes().ok());
+                });
+
+                return;
+            }
+
+            GossipRpcRequest::Sync(sync) => {
+                let (members, requested) = self.membership.compare_digest(&sync.digest);
+
+                GossipSyncAck {
+                    members: members.into_iter().map(GossipMember::from).collect(),
+                    requested,
+                    ..Default::default()
+                }
+                .write_to_bytes()
+            }
+
+            GossipRpcRequest::Push(push) => {
+                self.merge(push.members);
+
+                GossipAck {
+                    ack: true,
+                    ..Default::default()
+                }
+                .write_to_bytes()
+            }
+        };
+
+        let _ = result.send(response.ok());
+    }
+}
+
+impl Gossip {
+    /// Members are added once they've been discovered and registered with the [`RemoteRegistry`].
+    ///
+    /// [`RemoteRegistry`]: crate::remote::actor::registry::RemoteRegistry
+    async fn refresh_members(&mut self, system: &RemoteActorSystem) {
+        let nodes = system.get_nodes().await;
+        for node in nodes {
+            if node.status == NodeStatus::Terminated {
+                continue;
+            }
+
+            if let Some(update) = self.membership.add(node.into()) {
+                debug!(
+                    "[node={}] added member node_id={}",
+                    self.node_id, update.node.id
+                );
+
+                self.enqueue(update);
+            }
+        }
+    }
+
+    fn merge(&mut self, updates: Vec<GossipMember>) {
+        for update in updates {
+            let update = MemberUpdate::from(update);
+            match self.membership.merge(update.clone()) {
+                MergeResult::Ignored => {}
+
+                MergeResult::Refute => {
+                    if let Some(refutation) = self.membership.refute(update.incarnation) {
+                        info!(
+                            "[node={}] refuting suspicion (status={:?}), incarnation={}",
+                            self.node_id, update.status, refutation.incarnation
+                        );
+
+                        self.enqueue(refutation);
+                    }
+                }
+
+                MergeResult::Applied { new_member } => {
+                    trace!(
+                        "[node={}] member updated (node_id={}, status={:?}, incarnation={})",
+                        self.node_id,
+                        update.node.id,
+                        update.status,
+                        update.incarnation
+                    );
+
+                    if new_member && update.status != MemberStatus::Dead {
+                        self.discover(update.node.clone());
+                    }
+
+                    if update.status == MemberStatus::Dead {
+                        self.on_member_dead(&update.node);
+                    }
+
+                    self.enqueue(update);
+                }
+            }
+        }
+    }
+
+    /// Members learned about via gossip are discovered, so the node connects to them and
+    /// registers them.
+    fn discover(&self, node: RemoteNode) {
+        if let Some(system) = &self.system {
+            let _ = system.node_discovery().notify(Discover {
+                seed: Seed::Nodes(vec![node]),
+                on_discovery_complete: None,
+            });
+        }
+    }
+
+    fn on_member_dead(&self, node: &RemoteNode) {
+        warn!(
+            "[node={}] node_id={} declared dead, addr={}",
+            self.node_id, node.id, &node.addr
+        );
+
+        if let Some(system) = &self.system {
+            let system = system.clone();
+            let node = Arc::new(node.clone());
+            tokio::spawn(async move {
+                let _ = PubSub::publish_locally(
+                    SystemTopic,
+                    SystemEvent::Cluster(ClusterEvent::NodeRemoved(node)),
+                    &system,
+                )
+                .await;
+            });
+        }
+    }
+
+    /// Members are probed in a random order, each member is probed once before any member is
+    /// probed again.
+    fn next_probe_target(&mut self) -> Option<NodeId> {
+        while let Some(node_id) = self.probe_order.pop() {
+            let status = self.membership.get(node_id).map(|m| m.status);
+            if matches!(status, Some(MemberStatus::Alive | MemberStatus::Suspect)) {
+                return Some(node_id);
+            }
+        }
+
+        self.probe_order = self
+            .membership
+            .member_ids(&[MemberStatus::Alive, MemberStatus::Suspect]);
+
+        self.probe_order.shuffle(&mut rand::thread_rng());
+        self.probe_order.pop()
+    }
+
+    fn enqueue(&mut self, update: MemberUpdate) {
+        self.broadcasts
+            .retain(|b| b.update.node.id != update.node.id);
+        self.broadcasts.push(Broadcast {
+            update,
+            transmits: 0,
+        });
+    }
+
+    /// The updates to piggyback on the next outgoing message, preferring the updates that have
+    /// been transmitted the fewest times.
+    fn take_updates(&mut self) -> Vec<GossipMember> {
+        let members = self
+            .membership
+            .member_ids(&[MemberStatus::Alive, MemberStatus::Suspect])
+            .len()
+            + 1;
+
+        let log2_members = (usize::BITS - members.leading_zeros()) as usize;
+        let max_transmits = (self.config.retransmit_multiplier * log2_members).max(1);
+
+        self.broadcasts.sort_by_key(|b| b.transmits);
+
+        let updates = self
+            .broadcasts
+            .iter_mut()
+            .take(self.config.max_piggybacked_updates)
+            .map(|b| {
+                b.transmits += 1;
+                GossipMember::from(b.update.clone())
+            })
+            .collect();
+
+        self.broadcasts.retain(|b| b.transmits < max_transmits);
+        updates
+    }
+}
+
+/// Probes `target` directly, falling back to asking `helpers` to probe it if the direct probe
+/// isn't acknowledged in time.
+async fn probe(
+    system: &RemoteActorSystem,
+    target: NodeId,
+    helpers: Vec<NodeId>,
+    ping: GossipPing,
+    config: &GossipConfig,
+) -> ProbeResult {
+    let started_at = Instant::now();
+    let direct = GossipRpcRequest::Ping(ping.clone());
+    if let Some(ack) = rpc::send::<GossipAck>(system, target, direct, config.probe_timeout).await {
+        if ack.ack {
+            return ProbeResult {
+                target,
+                acked: true,
+                rtt: Some(started_at.elapsed()),
+                updates: ack.updates,
+            };
+        }
+    }
+
+    trace!(
+        "node_id={} did not acknowledge probe, probing indirectly via {:?}",
+        target,
+        &helpers
+    );
+
+    // helpers wait up to `probe_timeout` for the target to respond
+    let indirect_timeout = config.probe_timeout * 2;
+    let acks = join_all(helpers.into_iter().map(|helper| {
+        let request = GossipRpcRequest::PingReq(ping.clone());
+        rpc::send::<GossipAck>(system, helper, request, indirect_timeout)
+    }))
+    .await;
+
+    let mut result = ProbeResult {
+        target,
+        acked: false,
+        rtt: None,
+        updates: vec![],
+    };
+
+    for ack in acks.into_iter().flatten() {
+        result.acked |= ack.ack;
+        result.updates.extend(ack.updates);
+    }
+
+    result
+}
@@ -0,0 +1,386 @@
+use crate::remote::cluster::node::RemoteNode;
+use crate::remote::net::proto::network::{
+    GossipDigestEntry, GossipMember, GossipMemberStatus, RemoteNode as RemoteNodeProto,
+};
+use crate::remote::system::NodeId;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum MemberStatus {
+    Alive,
+
+    /// The member didn't acknowledge a direct or indirect probe, and will be declared dead
+    /// unless it refutes the suspicion with a higher incarnation.
+    Suspect,
+
+    Dead,
+}
+
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub node: RemoteNode,
+
+    /// Only incremented by the member itself, when refuting a suspicion.
+    pub incarnation: u64,
+    pub status: MemberStatus,
+
+    /// When the member's status last changed, as seen by this node.
+    pub status_changed_at: Instant,
+
+    /// When this node last received an acknowledgement from the member.
+    pub last_ack: Option<DateTime<Utc>>,
+
+    /// Round trip time of the last probe acknowledged by the member.
+    pub rtt: Option<Duration>,
+}
+
+/// A change to a member's state, disseminated to other members via gossip.
+#[derive(Clone, Debug)]
+pub struct MemberUpdate {
+    pub node: RemoteNode,
+    pub incarnation: u64,
+    pub status: MemberStatus,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum MergeResult {
+    /// The update was older than (or the same as) the state already known.
+    Ignored,
+
+    /// The update was applied, and should be disseminated further.
+    Applied { new_member: bool },
+
+    /// The update suspected (or declared dead) the current node, which should refute it.
+    Refute,
+}
+
+/// The members of the cluster as seen by this node, shared between the gossip actor and
+/// the heartbeat.
+#[derive(Clone)]
+pub struct Membership {
+    node_id: NodeId,
+    members: Arc<RwLock<HashMap<NodeId, Member>>>,
+}
+
+impl Membership {
+    pub fn new(node_id: NodeId) -> Membership {
+        Membership {
+            node_id,
+            members: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn get(&self, node_id: NodeId) -> Option<Member> {
+        self.members.read().get(&node_id).cloned()
+    }
+
+    pub fn members(&self) -> Vec<Member> {
+        self.members.read().values().cloned().collect()
+    }
+
+    /// Ids of the members (excluding the current node) that have the given status.
+    pub fn member_ids(&self, statuses: &[MemberStatus]) -> Vec<NodeId> {
+        let mut ids: Vec<NodeId> = self
+            .members
+            .read()
+            .values()
+            .filter(|m| m.node.id != self.node_id && statuses.contains(&m.status))
+            .map(|m| m.node.id)
+            .collect();
+
+        ids.sort();
+        ids
+    }
+
+    /// The current node's incarnation, 0 until the node has had to refute a suspicion.
+    pub fn incarnation(&self) -> u64 {
+        self.members
+            .read()
+            .get(&self.node_id)
+            .map_or(0, |m| m.incarnation)
+    }
+
+    /// Adds a node that has been discovered locally (rather than via gossip). A node that's
+    /// already known is replaced if it has since restarted.
+    pub(crate) fn add(&self, node: RemoteNode) -> Option<MemberUpdate> {
+        let mut members = self.members.write();
+        if let Some(member) = members.get(&node.id) {
+            if !restarted(&member.node, &node) {
+                return None;
+            }
+        }
+
+        let member = Member::alive(node, 0);
+        let update = member.update();
+        members.insert(member.node.id, member);
+        Some(update)
+    }
+
+    pub(crate) fn merge(&self, update: MemberUpdate) -> MergeResult {
+        let mut members = self.members.write();
+        let node_id = update.node.id;
+
+        if node_id == self.node_id {
+            return match members.get(&node_id) {
+                Some(member)
+                    if update.status != MemberStatus::Alive
+                        && update.incarnation >= member.incarnation =>
+                {
+                    MergeResult::Refute
+                }
+                _ => MergeResult::Ignored,
+            };
+        }
+
+        let member = match members.get_mut(&node_id) {
+            Some(member) => member,
+            None => {
+                if update.status == MemberStatus::Dead {
+                    return MergeResult::Ignored;
+                }
+
+                let mut member = Member::alive(update.node, update.incarnation);
+                member.status = update.status;
+                members.insert(node_id, member);
+                return MergeResult::Applied { new_member: true };
+            }
+        };
+
+        if restarted(&member.node, &update.node) {
+            if update.status == MemberStatus::Dead {
+                return MergeResult::Ignored;
+            }
+
+            *member = Member::alive(update.node, update.incarnation);
+            member.status = update.status;
+            return MergeResult::Applied { new_member: true };
+        }
+
+        if update.node.node_started_at < member.node.node_started_at {
+            return MergeResult::Ignored;
+        }
+
+        // dead members stay dead until they restart and rejoin with a new `node_started_at`
+        let applies = match update.status {
+            MemberStatus::Alive => {
+                member.status != MemberStatus::Dead && update.incarnation > member.incarnation
+            }
+            MemberStatus::Suspect => {
+                member.status != MemberStatus::Dead
+                    && (update.incarnation > member.incarnation
+                        || (update.incarnation == member.incarnation
+                            && member.status == MemberStatus::Alive))
+            }
+            MemberStatus::Dead => {
+                member.status != MemberStatus::Dead && update.incarnation >= member.incarnation
+            }
+        };
+
+        if !applies {
+            return MergeResult::Ignored;
+        }
+
+        member.set_status(update.status);
+        member.incarnation = update.incarnation;
+        MergeResult::Applied { new_member: false }
+    }
+
+    /// Increments the current node's incarnation so that it's greater than `incarnation`,
+    /// returning the update that refutes the suspicion.
+    pub(crate) fn refute(&self, incarnation: u64) -> Option<MemberUpdate> {
+        let mut members = self.members.write();
+        let member = members.get_mut(&self.node_id)?;
+
+        member.incarnation = member.incarnation.max(incarnation) + 1;
+        Some(member.update())
+    }
+
+    /// Suspects a member that failed to acknowledge a probe, returning the update if the member
+    /// was previously alive.
+    pub(crate) fn suspect(&self, node_id: NodeId) -> Option<MemberUpdate> {
+        self.transition(node_id, MemberStatus::Alive, MemberStatus::Suspect)
+    }
+
+    /// Members that have been suspected for longer than `suspicion_timeout` are declared dead,
+    /// returning the updates.
+    pub(crate) fn expire_suspects(&self, suspicion_timeout: Duration) -> Vec<MemberUpdate> {
+        let expired: Vec<NodeId> = self
+            .members
+            .read()
+            .values()
+            .filter(|m| {
+                m.status == MemberStatus::Suspect
+                    && m.status_changed_at.elapsed() >= suspicion_timeout
+            })
+            .map(|m| m.node.id)
+            .collect();
+
+        expired
+            .into_iter()
+            .filter_map(|id| self.transition(id, MemberStatus::Suspect, MemberStatus::Dead))
+            .collect()
+    }
+
+    pub(crate) fn on_ack(&self, node_id: NodeId, rtt: Option<Duration>) {
+        if let Some(member) = self.members.write().get_mut(&node_id) {
+            member.last_ack = Some(Utc::now());
+            if rtt.is_some() {
+                member.rtt = rtt;
+            }
+        }
+    }
+
+    pub(crate) fn digest(&self) -> Vec<GossipDigestEntry> {
+        self.members
+            .read()
+            .values()
+            .map(|m| GossipDigestEntry {
+                node_id: m.node.id,
+                incarnation: m.incarnation,
+                status: m.status.to_proto().into(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Compares a digest received from another member with the local state, returning the
+    /// members the other member is missing or has older state for, and the ids of the members
+    /// this node is missing or has older state for.
+    pub(crate) fn compare_digest(
+        &self,
+        digest: &[GossipDigestEntry],
+    ) -> (Vec<MemberUpdate>, Vec<NodeId>) {
+        let members = self.members.read();
+        let remote: HashMap<NodeId, (u64, MemberStatus)> = digest
+            .iter()
+            .map(|e| {
+                (
+                    e.node_id,
+                    (e.incarnation, MemberStatus::from_proto(e.status)),
+                )
+            })
+            .collect();
+
+        let newer = members
+            .values()
+            .filter(|m| match remote.get(&m.node.id) {
+                Some(remote) => (m.incarnation, m.status) > *remote,
+                None => true,
+            })
+            .map(Member::update)
+            .collect();
+
+        let requested = remote
+            .iter()
+            .filter(|(id, remote)| match members.get(id) {
+                Some(m) => **remote > (m.incarnation, m.status),
+                None => true,
+            })
+            .map(|(id, _)| *id)
+            .collect();
+
+        (newer, requested)
+    }
+
+    pub(crate) fn updates_for(&self, node_ids: &[NodeId]) -> Vec<MemberUpdate> {
+        let members = self.members.read();
+        node_ids
+            .iter()
+            .filter_map(|id| members.get(id))
+            .map(Member::update)
+            .collect()
+    }
+
+    fn transition(
+        &self,
+        node_id: NodeId,
+        from: MemberStatus,
+        to: MemberStatus,
+    ) -> Option<MemberUpdate> {
+        let mut members = self.members.write();
+        let member = members.get_mut(&node_id)?;
+        if member.status != from {
+            return None;
+        }
+
+        member.set_status(to);
+        Some(member.update())
+    }
+}
+
+impl Member {
+    fn alive(node: RemoteNode, incarnation: u64) -> Member {
+        Member {
+            node,
+            incarnation,
+            status: MemberStatus::Alive,
+            status_changed_at: Instant::now(),
+            last_ack: None,
+            rtt: None,
+        }
+    }
+
+    fn set_status(&mut self, status: MemberStatus) {
+        if self.status != status {
+            self.status = status;
+            self.status_changed_at = Instant::now();
+        }
+    }
+
+    pub fn update(&self) -> MemberUpdate {
+        MemberUpdate {
+            node: self.node.clone(),
+            incarnation: self.incarnation,
+            status: self.status,
+        }
+    }
+}
+
+impl MemberStatus {
+    fn to_proto(self) -> GossipMemberStatus {
+        match self {
+            MemberStatus::Alive => GossipMemberStatus::GossipAlive,
+            MemberStatus::Suspect => GossipMemberStatus::GossipSuspect,
+            MemberStatus::Dead => GossipMemberStatus::GossipDead,
+        }
+    }
+
+    fn from_proto(status: protobuf::EnumOrUnknown<GossipMemberStatus>) -> MemberStatus {
+        match status.enum_value_or_default() {
+            GossipMemberStatus::GossipAlive => MemberStatus::Alive,
+            GossipMemberStatus::GossipSuspect => MemberStatus::Suspect,
+            GossipMemberStatus::GossipDead => MemberStatus::Dead,
+        }
+    }
+}
+
+impl From<MemberUpdate> for GossipMember {
+    fn from(update: MemberUpdate) -> Self {
+        GossipMember {
+            node: Some(RemoteNodeProto::from(update.node)).into(),
+            incarnation: update.incarnation,
+            status: update.status.to_proto().into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<GossipMember> for MemberUpdate {
+    fn from(member: GossipMember) -> Self {
+        MemberUpdate {
+            node: member.node.unwrap_or_default().into(),
+            incarnation: member.incarnation,
+            status: MemberStatus::from_proto(member.status),
+        }
+    }
+}
+
+/// Whether `node` is a newer instance of `existing`, having restarted with the same node id.
+fn restarted(existing: &RemoteNode, node: &RemoteNode) -> bool {
+    node.node_started_at > existing.node_started_at
+}
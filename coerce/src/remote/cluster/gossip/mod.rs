@@ -0,0 +1,113 @@
+//! Gossip-based membership, with SWIM-style failure detection.
+//!
+//! By default, every node pings every other node on each heartbeat, so the number of pings
+//! grows quadratically with the size of the cluster. When gossip is enabled via
+//! [`RemoteSystemConfigBuilder::gossip`], the per-connection pings are disabled, and instead,
+//! each node probes a single member per [`GossipConfig::probe_interval`], cycling through the
+//! members in a random order.
+//!
+//! If a member doesn't acknowledge a probe within [`GossipConfig::probe_timeout`], up to
+//! [`GossipConfig::indirect_probes`] other members are asked to probe it on the node's behalf,
+//! so a single bad link doesn't cause a member to be suspected. If none of them receive an
+//! acknowledgement either, the member becomes suspected. A suspected member that doesn't refute
+//! the suspicion within [`GossipConfig::suspicion_timeout`] is declared dead.
+//!
+//! Changes to the membership are piggybacked on probes and their acknowledgements. Every member
+//! has an incarnation number, which only the member itself increments, when it learns that it's
+//! been suspected, so that the refutation supersedes the suspicion as it's gossiped around the
+//! cluster. Periodically, each node also exchanges a digest of its membership state with a
+//! random member, so that members converge even when piggybacked updates are missed.
+//!
+//! The membership state is reflected in the [`RemoteNodeStore`] by the heartbeat: alive members
+//! are `Healthy`, suspected members are `Suspect` and dead members are `Terminated`.
+//! [`ClusterEvent::NodeRemoved`] is published when a member is declared dead.
+//!
+//! [`RemoteSystemConfigBuilder::gossip`]: crate::remote::system::builder::RemoteSystemConfigBuilder::gossip
+//! [`RemoteNodeStore`]: crate::remote::cluster::node::RemoteNodeStore
+//! [`ClusterEvent::NodeRemoved`]: crate::remote::stream::system::ClusterEvent::NodeRemoved
+
+use crate::actor::system::ActorSystem;
+use crate::actor::{IntoActor, LocalActorRef};
+use crate::remote::cluster::gossip::detector::Gossip;
+use crate::remote::cluster::gossip::membership::Membership;
+use crate::remote::system::NodeId;
+use std::time::Duration;
+
+pub mod detector;
+pub mod membership;
+pub(crate) mod rpc;
+
+#[derive(Clone, Debug)]
+pub struct GossipConfig {
+    /// How often a member is probed.
+    pub probe_interval: Duration,
+
+    /// How long to wait for a member to acknowledge a direct probe before asking other members
+    /// to probe it indirectly.
+    pub probe_timeout: Duration,
+
+    /// Number of members asked to probe a member that didn't acknowledge a direct probe.
+    pub indirect_probes: usize,
+
+    /// How long a member remains suspected before it's declared dead.
+    pub suspicion_timeout: Duration,
+
+    /// Each membership update is piggybacked on up to `retransmit_multiplier * log2(members + 1)`
+    /// messages.
+    pub retransmit_multiplier: usize,
+
+    /// Maximum number of membership updates piggybacked on a single message.
+    pub max_piggybacked_updates: usize,
+
+    /// How often membership digests are exchanged with a random member.
+    pub sync_interval: Duration,
+}
+
+/// A handle to the node's gossip actor, and the membership maintained by it.
+#[derive(Clone)]
+pub struct GossipSystem {
+    actor_ref: LocalActorRef<Gossip>,
+    membership: Membership,
+}
+
+impl Default for GossipConfig {
+    fn default() -> Self {
+        Self {
+            probe_interval: Duration::from_secs(1),
+            probe_timeout: Duration::from_millis(500),
+            indirect_probes: 3,
+            suspicion_timeout: Duration::from_secs(5),
+            retransmit_multiplier: 4,
+            max_piggybacked_updates: 16,
+            sync_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+impl GossipSystem {
+    pub async fn start(
+        node_id: NodeId,
+        config: GossipConfig,
+        system: &ActorSystem,
+    ) -> GossipSystem {
+        let membership = Membership::new(node_id);
+        let actor_ref = Gossip::new(node_id, config, membership.clone())
+            .into_actor(Some("gossip"), system)
+            .await
+            .expect("gossip actor");
+
+        GossipSystem {
+            actor_ref,
+            membership,
+        }
+    }
+
+    pub fn actor_ref(&self) -> &LocalActorRef<Gossip> {
+        &self.actor_ref
+    }
+
+    /// The members of the cluster, as seen by this node.
+    pub fn membership(&self) -> &Membership {
+        &self.membership
+    }
+}
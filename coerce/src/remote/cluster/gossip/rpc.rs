@@ -0,0 +1,105 @@
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::{GossipPing, GossipPush, GossipRequest, GossipSync};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use protobuf::Message;
+use std::time::Duration;
+use uuid::Uuid;
+
+const PING: u32 = 1;
+const PING_REQ: u32 = 2;
+const SYNC: u32 = 3;
+const PUSH: u32 = 4;
+
+#[derive(Debug)]
+pub(crate) enum GossipRpcRequest {
+    /// A direct probe, acknowledged by the receiving member.
+    Ping(GossipPing),
+
+    /// Asks the receiving member to probe `target_node_id` on the sender's behalf.
+    PingReq(GossipPing),
+
+    /// Push-pull exchange of membership digests.
+    Sync(GossipSync),
+
+    /// Members requested by the receiving member during a sync.
+    Push(GossipPush),
+}
+
+impl GossipRpcRequest {
+    fn request_type(&self) -> u32 {
+        match self {
+            GossipRpcRequest::Ping(_) => PING,
+            GossipRpcRequest::PingReq(_) => PING_REQ,
+            GossipRpcRequest::Sync(_) => SYNC,
+            GossipRpcRequest::Push(_) => PUSH,
+        }
+    }
+
+    fn write_to_bytes(&self) -> Vec<u8> {
+        let bytes = match self {
+            GossipRpcRequest::Ping(request) => request.write_to_bytes(),
+            GossipRpcRequest::PingReq(request) => request.write_to_bytes(),
+            GossipRpcRequest::Sync(request) => request.write_to_bytes(),
+            GossipRpcRequest::Push(request) => request.write_to_bytes(),
+        };
+
+        bytes.expect("serialise gossip request")
+    }
+
+    pub fn from_proto(request: &GossipRequest) -> Option<GossipRpcRequest> {
+        let payload = request.payload.as_slice();
+        match request.request_type {
+            PING => GossipPing::parse_from_bytes(payload)
+                .ok()
+                .map(GossipRpcRequest::Ping),
+            PING_REQ => GossipPing::parse_from_bytes(payload)
+                .ok()
+                .map(GossipRpcRequest::PingReq),
+            SYNC => GossipSync::parse_from_bytes(payload)
+                .ok()
+                .map(GossipRpcRequest::Sync),
+            PUSH => GossipPush::parse_from_bytes(payload)
+                .ok()
+                .map(GossipRpcRequest::Push),
+            _ => None,
+        }
+    }
+
+    fn into_proto(self, message_id: Uuid) -> GossipRequest {
+        GossipRequest {
+            message_id: message_id.to_string(),
+            request_type: self.request_type(),
+            payload: self.write_to_bytes(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Sends a gossip request to `node_id`, returning `None` if no response was received within `timeout`.
+pub(crate) async fn send<Res: Message>(
+    system: &RemoteActorSystem,
+    node_id: NodeId,
+    request: GossipRpcRequest,
+    timeout: Duration,
+) -> Option<Res> {
+    let message_id = Uuid::new_v4();
+    let event = SessionEvent::Gossip(request.into_proto(message_id));
+
+    match tokio::time::timeout(
+        timeout,
+        system.node_rpc_proto::<Res>(message_id, event, node_id),
+    )
+    .await
+    {
+        Ok(Ok(response)) => Some(response),
+        Ok(Err(e)) => {
+            debug!("gossip request to node_id={} failed, e={:?}", node_id, e);
+            None
+        }
+        Err(_) => {
+            trace!("gossip request to node_id={} timed out", node_id);
+            system.pop_request(message_id);
+            None
+        }
+    }
+}
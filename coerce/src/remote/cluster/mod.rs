@@ -1,4 +1,8 @@
 pub mod builder;
 pub mod client;
 pub mod discovery;
+pub mod events;
+pub mod gossip;
 pub mod node;
+pub mod placement;
+pub mod split_brain;
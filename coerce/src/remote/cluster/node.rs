@@ -5,8 +5,9 @@ use hashring::HashRing;
 use crate::remote::config::SystemCapabilities;
 use crate::remote::net::message::{datetime_to_timestamp, timestamp_to_datetime};
 use crate::remote::net::proto::network;
+use crate::remote::net::protocol::PeerProtocol;
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use std::time::Duration;
@@ -21,6 +22,16 @@ pub enum NodeStatus {
     Joining,
     Healthy,
     Unhealthy,
+
+    /// Suspected of having failed by the gossip failure detector, see [`gossip`].
+    ///
+    /// [`gossip`]: crate::remote::cluster::gossip
+    Suspect,
+
+    /// The node is gracefully leaving the cluster, see [`shutdown`].
+    ///
+    /// [`shutdown`]: crate::remote::shutdown
+    Leaving,
     Terminated,
 }
 
@@ -34,6 +45,19 @@ pub type NodeAttributes = HashMap<Arc<str>, Arc<str>>;
 
 pub type NodeAttributesRef = Arc<NodeAttributes>;
 
+/// Roles a node was started with, see [`RemoteActorSystemBuilder::with_roles`].
+///
+/// [`RemoteActorSystemBuilder::with_roles`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_roles
+pub type NodeRoles = HashSet<Arc<str>>;
+
+pub type NodeRolesRef = Arc<NodeRoles>;
+
+/// The datacenter of nodes that weren't assigned one, see
+/// [`RemoteActorSystemBuilder::with_datacenter`].
+///
+/// [`RemoteActorSystemBuilder::with_datacenter`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_datacenter
+pub const DEFAULT_DATACENTER: &str = "default";
+
 #[derive(Debug, Clone)]
 pub struct RemoteNodeState {
     pub id: NodeId,
@@ -44,6 +68,8 @@ pub struct RemoteNodeState {
     pub node_started_at: Option<DateTime<Utc>>,
     pub status: NodeStatus,
     pub attributes: NodeAttributesRef,
+    pub roles: NodeRolesRef,
+    pub datacenter: Arc<str>,
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +79,8 @@ pub struct RemoteNode {
     pub tag: String,
     pub node_started_at: Option<DateTime<Utc>>,
     pub attributes: NodeAttributesRef,
+    pub roles: NodeRolesRef,
+    pub datacenter: Arc<str>,
 }
 
 impl Hash for RemoteNode {
@@ -69,6 +97,8 @@ pub struct NodeIdentity {
     pub node: RemoteNode,
     pub peers: Vec<RemoteNode>,
     pub capabilities: SystemCapabilities,
+    pub protocol: PeerProtocol,
+    pub application_version: String,
 }
 
 impl RemoteNodeStore {
@@ -99,6 +129,15 @@ impl RemoteNodeStore {
         }
     }
 
+    pub fn node_leaving(&mut self, node_id: NodeId) {
+        let node = self.get_mut(&node_id);
+        if let Some(node) = node {
+            if node.status != NodeStatus::Terminated {
+                node.status = NodeStatus::Leaving;
+            }
+        }
+    }
+
     pub fn get(&self, node_id: &NodeId) -> Option<&RemoteNodeState> {
         self.nodes.get(node_id)
     }
@@ -159,8 +198,18 @@ impl RemoteNodeState {
             last_heartbeat: None,
             status: NodeStatus::Joining,
             attributes: node.attributes.clone(),
+            roles: node.roles,
+            datacenter: node.datacenter,
         }
     }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.contains(role)
+    }
+
+    pub fn in_datacenter(&self, datacenter: &str) -> bool {
+        self.datacenter.as_ref() == datacenter
+    }
 }
 
 impl From<RemoteNodeState> for RemoteNode {
@@ -171,6 +220,8 @@ impl From<RemoteNodeState> for RemoteNode {
             tag: s.tag,
             node_started_at: s.node_started_at,
             attributes: s.attributes.clone(),
+            roles: s.roles,
+            datacenter: s.datacenter,
         }
     }
 }
@@ -188,6 +239,13 @@ impl From<network::RemoteNode> for RemoteNode {
                 .map(|(k, v)| (k.into(), v.into()))
                 .collect::<NodeAttributes>()
                 .into(),
+            roles: n
+                .roles
+                .into_iter()
+                .map(|r| r.into())
+                .collect::<NodeRoles>()
+                .into(),
+            datacenter: datacenter_from_proto(&n.datacenter),
         }
     }
 }
@@ -204,6 +262,8 @@ impl From<RemoteNode> for network::RemoteNode {
                 .iter()
                 .map(|(k, v)| (k.to_string(), v.to_string()))
                 .collect(),
+            roles: n.roles.iter().map(|r| r.to_string()).collect(),
+            datacenter: n.datacenter.to_string(),
             ..Self::default()
         }
     }
@@ -221,6 +281,8 @@ impl From<&RemoteNode> for network::RemoteNode {
                 .iter()
                 .map(|(k, v)| (k.to_string(), v.to_string()))
                 .collect(),
+            roles: n.roles.iter().map(|r| r.to_string()).collect(),
+            datacenter: n.datacenter.to_string(),
             ..Self::default()
         }
     }
@@ -238,6 +300,8 @@ impl From<RemoteNodeState> for network::RemoteNode {
                 .iter()
                 .map(|(k, v)| (k.to_string(), v.to_string()))
                 .collect(),
+            roles: s.roles.iter().map(|r| r.to_string()).collect(),
+            datacenter: s.datacenter.to_string(),
             ..Self::default()
         }
     }
@@ -260,10 +324,27 @@ impl From<&network::NodeIdentity> for RemoteNode {
                 .map(|(k, v)| (k.clone().into(), v.clone().into()))
                 .collect::<NodeAttributes>()
                 .into(),
+            roles: n
+                .roles
+                .iter()
+                .map(|r| r.as_str().into())
+                .collect::<NodeRoles>()
+                .into(),
+            datacenter: datacenter_from_proto(&n.datacenter),
         }
     }
 }
 
+/// Nodes running a version without datacenter support don't send a datacenter, which is
+/// treated as the default datacenter.
+pub(crate) fn datacenter_from_proto(datacenter: &str) -> Arc<str> {
+    if datacenter.is_empty() {
+        DEFAULT_DATACENTER.into()
+    } else {
+        datacenter.into()
+    }
+}
+
 impl Default for RemoteNodeState {
     fn default() -> Self {
         RemoteNodeState {
@@ -275,6 +356,8 @@ impl Default for RemoteNodeState {
             last_heartbeat: None,
             node_started_at: None,
             attributes: Arc::new(NodeAttributes::new()),
+            roles: Arc::new(NodeRoles::new()),
+            datacenter: DEFAULT_DATACENTER.into(),
         }
     }
 }
@@ -286,6 +369,8 @@ impl RemoteNode {
         tag: String,
         node_started_at: Option<DateTime<Utc>>,
         attributes: NodeAttributesRef,
+        roles: NodeRolesRef,
+        datacenter: Arc<str>,
     ) -> RemoteNode {
         RemoteNode {
             id,
@@ -293,8 +378,18 @@ impl RemoteNode {
             tag,
             node_started_at,
             attributes,
+            roles,
+            datacenter,
         }
     }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.contains(role)
+    }
+
+    pub fn in_datacenter(&self, datacenter: &str) -> bool {
+        self.datacenter.as_ref() == datacenter
+    }
 }
 
 impl ToString for RemoteNode {
@@ -0,0 +1,134 @@
+//! Placement strategies, used to select which node an actor is spawned on
+//! via [`RemoteActorSystem::spawn_remote`].
+//!
+//! [`RemoteActorSystem::spawn_remote`]: crate::remote::system::RemoteActorSystem::spawn_remote
+
+use crate::remote::cluster::node::NodeAttributesRef;
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::{GetNodeStatsEvent, NodeStats};
+use crate::remote::system::{NodeId, NodeRpcErr, RemoteActorSystem};
+use futures::future::join_all;
+use rand::seq::SliceRandom;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Placement {
+    /// The node with the fewest tracked actors
+    LeastLoaded,
+
+    /// A random node with the attribute `key` set to `value`
+    Attribute { key: Arc<str>, value: Arc<str> },
+
+    /// A random node
+    Random,
+
+    /// A specific node
+    Node(NodeId),
+}
+
+impl Placement {
+    pub fn attribute(key: impl Into<Arc<str>>, value: impl Into<Arc<str>>) -> Placement {
+        Placement::Attribute {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+struct Candidate {
+    node_id: NodeId,
+    attributes: NodeAttributesRef,
+}
+
+impl RemoteActorSystem {
+    /// Selects a node to place an actor of type `actor_type` on. Only healthy nodes that are able
+    /// to create the actor are considered, unless a specific node was requested.
+    pub async fn select_node(&self, placement: &Placement, actor_type: &str) -> Option<NodeId> {
+        match placement {
+            Placement::Node(node_id) => Some(*node_id),
+
+            Placement::LeastLoaded => {
+                let candidates = self.placement_candidates(actor_type).await;
+                self.least_loaded(&candidates).await
+            }
+
+            Placement::Attribute { key, value } => {
+                let candidates = self.placement_candidates(actor_type).await;
+                let candidates: Vec<&Candidate> = candidates
+                    .iter()
+                    .filter(|c| c.attributes.get(key) == Some(value))
+                    .collect();
+
+                candidates
+                    .choose(&mut rand::thread_rng())
+                    .map(|c| c.node_id)
+            }
+
+            Placement::Random => self
+                .placement_candidates(actor_type)
+                .await
+                .choose(&mut rand::thread_rng())
+                .map(|c| c.node_id),
+        }
+    }
+
+    pub async fn node_stats(&self, node_id: NodeId) -> Result<NodeStats, NodeRpcErr> {
+        if node_id == self.node_id() {
+            return Ok(self.local_node_stats().await);
+        }
+
+        let message_id = Uuid::new_v4();
+        self.node_rpc_proto::<NodeStats>(
+            message_id,
+            SessionEvent::GetNodeStats(GetNodeStatsEvent {
+                message_id: message_id.to_string(),
+                ..Default::default()
+            }),
+            node_id,
+        )
+        .await
+    }
+
+    pub async fn local_node_stats(&self) -> NodeStats {
+        NodeStats {
+            node_id: self.node_id(),
+            actor_count: self.actor_system().actor_count().await as u64,
+            ..Default::default()
+        }
+    }
+
+    async fn placement_candidates(&self, actor_type: &str) -> Vec<Candidate> {
+        let node_id = self.node_id();
+        let mut candidates: Vec<Candidate> = self
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| n.id != node_id && n.status.is_healthy())
+            .filter(|n| self.node_supports_actor(n.id, actor_type))
+            .map(|n| Candidate {
+                node_id: n.id,
+                attributes: n.attributes,
+            })
+            .collect();
+
+        if self.config().actor_handler(actor_type).is_some() {
+            candidates.push(Candidate {
+                node_id,
+                attributes: self.config().get_attributes().clone(),
+            });
+        }
+
+        candidates
+    }
+
+    async fn least_loaded(&self, candidates: &[Candidate]) -> Option<NodeId> {
+        let stats = join_all(candidates.iter().map(|c| self.node_stats(c.node_id))).await;
+
+        stats
+            .into_iter()
+            .filter_map(|stats| stats.ok())
+            .min_by_key(|stats| (stats.actor_count, stats.node_id))
+            .map(|stats| stats.node_id)
+    }
+}
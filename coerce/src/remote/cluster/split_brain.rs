@@ -0,0 +1,206 @@
+//! Split-brain resolution
+//!
+//! Leaders are elected by each node independently (the oldest healthy node), so when the
+//! cluster is partitioned, each side of the partition elects its own leader, and each side's
+//! shard coordinator allocates the same shards, resulting in duplicate entities.
+//!
+//! When a split-brain resolver is configured via [`RemoteSystemConfigBuilder::split_brain_resolver`],
+//! each node waits for the set of unreachable nodes to remain unchanged for the configured
+//! `stable_after` period, and then uses the configured [`SplitBrainStrategy`] to decide which
+//! side of the partition survives. The surviving side downs the unreachable nodes, removing them
+//! from the cluster, and every node on the other side downs itself, stopping its shard hosts and
+//! leaving the cluster via its [`CoordinatedShutdown`], which stops the actor system.
+//!
+//! Every node on both sides of the partition makes the same decision, without needing to
+//! communicate with the other side.
+//!
+//! [`RemoteSystemConfigBuilder::split_brain_resolver`]: crate::remote::system::builder::RemoteSystemConfigBuilder::split_brain_resolver
+//! [`CoordinatedShutdown`]: crate::remote::shutdown::CoordinatedShutdown
+
+use crate::remote::cluster::node::{NodeStatus, RemoteNodeState};
+use crate::remote::system::NodeId;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[derive(Clone, Debug)]
+pub struct SplitBrainResolverConfig {
+    pub strategy: SplitBrainStrategy,
+
+    /// How long the set of reachable and unreachable nodes must remain unchanged before
+    /// a decision is made.
+    pub stable_after: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub enum SplitBrainStrategy {
+    /// Keeps the side with the majority of nodes. If both sides have the same number of nodes,
+    /// the side containing the node with the lowest id is kept.
+    KeepMajority,
+
+    /// Keeps any side with at least `quorum_size` nodes, any side with fewer nodes is downed.
+    /// `quorum_size` should be greater than half of the maximum cluster size.
+    StaticQuorum { quorum_size: usize },
+
+    /// Keeps the side containing the oldest node. If `down_if_alone` is set and the oldest
+    /// node is partitioned from every other node, the oldest node is downed instead.
+    KeepOldest { down_if_alone: bool },
+
+    /// Keeps the side that can reach the node at `referee_addr`, as long as the side has at
+    /// least `down_all_if_less_than_nodes` nodes, otherwise every node is downed.
+    KeepReferee {
+        referee_addr: String,
+        down_all_if_less_than_nodes: usize,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SplitBrainDecision {
+    /// This node is on the surviving side, the unreachable nodes are downed.
+    DownUnreachable,
+
+    /// This node is not on the surviving side, downs itself and leaves the cluster.
+    DownSelf,
+}
+
+/// The members of the cluster, as seen by a single node.
+pub struct ClusterView<'a> {
+    pub self_node_id: NodeId,
+    pub reachable: Vec<&'a RemoteNodeState>,
+    pub unreachable: Vec<&'a RemoteNodeState>,
+}
+
+impl Default for SplitBrainResolverConfig {
+    fn default() -> Self {
+        Self {
+            strategy: SplitBrainStrategy::KeepMajority,
+            stable_after: Duration::from_secs(20),
+        }
+    }
+}
+
+impl<'a> ClusterView<'a> {
+    /// Nodes which haven't responded to a ping yet aren't yet considered members.
+    pub fn new(self_node_id: NodeId, nodes: &'a [RemoteNodeState]) -> ClusterView<'a> {
+        let (reachable, unreachable) = nodes
+            .iter()
+            .filter(|n| n.status != NodeStatus::Joining)
+            .partition(|n| n.id == self_node_id || n.status.is_healthy());
+
+        ClusterView {
+            self_node_id,
+            reachable,
+            unreachable,
+        }
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        !self.unreachable.is_empty()
+    }
+
+    fn member_count(&self) -> usize {
+        self.reachable.len() + self.unreachable.len()
+    }
+}
+
+impl SplitBrainStrategy {
+    pub fn decide(&self, view: &ClusterView) -> SplitBrainDecision {
+        let keep = match self {
+            SplitBrainStrategy::KeepMajority => {
+                let reachable = view.reachable.len() * 2;
+                let members = view.member_count();
+                if reachable == members {
+                    let lowest_id =
+                        |nodes: &Vec<&RemoteNodeState>| nodes.iter().map(|n| n.id).min();
+                    lowest_id(&view.reachable) < lowest_id(&view.unreachable)
+                } else {
+                    reachable > members
+                }
+            }
+
+            SplitBrainStrategy::StaticQuorum { quorum_size } => {
+                view.reachable.len() >= *quorum_size
+            }
+
+            SplitBrainStrategy::KeepOldest { down_if_alone } => {
+                let oldest = view
+                    .reachable
+                    .iter()
+                    .chain(view.unreachable.iter())
+                    .min_by_key(|n| (n.node_started_at.unwrap_or(DateTime::<Utc>::MIN_UTC), n.id))
+                    .map(|n| n.id);
+
+                let oldest_reachable = view.reachable.iter().any(|n| Some(n.id) == oldest);
+                if oldest_reachable {
+                    let oldest_alone = view.reachable.len() == 1 && view.unreachable.len() > 1;
+                    !(*down_if_alone && oldest_alone)
+                } else {
+                    let oldest_alone = view.unreachable.len() == 1 && view.reachable.len() > 1;
+                    *down_if_alone && oldest_alone
+                }
+            }
+
+            SplitBrainStrategy::KeepReferee {
+                referee_addr,
+                down_all_if_less_than_nodes,
+            } => {
+                view.reachable.iter().any(|n| &n.addr == referee_addr)
+                    && view.reachable.len() >= *down_all_if_less_than_nodes
+            }
+        };
+
+        if keep {
+            SplitBrainDecision::DownUnreachable
+        } else {
+            SplitBrainDecision::DownSelf
+        }
+    }
+}
+
+/// Tracks how long the current partition has been stable for, deciding which side survives
+/// once it has been stable for `stable_after`.
+pub(crate) struct SplitBrainResolver {
+    config: SplitBrainResolverConfig,
+    observed: Option<(BTreeSet<NodeId>, BTreeSet<NodeId>)>,
+    stable_since: Instant,
+}
+
+impl SplitBrainResolver {
+    pub fn new(config: SplitBrainResolverConfig) -> SplitBrainResolver {
+        SplitBrainResolver {
+            config,
+            observed: None,
+            stable_since: Instant::now(),
+        }
+    }
+
+    /// Called with the latest state of each node, returns a decision (and the nodes that are
+    /// unreachable) once the partition has been stable for long enough.
+    pub fn on_heartbeat(
+        &mut self,
+        self_node_id: NodeId,
+        nodes: &[RemoteNodeState],
+    ) -> Option<(SplitBrainDecision, Vec<RemoteNodeState>)> {
+        let view = ClusterView::new(self_node_id, nodes);
+        let observed = (
+            view.reachable.iter().map(|n| n.id).collect(),
+            view.unreachable.iter().map(|n| n.id).collect(),
+        );
+
+        if self.observed.as_ref() != Some(&observed) {
+            self.observed = Some(observed);
+            self.stable_since = Instant::now();
+        }
+
+        if !view.is_partitioned() || self.stable_since.elapsed() < self.config.stable_after {
+            return None;
+        }
+
+        let decision = self.config.strategy.decide(&view);
+        let unreachable = view.unreachable.into_iter().cloned().collect();
+
+        self.observed = None;
+        Some((decision, unreachable))
+    }
+}
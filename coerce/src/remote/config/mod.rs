@@ -1,12 +1,24 @@
 use crate::actor::message::Message;
 use crate::actor::Actor;
 use crate::remote::actor::{BoxedActorHandler, BoxedMessageHandler};
-use crate::remote::cluster::node::NodeAttributesRef;
+use crate::remote::cluster::gossip::GossipConfig;
+use crate::remote::cluster::node::{
+    NodeAttributesRef, NodeRoles, NodeRolesRef, DEFAULT_DATACENTER,
+};
+use crate::remote::cluster::split_brain::SplitBrainResolverConfig;
 use crate::remote::handler::{RemoteActorMarker, RemoteActorMessageMarker};
 use crate::remote::heartbeat::HeartbeatConfig;
+use crate::remote::net::config::NetworkConfig;
 use crate::remote::net::security::ClientAuth;
+use crate::remote::net::transport::{TcpTransport, Transport};
+use crate::remote::raft::RaftConfig;
+use crate::remote::shutdown::CoordinatedShutdownConfig;
+
+#[cfg(feature = "remote-tls")]
+use crate::remote::net::security::tls::TlsConfig;
 use std::any::TypeId;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct SystemCapabilities {
@@ -14,6 +26,30 @@ pub struct SystemCapabilities {
     pub messages: Vec<String>,
 }
 
+impl SystemCapabilities {
+    /// Actors and messages supported by this node, but not by `other`.
+    pub fn missing_from(&self, other: &SystemCapabilities) -> SystemCapabilities {
+        SystemCapabilities {
+            actors: self
+                .actors
+                .iter()
+                .filter(|a| !other.actors.contains(a))
+                .cloned()
+                .collect(),
+            messages: self
+                .messages
+                .iter()
+                .filter(|m| !other.messages.contains(m))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actors.is_empty() && self.messages.is_empty()
+    }
+}
+
 pub struct RemoteSystemConfig {
     node_tag: String,
     node_version: String,
@@ -22,13 +58,25 @@ pub struct RemoteSystemConfig {
     message_handlers: HashMap<String, BoxedMessageHandler>,
     actor_handlers: HashMap<String, BoxedActorHandler>,
     heartbeat_config: HeartbeatConfig,
+    split_brain_resolver: Option<SplitBrainResolverConfig>,
+    raft_config: Option<RaftConfig>,
+    gossip_config: Option<GossipConfig>,
+    network_config: NetworkConfig,
+    coordinated_shutdown_config: CoordinatedShutdownConfig,
     node_attributes: NodeAttributesRef,
+    node_roles: NodeRolesRef,
+    leader_roles: NodeRoles,
+    node_datacenter: Arc<str>,
     security: RemoteSystemSecurity,
+    transport: Arc<dyn Transport>,
 }
 
 #[derive(Default)]
 pub struct RemoteSystemSecurity {
     client_auth: ClientAuth,
+
+    #[cfg(feature = "remote-tls")]
+    tls: Option<TlsConfig>,
 }
 
 impl RemoteSystemConfig {
@@ -51,11 +99,61 @@ impl RemoteSystemConfig {
             message_handlers,
             actor_handlers,
             heartbeat_config,
+            split_brain_resolver: None,
+            raft_config: None,
+            gossip_config: None,
+            network_config: NetworkConfig::default(),
+            coordinated_shutdown_config: CoordinatedShutdownConfig::default(),
             node_attributes,
+            node_roles: NodeRolesRef::default(),
+            leader_roles: NodeRoles::default(),
+            node_datacenter: DEFAULT_DATACENTER.into(),
             security,
+            transport: Arc::new(TcpTransport::new()),
         }
     }
 
+    pub fn with_split_brain_resolver(mut self, config: Option<SplitBrainResolverConfig>) -> Self {
+        self.split_brain_resolver = config;
+        self
+    }
+
+    pub fn with_raft(mut self, config: Option<RaftConfig>) -> Self {
+        self.raft_config = config;
+        self
+    }
+
+    pub fn with_gossip(mut self, config: Option<GossipConfig>) -> Self {
+        self.gossip_config = config;
+        self
+    }
+
+    pub fn with_network(mut self, config: NetworkConfig) -> Self {
+        self.network_config = config;
+        self
+    }
+
+    pub fn with_coordinated_shutdown(mut self, config: CoordinatedShutdownConfig) -> Self {
+        self.coordinated_shutdown_config = config;
+        self
+    }
+
+    pub fn with_roles(mut self, roles: NodeRolesRef, leader_roles: NodeRoles) -> Self {
+        self.node_roles = roles;
+        self.leader_roles = leader_roles;
+        self
+    }
+
+    pub fn with_datacenter(mut self, datacenter: Arc<str>) -> Self {
+        self.node_datacenter = datacenter;
+        self
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
     pub fn node_tag(&self) -> &str {
         &self.node_tag
     }
@@ -92,6 +190,26 @@ impl RemoteSystemConfig {
         &self.heartbeat_config
     }
 
+    pub fn split_brain_resolver(&self) -> Option<&SplitBrainResolverConfig> {
+        self.split_brain_resolver.as_ref()
+    }
+
+    pub fn raft_config(&self) -> Option<&RaftConfig> {
+        self.raft_config.as_ref()
+    }
+
+    pub fn gossip_config(&self) -> Option<&GossipConfig> {
+        self.gossip_config.as_ref()
+    }
+
+    pub fn network_config(&self) -> &NetworkConfig {
+        &self.network_config
+    }
+
+    pub fn coordinated_shutdown_config(&self) -> &CoordinatedShutdownConfig {
+        &self.coordinated_shutdown_config
+    }
+
     pub fn get_capabilities(&self) -> SystemCapabilities {
         let mut actors: Vec<String> = self.actor_types.values().map(|a| a.clone()).collect();
         actors.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
@@ -106,17 +224,53 @@ impl RemoteSystemConfig {
         &self.node_attributes
     }
 
+    pub fn get_roles(&self) -> &NodeRolesRef {
+        &self.node_roles
+    }
+
+    pub fn get_datacenter(&self) -> &Arc<str> {
+        &self.node_datacenter
+    }
+
+    pub fn leader_roles(&self) -> &NodeRoles {
+        &self.leader_roles
+    }
+
+    /// Whether a node with the provided `roles` is eligible to become the cluster leader. When no
+    /// leader roles have been configured, every node is eligible.
+    pub fn is_leader_eligible(&self, roles: &NodeRoles) -> bool {
+        self.leader_roles.is_empty() || roles.iter().any(|r| self.leader_roles.contains(r))
+    }
+
     pub fn security(&self) -> &RemoteSystemSecurity {
         &self.security
     }
+
+    pub fn transport(&self) -> &Arc<dyn Transport> {
+        &self.transport
+    }
 }
 
 impl RemoteSystemSecurity {
     pub fn new(client_auth: ClientAuth) -> Self {
-        Self { client_auth }
+        Self {
+            client_auth,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "remote-tls")]
+    pub fn with_tls(mut self, tls: Option<TlsConfig>) -> Self {
+        self.tls = tls;
+        self
     }
 
     pub fn client_authentication(&self) -> &ClientAuth {
         &self.client_auth
     }
+
+    #[cfg(feature = "remote-tls")]
+    pub fn tls(&self) -> Option<&TlsConfig> {
+        self.tls.as_ref()
+    }
 }
@@ -1,4 +1,5 @@
 pub mod health;
+pub mod phi;
 
 use crate::actor::context::ActorContext;
 use crate::actor::message::{Handler, Message};
@@ -6,41 +7,82 @@ use crate::actor::scheduler::timer::{Timer, TimerTick};
 use crate::actor::system::ActorSystem;
 use crate::actor::{Actor, BoxedActorRef, IntoActor, LocalActorRef};
 use crate::actor::{ActorId, CoreActorRef};
-use crate::remote::actor::message::{NodeTerminated, SetRemote};
-use crate::remote::cluster::node::{NodeStatus, RemoteNodeState};
+use crate::remote::actor::message::{NodeLeaving, NodeTerminated, RemoveNode, SetRemote};
+use crate::remote::cluster::gossip::membership::{Member, MemberStatus};
+use crate::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState};
+use crate::remote::cluster::split_brain::{SplitBrainDecision, SplitBrainResolver};
 use crate::remote::net::proto::network::PongEvent;
 use crate::remote::stream::pubsub::PubSub;
-use crate::remote::stream::system::ClusterEvent::LeaderChanged;
+use crate::remote::stream::system::ClusterEvent;
+use crate::remote::stream::system::ClusterEvent::{
+    DatacenterLeaderChanged, LeaderChanged, NodeDowned, NodeRemoved,
+};
 use crate::remote::stream::system::{SystemEvent, SystemTopic};
 use crate::remote::system::{NodeId, RemoteActorSystem};
 use chrono::{DateTime, Utc, MIN_DATETIME};
 
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{HashMap, VecDeque};
 
-use std::ops::Add;
+use std::sync::Arc;
 
 use crate::remote::heartbeat::health::{
     GetHealth, RegisterHealthCheck, RemoveHealthCheck, SystemHealth,
 };
-use std::time::{Duration, Instant};
+use crate::remote::heartbeat::phi::{PhiAccrualConfig, PhiAccrualDetector};
+use std::time::Duration;
 use tokio::sync::oneshot;
 use tokio::sync::oneshot::Sender;
+use tokio::time::Instant;
 
 pub struct Heartbeat {
     system: Option<RemoteActorSystem>,
     heartbeat_timer: Option<Timer>,
-    last_heartbeat: Option<DateTime<Utc>>,
+    last_heartbeat: Option<Instant>,
     node_pings: HashMap<NodeId, NodePing>,
+    last_pongs: HashMap<NodeId, Instant>,
+    failure_detectors: HashMap<NodeId, PhiAccrualDetector>,
     on_next_leader_changed: VecDeque<Sender<NodeId>>,
     health_check_actors: Vec<BoxedActorRef>,
+    split_brain_resolver: Option<SplitBrainResolver>,
+    downed: bool,
 }
 
+#[derive(Clone)]
 pub struct HeartbeatConfig {
     pub interval: Duration,
     pub ping_timeout: Duration,
     pub unhealthy_node_heartbeat_timeout: Duration,
     pub terminated_node_heartbeat_timeout: Duration,
+    pub failure_detector: FailureDetector,
+
+    /// Overrides the timeouts used for nodes in a different datacenter to the current node,
+    /// where links typically have a higher and more variable latency. When not set, the same
+    /// timeouts are used for every node.
+    pub cross_datacenter: Option<CrossDatacenterHeartbeatConfig>,
+}
+
+/// Timeouts used for nodes in a different datacenter, see [`HeartbeatConfig::cross_datacenter`].
+#[derive(Clone, Debug)]
+pub struct CrossDatacenterHeartbeatConfig {
+    pub ping_timeout: Duration,
+    pub unhealthy_node_heartbeat_timeout: Duration,
+    pub terminated_node_heartbeat_timeout: Duration,
+}
+
+/// How the heartbeat decides whether a node is healthy, based on the pings sent to it.
+#[derive(Clone, Debug, Default)]
+pub enum FailureDetector {
+    /// A node is marked as unhealthy once it hasn't responded to a ping within
+    /// `unhealthy_node_heartbeat_timeout`, or took longer than that to respond.
+    #[default]
+    FixedTimeout,
+
+    /// A node is marked as unhealthy once its φ reaches the configured threshold, see [`phi`]
+    /// for more details. Nodes are still marked as terminated once they haven't responded to a
+    /// ping within `terminated_node_heartbeat_timeout`.
+    PhiAccrual(PhiAccrualConfig),
 }
 
 impl Heartbeat {
@@ -50,8 +92,12 @@ impl Heartbeat {
             heartbeat_timer: None,
             last_heartbeat: None,
             node_pings: HashMap::new(),
+            last_pongs: HashMap::new(),
+            failure_detectors: HashMap::new(),
             on_next_leader_changed: VecDeque::new(),
             health_check_actors: Vec::new(),
+            split_brain_resolver: None,
+            downed: false,
         }
         .into_actor(Some("heartbeat"), sys)
         .await
@@ -107,6 +153,12 @@ impl Handler<SetRemote> for Heartbeat {
             actors.push(stream_mediator.clone().into());
         }
 
+        self.split_brain_resolver = system
+            .config()
+            .split_brain_resolver()
+            .cloned()
+            .map(SplitBrainResolver::new);
+
         self.system = Some(system);
         self.health_check_actors = actors;
 
@@ -121,6 +173,38 @@ impl Default for HeartbeatConfig {
             ping_timeout: Duration::from_secs(15),
             unhealthy_node_heartbeat_timeout: Duration::from_millis(1500),
             terminated_node_heartbeat_timeout: Duration::from_secs(30),
+            failure_detector: FailureDetector::FixedTimeout,
+            cross_datacenter: None,
+        }
+    }
+}
+
+impl Default for CrossDatacenterHeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            ping_timeout: Duration::from_secs(30),
+            unhealthy_node_heartbeat_timeout: Duration::from_secs(5),
+            terminated_node_heartbeat_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl HeartbeatConfig {
+    /// The configuration used for a node in `datacenter`, as seen from a node in
+    /// `local_datacenter`.
+    pub fn for_link(&self, local_datacenter: &str, datacenter: &str) -> Cow<'_, HeartbeatConfig> {
+        match &self.cross_datacenter {
+            Some(cross_datacenter) if local_datacenter != datacenter => {
+                Cow::Owned(HeartbeatConfig {
+                    ping_timeout: cross_datacenter.ping_timeout,
+                    unhealthy_node_heartbeat_timeout: cross_datacenter
+                        .unhealthy_node_heartbeat_timeout,
+                    terminated_node_heartbeat_timeout: cross_datacenter
+                        .terminated_node_heartbeat_timeout,
+                    ..self.clone()
+                })
+            }
+            _ => Cow::Borrowed(self),
         }
     }
 }
@@ -136,7 +220,7 @@ impl TimerTick for HeartbeatTick {}
 
 #[derive(Debug, Clone)]
 pub enum PingResult {
-    Ok(PongEvent, Duration, DateTime<Utc>),
+    Ok(PongEvent, Duration, Instant),
     Timeout,
     Disconnected,
     Err,
@@ -160,6 +244,14 @@ impl Message for NodePing {
 
 pub struct OnLeaderChanged(pub Sender<NodeId>);
 
+/// Sent by the Raft module when a leader has been elected, which replaces the leader chosen by
+/// the heartbeat when Raft is enabled, or with `None` once the node no longer knows of a leader.
+pub(crate) struct LeaderElected(pub Option<NodeId>);
+
+impl Message for LeaderElected {
+    type Result = ();
+}
+
 impl Actor for Heartbeat {}
 
 impl Message for OnLeaderChanged {
@@ -169,6 +261,20 @@ impl Message for OnLeaderChanged {
 #[async_trait]
 impl Handler<NodePing> for Heartbeat {
     async fn handle(&mut self, message: NodePing, _ctx: &mut ActorContext) {
+        if let (Some(system), PingResult::Ok(_, _, pong_received_at)) = (&self.system, &message.1) {
+            self.last_pongs.insert(message.0, *pong_received_at);
+
+            let heartbeat_config = system.config().heartbeat_config();
+            if let FailureDetector::PhiAccrual(config) = &heartbeat_config.failure_detector {
+                self.failure_detectors
+                    .entry(message.0)
+                    .or_insert_with(|| {
+                        PhiAccrualDetector::new(config.clone(), heartbeat_config.interval)
+                    })
+                    .heartbeat(*pong_received_at);
+            }
+        }
+
         let _ = self.node_pings.insert(message.0, message);
     }
 }
@@ -179,13 +285,32 @@ impl Handler<NodeTerminated> for Heartbeat {
         let node_id = message.0;
         if let Some(system) = &self.system {
             let _ = system.registry().send(message).await;
+            system.on_watched_node_terminated(node_id);
         }
 
         self.node_pings.remove(&node_id);
+        self.last_pongs.remove(&node_id);
+        self.failure_detectors.remove(&node_id);
         self.handle(HeartbeatTick, ctx).await;
     }
 }
 
+#[async_trait]
+impl Handler<NodeLeaving> for Heartbeat {
+    async fn handle(&mut self, message: NodeLeaving, _ctx: &mut ActorContext) {
+        let node_id = message.0;
+        if let Some(system) = &self.system {
+            let _ = system.registry().send(message).await;
+            let _ = PubSub::publish_locally(
+                SystemTopic,
+                SystemEvent::Cluster(ClusterEvent::NodeLeaving(node_id)),
+                system,
+            )
+            .await;
+        }
+    }
+}
+
 #[async_trait]
 impl Handler<OnLeaderChanged> for Heartbeat {
     async fn handle(&mut self, message: OnLeaderChanged, _ctx: &mut ActorContext) {
@@ -193,6 +318,34 @@ impl Handler<OnLeaderChanged> for Heartbeat {
     }
 }
 
+#[async_trait]
+impl Handler<LeaderElected> for Heartbeat {
+    async fn handle(&mut self, message: LeaderElected, _ctx: &mut ActorContext) {
+        let system = self.system.as_ref().unwrap();
+        match message.0 {
+            Some(leader_id) if Some(leader_id) != system.current_leader() => {
+                info!(
+                    "[node={}] raft leader of cluster: {}",
+                    system.node_id(),
+                    leader_id
+                );
+
+                self.update_leader(leader_id);
+            }
+            None => {
+                if let Some(leader_id) = system.clear_leader() {
+                    info!(
+                        "[node={}] raft leader lost (previous leader: {})",
+                        system.node_id(),
+                        leader_id
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 #[async_trait]
 impl Handler<HeartbeatTick> for Heartbeat {
     async fn handle(&mut self, _msg: HeartbeatTick, _ctx: &mut ActorContext) {
@@ -216,12 +369,17 @@ impl Handler<HeartbeatTick> for Heartbeat {
         );
 
         let mut new_leader_id = None;
+        let mut new_datacenter_leader_id = None;
         let mut updates = vec![];
 
         for node in nodes {
             if node.id == current_node {
                 let mut node = node;
-                node.status = NodeStatus::Healthy;
+                node.status = if system.is_leaving() {
+                    NodeStatus::Leaving
+                } else {
+                    NodeStatus::Healthy
+                };
                 node.last_heartbeat = Some(Utc::now());
                 updates.push(node);
 
@@ -229,12 +387,34 @@ impl Handler<HeartbeatTick> for Heartbeat {
             }
 
             let node_id = node.id;
-            updates.push(update_node(
-                current_node,
-                node,
-                self.node_pings.get(&node_id).map(|r| r.1.clone()),
-                system.config().heartbeat_config(),
-            ));
+            let previous_status = node.status;
+
+            // when gossip is enabled, nodes aren't pinged, the node status is derived from the
+            // gossip membership instead
+            let mut node = if let Some(gossip) = system.gossip() {
+                update_node_from_member(current_node, node, gossip.membership().get(node_id))
+            } else {
+                let heartbeat_config = system
+                    .config()
+                    .heartbeat_config()
+                    .for_link(system.datacenter(), &node.datacenter);
+
+                update_node(
+                    current_node,
+                    node,
+                    self.node_pings.get(&node_id).map(|r| r.1.clone()),
+                    self.last_pongs.get(&node_id).copied(),
+                    self.failure_detectors.get(&node_id),
+                    &heartbeat_config,
+                )
+            };
+
+            // a node that's leaving the cluster remains `Leaving` until it has terminated
+            if previous_status == NodeStatus::Leaving && node.status != NodeStatus::Terminated {
+                node.status = NodeStatus::Leaving;
+            }
+
+            updates.push(node);
         }
 
         trace!(
@@ -254,8 +434,12 @@ impl Handler<HeartbeatTick> for Heartbeat {
             }
         });
 
-        if self.last_heartbeat.is_some() {
-            let oldest_healthy_node = updates.iter().filter(|n| n.status.is_healthy()).next();
+        // when raft is enabled, the leader is elected by raft instead
+        if self.last_heartbeat.is_some() && !self.downed && system.raft().is_none() {
+            let config = system.config();
+            let oldest_healthy_node = updates
+                .iter()
+                .find(|n| n.status.is_healthy() && config.is_leader_eligible(&n.roles));
 
             match oldest_healthy_node {
                 None => {}
@@ -274,16 +458,138 @@ impl Handler<HeartbeatTick> for Heartbeat {
             }
         }
 
+        // each datacenter has its own leader, chosen the same way as the cluster leader, but only
+        // from the nodes within the datacenter
+        if self.last_heartbeat.is_some() && !self.downed {
+            let config = system.config();
+            let datacenter = system.datacenter();
+            let oldest_healthy_node = updates.iter().find(|n| {
+                n.status.is_healthy()
+                    && n.in_datacenter(datacenter)
+                    && config.is_leader_eligible(&n.roles)
+            });
+
+            if let Some(oldest_healthy_node) = oldest_healthy_node {
+                if Some(oldest_healthy_node.id) != system.datacenter_leader() {
+                    info!(
+                        "[node={}] leader of datacenter {}: {}",
+                        system.node_id(),
+                        datacenter,
+                        oldest_healthy_node.id
+                    );
+
+                    new_datacenter_leader_id = Some(oldest_healthy_node.id)
+                }
+            }
+        }
+
+        for node in updates
+            .iter()
+            .filter(|n| n.status == NodeStatus::Terminated)
+        {
+            system.on_watched_node_terminated(node.id);
+        }
+
+        let split_brain_decision = match &mut self.split_brain_resolver {
+            Some(resolver) if !self.downed => resolver.on_heartbeat(current_node, &updates),
+            _ => None,
+        };
+
         system.update_nodes(updates).await;
-        self.last_heartbeat = Some(Utc::now());
+        self.last_heartbeat = Some(Instant::now());
+
+        if let Some((decision, unreachable)) = split_brain_decision {
+            self.resolve_split_brain(decision, unreachable).await;
+            return;
+        }
 
         if let Some(new_leader_id) = new_leader_id {
             self.update_leader(new_leader_id);
         }
+
+        if let Some(new_datacenter_leader_id) = new_datacenter_leader_id {
+            self.update_datacenter_leader(new_datacenter_leader_id);
+        }
     }
 }
 
 impl Heartbeat {
+    async fn resolve_split_brain(
+        &mut self,
+        decision: SplitBrainDecision,
+        unreachable: Vec<RemoteNodeState>,
+    ) {
+        let system = self.system.as_ref().unwrap().clone();
+        let node_id = system.node_id();
+
+        match decision {
+            SplitBrainDecision::DownUnreachable => {
+                for node in unreachable {
+                    warn!(
+                        "[node={}] downing unreachable node_id={}, addr={}",
+                        node_id, node.id, &node.addr
+                    );
+
+                    let _ = system.registry().send(RemoveNode(node.id)).await;
+                    self.node_pings.remove(&node.id);
+                    self.last_pongs.remove(&node.id);
+                    self.failure_detectors.remove(&node.id);
+                    system.on_watched_node_terminated(node.id);
+
+                    let node: RemoteNode = node.into();
+                    let _ = PubSub::publish_locally(
+                        SystemTopic,
+                        SystemEvent::Cluster(NodeDowned(node.id)),
+                        &system,
+                    )
+                    .await;
+
+                    let _ = PubSub::publish_locally(
+                        SystemTopic,
+                        SystemEvent::Cluster(NodeRemoved(Arc::new(node))),
+                        &system,
+                    )
+                    .await;
+                }
+            }
+
+            SplitBrainDecision::DownSelf => {
+                error!(
+                    "[node={}] node is on the losing side of a network partition, downing self (unreachable_nodes={:?})",
+                    node_id,
+                    unreachable.iter().map(|n| n.id).collect::<Vec<_>>()
+                );
+
+                self.downed = true;
+                let _ = PubSub::publish_locally(
+                    SystemTopic,
+                    SystemEvent::Cluster(NodeDowned(node_id)),
+                    &system,
+                )
+                .await;
+
+                // the node leaves the cluster via the coordinated shutdown, which stops the
+                // actor system (including the heartbeat), so it can't be awaited from here
+                tokio::spawn(async move { system.leave().await });
+            }
+        }
+    }
+
+    fn update_datacenter_leader(&mut self, node_id: NodeId) {
+        let system = self.system.as_ref().unwrap();
+        system.update_datacenter_leader(node_id);
+
+        let sys = system.clone();
+        tokio::spawn(async move {
+            let _ = PubSub::publish_locally(
+                SystemTopic,
+                SystemEvent::Cluster(DatacenterLeaderChanged(node_id)),
+                &sys,
+            )
+            .await;
+        });
+    }
+
     fn update_leader(&mut self, node_id: NodeId) {
         let system = self.system.as_ref().unwrap();
         system.update_leader(node_id);
@@ -308,13 +614,15 @@ fn update_node(
     node_id: NodeId,
     mut node: RemoteNodeState,
     ping: Option<PingResult>,
+    last_pong: Option<Instant>,
+    failure_detector: Option<&PhiAccrualDetector>,
     heartbeat_config: &HeartbeatConfig,
 ) -> RemoteNodeState {
     match &ping {
         None => {}
         Some(ping) => match ping {
             PingResult::Ok(_pong, ping_latency, pong_received_at) => {
-                node.last_heartbeat = Some(*pong_received_at);
+                node.last_heartbeat = Some(to_datetime(*pong_received_at));
                 node.ping_latency = Some(*ping_latency);
             }
             PingResult::Timeout | PingResult::Disconnected | PingResult::Err => {
@@ -323,29 +631,124 @@ fn update_node(
         },
     }
 
-    node.status = node_status(
-        node_id,
-        node.id,
-        node.status,
-        &node.last_heartbeat,
-        ping,
-        &heartbeat_config,
-    );
+    node.status = match (&ping, failure_detector) {
+        (Some(PingResult::Disconnected), _) | (None, _) | (_, None) => node_status(
+            node_id,
+            node.id,
+            node.status,
+            last_pong,
+            ping,
+            heartbeat_config,
+        ),
+
+        (Some(_), Some(failure_detector)) => phi_node_status(
+            node_id,
+            node.id,
+            node.status,
+            failure_detector,
+            heartbeat_config,
+        ),
+    };
 
     node
 }
 
+fn phi_node_status(
+    node_id: NodeId,
+    peer_node_id: NodeId,
+    previous_status: NodeStatus,
+    failure_detector: &PhiAccrualDetector,
+    config: &HeartbeatConfig,
+) -> NodeStatus {
+    let now = Instant::now();
+    let time_since_heartbeat = failure_detector
+        .last_heartbeat()
+        .map(|h| now.saturating_duration_since(h))
+        .unwrap_or_default();
+
+    if time_since_heartbeat >= config.terminated_node_heartbeat_timeout {
+        error!(
+            "[node={}] node_id={} has not responded to a ping in {} millis, marking node as terminated",
+            node_id,
+            peer_node_id,
+            time_since_heartbeat.as_millis()
+        );
+
+        NodeStatus::Terminated
+    } else if !failure_detector.is_available(now) {
+        if previous_status != NodeStatus::Unhealthy {
+            warn!(
+                "[node={}] node_id={} has not responded to a ping in {} millis (phi={:.2}), marking as unhealthy",
+                node_id,
+                peer_node_id,
+                time_since_heartbeat.as_millis(),
+                failure_detector.phi(now)
+            );
+        }
+
+        NodeStatus::Unhealthy
+    } else {
+        if previous_status != NodeStatus::Healthy {
+            info!(
+                "[node={}] remote node_id={} is now healthy",
+                node_id, peer_node_id
+            );
+        }
+
+        NodeStatus::Healthy
+    }
+}
+
+fn update_node_from_member(
+    node_id: NodeId,
+    mut node: RemoteNodeState,
+    member: Option<Member>,
+) -> RemoteNodeState {
+    let member = match member {
+        Some(member) if member.node.node_started_at >= node.node_started_at => member,
+        _ => return node,
+    };
+
+    let status = match member.status {
+        MemberStatus::Alive => NodeStatus::Healthy,
+        MemberStatus::Suspect => NodeStatus::Suspect,
+        MemberStatus::Dead => NodeStatus::Terminated,
+    };
+
+    if status != node.status {
+        match status {
+            NodeStatus::Healthy => info!(
+                "[node={}] remote node_id={} is now healthy",
+                node_id, node.id
+            ),
+            NodeStatus::Suspect => warn!(
+                "[node={}] node_id={} is suspected of having failed (incarnation={})",
+                node_id, node.id, member.incarnation
+            ),
+            _ => error!(
+                "[node={}] node_id={} was declared dead, marking node as terminated",
+                node_id, node.id
+            ),
+        }
+    }
+
+    node.status = status;
+    node.last_heartbeat = member.last_ack.or(node.last_heartbeat);
+    node.ping_latency = member.rtt;
+    node
+}
+
 fn node_status(
     node_id: NodeId,
     peer_node_id: NodeId,
     previous_status: NodeStatus,
-    last_heartbeat: &Option<DateTime<Utc>>,
+    last_pong: Option<Instant>,
     ping: Option<PingResult>,
     config: &HeartbeatConfig,
 ) -> NodeStatus {
     match ping {
         Some(PingResult::Ok(_, ping_latency, pong_received_at)) => {
-            let time_since_ping = (Utc::now() - pong_received_at).to_std().unwrap();
+            let time_since_ping = pong_received_at.elapsed();
 
             if time_since_ping >= config.terminated_node_heartbeat_timeout {
                 error!(
@@ -396,10 +799,10 @@ fn node_status(
         }
 
         Some(PingResult::Timeout) => {
-            let terminated = last_heartbeat.map_or(true, |h| {
-                h.add(chrono::Duration::from_std(config.terminated_node_heartbeat_timeout).unwrap())
-                    >= Utc::now()
-            });
+            let terminated = match last_pong {
+                Some(last_pong) => last_pong.elapsed() >= config.terminated_node_heartbeat_timeout,
+                None => true,
+            };
 
             if terminated {
                 error!(
@@ -440,3 +843,11 @@ fn node_status(
         }
     }
 }
+
+/// The wall-clock time of `instant`, which is only used to report when a node last responded,
+/// liveness is always decided using the monotonic [`Instant`].
+fn to_datetime(instant: Instant) -> DateTime<Utc> {
+    let elapsed =
+        chrono::Duration::from_std(instant.elapsed()).unwrap_or_else(|_| chrono::Duration::zero());
+    Utc::now() - elapsed
+}
@@ -0,0 +1,146 @@
+//! Phi-accrual failure detection, as described by Hayashibara et al. in
+//! "The φ Accrual Failure Detector".
+//!
+//! Rather than deciding whether a node is available using a fixed timeout, the detector keeps a
+//! history of the intervals between the pongs received from the node, and uses it to compute φ,
+//! a measure of how suspicious it is that no pong has been received since the last one. A φ of
+//! 1 means the likelihood of a false positive is ~10%, 2 means ~1%, 3 means ~0.1% and so on.
+//!
+//! Since the expected interval adapts to the latency actually observed, the detector is far less
+//! prone to flapping on noisy networks than fixed timeouts are.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[derive(Clone, Debug)]
+pub struct PhiAccrualConfig {
+    /// A node is marked as unhealthy once its φ reaches the threshold.
+    pub threshold: f64,
+
+    /// Number of intervals kept in the history of each node.
+    pub max_sample_size: usize,
+
+    /// Lower bound of the standard deviation used when computing φ, so that a history of very
+    /// consistent intervals doesn't make the detector overly sensitive.
+    pub min_std_deviation: Duration,
+
+    /// Pauses of up to this duration (for example, caused by a busy runtime or a brief network
+    /// blip) are tolerated without φ increasing.
+    pub acceptable_heartbeat_pause: Duration,
+}
+
+impl Default for PhiAccrualConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 8.0,
+            max_sample_size: 200,
+            min_std_deviation: Duration::from_millis(100),
+            acceptable_heartbeat_pause: Duration::from_secs(3),
+        }
+    }
+}
+
+/// The heartbeat history of a single node.
+#[derive(Clone, Debug)]
+pub struct PhiAccrualDetector {
+    config: PhiAccrualConfig,
+    first_heartbeat_estimate: Duration,
+    intervals: VecDeque<f64>,
+    interval_sum: f64,
+    interval_squared_sum: f64,
+    last_heartbeat: Option<Instant>,
+}
+
+impl PhiAccrualDetector {
+    /// Creates a detector, with the history bootstrapped using `first_heartbeat_estimate`
+    /// (typically the heartbeat interval) once the first heartbeat is received.
+    pub fn new(config: PhiAccrualConfig, first_heartbeat_estimate: Duration) -> Self {
+        Self {
+            config,
+            first_heartbeat_estimate,
+            intervals: VecDeque::new(),
+            interval_sum: 0.0,
+            interval_squared_sum: 0.0,
+            last_heartbeat: None,
+        }
+    }
+
+    pub fn last_heartbeat(&self) -> Option<Instant> {
+        self.last_heartbeat
+    }
+
+    /// Records a heartbeat (a pong) received at `received_at`.
+    pub fn heartbeat(&mut self, received_at: Instant) {
+        match self.last_heartbeat {
+            None => {
+                // bootstrap the history with a mean of the estimate and a large standard
+                // deviation, which is quickly replaced by the intervals actually observed
+                let mean = self.first_heartbeat_estimate.as_secs_f64() * 1000.0;
+                let std_deviation = mean / 4.0;
+
+                self.record(mean - std_deviation);
+                self.record(mean + std_deviation);
+            }
+
+            Some(last_heartbeat) if received_at > last_heartbeat => {
+                let interval = received_at.duration_since(last_heartbeat);
+                self.record(interval.as_secs_f64() * 1000.0);
+            }
+
+            Some(_) => return,
+        }
+
+        self.last_heartbeat = Some(received_at);
+    }
+
+    /// The suspicion level of the node at `now`, 0.0 if no heartbeat has been received yet.
+    pub fn phi(&self, now: Instant) -> f64 {
+        let last_heartbeat = match self.last_heartbeat {
+            Some(last_heartbeat) => last_heartbeat,
+            None => return 0.0,
+        };
+
+        let elapsed = now.saturating_duration_since(last_heartbeat).as_secs_f64() * 1000.0;
+
+        let samples = self.intervals.len() as f64;
+        let mean = self.interval_sum / samples;
+        let variance = (self.interval_squared_sum / samples) - (mean * mean);
+        let min_std_deviation = self.config.min_std_deviation.as_secs_f64() * 1000.0;
+        let std_deviation = variance.max(0.0).sqrt().max(min_std_deviation);
+
+        let mean = mean + self.config.acceptable_heartbeat_pause.as_secs_f64() * 1000.0;
+        phi(elapsed, mean, std_deviation)
+    }
+
+    pub fn is_available(&self, now: Instant) -> bool {
+        self.phi(now) < self.config.threshold
+    }
+
+    fn record(&mut self, interval: f64) {
+        if self.intervals.len() >= self.config.max_sample_size.max(1) {
+            if let Some(oldest) = self.intervals.pop_front() {
+                self.interval_sum -= oldest;
+                self.interval_squared_sum -= oldest * oldest;
+            }
+        }
+
+        self.intervals.push_back(interval);
+        self.interval_sum += interval;
+        self.interval_squared_sum += interval * interval;
+    }
+}
+
+/// φ for the time elapsed since the last heartbeat, assuming the intervals are normally
+/// distributed. The cumulative distribution function is approximated using a logistic function,
+/// which is accurate to within 0.0002 and avoids computing the error function.
+fn phi(elapsed: f64, mean: f64, std_deviation: f64) -> f64 {
+    let y = (elapsed - mean) / std_deviation;
+    let e = (-y * (1.5976 + 0.070566 * y * y)).exp();
+
+    if elapsed > mean {
+        -(e / (1.0 + e)).log10()
+    } else {
+        -(1.0 - 1.0 / (1.0 + e)).log10()
+    }
+}
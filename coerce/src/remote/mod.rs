@@ -97,6 +97,7 @@ pub mod handler;
 pub mod heartbeat;
 pub mod net;
 pub mod raft;
+pub mod shutdown;
 pub mod stream;
 pub mod system;
 pub mod tracing;
@@ -0,0 +1,148 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::Actor;
+use crate::remote::net::client::connect::Disconnected;
+use crate::remote::net::client::{ClientState, RemoteClient};
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::BatchEvent;
+use crate::remote::net::protocol::FEATURE_BATCHING;
+use crate::remote::net::StreamData;
+use crate::remote::system::RemoteActorSystem;
+use bytes::Bytes;
+
+pub struct FlushBatch;
+
+impl Message for FlushBatch {
+    type Result = ();
+}
+
+#[derive(Default)]
+pub(crate) struct PendingBatch {
+    events: Vec<Vec<u8>>,
+    bytes: usize,
+    flush_scheduled: bool,
+}
+
+#[async_trait]
+impl Handler<FlushBatch> for RemoteClient {
+    async fn handle(&mut self, _: FlushBatch, ctx: &mut ActorContext) {
+        self.flush_batch(ctx).await;
+    }
+}
+
+impl RemoteClient {
+    /// Whether writes should be coalesced into batches, only when enabled locally
+    /// and supported by the connected peer.
+    pub(crate) fn batching_enabled(&self) -> bool {
+        if !self.batching_config.enabled {
+            return false;
+        }
+
+        match &self.state {
+            Some(ClientState::Connected(state)) => state.protocol.supports(FEATURE_BATCHING),
+            _ => false,
+        }
+    }
+
+    pub(crate) async fn batch_message(&mut self, message_bytes: Vec<u8>, ctx: &mut ActorContext) {
+        self.pending_batch.bytes += message_bytes.len();
+        self.pending_batch.events.push(message_bytes);
+
+        if self.pending_batch.events.len() >= self.batching_config.max_batch_messages
+            || self.pending_batch.bytes >= self.batching_config.max_batch_bytes
+        {
+            self.flush_batch(ctx).await;
+        } else if !self.pending_batch.flush_scheduled {
+            self.pending_batch.flush_scheduled = true;
+
+            let actor_ref = self.actor_ref(ctx);
+            let linger = self.batching_config.linger;
+            if linger.is_zero() {
+                let _ = actor_ref.notify(FlushBatch);
+            } else {
+                tokio::spawn(async move {
+                    tokio::time::sleep(linger).await;
+                    let _ = actor_ref.notify(FlushBatch);
+                });
+            }
+        }
+    }
+
+    pub async fn flush_batch(&mut self, ctx: &mut ActorContext) {
+        let remote = ctx.system().remote_owned();
+        if !self.write_batch(&remote).await {
+            info!("stream write error, handling `Disconnected`");
+            self.handle(Disconnected, ctx).await;
+        }
+    }
+
+    /// Writes the pending batch as a single frame, returning false if the connection failed,
+    /// in which case the batched messages are moved to the write buffer.
+    pub(crate) async fn write_batch(&mut self, remote: &RemoteActorSystem) -> bool {
+        self.pending_batch.flush_scheduled = false;
+        if self.pending_batch.events.is_empty() {
+            return true;
+        }
+
+        let mut events = std::mem::take(&mut self.pending_batch.events);
+        self.pending_batch.bytes = 0;
+
+        let frame = if events.len() == 1 {
+            events.pop().unwrap()
+        } else {
+            trace!(
+                "writing batch (addr={}, events={})",
+                &self.addr,
+                events.len()
+            );
+
+            let batch = SessionEvent::Batch(BatchEvent {
+                events,
+                ..Default::default()
+            });
+
+            match batch.write_to_bytes() {
+                Some(frame) => frame,
+                None => {
+                    warn!("failed to encode batch (addr={})", &self.addr);
+                    return true;
+                }
+            }
+        };
+
+        let frame = Bytes::from(frame);
+        let write_result = match &mut self.state {
+            Some(ClientState::Connected(state)) => state.write_frame(frame.clone()).await.is_ok(),
+            _ => false,
+        };
+
+        if !write_result {
+            warn!(
+                "failed to write batch (addr={}), buffering messages",
+                &self.addr
+            );
+
+            for event in unbatch(frame.to_vec()) {
+                self.buffer_message(event, remote);
+            }
+        }
+
+        write_result
+    }
+
+    /// Moves any messages waiting to be batched to the write buffer, so they're written
+    /// (in order) once the connection is re-established.
+    pub(crate) fn buffer_pending_batch(&mut self, remote: &RemoteActorSystem) {
+        self.pending_batch.bytes = 0;
+        for event in std::mem::take(&mut self.pending_batch.events) {
+            self.buffer_message(event, remote);
+        }
+    }
+}
+
+fn unbatch(frame: Vec<u8>) -> Vec<Vec<u8>> {
+    match SessionEvent::read_from_bytes(frame.clone()) {
+        Some(SessionEvent::Batch(batch)) => batch.events,
+        _ => vec![frame],
+    }
+}
@@ -5,6 +5,7 @@ use crate::actor::{Actor, LocalActorRef};
 use crate::remote::actor::message::ClientConnected;
 use crate::remote::cluster::discovery::{Discover, Seed};
 use crate::remote::cluster::node::RemoteNode;
+use crate::remote::net::client::lane::ConnectionLane;
 use crate::remote::net::client::ping::PingTick;
 use crate::remote::net::client::receive::{ClientMessageReceiver, HandshakeAcknowledge};
 use crate::remote::net::client::send::write_bytes;
@@ -12,6 +13,7 @@ use crate::remote::net::client::{
     BeginHandshake, ClientState, ConnectionState, HandshakeAckCallback, HandshakeStatus,
     RemoteClient,
 };
+use crate::remote::net::compression::FrameCompression;
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network::{self as proto, IdentifyEvent};
 use crate::remote::net::{receive_loop, StreamData};
@@ -19,10 +21,8 @@ use crate::remote::net::{receive_loop, StreamData};
 use bytes::Bytes;
 use protobuf::EnumOrUnknown;
 use std::time::Duration;
-use tokio::net::TcpStream;
 use tokio::sync::oneshot;
 use tokio::sync::oneshot::Sender;
-use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
 use valuable::Valuable;
 
 pub struct Connect;
@@ -36,27 +36,26 @@ impl RemoteClient {
         ctx: &mut ActorContext,
     ) -> Option<ConnectionState> {
         let log_ctx = ctx.log();
-        let stream = TcpStream::connect(&self.addr).await;
-        if stream.is_err() {
-            let error = stream.unwrap_err();
-            error!(
-                ctx = log_ctx.as_value(),
-                "connection to {} failed, error: {}", &self.addr, error
-            );
-            return None;
-        }
+        let remote = ctx.system().remote_owned();
+        let network_config = remote.config().network_config();
+        let mut connection = match remote.config().transport().connect(&self.addr).await {
+            Ok(connection) => connection,
+            Err(error) => {
+                error!(
+                    ctx = log_ctx.as_value(),
+                    "connection to {} failed, error: {}", &self.addr, error
+                );
+                return None;
+            }
+        };
 
-        let stream = stream.unwrap();
-        let (read, writer) = tokio::io::split(stream);
+        connection.set_max_frame_length(network_config.max_frame_length);
 
-        let codec = LengthDelimitedCodec::new();
-        let reader = FramedRead::new(read, codec.clone());
-        let mut write = FramedWrite::new(writer, codec.clone());
+        let reader = connection.reader;
+        let mut write = connection.writer;
 
         let (identity_tx, identity_rx) = oneshot::channel();
 
-        let remote = ctx.system().remote_owned();
-
         let identify = SessionEvent::Identify(IdentifyEvent {
             source_node_id: remote.node_id(),
             source_node_tag: remote.node_tag().to_string(),
@@ -65,6 +64,8 @@ impl RemoteClient {
                 .security()
                 .client_authentication()
                 .generate_token(),
+            protocol: Some((&network_config.protocol).into()).into(),
+            application_version: remote.config().node_version().to_string(),
             ..Default::default()
         });
 
@@ -84,14 +85,23 @@ impl RemoteClient {
         let receive_task = tokio::spawn(receive_loop(
             remote.clone(),
             reader,
-            ClientMessageReceiver::new(self.actor_ref(ctx), identity_tx, self.addr.clone()),
+            ClientMessageReceiver::new(
+                self.actor_ref(ctx),
+                identity_tx,
+                self.addr.clone(),
+                network_config.max_decode_errors,
+            ),
         ));
 
-        self.ping_timer = Some(Timer::start_immediately(
-            self.actor_ref(ctx),
-            ctx.system().remote().config().heartbeat_config().interval,
-            PingTick,
-        ));
+        // only the control connection is used for pings, other lanes share its health. When gossip
+        // is enabled, members are probed by the gossip failure detector instead
+        if self.lane == ConnectionLane::Control && remote.gossip().is_none() {
+            self.ping_timer = Some(Timer::start_immediately(
+                self.actor_ref(ctx),
+                ctx.system().remote().config().heartbeat_config().interval,
+                PingTick,
+            ));
+        }
 
         let identity = match identity_rx.await {
             Ok(identity) => identity,
@@ -104,8 +114,56 @@ impl RemoteClient {
             }
         };
 
+        let protocol = match network_config.protocol.negotiate(&identity.protocol) {
+            Ok(protocol) => protocol,
+            Err(error) => {
+                error!(
+                    ctx = log_ctx.as_value(),
+                    "incompatible peer (addr={}, node_id={}, application_version={}), error: {}",
+                    &self.addr,
+                    identity.node.id,
+                    &identity.application_version,
+                    error
+                );
+
+                receive_task.abort();
+                return None;
+            }
+        };
+
+        let missing_capabilities = remote
+            .config()
+            .get_capabilities()
+            .missing_from(&identity.capabilities);
+
+        if !missing_capabilities.is_empty() {
+            warn!(
+                ctx = log_ctx.as_value(),
+                "peer (addr={}, node_id={}, application_version={}) is missing actors={:?}, messages={:?}",
+                &self.addr,
+                identity.node.id,
+                &identity.application_version,
+                &missing_capabilities.actors,
+                &missing_capabilities.messages
+            );
+        }
+
+        remote.update_node_capabilities(identity.node.id, identity.capabilities.clone());
+
+        debug!(
+            ctx = log_ctx.as_value(),
+            "negotiated protocol version={} (addr={}, features={:?})",
+            protocol.version,
+            &self.addr,
+            &protocol.features
+        );
+
+        let compression = FrameCompression::negotiate(&network_config.compression, &protocol);
+
         Some(ConnectionState {
             identity,
+            protocol,
+            compression,
             handshake: HandshakeStatus::None,
             write,
             receive_task,
@@ -131,17 +189,21 @@ impl Handler<Connect> for RemoteClient {
         }
 
         if let Some(connection_state) = self.connect(message, ctx).await {
-            let client_actor_ref = self.actor_ref(ctx);
-            let _ = ctx
-                .system()
-                .remote()
-                .client_registry()
-                .send(ClientConnected {
-                    addr: connection_state.identity.node.addr.clone(),
-                    remote_node_id: connection_state.identity.node.id,
-                    client_actor_ref,
-                })
-                .await;
+            let is_control = self.lane == ConnectionLane::Control;
+            if is_control {
+                let client_actor_ref = self.actor_ref(ctx);
+                let _ = ctx
+                    .system()
+                    .remote()
+                    .client_registry()
+                    .send(ClientConnected {
+                        addr: self.addr.clone(),
+                        remote_node_id: connection_state.identity.node.id,
+                        client_type: self.client_type,
+                        client_actor_ref,
+                    })
+                    .await;
+            }
 
             while let Some(callback) = self.on_identified_callbacks.pop() {
                 let _ = callback.send(Some(connection_state.identity.clone()));
@@ -150,14 +212,19 @@ impl Handler<Connect> for RemoteClient {
             self.node_id = Some(connection_state.identity.node.id);
             self.state = Some(ClientState::Connected(connection_state));
 
-            debug!("RemoteClient connected to node (addr={})", &self.addr);
+            debug!(
+                "RemoteClient connected to node (addr={}, lane={})",
+                &self.addr, &self.lane
+            );
 
-            let _ = ctx.system().remote().node_discovery().notify(Discover {
-                seed: Seed::Addr(self.addr.clone()),
-                on_discovery_complete: None,
-            });
+            if is_control {
+                let _ = ctx.system().remote().node_discovery().notify(Discover {
+                    seed: Seed::Addr(self.addr.clone()),
+                    on_discovery_complete: None,
+                });
+            }
 
-            self.flush_buffered_writes().await;
+            self.flush_buffered_writes(ctx.system().remote()).await;
         } else {
             while let Some(callback) = self.on_identified_callbacks.pop() {
                 let _ = callback.send(None);
@@ -211,6 +278,7 @@ impl Handler<BeginHandshake> for RemoteClient {
                             node_id,
                             node_tag,
                             token: vec![],
+                            protocol_version: connection.protocol.version,
                             client_type: EnumOrUnknown::new(self.client_type.into()),
                             trace_id: message.request_id.to_string(),
                             nodes: message
@@ -273,6 +341,9 @@ impl Handler<HandshakeAcknowledge> for RemoteClient {
 #[async_trait]
 impl Handler<Disconnected> for RemoteClient {
     async fn handle(&mut self, _msg: Disconnected, ctx: &mut ActorContext) {
+        self.buffer_pending_batch(ctx.system().remote());
+        self.expire_buffered_writes(ctx.system().remote());
+
         if let Some(true) = self.state.as_ref().map(|n| n.is_connected()) {
             warn!(
                 "RemoteClient disconnected from node (addr={}), attempting re-connection in {}ms",
@@ -0,0 +1,156 @@
+//! Dedicated connections ("lanes") to a single peer.
+//!
+//! Each lane is backed by its own [`RemoteClient`], so a burst of traffic on one lane (for example
+//! a large volume of pubsub messages) doesn't delay traffic on another, such as heartbeats
+//! or request results. Events are assigned to a lane by their type, and events within a lane that
+//! must remain ordered (messages to the same actor, or publishes to the same topic) are always
+//! written to the same connection. Every event addressed to an actor, including registrations and
+//! watches, is written to the request lane, so events for the same actor are never reordered
+//! across lanes.
+
+use crate::actor::LocalActorRef;
+use crate::remote::net::client::RemoteClient;
+use crate::remote::net::config::ConnectionLanesConfig;
+use crate::remote::net::message::SessionEvent;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ConnectionLane {
+    /// Identification, handshakes, pings and cluster events.
+    Control,
+
+    /// Actor messages, registrations, lookups, watches and their results.
+    Request,
+
+    /// Stream (pubsub) messages.
+    Streaming,
+}
+
+/// Clients connected to a single peer, one per connection.
+pub struct LaneClients {
+    control: LocalActorRef<RemoteClient>,
+    request: Vec<LocalActorRef<RemoteClient>>,
+    streaming: Vec<LocalActorRef<RemoteClient>>,
+}
+
+impl ConnectionLane {
+    /// Number of connections opened to each peer for this lane.
+    pub fn connections(&self, config: &ConnectionLanesConfig) -> usize {
+        match self {
+            ConnectionLane::Control => 1,
+            ConnectionLane::Request => config.request_connections,
+            ConnectionLane::Streaming => config.streaming_connections,
+        }
+    }
+}
+
+impl LaneClients {
+    pub fn new(control: LocalActorRef<RemoteClient>) -> Self {
+        Self {
+            control,
+            request: vec![],
+            streaming: vec![],
+        }
+    }
+
+    pub fn control(&self) -> &LocalActorRef<RemoteClient> {
+        &self.control
+    }
+
+    pub fn set_lane(&mut self, lane: ConnectionLane, clients: Vec<LocalActorRef<RemoteClient>>) {
+        match lane {
+            ConnectionLane::Control => {}
+            ConnectionLane::Request => self.request = clients,
+            ConnectionLane::Streaming => self.streaming = clients,
+        }
+    }
+
+    pub fn has_lanes(&self) -> bool {
+        !self.request.is_empty() || !self.streaming.is_empty()
+    }
+
+    /// Selects the client the event should be written to, falling back to the control
+    /// connection when no connections were opened for the event's lane.
+    pub fn route(&self, event: &SessionEvent) -> &LocalActorRef<RemoteClient> {
+        let clients = match event.lane() {
+            ConnectionLane::Control => return &self.control,
+            ConnectionLane::Request => &self.request,
+            ConnectionLane::Streaming => &self.streaming,
+        };
+
+        match clients.len() {
+            0 => &self.control,
+            1 => &clients[0],
+            len => &clients[routing_hash(event) as usize % len],
+        }
+    }
+
+    pub fn clients(&self) -> impl Iterator<Item = &LocalActorRef<RemoteClient>> {
+        std::iter::once(&self.control)
+            .chain(self.request.iter())
+            .chain(self.streaming.iter())
+    }
+}
+
+impl SessionEvent {
+    /// The lane this event is written to, when lanes are enabled.
+    pub fn lane(&self) -> ConnectionLane {
+        match self {
+            SessionEvent::NotifyActor(_)
+            | SessionEvent::CreateActor(_)
+            | SessionEvent::FindActor(_)
+            | SessionEvent::RegisterActor(_)
+            | SessionEvent::WatchActor(_)
+            | SessionEvent::ActorTerminated(_)
+            | SessionEvent::GetNodeStats(_)
+            | SessionEvent::GetClusterNodes(_)
+            | SessionEvent::Result(_)
+            | SessionEvent::Err(_) => ConnectionLane::Request,
+
+            SessionEvent::StreamPublish(_) => ConnectionLane::Streaming,
+
+            SessionEvent::Identify(_)
+            | SessionEvent::Ping(_)
+            | SessionEvent::Pong(_)
+            | SessionEvent::Handshake(_)
+            | SessionEvent::Raft(_)
+            | SessionEvent::Gossip(_)
+            | SessionEvent::Leave(_)
+            | SessionEvent::Batch(_) => ConnectionLane::Control,
+        }
+    }
+}
+
+/// Events that must be received in the order they were written share the same routing hash,
+/// so they're always written to the same connection.
+fn routing_hash(event: &SessionEvent) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    match event {
+        SessionEvent::NotifyActor(msg) => msg.actor_id.hash(&mut hasher),
+        SessionEvent::CreateActor(msg) => msg.actor_id.hash(&mut hasher),
+        SessionEvent::FindActor(msg) => msg.actor_id.hash(&mut hasher),
+        SessionEvent::RegisterActor(msg) => msg.actor_id.hash(&mut hasher),
+        SessionEvent::WatchActor(msg) => msg.actor_id.hash(&mut hasher),
+        SessionEvent::ActorTerminated(msg) => msg.actor_id.hash(&mut hasher),
+        SessionEvent::StreamPublish(msg) => msg.topic.hash(&mut hasher),
+        SessionEvent::Result(res) => res.message_id.hash(&mut hasher),
+        SessionEvent::Err(err) => err.message_id.hash(&mut hasher),
+        SessionEvent::GetNodeStats(req) => req.message_id.hash(&mut hasher),
+        SessionEvent::GetClusterNodes(req) => req.message_id.hash(&mut hasher),
+        _ => {}
+    }
+
+    hasher.finish()
+}
+
+impl Display for ConnectionLane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectionLane::Control => write!(f, "control"),
+            ConnectionLane::Request => write!(f, "request"),
+            ConnectionLane::Streaming => write!(f, "streaming"),
+        }
+    }
+}
@@ -4,12 +4,9 @@ use futures::SinkExt;
 use std::collections::VecDeque;
 use std::fmt::{Display, Formatter};
 use std::time::{Duration, Instant};
-use tokio::io::WriteHalf;
-use tokio::net::TcpStream;
 use tokio::sync::oneshot;
 use tokio::sync::oneshot::{Receiver, Sender};
 use tokio::task::JoinHandle;
-use tokio_util::codec::{FramedWrite, LengthDelimitedCodec};
 use uuid::Uuid;
 
 use crate::actor::context::ActorContext;
@@ -18,16 +15,24 @@ use crate::actor::scheduler::timer::Timer;
 use crate::actor::{Actor, ActorRefErr, IntoActor, LocalActorRef};
 
 use crate::remote::cluster::node::{NodeIdentity, RemoteNode};
+use crate::remote::net::client::batch::PendingBatch;
 use crate::remote::net::client::connect::Connect;
+use crate::remote::net::client::lane::ConnectionLane;
 use crate::remote::net::client::receive::HandshakeAcknowledge;
 use crate::remote::net::client::send::write_bytes;
+use crate::remote::net::compression::FrameCompression;
+use crate::remote::net::config::{BatchingConfig, WriteBufferConfig};
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network as proto;
 use crate::remote::net::proto::network::PingEvent;
+use crate::remote::net::protocol::NegotiatedProtocol;
+use crate::remote::net::transport::ConnectionWriter;
 use crate::remote::net::StreamData;
 use crate::remote::system::{NodeId, RemoteActorSystem};
 
+pub mod batch;
 pub mod connect;
+pub mod lane;
 pub mod ping;
 pub mod receive;
 pub mod send;
@@ -36,15 +41,24 @@ pub struct RemoteClient {
     addr: String,
     node_id: Option<NodeId>,
     client_type: ClientType,
+    lane: ConnectionLane,
     state: Option<ClientState>,
     stop: Option<Sender<bool>>,
     write_buffer_bytes_total: usize,
-    write_buffer: VecDeque<Vec<u8>>,
+    write_buffer: VecDeque<BufferedWrite>,
+    write_buffer_config: WriteBufferConfig,
+    batching_config: BatchingConfig,
+    pending_batch: PendingBatch,
     on_identified_callbacks: Vec<Sender<Option<NodeIdentity>>>,
     on_handshake_ack_callbacks: Vec<HandshakeAckCallback>,
     ping_timer: Option<Timer>,
 }
 
+struct BufferedWrite {
+    bytes: Vec<u8>,
+    buffered_at: Instant,
+}
+
 struct HandshakeAckCallback {
     request_id: Uuid,
     callback: Sender<()>,
@@ -60,15 +74,34 @@ impl RemoteClient {
         system: RemoteActorSystem,
         client_type: ClientType,
     ) -> LocalActorRef<Self> {
-        let actor_id = Some(format!("remote-client-{}", &addr));
+        Self::new_lane(addr, system, client_type, ConnectionLane::Control, 0).await
+    }
+
+    /// Creates a client for a dedicated lane, `index` distinguishes multiple connections
+    /// to the same peer within a lane.
+    pub async fn new_lane(
+        addr: String,
+        system: RemoteActorSystem,
+        client_type: ClientType,
+        lane: ConnectionLane,
+        index: usize,
+    ) -> LocalActorRef<Self> {
+        let actor_id = Some(match lane {
+            ConnectionLane::Control => format!("remote-client-{}", &addr),
+            lane => format!("remote-client-{}-{}-{}", &addr, lane, index),
+        });
         debug!(
             "Creating RemoteClient (actor_id={})",
             actor_id.as_ref().unwrap()
         );
 
+        let write_buffer_config = system.config().network_config().write_buffer.clone();
+        let batching_config = system.config().network_config().batching.clone();
+
         RemoteClient {
             addr,
             client_type,
+            lane,
             node_id: None,
             stop: None,
             state: Some(ClientState::Idle {
@@ -76,6 +109,9 @@ impl RemoteClient {
             }),
             write_buffer: VecDeque::new(),
             write_buffer_bytes_total: 0,
+            write_buffer_config,
+            batching_config,
+            pending_batch: PendingBatch::default(),
             on_identified_callbacks: vec![],
             on_handshake_ack_callbacks: vec![],
             ping_timer: None,
@@ -85,6 +121,10 @@ impl RemoteClient {
         .unwrap()
     }
 
+    pub fn lane(&self) -> ConnectionLane {
+        self.lane
+    }
+
     pub fn close(&mut self) -> bool {
         if let Some(stop) = self.stop.take() {
             stop.send(true).is_ok()
@@ -202,12 +242,14 @@ impl Actor for RemoteClient {
     }
 
     async fn stopped(&mut self, ctx: &mut ActorContext) {
+        let _ = self.write_batch(ctx.system().remote()).await;
+
         match &mut self.state {
             None => {}
             Some(state) => match state {
                 ClientState::Idle { .. } => {}
                 ClientState::Connected(connection) => {
-                    if ctx.system().is_terminated() {
+                    if self.lane == ConnectionLane::Control && ctx.system().is_terminated() {
                         debug!(
                             "system shutdown, notifying node(addr={}, id={:?})",
                             &self.addr, &self.node_id
@@ -255,8 +297,10 @@ pub enum ClientState {
 
 pub struct ConnectionState {
     identity: NodeIdentity,
+    protocol: NegotiatedProtocol,
+    compression: Option<FrameCompression>,
     handshake: HandshakeStatus,
-    write: FramedWrite<WriteHalf<TcpStream>, LengthDelimitedCodec>,
+    write: ConnectionWriter,
     receive_task: JoinHandle<()>,
 }
 
@@ -285,7 +329,7 @@ impl Display for RemoteClientErr {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ClientType {
     Client,
     Worker,
@@ -1,7 +1,6 @@
-use chrono::Utc;
 use protobuf::Message as ProtoMessage;
-use std::time::Instant;
 use tokio::sync::oneshot;
+use tokio::time::Instant;
 
 use uuid::Uuid;
 
@@ -33,9 +32,12 @@ impl Handler<PingTick> for RemoteClient {
 
         trace!("ping tick, client_addr={}", &self.addr);
 
-        let node_id = if let Some(state) = &self.state {
+        let (node_id, datacenter) = if let Some(state) = &self.state {
             match state {
-                ClientState::Connected(state) => state.identity.node.id,
+                ClientState::Connected(state) => (
+                    state.identity.node.id,
+                    state.identity.node.datacenter.clone(),
+                ),
                 _ => {
                     if let Some(node_id) = self.node_id {
                         let _ = heartbeat.notify(NodePing(node_id, PingResult::Disconnected));
@@ -76,15 +78,21 @@ impl Handler<PingTick> for RemoteClient {
         let ping_start = Instant::now();
         if self.write(ping_event, ctx).await.is_ok() {
             tokio::spawn(async move {
-                let timeout = remote.config().heartbeat_config().ping_timeout;
+                let timeout = remote
+                    .config()
+                    .heartbeat_config()
+                    .for_link(remote.datacenter(), &datacenter)
+                    .ping_timeout;
                 let ping_result = match tokio::time::timeout(timeout, res_rx).await {
                     Ok(res) => match res {
                         Ok(pong) => match pong {
                             RemoteResponse::Ok(pong_bytes) => {
-                                let pong = PongEvent::parse_from_bytes(&pong_bytes).unwrap();
-                                let now = Utc::now();
-
-                                PingResult::Ok(pong, ping_start.elapsed(), now)
+                                match PongEvent::parse_from_bytes(&pong_bytes) {
+                                    Ok(pong) => {
+                                        PingResult::Ok(pong, ping_start.elapsed(), Instant::now())
+                                    }
+                                    Err(_) => PingResult::Err,
+                                }
                             }
                             RemoteResponse::Err(_err_bytes) => PingResult::Err,
                         },
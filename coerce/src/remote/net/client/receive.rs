@@ -6,7 +6,8 @@ use crate::remote::actor::RemoteResponse;
 use crate::remote::cluster::node::{NodeIdentity, RemoteNode};
 use crate::remote::net::client::connect::Disconnected;
 use crate::remote::net::client::RemoteClient;
-use crate::remote::net::message::{timestamp_to_datetime, ClientEvent};
+use crate::remote::net::message::{timestamp_to_datetime, ClientEvent, DecodeErr};
+use crate::remote::net::metrics::NetworkMetrics;
 use crate::remote::net::proto::network::PongEvent;
 use crate::remote::net::StreamReceiver;
 use crate::remote::system::{NodeId, RemoteActorSystem};
@@ -24,6 +25,8 @@ pub struct ClientMessageReceiver {
     identity_sender: Option<Sender<NodeIdentity>>,
     should_close: bool,
     addr: String,
+    decode_errors: usize,
+    max_decode_errors: usize,
 }
 
 impl ClientMessageReceiver {
@@ -31,6 +34,7 @@ impl ClientMessageReceiver {
         actor_ref: LocalActorRef<RemoteClient>,
         identity_sender: Sender<NodeIdentity>,
         addr: String,
+        max_decode_errors: usize,
     ) -> ClientMessageReceiver {
         let identity_sender = Some(identity_sender);
         Self {
@@ -38,6 +42,8 @@ impl ClientMessageReceiver {
             identity_sender,
             addr,
             should_close: false,
+            decode_errors: 0,
+            max_decode_errors,
         }
     }
 }
@@ -71,6 +77,8 @@ impl StreamReceiver for ClientMessageReceiver {
                                 messages: capabilities.messages.to_vec(),
                             })
                             .unwrap_or_else(|| SystemCapabilities::default()),
+                        protocol: identity.protocol.into_option().into(),
+                        application_version: identity.application_version,
                     });
                 } else {
                     debug!("received `Identity` but the client was already identified");
@@ -107,7 +115,12 @@ impl StreamReceiver for ClientMessageReceiver {
                 }
             }
             ClientEvent::Result(res) => {
-                match sys.pop_request(Uuid::from_str(&res.message_id).unwrap()) {
+                let message_id = match parse_message_id(&res.message_id, &self.addr) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                match sys.pop_request(message_id) {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Ok(res.result));
                     }
@@ -123,9 +136,15 @@ impl StreamReceiver for ClientMessageReceiver {
             }
             ClientEvent::Err(e) => {
                 info!("received client error!");
-                match sys.pop_request(Uuid::from_str(&e.message_id).unwrap()) {
+                let message_id = match parse_message_id(&e.message_id, &self.addr) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                match sys.pop_request(message_id) {
                     Some(res_tx) => {
-                        let _ = res_tx.send(RemoteResponse::Err(e.error.unwrap().into()));
+                        let error = e.error.unwrap_or_default().into();
+                        let _ = res_tx.send(RemoteResponse::Err(error));
                     }
                     None => {
                         //                                          :P
@@ -135,7 +154,12 @@ impl StreamReceiver for ClientMessageReceiver {
             }
             ClientEvent::Ping(_ping) => {}
             ClientEvent::Pong(pong) => {
-                match sys.pop_request(Uuid::from_str(&pong.message_id).unwrap()) {
+                let message_id = match parse_message_id(&pong.message_id, &self.addr) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                match sys.pop_request(message_id) {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Ok(
                             PongEvent {
@@ -160,8 +184,26 @@ impl StreamReceiver for ClientMessageReceiver {
         let _ = self.actor_ref.send(Disconnected).await;
     }
 
-    fn on_deserialisation_failed(&mut self) {
-        warn!("message serialisation failed (addr={})", &self.addr);
+    fn on_deserialisation_failed(&mut self, error: DecodeErr) {
+        NetworkMetrics::incr_decode_errors();
+
+        self.decode_errors += 1;
+        warn!(
+            "message deserialisation failed (addr={}, event_type={:?}, decode_errors={}) - error: {}",
+            &self.addr,
+            error.event_type(),
+            self.decode_errors,
+            error
+        );
+
+        if self.decode_errors >= self.max_decode_errors {
+            error!(
+                "received too many invalid frames (addr={}, decode_errors={}), closing connection",
+                &self.addr, self.decode_errors
+            );
+
+            self.should_close = true;
+        }
     }
 
     fn on_stream_lost(&mut self, error: Error) {
@@ -179,3 +221,16 @@ impl StreamReceiver for ClientMessageReceiver {
         self.should_close
     }
 }
+
+fn parse_message_id(message_id: &str, addr: &str) -> Option<Uuid> {
+    match Uuid::from_str(message_id) {
+        Ok(message_id) => Some(message_id),
+        Err(e) => {
+            warn!(
+                "received invalid message_id (addr={}, message_id={}) - error: {}",
+                addr, message_id, e
+            );
+            None
+        }
+    }
+}
@@ -1,13 +1,22 @@
 use crate::actor::context::ActorContext;
 use crate::actor::message::{Handler, Message};
+use crate::actor::ActorRefErr;
+use crate::remote::actor::RemoteResponse;
 use crate::remote::net::client::connect::Disconnected;
-use crate::remote::net::client::{ClientState, ConnectionState, RemoteClient, RemoteClientErr};
+use crate::remote::net::client::{
+    BufferedWrite, ClientState, ConnectionState, RemoteClient, RemoteClientErr,
+};
+use crate::remote::net::config::BufferOverflowPolicy;
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::metrics::NetworkMetrics;
+use crate::remote::net::transport::ConnectionWriter;
 use crate::remote::net::StreamData;
+use crate::remote::system::RemoteActorSystem;
 use bytes::{Bytes, BytesMut};
 use futures::SinkExt;
-use tokio::io::WriteHalf;
-use tokio::net::TcpStream;
-use tokio_util::codec::{FramedWrite, LengthDelimitedCodec};
+use std::str::FromStr;
+use std::time::Instant;
+use uuid::Uuid;
 
 pub struct Write<M: StreamData>(pub M);
 
@@ -27,6 +36,17 @@ impl<M: StreamData> Handler<Write<M>> for RemoteClient {
 }
 
 impl ConnectionState {
+    /// Writes a frame to the connection, compressing it if compression was negotiated with
+    /// the peer.
+    pub(crate) async fn write_frame(&mut self, frame: Bytes) -> Result<(), RemoteClientErr> {
+        let frame = match self.compression.and_then(|c| c.compress(&frame)) {
+            Some(compressed) => Bytes::from(compressed),
+            None => frame,
+        };
+
+        write_bytes(frame, &mut self.write).await
+    }
+
     pub async fn write(&mut self, bytes: Vec<u8>) -> Result<(), Option<Vec<u8>>> {
         if let Err(e) = write_bytes(Bytes::from(bytes), &mut self.write).await {
             match e {
@@ -40,7 +60,9 @@ impl ConnectionState {
 }
 
 impl RemoteClient {
-    pub async fn flush_buffered_writes(&mut self) {
+    pub async fn flush_buffered_writes(&mut self, remote: &RemoteActorSystem) {
+        self.expire_buffered_writes(remote);
+
         let connection_state = match &mut self.state {
             Some(ClientState::Connected(connection_state)) => connection_state,
             _ => return,
@@ -53,22 +75,184 @@ impl RemoteClient {
         );
 
         while let Some(buffered_message) = self.write_buffer.pop_front() {
-            let len = buffered_message.len();
-            let bytes = Bytes::from(buffered_message);
-            if let Ok(()) = write_bytes(bytes.clone(), &mut connection_state.write).await {
+            let len = buffered_message.bytes.len();
+            let bytes = Bytes::from(buffered_message.bytes);
+            if let Ok(()) = connection_state.write_frame(bytes.clone()).await {
                 self.write_buffer_bytes_total -= len;
             } else {
-                self.write_buffer.push_front(bytes.to_vec());
+                self.write_buffer.push_front(BufferedWrite {
+                    bytes: bytes.to_vec(),
+                    buffered_at: buffered_message.buffered_at,
+                });
 
                 // write failed, no point trying again - break and reconnect/retry later
                 break;
             }
         }
+
+        self.record_buffer_metrics();
     }
 
-    pub fn buffer_message(&mut self, message_bytes: Vec<u8>) {
-        self.write_buffer_bytes_total += message_bytes.len();
-        self.write_buffer.push_back(message_bytes);
+    /// Buffers a message until the peer is reachable again, applying the configured
+    /// [`BufferOverflowPolicy`] when the buffer is full.
+    ///
+    /// Any request discarded from the buffer is failed, so the sender isn't left waiting for a
+    /// response that will never arrive.
+    pub fn buffer_message(&mut self, message_bytes: Vec<u8>, remote: &RemoteActorSystem) {
+        self.expire_buffered_writes(remote);
+
+        let len = message_bytes.len();
+        if !self.has_buffer_capacity(len) {
+            let overflow_policy = self.write_buffer_config.overflow_policy;
+            let fits_empty_buffer = len <= self.write_buffer_config.max_bytes
+                && self.write_buffer_config.max_messages > 0;
+
+            warn!(
+                "write buffer full (addr={}, buffered_messages={}, buffered_bytes={}, policy={:?})",
+                &self.addr,
+                self.write_buffer.len(),
+                self.write_buffer_bytes_total,
+                overflow_policy
+            );
+
+            match overflow_policy {
+                BufferOverflowPolicy::DropOldest if fits_empty_buffer => {
+                    while !self.has_buffer_capacity(len) {
+                        match self.pop_buffered_write() {
+                            Some(oldest) => self.discard_buffered_write(
+                                oldest.bytes,
+                                ActorRefErr::ActorUnavailable,
+                                "dropped",
+                                remote,
+                            ),
+                            None => break,
+                        }
+                    }
+                }
+
+                BufferOverflowPolicy::FailPending if fits_empty_buffer => {
+                    while let Some(buffered) = self.pop_buffered_write() {
+                        self.discard_buffered_write(
+                            buffered.bytes,
+                            ActorRefErr::ActorUnavailable,
+                            "failed",
+                            remote,
+                        );
+                    }
+                }
+
+                _ => {
+                    self.discard_buffered_write(
+                        message_bytes,
+                        ActorRefErr::ActorUnavailable,
+                        "rejected",
+                        remote,
+                    );
+
+                    self.record_buffer_metrics();
+                    return;
+                }
+            }
+        }
+
+        self.write_buffer_bytes_total += len;
+        self.write_buffer.push_back(BufferedWrite {
+            bytes: message_bytes,
+            buffered_at: Instant::now(),
+        });
+
+        self.record_buffer_metrics();
+    }
+
+    /// Discards buffered messages that have been waiting longer than the configured TTL,
+    /// so stale requests aren't replayed once the peer is reachable again.
+    pub fn expire_buffered_writes(&mut self, remote: &RemoteActorSystem) {
+        let ttl = match self.write_buffer_config.ttl {
+            Some(ttl) => ttl,
+            None => return,
+        };
+
+        let mut expired = 0;
+        while let Some(buffered) = self.write_buffer.front() {
+            let buffered_for = buffered.buffered_at.elapsed();
+            if buffered_for < ttl {
+                break;
+            }
+
+            let buffered = self.pop_buffered_write().unwrap();
+            self.discard_buffered_write(
+                buffered.bytes,
+                ActorRefErr::Timeout {
+                    time_taken_millis: buffered_for.as_millis() as u64,
+                },
+                "expired",
+                remote,
+            );
+
+            expired += 1;
+        }
+
+        if expired > 0 {
+            debug!(
+                "discarded {} expired messages (addr={}, total_buffered={})",
+                expired,
+                &self.addr,
+                self.write_buffer.len()
+            );
+
+            self.record_buffer_metrics();
+        }
+    }
+
+    pub fn buffered_message_count(&self) -> usize {
+        self.write_buffer.len()
+    }
+
+    pub fn buffered_bytes_total(&self) -> usize {
+        self.write_buffer_bytes_total
+    }
+
+    fn has_buffer_capacity(&self, len: usize) -> bool {
+        self.write_buffer.len() < self.write_buffer_config.max_messages
+            && self.write_buffer_bytes_total + len <= self.write_buffer_config.max_bytes
+    }
+
+    fn pop_buffered_write(&mut self) -> Option<BufferedWrite> {
+        let buffered = self.write_buffer.pop_front()?;
+        self.write_buffer_bytes_total -= buffered.bytes.len();
+        Some(buffered)
+    }
+
+    fn discard_buffered_write(
+        &self,
+        bytes: Vec<u8>,
+        error: ActorRefErr,
+        reason: &'static str,
+        remote: &RemoteActorSystem,
+    ) {
+        NetworkMetrics::incr_client_buffer_discarded(&self.addr, reason);
+
+        let request_id = match pending_request_id(bytes) {
+            Some(request_id) => request_id,
+            None => return,
+        };
+
+        if let Some(res_tx) = remote.pop_request(request_id) {
+            debug!(
+                "buffered request {} (request_id={}, addr={}), error: {}",
+                reason, &request_id, &self.addr, &error
+            );
+
+            let _ = res_tx.send(RemoteResponse::Err(error));
+        }
+    }
+
+    fn record_buffer_metrics(&self) {
+        NetworkMetrics::set_client_buffered(
+            &self.addr,
+            self.write_buffer_bytes_total,
+            self.write_buffer.len(),
+        );
     }
 
     pub async fn write<M: StreamData>(
@@ -80,6 +264,11 @@ impl RemoteClient {
         M: Sync + Send,
     {
         if let Some(bytes) = message.write_to_bytes() {
+            if self.batching_enabled() {
+                self.batch_message(bytes, ctx).await;
+                return Ok(());
+            }
+
             let mut buffer_message = None;
 
             let stream_write_error = match &mut self.state.as_mut().unwrap() {
@@ -96,7 +285,7 @@ impl RemoteClient {
 
                 ClientState::Connected(state) => {
                     let bytes = Bytes::from(bytes);
-                    if let Err(e) = write_bytes(bytes.clone(), &mut state.write).await {
+                    if let Err(e) = state.write_frame(bytes.clone()).await {
                         match e {
                             RemoteClientErr::StreamErr(_e) => {
                                 warn!("node {} (addr={}) is unreachable but marked as connected, buffering message (total_buffered={})",
@@ -117,7 +306,7 @@ impl RemoteClient {
             };
 
             if let Some(message_bytes) = buffer_message {
-                self.buffer_message(message_bytes);
+                self.buffer_message(message_bytes, ctx.system().remote());
             }
 
             if stream_write_error {
@@ -132,9 +321,22 @@ impl RemoteClient {
     }
 }
 
+/// The id of the request awaiting a response for the buffered message, if any.
+fn pending_request_id(bytes: Vec<u8>) -> Option<Uuid> {
+    let message_id = match SessionEvent::read_from_bytes(bytes)? {
+        SessionEvent::NotifyActor(request) => request.message_id,
+        SessionEvent::FindActor(find_actor) => find_actor.message_id,
+        SessionEvent::CreateActor(create_actor) => create_actor.message_id,
+        SessionEvent::Ping(ping) => ping.message_id,
+        _ => return None,
+    };
+
+    Uuid::from_str(&message_id).ok()
+}
+
 pub(crate) async fn write_bytes(
     bytes: Bytes,
-    writer: &mut FramedWrite<WriteHalf<TcpStream>, LengthDelimitedCodec>,
+    writer: &mut ConnectionWriter,
 ) -> Result<(), RemoteClientErr> {
     match writer.send(bytes).await {
         Ok(()) => Ok(()),
@@ -0,0 +1,34 @@
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{self, Read, Write};
+
+pub fn compress(data: &[u8], level: u32) -> io::Result<Vec<u8>> {
+    let mut encoder =
+        DeflateEncoder::new(Vec::with_capacity(data.len() / 2), Compression::new(level));
+
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+pub fn decompress(data: &[u8], max_length: usize) -> io::Result<Vec<u8>> {
+    let mut decompressed = vec![];
+
+    // reading one byte past the limit allows oversized frames to be detected
+    // without decompressing the entire payload.
+    DeflateDecoder::new(data)
+        .take(max_length as u64 + 1)
+        .read_to_end(&mut decompressed)?;
+
+    if decompressed.len() > max_length {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "decompressed frame exceeds the maximum frame length ({})",
+                max_length
+            ),
+        ));
+    }
+
+    Ok(decompressed)
+}
@@ -0,0 +1,188 @@
+//! Compression of frames written to other nodes.
+//!
+//! Compression is negotiated per connection, each node advertises the codecs it can decode as
+//! protocol features, and only compresses frames when the peer supports the codec and the frame
+//! is larger than the configured threshold. Compressed frames are wrapped in a
+//! [`CompressedEvent`], and are decompressed before being decoded by the receiver.
+//!
+//! Each codec is only available when its feature is enabled, DEFLATE requires the
+//! `remote-compression` feature. Without any codecs, no compression is advertised to peers, and
+//! frames are always written uncompressed.
+
+use crate::remote::net::config::CompressionConfig;
+use crate::remote::net::message::DecodeErr;
+use crate::remote::net::metrics::NetworkMetrics;
+use crate::remote::net::proto::network::{CompressedEvent, CompressionType, Event};
+use crate::remote::net::protocol::NegotiatedProtocol;
+use protobuf::Message;
+use std::io;
+
+#[cfg(feature = "remote-compression")]
+mod deflate;
+
+/// Frames compressed with DEFLATE can be decoded.
+pub const FEATURE_COMPRESSION_DEFLATE: &str = "compression-deflate";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CompressionCodec {
+    #[cfg(feature = "remote-compression")]
+    Deflate,
+}
+
+/// Compression applied to frames written to a single connection.
+#[derive(Copy, Clone, Debug)]
+pub struct FrameCompression {
+    codec: CompressionCodec,
+    threshold: usize,
+    level: u32,
+}
+
+impl CompressionCodec {
+    /// Every codec available in this build, in order of preference.
+    pub fn supported() -> Vec<CompressionCodec> {
+        vec![
+            #[cfg(feature = "remote-compression")]
+            CompressionCodec::Deflate,
+        ]
+    }
+
+    /// The protocol feature advertised by nodes that can decode this codec.
+    pub fn feature(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "remote-compression")]
+            CompressionCodec::Deflate => FEATURE_COMPRESSION_DEFLATE,
+        }
+    }
+
+    /// The codec used to compress frames marked with `compression`, if it's available.
+    fn from_type(compression: CompressionType) -> Option<CompressionCodec> {
+        match compression {
+            #[cfg(feature = "remote-compression")]
+            CompressionType::Deflate => Some(CompressionCodec::Deflate),
+            _ => None,
+        }
+    }
+
+    fn compress(&self, _data: &[u8], _level: u32) -> io::Result<Vec<u8>> {
+        match *self {
+            #[cfg(feature = "remote-compression")]
+            CompressionCodec::Deflate => deflate::compress(_data, _level),
+        }
+    }
+
+    fn decompress(&self, _data: &[u8], _max_length: usize) -> io::Result<Vec<u8>> {
+        match *self {
+            #[cfg(feature = "remote-compression")]
+            CompressionCodec::Deflate => deflate::decompress(_data, _max_length),
+        }
+    }
+}
+
+impl FrameCompression {
+    /// Picks the first codec in the configured preference order that the peer can decode,
+    /// returns `None` when compression is disabled or no codec is supported by both nodes.
+    pub fn negotiate(
+        config: &CompressionConfig,
+        protocol: &NegotiatedProtocol,
+    ) -> Option<FrameCompression> {
+        if !config.enabled {
+            return None;
+        }
+
+        config
+            .codecs
+            .iter()
+            .find(|codec| protocol.supports(codec.feature()))
+            .map(|codec| FrameCompression {
+                codec: *codec,
+                threshold: config.threshold,
+                level: config.level,
+            })
+    }
+
+    pub fn codec(&self) -> CompressionCodec {
+        self.codec
+    }
+
+    /// Compresses the frame if it's larger than the threshold, returns `None` when the frame
+    /// should be written uncompressed, including frames that don't get any smaller.
+    pub fn compress(&self, frame: &[u8]) -> Option<Vec<u8>> {
+        if frame.len() < self.threshold {
+            return None;
+        }
+
+        let payload = match self.codec.compress(frame, self.level) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("failed to compress frame, error: {}", e);
+                return None;
+            }
+        };
+
+        let compressed = CompressedEvent {
+            compression: CompressionType::from(self.codec).into(),
+            uncompressed_length: frame.len() as u32,
+            payload,
+            ..Default::default()
+        };
+
+        match compressed.write_to_bytes() {
+            Ok(mut compressed) if compressed.len() < frame.len() => {
+                compressed.insert(0, Event::Compressed as u8);
+                NetworkMetrics::incr_compressed_bytes(frame.len() as u64, compressed.len() as u64);
+                Some(compressed)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Decompresses the frame if it was compressed by the sender, otherwise it's returned as-is.
+pub fn decompress_frame(frame: Vec<u8>, max_frame_length: usize) -> Result<Vec<u8>, DecodeErr> {
+    match frame.first() {
+        Some(event) if *event == Event::Compressed as u8 => {}
+        _ => return Ok(frame),
+    }
+
+    let compressed = CompressedEvent::parse_from_bytes(&frame[1..]).map_err(|error| {
+        DecodeErr::InvalidMessage {
+            event: Event::Compressed,
+            error,
+        }
+    })?;
+
+    let compression = compressed.compression.enum_value();
+    let codec = match compression.ok().and_then(CompressionCodec::from_type) {
+        Some(codec) => codec,
+        None => {
+            return Err(DecodeErr::Decompression(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported compression type: {:?}", compression),
+            )))
+        }
+    };
+
+    if compressed.uncompressed_length as usize > max_frame_length {
+        return Err(DecodeErr::Decompression(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "uncompressed length ({}) exceeds the maximum frame length ({})",
+                compressed.uncompressed_length, max_frame_length
+            ),
+        )));
+    }
+
+    codec
+        .decompress(&compressed.payload, max_frame_length)
+        .map_err(DecodeErr::Decompression)
+}
+
+impl From<CompressionCodec> for CompressionType {
+    fn from(codec: CompressionCodec) -> Self {
+        match codec {
+            #[cfg(feature = "remote-compression")]
+            CompressionCodec::Deflate => CompressionType::Deflate,
+        }
+    }
+}
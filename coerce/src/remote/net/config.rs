@@ -0,0 +1,171 @@
+use crate::remote::net::compression::CompressionCodec;
+use crate::remote::net::protocol::ProtocolConfig;
+use std::time::Duration;
+
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+pub const DEFAULT_MAX_DECODE_ERRORS: usize = 10;
+pub const DEFAULT_WRITE_BUFFER_MAX_BYTES: usize = 64 * 1024 * 1024;
+pub const DEFAULT_WRITE_BUFFER_MAX_MESSAGES: usize = 100_000;
+pub const DEFAULT_WRITE_BUFFER_TTL: Duration = Duration::from_secs(60);
+pub const DEFAULT_BATCH_LINGER: Duration = Duration::from_millis(1);
+pub const DEFAULT_BATCH_MAX_BYTES: usize = 64 * 1024;
+pub const DEFAULT_BATCH_MAX_MESSAGES: usize = 128;
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 4 * 1024;
+pub const DEFAULT_COMPRESSION_LEVEL: u32 = 6;
+pub const DEFAULT_REQUEST_CONNECTIONS: usize = 1;
+pub const DEFAULT_STREAMING_CONNECTIONS: usize = 1;
+
+#[derive(Clone, Debug)]
+pub struct NetworkConfig {
+    /// Maximum length (in bytes) of a single frame sent or received by this node, the connection
+    /// is closed when a larger frame is received.
+    pub max_frame_length: usize,
+
+    /// Number of frames that fail to decode before the connection is closed.
+    pub max_decode_errors: usize,
+
+    /// Protocol versions and features advertised to other nodes.
+    pub protocol: ProtocolConfig,
+
+    /// Limits for messages buffered by a client while the peer is unreachable.
+    pub write_buffer: WriteBufferConfig,
+
+    /// Coalescing of outgoing messages into batched frames.
+    pub batching: BatchingConfig,
+
+    /// Compression of frames written to peers that support it.
+    pub compression: CompressionConfig,
+
+    /// Dedicated connections to each peer, separating control, request and streaming traffic.
+    pub lanes: ConnectionLanesConfig,
+}
+
+/// When lanes are enabled, requests and stream messages are written over their own connections,
+/// so they can't delay heartbeats and cluster events written over the control connection.
+#[derive(Clone, Debug)]
+pub struct ConnectionLanesConfig {
+    pub enabled: bool,
+
+    /// Number of connections used for actor messages and their results.
+    pub request_connections: usize,
+
+    /// Number of connections used for stream messages.
+    pub streaming_connections: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompressionConfig {
+    pub enabled: bool,
+
+    /// Codecs used to compress frames, in order of preference. Frames are only compressed
+    /// when the peer supports at least one of them.
+    pub codecs: Vec<CompressionCodec>,
+
+    /// Frames smaller than this (in bytes) are never compressed.
+    pub threshold: usize,
+
+    /// Compression level, from 0 (fastest) to 9 (smallest).
+    pub level: u32,
+}
+
+/// Outgoing messages are coalesced into a single frame when batching is enabled and supported by
+/// the peer, trading a small amount of latency for throughput.
+#[derive(Clone, Debug)]
+pub struct BatchingConfig {
+    pub enabled: bool,
+
+    /// How long a batch is held open for more messages before it's written, a zero linger only
+    /// coalesces messages that are already waiting to be written.
+    pub linger: Duration,
+
+    /// The batch is written as soon as it reaches this size (in bytes).
+    pub max_batch_bytes: usize,
+
+    /// The batch is written as soon as it contains this many messages.
+    pub max_batch_messages: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct WriteBufferConfig {
+    /// Maximum total size (in bytes) of messages buffered for a single peer.
+    pub max_bytes: usize,
+
+    /// Maximum number of messages buffered for a single peer.
+    pub max_messages: usize,
+
+    /// How long a message can stay buffered before it's discarded, rather than being
+    /// written once the peer becomes reachable again. `None` keeps messages until they're written.
+    pub ttl: Option<Duration>,
+
+    /// What happens when a message is written while the buffer is full.
+    pub overflow_policy: BufferOverflowPolicy,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BufferOverflowPolicy {
+    /// Discard the oldest buffered messages until the new message fits.
+    DropOldest,
+
+    /// Discard the new message, leaving the buffer untouched.
+    RejectNew,
+
+    /// Discard every buffered message before buffering the new message.
+    FailPending,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            max_decode_errors: DEFAULT_MAX_DECODE_ERRORS,
+            protocol: ProtocolConfig::default(),
+            write_buffer: WriteBufferConfig::default(),
+            batching: BatchingConfig::default(),
+            compression: CompressionConfig::default(),
+            lanes: ConnectionLanesConfig::default(),
+        }
+    }
+}
+
+impl Default for WriteBufferConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_WRITE_BUFFER_MAX_BYTES,
+            max_messages: DEFAULT_WRITE_BUFFER_MAX_MESSAGES,
+            ttl: Some(DEFAULT_WRITE_BUFFER_TTL),
+            overflow_policy: BufferOverflowPolicy::DropOldest,
+        }
+    }
+}
+
+impl Default for BatchingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            linger: DEFAULT_BATCH_LINGER,
+            max_batch_bytes: DEFAULT_BATCH_MAX_BYTES,
+            max_batch_messages: DEFAULT_BATCH_MAX_MESSAGES,
+        }
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            codecs: CompressionCodec::supported(),
+            threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            level: DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+}
+
+impl Default for ConnectionLanesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            request_connections: DEFAULT_REQUEST_CONNECTIONS,
+            streaming_connections: DEFAULT_STREAMING_CONNECTIONS,
+        }
+    }
+}
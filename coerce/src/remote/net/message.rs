@@ -1,9 +1,10 @@
 use crate::actor::message::{MessageUnwrapErr, MessageWrapErr};
 use crate::actor::{ActorRefErr, ToActorId};
 use crate::remote::net::proto::network::{
-    ActorAddress, ClientErr, ClientHandshake, ClientResult, CreateActorEvent, Event,
-    FindActorEvent, IdentifyEvent, MessageRequest, NodeIdentity, PingEvent, PongEvent, RaftRequest,
-    SessionHandshake, StreamPublishEvent,
+    ActorAddress, ActorTerminatedEvent, BatchEvent, ClientErr, ClientHandshake, ClientResult,
+    CreateActorEvent, Event, FindActorEvent, GetClusterNodesEvent, GetNodeStatsEvent,
+    GossipRequest, IdentifyEvent, LeaveEvent, MessageRequest, NodeIdentity, PingEvent, PongEvent,
+    RaftRequest, SessionHandshake, StreamPublishEvent, WatchActorEvent,
 };
 use crate::remote::net::{proto, StreamData};
 use chrono::{DateTime, NaiveDateTime, Utc};
@@ -35,6 +36,13 @@ pub enum SessionEvent {
     Result(ClientResult),
     Err(ClientErr),
     Raft(RaftRequest),
+    Batch(BatchEvent),
+    WatchActor(WatchActorEvent),
+    ActorTerminated(ActorTerminatedEvent),
+    GetNodeStats(GetNodeStatsEvent),
+    GetClusterNodes(GetClusterNodesEvent),
+    Gossip(GossipRequest),
+    Leave(LeaveEvent),
 }
 
 #[derive(Debug)]
@@ -51,29 +59,19 @@ impl Display for ClientError {
 
 impl StreamData for ClientEvent {
     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-        match data.split_first() {
-            Some((event, message)) => match Event::from_i32(*event as i32) {
-                Some(Event::Identity) => Some(ClientEvent::Identity(
-                    NodeIdentity::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Handshake) => Some(ClientEvent::Handshake(
-                    ClientHandshake::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Result) => Some(ClientEvent::Result(
-                    ClientResult::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Err) => Some(ClientEvent::Err(
-                    ClientErr::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Ping) => Some(ClientEvent::Ping(
-                    PingEvent::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Pong) => Some(ClientEvent::Pong(
-                    PongEvent::parse_from_bytes(message).unwrap(),
-                )),
-                _ => None,
-            },
-            None => None,
+        Self::try_read_from_bytes(data).ok()
+    }
+
+    fn try_read_from_bytes(data: Vec<u8>) -> Result<Self, DecodeErr> {
+        let (event, message) = read_event(&data)?;
+        match event {
+            Event::Identity => Ok(ClientEvent::Identity(decode(event, message)?)),
+            Event::Handshake => Ok(ClientEvent::Handshake(decode(event, message)?)),
+            Event::Result => Ok(ClientEvent::Result(decode(event, message)?)),
+            Event::Err => Ok(ClientEvent::Err(decode(event, message)?)),
+            Event::Ping => Ok(ClientEvent::Ping(decode(event, message)?)),
+            Event::Pong => Ok(ClientEvent::Pong(decode(event, message)?)),
+            _ => Err(DecodeErr::UnexpectedEvent(event)),
         }
     }
 
@@ -93,44 +91,34 @@ impl StreamData for ClientEvent {
 
 impl StreamData for SessionEvent {
     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-        match data.split_first() {
-            Some((event, message)) => match Event::from_i32(*event as i32) {
-                Some(Event::Identify) => Some(SessionEvent::Identify(
-                    IdentifyEvent::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Handshake) => Some(SessionEvent::Handshake(
-                    SessionHandshake::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Ping) => Some(SessionEvent::Ping(
-                    PingEvent::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Pong) => Some(SessionEvent::Pong(
-                    PongEvent::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::CreateActor) => Some(SessionEvent::CreateActor(
-                    CreateActorEvent::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::FindActor) => Some(SessionEvent::FindActor(
-                    FindActorEvent::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::NotifyActor) => Some(SessionEvent::NotifyActor(
-                    MessageRequest::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::RegisterActor) => Some(SessionEvent::RegisterActor(
-                    ActorAddress::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::StreamPublish) => Some(SessionEvent::StreamPublish(Arc::new(
-                    StreamPublishEvent::parse_from_bytes(message).unwrap(),
-                ))),
-                Some(Event::Result) => Some(SessionEvent::Result(
-                    ClientResult::parse_from_bytes(message).unwrap(),
-                )),
-                Some(Event::Err) => Some(SessionEvent::Err(
-                    ClientErr::parse_from_bytes(message).unwrap(),
-                )),
-                _ => None,
-            },
-            None => None,
+        Self::try_read_from_bytes(data).ok()
+    }
+
+    fn try_read_from_bytes(data: Vec<u8>) -> Result<Self, DecodeErr> {
+        let (event, message) = read_event(&data)?;
+        match event {
+            Event::Identify => Ok(SessionEvent::Identify(decode(event, message)?)),
+            Event::Handshake => Ok(SessionEvent::Handshake(decode(event, message)?)),
+            Event::Ping => Ok(SessionEvent::Ping(decode(event, message)?)),
+            Event::Pong => Ok(SessionEvent::Pong(decode(event, message)?)),
+            Event::CreateActor => Ok(SessionEvent::CreateActor(decode(event, message)?)),
+            Event::FindActor => Ok(SessionEvent::FindActor(decode(event, message)?)),
+            Event::NotifyActor => Ok(SessionEvent::NotifyActor(decode(event, message)?)),
+            Event::RegisterActor => Ok(SessionEvent::RegisterActor(decode(event, message)?)),
+            Event::StreamPublish => Ok(SessionEvent::StreamPublish(Arc::new(decode(
+                event, message,
+            )?))),
+            Event::Result => Ok(SessionEvent::Result(decode(event, message)?)),
+            Event::Err => Ok(SessionEvent::Err(decode(event, message)?)),
+            Event::Batch => Ok(SessionEvent::Batch(decode(event, message)?)),
+            Event::WatchActor => Ok(SessionEvent::WatchActor(decode(event, message)?)),
+            Event::ActorTerminated => Ok(SessionEvent::ActorTerminated(decode(event, message)?)),
+            Event::GetNodeStats => Ok(SessionEvent::GetNodeStats(decode(event, message)?)),
+            Event::GetClusterNodes => Ok(SessionEvent::GetClusterNodes(decode(event, message)?)),
+            Event::Raft => Ok(SessionEvent::Raft(decode(event, message)?)),
+            Event::Gossip => Ok(SessionEvent::Gossip(decode(event, message)?)),
+            Event::Leave => Ok(SessionEvent::Leave(decode(event, message)?)),
+            _ => Err(DecodeErr::UnexpectedEvent(event)),
         }
     }
 
@@ -147,13 +135,71 @@ impl StreamData for SessionEvent {
             SessionEvent::Result(e) => (Event::Result, e.write_to_bytes()),
             SessionEvent::Identify(e) => (Event::Identify, e.write_to_bytes()),
             SessionEvent::Err(e) => (Event::Err, e.write_to_bytes()),
-            _ => return None,
+            SessionEvent::Batch(e) => (Event::Batch, e.write_to_bytes()),
+            SessionEvent::WatchActor(e) => (Event::WatchActor, e.write_to_bytes()),
+            SessionEvent::ActorTerminated(e) => (Event::ActorTerminated, e.write_to_bytes()),
+            SessionEvent::GetNodeStats(e) => (Event::GetNodeStats, e.write_to_bytes()),
+            SessionEvent::GetClusterNodes(e) => (Event::GetClusterNodes, e.write_to_bytes()),
+            SessionEvent::Raft(e) => (Event::Raft, e.write_to_bytes()),
+            SessionEvent::Gossip(e) => (Event::Gossip, e.write_to_bytes()),
+            SessionEvent::Leave(e) => (Event::Leave, e.write_to_bytes()),
         };
 
         write_event(event_id, message)
     }
 }
 
+/// Reason a frame received from another node couldn't be decoded.
+#[derive(Debug)]
+pub enum DecodeErr {
+    EmptyFrame,
+    UnknownEvent(u8),
+    UnexpectedEvent(Event),
+    InvalidMessage { event: Event, error: Error },
+    InvalidData { type_name: &'static str },
+    Decompression(std::io::Error),
+}
+
+impl DecodeErr {
+    /// The event type of the frame, if it was known.
+    pub fn event_type(&self) -> Option<Event> {
+        match &self {
+            DecodeErr::UnexpectedEvent(event) => Some(*event),
+            DecodeErr::InvalidMessage { event, .. } => Some(*event),
+            DecodeErr::Decompression(_) => Some(Event::Compressed),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DecodeErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            DecodeErr::EmptyFrame => write!(f, "empty frame"),
+            DecodeErr::UnknownEvent(event) => write!(f, "unknown event type ({})", event),
+            DecodeErr::UnexpectedEvent(event) => write!(f, "unexpected event type ({:?})", event),
+            DecodeErr::InvalidMessage { event, error } => {
+                write!(f, "invalid {:?} message: {}", event, error)
+            }
+            DecodeErr::InvalidData { type_name } => write!(f, "invalid {} data", type_name),
+            DecodeErr::Decompression(error) => write!(f, "invalid compressed frame: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for DecodeErr {}
+
+fn read_event(data: &[u8]) -> Result<(Event, &[u8]), DecodeErr> {
+    let (event, message) = data.split_first().ok_or(DecodeErr::EmptyFrame)?;
+    let event = Event::from_i32(*event as i32).ok_or(DecodeErr::UnknownEvent(*event))?;
+
+    Ok((event, message))
+}
+
+fn decode<M: Message>(event: Event, message: &[u8]) -> Result<M, DecodeErr> {
+    M::parse_from_bytes(message).map_err(|error| DecodeErr::InvalidMessage { event, error })
+}
+
 fn write_event(event_id: Event, message: Result<Vec<u8>, Error>) -> Option<Vec<u8>> {
     match message {
         Ok(mut message) => {
@@ -252,24 +298,24 @@ impl From<proto::network::ActorRefErr> for ActorRefErr {
         use proto::network::MessageUnwrapErr as ProtoUnwrapErr;
         use proto::network::MessageWrapErr as ProtoWrapErr;
 
-        match err.type_.unwrap() {
+        match err.type_.enum_value_or_default() {
             ErrorType::ActorUnavailable => ActorRefErr::ActorUnavailable,
             ErrorType::NotFound => ActorRefErr::NotFound(err.actor_id.to_actor_id()),
             ErrorType::AlreadyExists => ActorRefErr::AlreadyExists(err.actor_id.to_actor_id()),
             ErrorType::Serialisation => {
-                ActorRefErr::Serialisation(match err.serialization_error.unwrap() {
+                ActorRefErr::Serialisation(match err.serialization_error.enum_value_or_default() {
                     ProtoWrapErr::WrapUnsupported => MessageWrapErr::NotTransmittable,
                     ProtoWrapErr::SerializationErr => MessageWrapErr::SerializationErr,
                     ProtoWrapErr::UnknownWrapErr => MessageWrapErr::Unknown,
                 })
             }
-            ErrorType::Deserialisation => {
-                ActorRefErr::Deserialisation(match err.deserialization_error.unwrap() {
+            ErrorType::Deserialisation => ActorRefErr::Deserialisation(
+                match err.deserialization_error.enum_value_or_default() {
                     ProtoUnwrapErr::UnwrapUnsupported => MessageUnwrapErr::NotTransmittable,
                     ProtoUnwrapErr::DeserializationErr => MessageUnwrapErr::DeserializationErr,
                     ProtoUnwrapErr::UnknownUnwrapErr => MessageUnwrapErr::Unknown,
-                })
-            }
+                },
+            ),
             ErrorType::Timeout => ActorRefErr::Timeout {
                 time_taken_millis: err.time_taken_millis,
             },
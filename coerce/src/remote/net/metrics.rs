@@ -1,5 +1,14 @@
 pub const METRIC_NETWORK_BYTES_RECV: &str = "coerce_network_bytes_recv";
 pub const METRIC_NETWORK_BYTES_SENT: &str = "coerce_network_bytes_sent";
+pub const METRIC_NETWORK_BYTES_UNCOMPRESSED: &str = "coerce_network_bytes_uncompressed";
+pub const METRIC_NETWORK_BYTES_COMPRESSED: &str = "coerce_network_bytes_compressed";
+pub const METRIC_NETWORK_DECODE_ERRORS: &str = "coerce_network_decode_errors";
+pub const METRIC_NETWORK_CLIENT_BUFFERED_BYTES: &str = "coerce_network_client_buffered_bytes";
+pub const METRIC_NETWORK_CLIENT_BUFFERED_MESSAGES: &str = "coerce_network_client_buffered_messages";
+pub const METRIC_NETWORK_CLIENT_BUFFER_DISCARDED: &str = "coerce_network_client_buffer_discarded";
+
+pub const LABEL_PEER_ADDR: &str = "peer_addr";
+pub const LABEL_REASON: &str = "reason";
 
 pub struct NetworkMetrics;
 
@@ -15,4 +24,51 @@ impl NetworkMetrics {
         #[cfg(feature = "metrics")]
         counter!(METRIC_NETWORK_BYTES_SENT, len);
     }
+
+    /// Records the size of a frame before and after it was compressed.
+    #[inline]
+    pub fn incr_compressed_bytes(uncompressed_len: u64, compressed_len: u64) {
+        #[cfg(feature = "metrics")]
+        {
+            counter!(METRIC_NETWORK_BYTES_UNCOMPRESSED, uncompressed_len);
+            counter!(METRIC_NETWORK_BYTES_COMPRESSED, compressed_len);
+        }
+
+        #[cfg(not(feature = "metrics"))]
+        let _ = (uncompressed_len, compressed_len);
+    }
+
+    #[inline]
+    pub fn incr_decode_errors() {
+        #[cfg(feature = "metrics")]
+        counter!(METRIC_NETWORK_DECODE_ERRORS, 1);
+    }
+
+    #[inline]
+    pub fn set_client_buffered(peer_addr: &str, bytes: usize, messages: usize) {
+        #[cfg(feature = "metrics")]
+        {
+            gauge!(METRIC_NETWORK_CLIENT_BUFFERED_BYTES,
+                bytes as f64,
+                LABEL_PEER_ADDR => peer_addr.to_string());
+
+            gauge!(METRIC_NETWORK_CLIENT_BUFFERED_MESSAGES,
+                messages as f64,
+                LABEL_PEER_ADDR => peer_addr.to_string());
+        }
+
+        #[cfg(not(feature = "metrics"))]
+        let _ = (peer_addr, bytes, messages);
+    }
+
+    #[inline]
+    pub fn incr_client_buffer_discarded(peer_addr: &str, reason: &'static str) {
+        #[cfg(feature = "metrics")]
+        increment_counter!(METRIC_NETWORK_CLIENT_BUFFER_DISCARDED,
+            LABEL_PEER_ADDR => peer_addr.to_string(),
+            LABEL_REASON => reason);
+
+        #[cfg(not(feature = "metrics"))]
+        let _ = (peer_addr, reason);
+    }
 }
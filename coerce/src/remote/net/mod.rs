@@ -1,3 +1,4 @@
+use crate::remote::net::message::DecodeErr;
 use crate::remote::system::RemoteActorSystem;
 
 use std::future::Future;
@@ -11,15 +12,26 @@ use futures::StreamExt;
 use tokio_util::codec::{FramedRead, LengthDelimitedCodec};
 
 pub mod client;
+pub mod compression;
+pub mod config;
 pub mod message;
 pub mod metrics;
 pub mod proto;
+pub mod protocol;
 pub mod security;
 pub mod server;
+pub mod transport;
 
 pub trait StreamData: 'static + Send + Sync + Sized {
     fn read_from_bytes(data: Vec<u8>) -> Option<Self>;
 
+    /// Decodes `data`, providing the reason decoding failed rather than just `None`.
+    fn try_read_from_bytes(data: Vec<u8>) -> Result<Self, DecodeErr> {
+        Self::read_from_bytes(data).ok_or(DecodeErr::InvalidData {
+            type_name: std::any::type_name::<Self>(),
+        })
+    }
+
     fn write_to_bytes(&self) -> Option<Vec<u8>>;
 }
 
@@ -31,7 +43,7 @@ pub trait StreamReceiver {
 
     async fn on_close(&mut self, sys: &RemoteActorSystem);
 
-    fn on_deserialisation_failed(&mut self);
+    fn on_deserialisation_failed(&mut self, error: DecodeErr);
 
     fn on_stream_lost(&mut self, error: Error);
 
@@ -86,19 +98,24 @@ pub async fn receive_loop<R: StreamReceiver, S: tokio::io::AsyncRead + Unpin>(
 ) where
     R: Send,
 {
+    let max_frame_length = system.config().network_config().max_frame_length;
     let mut reader = read;
     while let Some(res) = reader.next().await {
         match res {
-            Ok(res) => match R::Message::read_from_bytes(res.to_vec()) {
-                Some(msg) => {
+            Ok(res) => match compression::decompress_frame(res.to_vec(), max_frame_length)
+                .and_then(R::Message::try_read_from_bytes)
+            {
+                Ok(msg) => {
                     receiver.on_receive(msg, &system).await;
                     if receiver.should_close() {
                         break;
                     }
                 }
-                None => {
-                    // TODO: either pass the buffer into here or more context, this is pretty useless at the moment..
-                    receiver.on_deserialisation_failed();
+                Err(error) => {
+                    receiver.on_deserialisation_failed(error);
+                    if receiver.should_close() {
+                        break;
+                    }
                 }
             },
             Err(e) => {
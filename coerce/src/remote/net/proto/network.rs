@@ -39,6 +39,10 @@ pub struct RemoteNode {
     pub tag: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.RemoteNode.attributes)
     pub attributes: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:coerce.network.RemoteNode.roles)
+    pub roles: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:coerce.network.RemoteNode.datacenter)
+    pub datacenter: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.RemoteNode.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -56,7 +60,7 @@ impl RemoteNode {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "node_id",
@@ -83,6 +87,16 @@ impl RemoteNode {
             |m: &RemoteNode| { &m.attributes },
             |m: &mut RemoteNode| { &mut m.attributes },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "roles",
+            |m: &RemoteNode| { &m.roles },
+            |m: &mut RemoteNode| { &mut m.roles },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "datacenter",
+            |m: &RemoteNode| { &m.datacenter },
+            |m: &mut RemoteNode| { &mut m.datacenter },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RemoteNode>(
             "RemoteNode",
             fields,
@@ -128,6 +142,12 @@ impl ::protobuf::Message for RemoteNode {
                     is.pop_limit(old_limit);
                     self.attributes.insert(key, value);
                 },
+                50 => {
+                    self.roles.push(is.read_string()?);
+                },
+                58 => {
+                    self.datacenter = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -159,6 +179,12 @@ impl ::protobuf::Message for RemoteNode {
             entry_size += ::protobuf::rt::string_size(2, &v);
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
         };
+        for value in &self.roles {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        if !self.datacenter.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.datacenter);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -186,6 +212,12 @@ impl ::protobuf::Message for RemoteNode {
             os.write_string(1, &k)?;
             os.write_string(2, &v)?;
         };
+        for v in &self.roles {
+            os.write_string(6, &v)?;
+        };
+        if !self.datacenter.is_empty() {
+            os.write_string(7, &self.datacenter)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -208,6 +240,8 @@ impl ::protobuf::Message for RemoteNode {
         self.node_started_at.clear();
         self.tag.clear();
         self.attributes.clear();
+        self.roles.clear();
+        self.datacenter.clear();
         self.special_fields.clear();
     }
 
@@ -244,6 +278,10 @@ pub struct IdentifyEvent {
     pub source_node_tag: ::std::string::String,
     // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.token)
     pub token: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.protocol)
+    pub protocol: ::protobuf::MessageField<ProtocolSupport>,
+    // @@protoc_insertion_point(field:coerce.network.IdentifyEvent.application_version)
+    pub application_version: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.IdentifyEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -261,7 +299,7 @@ impl IdentifyEvent {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "source_node_id",
@@ -278,6 +316,16 @@ impl IdentifyEvent {
             |m: &IdentifyEvent| { &m.token },
             |m: &mut IdentifyEvent| { &mut m.token },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtocolSupport>(
+            "protocol",
+            |m: &IdentifyEvent| { &m.protocol },
+            |m: &mut IdentifyEvent| { &mut m.protocol },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "application_version",
+            |m: &IdentifyEvent| { &m.application_version },
+            |m: &mut IdentifyEvent| { &mut m.application_version },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<IdentifyEvent>(
             "IdentifyEvent",
             fields,
@@ -305,6 +353,12 @@ impl ::protobuf::Message for IdentifyEvent {
                 26 => {
                     self.token = is.read_string()?;
                 },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol)?;
+                },
+                42 => {
+                    self.application_version = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -326,6 +380,13 @@ impl ::protobuf::Message for IdentifyEvent {
         if !self.token.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.token);
         }
+        if let Some(v) = self.protocol.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.application_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.application_version);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -341,6 +402,12 @@ impl ::protobuf::Message for IdentifyEvent {
         if !self.token.is_empty() {
             os.write_string(3, &self.token)?;
         }
+        if let Some(v) = self.protocol.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if !self.application_version.is_empty() {
+            os.write_string(5, &self.application_version)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -361,6 +428,8 @@ impl ::protobuf::Message for IdentifyEvent {
         self.source_node_id = 0;
         self.source_node_tag.clear();
         self.token.clear();
+        self.protocol.clear();
+        self.application_version.clear();
         self.special_fields.clear();
     }
 
@@ -369,6 +438,8 @@ impl ::protobuf::Message for IdentifyEvent {
             source_node_id: 0,
             source_node_tag: ::std::string::String::new(),
             token: ::std::string::String::new(),
+            protocol: ::protobuf::MessageField::none(),
+            application_version: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -392,6 +463,182 @@ impl ::protobuf::reflect::ProtobufValue for IdentifyEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.ProtocolSupport)
+pub struct ProtocolSupport {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.ProtocolSupport.min_version)
+    pub min_version: u32,
+    // @@protoc_insertion_point(field:coerce.network.ProtocolSupport.max_version)
+    pub max_version: u32,
+    // @@protoc_insertion_point(field:coerce.network.ProtocolSupport.features)
+    pub features: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:coerce.network.ProtocolSupport.required_features)
+    pub required_features: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.ProtocolSupport.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ProtocolSupport {
+    fn default() -> &'a ProtocolSupport {
+        <ProtocolSupport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ProtocolSupport {
+    pub fn new() -> ProtocolSupport {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "min_version",
+            |m: &ProtocolSupport| { &m.min_version },
+            |m: &mut ProtocolSupport| { &mut m.min_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_version",
+            |m: &ProtocolSupport| { &m.max_version },
+            |m: &mut ProtocolSupport| { &mut m.max_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "features",
+            |m: &ProtocolSupport| { &m.features },
+            |m: &mut ProtocolSupport| { &mut m.features },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "required_features",
+            |m: &ProtocolSupport| { &m.required_features },
+            |m: &mut ProtocolSupport| { &mut m.required_features },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ProtocolSupport>(
+            "ProtocolSupport",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ProtocolSupport {
+    const NAME: &'static str = "ProtocolSupport";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.min_version = is.read_uint32()?;
+                },
+                16 => {
+                    self.max_version = is.read_uint32()?;
+                },
+                26 => {
+                    self.features.push(is.read_string()?);
+                },
+                34 => {
+                    self.required_features.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.min_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.min_version);
+        }
+        if self.max_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.max_version);
+        }
+        for value in &self.features {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.required_features {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.min_version != 0 {
+            os.write_uint32(1, self.min_version)?;
+        }
+        if self.max_version != 0 {
+            os.write_uint32(2, self.max_version)?;
+        }
+        for v in &self.features {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.required_features {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ProtocolSupport {
+        ProtocolSupport::new()
+    }
+
+    fn clear(&mut self) {
+        self.min_version = 0;
+        self.max_version = 0;
+        self.features.clear();
+        self.required_features.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ProtocolSupport {
+        static instance: ProtocolSupport = ProtocolSupport {
+            min_version: 0,
+            max_version: 0,
+            features: ::std::vec::Vec::new(),
+            required_features: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ProtocolSupport {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ProtocolSupport").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ProtocolSupport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProtocolSupport {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:coerce.network.NodeIdentity)
 pub struct NodeIdentity {
@@ -414,6 +661,12 @@ pub struct NodeIdentity {
     pub capabilities: ::protobuf::MessageField<SystemCapabilities>,
     // @@protoc_insertion_point(field:coerce.network.NodeIdentity.attributes)
     pub attributes: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:coerce.network.NodeIdentity.protocol)
+    pub protocol: ::protobuf::MessageField<ProtocolSupport>,
+    // @@protoc_insertion_point(field:coerce.network.NodeIdentity.roles)
+    pub roles: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:coerce.network.NodeIdentity.datacenter)
+    pub datacenter: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.network.NodeIdentity.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -431,7 +684,7 @@ impl NodeIdentity {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut fields = ::std::vec::Vec::with_capacity(12);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "node_id",
@@ -478,6 +731,21 @@ impl NodeIdentity {
             |m: &NodeIdentity| { &m.attributes },
             |m: &mut NodeIdentity| { &mut m.attributes },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ProtocolSupport>(
+            "protocol",
+            |m: &NodeIdentity| { &m.protocol },
+            |m: &mut NodeIdentity| { &mut m.protocol },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "roles",
+            |m: &NodeIdentity| { &m.roles },
+            |m: &mut NodeIdentity| { &mut m.roles },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "datacenter",
+            |m: &NodeIdentity| { &m.datacenter },
+            |m: &mut NodeIdentity| { &mut m.datacenter },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeIdentity>(
             "NodeIdentity",
             fields,
@@ -535,6 +803,15 @@ impl ::protobuf::Message for NodeIdentity {
                     is.pop_limit(old_limit);
                     self.attributes.insert(key, value);
                 },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.protocol)?;
+                },
+                90 => {
+                    self.roles.push(is.read_string()?);
+                },
+                98 => {
+                    self.datacenter = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -580,6 +857,16 @@ impl ::protobuf::Message for NodeIdentity {
             entry_size += ::protobuf::rt::string_size(2, &v);
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
         };
+        if let Some(v) = self.protocol.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.roles {
+            my_size += ::protobuf::rt::string_size(11, &value);
+        };
+        if !self.datacenter.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.datacenter);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -619,6 +906,15 @@ impl ::protobuf::Message for NodeIdentity {
             os.write_string(1, &k)?;
             os.write_string(2, &v)?;
         };
+        if let Some(v) = self.protocol.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        for v in &self.roles {
+            os.write_string(11, &v)?;
+        };
+        if !self.datacenter.is_empty() {
+            os.write_string(12, &self.datacenter)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -645,6 +941,9 @@ impl ::protobuf::Message for NodeIdentity {
         self.peers.clear();
         self.capabilities.clear();
         self.attributes.clear();
+        self.protocol.clear();
+        self.roles.clear();
+        self.datacenter.clear();
         self.special_fields.clear();
     }
 
@@ -1641,73 +1940,45 @@ impl ::protobuf::reflect::ProtobufValue for PongEvent {
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.CreateActorEvent)
-pub struct CreateActorEvent {
+// @@protoc_insertion_point(message:coerce.network.BatchEvent)
+pub struct BatchEvent {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.message_id)
-    pub message_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.actor_id)
-    pub actor_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.actor_type)
-    pub actor_type: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.recipe)
-    pub recipe: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.BatchEvent.events)
+    pub events: ::std::vec::Vec<::std::vec::Vec<u8>>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.CreateActorEvent.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.BatchEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CreateActorEvent {
-    fn default() -> &'a CreateActorEvent {
-        <CreateActorEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a BatchEvent {
+    fn default() -> &'a BatchEvent {
+        <BatchEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CreateActorEvent {
-    pub fn new() -> CreateActorEvent {
+impl BatchEvent {
+    pub fn new() -> BatchEvent {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message_id",
-            |m: &CreateActorEvent| { &m.message_id },
-            |m: &mut CreateActorEvent| { &mut m.message_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "actor_id",
-            |m: &CreateActorEvent| { &m.actor_id },
-            |m: &mut CreateActorEvent| { &mut m.actor_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "actor_type",
-            |m: &CreateActorEvent| { &m.actor_type },
-            |m: &mut CreateActorEvent| { &mut m.actor_type },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "recipe",
-            |m: &CreateActorEvent| { &m.recipe },
-            |m: &mut CreateActorEvent| { &mut m.recipe },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &CreateActorEvent| { &m.trace_id },
-            |m: &mut CreateActorEvent| { &mut m.trace_id },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &BatchEvent| { &m.events },
+            |m: &mut BatchEvent| { &mut m.events },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateActorEvent>(
-            "CreateActorEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchEvent>(
+            "BatchEvent",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CreateActorEvent {
-    const NAME: &'static str = "CreateActorEvent";
+impl ::protobuf::Message for BatchEvent {
+    const NAME: &'static str = "BatchEvent";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1717,19 +1988,7 @@ impl ::protobuf::Message for CreateActorEvent {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.message_id = is.read_string()?;
-                },
-                18 => {
-                    self.actor_id = is.read_string()?;
-                },
-                26 => {
-                    self.actor_type = is.read_string()?;
-                },
-                34 => {
-                    self.recipe = is.read_bytes()?;
-                },
-                42 => {
-                    self.trace_id = is.read_string()?;
+                    self.events.push(is.read_bytes()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1743,42 +2002,18 @@ impl ::protobuf::Message for CreateActorEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.message_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.message_id);
-        }
-        if !self.actor_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.actor_id);
-        }
-        if !self.actor_type.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.actor_type);
-        }
-        if !self.recipe.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.recipe);
-        }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(5, &self.trace_id);
-        }
+        for value in &self.events {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.message_id.is_empty() {
-            os.write_string(1, &self.message_id)?;
-        }
-        if !self.actor_id.is_empty() {
-            os.write_string(2, &self.actor_id)?;
-        }
-        if !self.actor_type.is_empty() {
-            os.write_string(3, &self.actor_type)?;
-        }
-        if !self.recipe.is_empty() {
-            os.write_bytes(4, &self.recipe)?;
-        }
-        if !self.trace_id.is_empty() {
-            os.write_string(5, &self.trace_id)?;
-        }
+        for v in &self.events {
+            os.write_bytes(1, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1791,72 +2026,64 @@ impl ::protobuf::Message for CreateActorEvent {
         &mut self.special_fields
     }
 
-    fn new() -> CreateActorEvent {
-        CreateActorEvent::new()
+    fn new() -> BatchEvent {
+        BatchEvent::new()
     }
 
     fn clear(&mut self) {
-        self.message_id.clear();
-        self.actor_id.clear();
-        self.actor_type.clear();
-        self.recipe.clear();
-        self.trace_id.clear();
+        self.events.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CreateActorEvent {
-        static instance: CreateActorEvent = CreateActorEvent {
-            message_id: ::std::string::String::new(),
-            actor_id: ::std::string::String::new(),
-            actor_type: ::std::string::String::new(),
-            recipe: ::std::vec::Vec::new(),
-            trace_id: ::std::string::String::new(),
+    fn default_instance() -> &'static BatchEvent {
+        static instance: BatchEvent = BatchEvent {
+            events: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for CreateActorEvent {
+impl ::protobuf::MessageFull for BatchEvent {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateActorEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchEvent").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CreateActorEvent {
+impl ::std::fmt::Display for BatchEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CreateActorEvent {
+impl ::protobuf::reflect::ProtobufValue for BatchEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.FindActorEvent)
-pub struct FindActorEvent {
+// @@protoc_insertion_point(message:coerce.network.WatchActorEvent)
+pub struct WatchActorEvent {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.FindActorEvent.message_id)
-    pub message_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.FindActorEvent.actor_id)
+    // @@protoc_insertion_point(field:coerce.network.WatchActorEvent.actor_id)
     pub actor_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.FindActorEvent.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.WatchActorEvent.watcher_node_id)
+    pub watcher_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.WatchActorEvent.unwatch)
+    pub unwatch: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.FindActorEvent.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.WatchActorEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a FindActorEvent {
-    fn default() -> &'a FindActorEvent {
-        <FindActorEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a WatchActorEvent {
+    fn default() -> &'a WatchActorEvent {
+        <WatchActorEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl FindActorEvent {
-    pub fn new() -> FindActorEvent {
+impl WatchActorEvent {
+    pub fn new() -> WatchActorEvent {
         ::std::default::Default::default()
     }
 
@@ -1864,30 +2091,30 @@ impl FindActorEvent {
         let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message_id",
-            |m: &FindActorEvent| { &m.message_id },
-            |m: &mut FindActorEvent| { &mut m.message_id },
+            "actor_id",
+            |m: &WatchActorEvent| { &m.actor_id },
+            |m: &mut WatchActorEvent| { &mut m.actor_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "actor_id",
-            |m: &FindActorEvent| { &m.actor_id },
-            |m: &mut FindActorEvent| { &mut m.actor_id },
+            "watcher_node_id",
+            |m: &WatchActorEvent| { &m.watcher_node_id },
+            |m: &mut WatchActorEvent| { &mut m.watcher_node_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &FindActorEvent| { &m.trace_id },
-            |m: &mut FindActorEvent| { &mut m.trace_id },
+            "unwatch",
+            |m: &WatchActorEvent| { &m.unwatch },
+            |m: &mut WatchActorEvent| { &mut m.unwatch },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FindActorEvent>(
-            "FindActorEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<WatchActorEvent>(
+            "WatchActorEvent",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for FindActorEvent {
-    const NAME: &'static str = "FindActorEvent";
+impl ::protobuf::Message for WatchActorEvent {
+    const NAME: &'static str = "WatchActorEvent";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1897,13 +2124,13 @@ impl ::protobuf::Message for FindActorEvent {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.message_id = is.read_string()?;
-                },
-                18 => {
                     self.actor_id = is.read_string()?;
                 },
-                26 => {
-                    self.trace_id = is.read_string()?;
+                16 => {
+                    self.watcher_node_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.unwatch = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1917,14 +2144,14 @@ impl ::protobuf::Message for FindActorEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.message_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.message_id);
-        }
         if !self.actor_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.actor_id);
+            my_size += ::protobuf::rt::string_size(1, &self.actor_id);
         }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.trace_id);
+        if self.watcher_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.watcher_node_id);
+        }
+        if self.unwatch != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1932,14 +2159,14 @@ impl ::protobuf::Message for FindActorEvent {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.message_id.is_empty() {
-            os.write_string(1, &self.message_id)?;
-        }
         if !self.actor_id.is_empty() {
-            os.write_string(2, &self.actor_id)?;
+            os.write_string(1, &self.actor_id)?;
         }
-        if !self.trace_id.is_empty() {
-            os.write_string(3, &self.trace_id)?;
+        if self.watcher_node_id != 0 {
+            os.write_uint64(2, self.watcher_node_id)?;
+        }
+        if self.unwatch != false {
+            os.write_bool(3, self.unwatch)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1953,68 +2180,68 @@ impl ::protobuf::Message for FindActorEvent {
         &mut self.special_fields
     }
 
-    fn new() -> FindActorEvent {
-        FindActorEvent::new()
+    fn new() -> WatchActorEvent {
+        WatchActorEvent::new()
     }
 
     fn clear(&mut self) {
-        self.message_id.clear();
         self.actor_id.clear();
-        self.trace_id.clear();
+        self.watcher_node_id = 0;
+        self.unwatch = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static FindActorEvent {
-        static instance: FindActorEvent = FindActorEvent {
-            message_id: ::std::string::String::new(),
+    fn default_instance() -> &'static WatchActorEvent {
+        static instance: WatchActorEvent = WatchActorEvent {
             actor_id: ::std::string::String::new(),
-            trace_id: ::std::string::String::new(),
+            watcher_node_id: 0,
+            unwatch: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for FindActorEvent {
+impl ::protobuf::MessageFull for WatchActorEvent {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("FindActorEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("WatchActorEvent").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for FindActorEvent {
+impl ::std::fmt::Display for WatchActorEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for FindActorEvent {
+impl ::protobuf::reflect::ProtobufValue for WatchActorEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.ActorAddress)
-pub struct ActorAddress {
+// @@protoc_insertion_point(message:coerce.network.ActorTerminatedEvent)
+pub struct ActorTerminatedEvent {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.ActorAddress.actor_id)
+    // @@protoc_insertion_point(field:coerce.network.ActorTerminatedEvent.actor_id)
     pub actor_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.ActorAddress.node_id)
-    pub node_id: ::protobuf::MessageField<::protobuf::well_known_types::wrappers::UInt64Value>,
-    // @@protoc_insertion_point(field:coerce.network.ActorAddress.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.ActorTerminatedEvent.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.ActorTerminatedEvent.reason)
+    pub reason: ::protobuf::EnumOrUnknown<TerminationReason>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.ActorAddress.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.ActorTerminatedEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a ActorAddress {
-    fn default() -> &'a ActorAddress {
-        <ActorAddress as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ActorTerminatedEvent {
+    fn default() -> &'a ActorTerminatedEvent {
+        <ActorTerminatedEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ActorAddress {
-    pub fn new() -> ActorAddress {
+impl ActorTerminatedEvent {
+    pub fn new() -> ActorTerminatedEvent {
         ::std::default::Default::default()
     }
 
@@ -2023,29 +2250,29 @@ impl ActorAddress {
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "actor_id",
-            |m: &ActorAddress| { &m.actor_id },
-            |m: &mut ActorAddress| { &mut m.actor_id },
+            |m: &ActorTerminatedEvent| { &m.actor_id },
+            |m: &mut ActorTerminatedEvent| { &mut m.actor_id },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::wrappers::UInt64Value>(
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "node_id",
-            |m: &ActorAddress| { &m.node_id },
-            |m: &mut ActorAddress| { &mut m.node_id },
+            |m: &ActorTerminatedEvent| { &m.node_id },
+            |m: &mut ActorTerminatedEvent| { &mut m.node_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &ActorAddress| { &m.trace_id },
-            |m: &mut ActorAddress| { &mut m.trace_id },
+            "reason",
+            |m: &ActorTerminatedEvent| { &m.reason },
+            |m: &mut ActorTerminatedEvent| { &mut m.reason },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ActorAddress>(
-            "ActorAddress",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ActorTerminatedEvent>(
+            "ActorTerminatedEvent",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for ActorAddress {
-    const NAME: &'static str = "ActorAddress";
+impl ::protobuf::Message for ActorTerminatedEvent {
+    const NAME: &'static str = "ActorTerminatedEvent";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2057,11 +2284,11 @@ impl ::protobuf::Message for ActorAddress {
                 10 => {
                     self.actor_id = is.read_string()?;
                 },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node_id)?;
+                16 => {
+                    self.node_id = is.read_uint64()?;
                 },
-                26 => {
-                    self.trace_id = is.read_string()?;
+                24 => {
+                    self.reason = is.read_enum_or_unknown()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2078,12 +2305,11 @@ impl ::protobuf::Message for ActorAddress {
         if !self.actor_id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.actor_id);
         }
-        if let Some(v) = self.node_id.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.node_id);
         }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.trace_id);
+        if self.reason != ::protobuf::EnumOrUnknown::new(TerminationReason::Stopped) {
+            my_size += ::protobuf::rt::int32_size(3, self.reason.value());
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2094,11 +2320,11 @@ impl ::protobuf::Message for ActorAddress {
         if !self.actor_id.is_empty() {
             os.write_string(1, &self.actor_id)?;
         }
-        if let Some(v) = self.node_id.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if self.node_id != 0 {
+            os.write_uint64(2, self.node_id)?;
         }
-        if !self.trace_id.is_empty() {
-            os.write_string(3, &self.trace_id)?;
+        if self.reason != ::protobuf::EnumOrUnknown::new(TerminationReason::Stopped) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.reason))?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2112,127 +2338,85 @@ impl ::protobuf::Message for ActorAddress {
         &mut self.special_fields
     }
 
-    fn new() -> ActorAddress {
-        ActorAddress::new()
+    fn new() -> ActorTerminatedEvent {
+        ActorTerminatedEvent::new()
     }
 
     fn clear(&mut self) {
         self.actor_id.clear();
-        self.node_id.clear();
-        self.trace_id.clear();
+        self.node_id = 0;
+        self.reason = ::protobuf::EnumOrUnknown::new(TerminationReason::Stopped);
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static ActorAddress {
-        static instance: ActorAddress = ActorAddress {
+    fn default_instance() -> &'static ActorTerminatedEvent {
+        static instance: ActorTerminatedEvent = ActorTerminatedEvent {
             actor_id: ::std::string::String::new(),
-            node_id: ::protobuf::MessageField::none(),
-            trace_id: ::std::string::String::new(),
+            node_id: 0,
+            reason: ::protobuf::EnumOrUnknown::from_i32(0),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for ActorAddress {
+impl ::protobuf::MessageFull for ActorTerminatedEvent {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("ActorAddress").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ActorTerminatedEvent").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for ActorAddress {
+impl ::std::fmt::Display for ActorTerminatedEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ActorAddress {
+impl ::protobuf::reflect::ProtobufValue for ActorTerminatedEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.MessageRequest)
-pub struct MessageRequest {
+// @@protoc_insertion_point(message:coerce.network.GetNodeStatsEvent)
+pub struct GetNodeStatsEvent {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.message_id)
+    // @@protoc_insertion_point(field:coerce.network.GetNodeStatsEvent.message_id)
     pub message_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.handler_type)
-    pub handler_type: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.actor_id)
-    pub actor_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.message)
-    pub message: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.trace_id)
-    pub trace_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.requires_response)
-    pub requires_response: bool,
-    // @@protoc_insertion_point(field:coerce.network.MessageRequest.origin_node_id)
-    pub origin_node_id: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.MessageRequest.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.GetNodeStatsEvent.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a MessageRequest {
-    fn default() -> &'a MessageRequest {
-        <MessageRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetNodeStatsEvent {
+    fn default() -> &'a GetNodeStatsEvent {
+        <GetNodeStatsEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MessageRequest {
-    pub fn new() -> MessageRequest {
+impl GetNodeStatsEvent {
+    pub fn new() -> GetNodeStatsEvent {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "message_id",
-            |m: &MessageRequest| { &m.message_id },
-            |m: &mut MessageRequest| { &mut m.message_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "handler_type",
-            |m: &MessageRequest| { &m.handler_type },
-            |m: &mut MessageRequest| { &mut m.handler_type },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "actor_id",
-            |m: &MessageRequest| { &m.actor_id },
-            |m: &mut MessageRequest| { &mut m.actor_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message",
-            |m: &MessageRequest| { &m.message },
-            |m: &mut MessageRequest| { &mut m.message },
+            |m: &GetNodeStatsEvent| { &m.message_id },
+            |m: &mut GetNodeStatsEvent| { &mut m.message_id },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &MessageRequest| { &m.trace_id },
-            |m: &mut MessageRequest| { &mut m.trace_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "requires_response",
-            |m: &MessageRequest| { &m.requires_response },
-            |m: &mut MessageRequest| { &mut m.requires_response },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "origin_node_id",
-            |m: &MessageRequest| { &m.origin_node_id },
-            |m: &mut MessageRequest| { &mut m.origin_node_id },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MessageRequest>(
-            "MessageRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetNodeStatsEvent>(
+            "GetNodeStatsEvent",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for MessageRequest {
-    const NAME: &'static str = "MessageRequest";
+impl ::protobuf::Message for GetNodeStatsEvent {
+    const NAME: &'static str = "GetNodeStatsEvent";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2244,24 +2428,6 @@ impl ::protobuf::Message for MessageRequest {
                 10 => {
                     self.message_id = is.read_string()?;
                 },
-                18 => {
-                    self.handler_type = is.read_string()?;
-                },
-                26 => {
-                    self.actor_id = is.read_string()?;
-                },
-                34 => {
-                    self.message = is.read_bytes()?;
-                },
-                42 => {
-                    self.trace_id = is.read_string()?;
-                },
-                48 => {
-                    self.requires_response = is.read_bool()?;
-                },
-                56 => {
-                    self.origin_node_id = is.read_uint64()?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -2277,24 +2443,6 @@ impl ::protobuf::Message for MessageRequest {
         if !self.message_id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.message_id);
         }
-        if !self.handler_type.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.handler_type);
-        }
-        if !self.actor_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.actor_id);
-        }
-        if !self.message.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.message);
-        }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(5, &self.trace_id);
-        }
-        if self.requires_response != false {
-            my_size += 1 + 1;
-        }
-        if self.origin_node_id != 0 {
-            my_size += ::protobuf::rt::uint64_size(7, self.origin_node_id);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -2304,24 +2452,6 @@ impl ::protobuf::Message for MessageRequest {
         if !self.message_id.is_empty() {
             os.write_string(1, &self.message_id)?;
         }
-        if !self.handler_type.is_empty() {
-            os.write_string(2, &self.handler_type)?;
-        }
-        if !self.actor_id.is_empty() {
-            os.write_string(3, &self.actor_id)?;
-        }
-        if !self.message.is_empty() {
-            os.write_bytes(4, &self.message)?;
-        }
-        if !self.trace_id.is_empty() {
-            os.write_string(5, &self.trace_id)?;
-        }
-        if self.requires_response != false {
-            os.write_bool(6, self.requires_response)?;
-        }
-        if self.origin_node_id != 0 {
-            os.write_uint64(7, self.origin_node_id)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2334,128 +2464,88 @@ impl ::protobuf::Message for MessageRequest {
         &mut self.special_fields
     }
 
-    fn new() -> MessageRequest {
-        MessageRequest::new()
+    fn new() -> GetNodeStatsEvent {
+        GetNodeStatsEvent::new()
     }
 
     fn clear(&mut self) {
         self.message_id.clear();
-        self.handler_type.clear();
-        self.actor_id.clear();
-        self.message.clear();
-        self.trace_id.clear();
-        self.requires_response = false;
-        self.origin_node_id = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static MessageRequest {
-        static instance: MessageRequest = MessageRequest {
+    fn default_instance() -> &'static GetNodeStatsEvent {
+        static instance: GetNodeStatsEvent = GetNodeStatsEvent {
             message_id: ::std::string::String::new(),
-            handler_type: ::std::string::String::new(),
-            actor_id: ::std::string::String::new(),
-            message: ::std::vec::Vec::new(),
-            trace_id: ::std::string::String::new(),
-            requires_response: false,
-            origin_node_id: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for MessageRequest {
+impl ::protobuf::MessageFull for GetNodeStatsEvent {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("MessageRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetNodeStatsEvent").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for MessageRequest {
+impl ::std::fmt::Display for GetNodeStatsEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MessageRequest {
+impl ::protobuf::reflect::ProtobufValue for GetNodeStatsEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.SessionHandshake)
-pub struct SessionHandshake {
+// @@protoc_insertion_point(message:coerce.network.NodeStats)
+pub struct NodeStats {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.node_id)
+    // @@protoc_insertion_point(field:coerce.network.NodeStats.node_id)
     pub node_id: u64,
-    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.nodes)
-    pub nodes: ::std::vec::Vec<RemoteNode>,
-    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.token)
-    pub token: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.node_tag)
-    pub node_tag: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.client_type)
-    pub client_type: ::protobuf::EnumOrUnknown<ClientType>,
-    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.NodeStats.actor_count)
+    pub actor_count: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.SessionHandshake.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.NodeStats.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SessionHandshake {
-    fn default() -> &'a SessionHandshake {
-        <SessionHandshake as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a NodeStats {
+    fn default() -> &'a NodeStats {
+        <NodeStats as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SessionHandshake {
-    pub fn new() -> SessionHandshake {
+impl NodeStats {
+    pub fn new() -> NodeStats {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "node_id",
-            |m: &SessionHandshake| { &m.node_id },
-            |m: &mut SessionHandshake| { &mut m.node_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "nodes",
-            |m: &SessionHandshake| { &m.nodes },
-            |m: &mut SessionHandshake| { &mut m.nodes },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "token",
-            |m: &SessionHandshake| { &m.token },
-            |m: &mut SessionHandshake| { &mut m.token },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "node_tag",
-            |m: &SessionHandshake| { &m.node_tag },
-            |m: &mut SessionHandshake| { &mut m.node_tag },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "client_type",
-            |m: &SessionHandshake| { &m.client_type },
-            |m: &mut SessionHandshake| { &mut m.client_type },
+            |m: &NodeStats| { &m.node_id },
+            |m: &mut NodeStats| { &mut m.node_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &SessionHandshake| { &m.trace_id },
-            |m: &mut SessionHandshake| { &mut m.trace_id },
+            "actor_count",
+            |m: &NodeStats| { &m.actor_count },
+            |m: &mut NodeStats| { &mut m.actor_count },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SessionHandshake>(
-            "SessionHandshake",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeStats>(
+            "NodeStats",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SessionHandshake {
-    const NAME: &'static str = "SessionHandshake";
+impl ::protobuf::Message for NodeStats {
+    const NAME: &'static str = "NodeStats";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2467,20 +2557,8 @@ impl ::protobuf::Message for SessionHandshake {
                 8 => {
                     self.node_id = is.read_uint64()?;
                 },
-                18 => {
-                    self.nodes.push(is.read_message()?);
-                },
-                26 => {
-                    self.token = is.read_bytes()?;
-                },
-                34 => {
-                    self.node_tag = is.read_string()?;
-                },
-                40 => {
-                    self.client_type = is.read_enum_or_unknown()?;
-                },
-                50 => {
-                    self.trace_id = is.read_string()?;
+                16 => {
+                    self.actor_count = is.read_uint64()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2497,46 +2575,5219 @@ impl ::protobuf::Message for SessionHandshake {
         if self.node_id != 0 {
             my_size += ::protobuf::rt::uint64_size(1, self.node_id);
         }
-        for value in &self.nodes {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.actor_count != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.actor_count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        if self.actor_count != 0 {
+            os.write_uint64(2, self.actor_count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NodeStats {
+        NodeStats::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.actor_count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NodeStats {
+        static instance: NodeStats = NodeStats {
+            node_id: 0,
+            actor_count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
         };
-        if !self.token.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.token);
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NodeStats {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NodeStats").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NodeStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeStats {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.GetClusterNodesEvent)
+pub struct GetClusterNodesEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.GetClusterNodesEvent.message_id)
+    pub message_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.GetClusterNodesEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetClusterNodesEvent {
+    fn default() -> &'a GetClusterNodesEvent {
+        <GetClusterNodesEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetClusterNodesEvent {
+    pub fn new() -> GetClusterNodesEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &GetClusterNodesEvent| { &m.message_id },
+            |m: &mut GetClusterNodesEvent| { &mut m.message_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetClusterNodesEvent>(
+            "GetClusterNodesEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetClusterNodesEvent {
+    const NAME: &'static str = "GetClusterNodesEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
-        if !self.node_tag.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.node_tag);
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
         }
-        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::Client) {
-            my_size += ::protobuf::rt::int32_size(5, self.client_type.value());
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetClusterNodesEvent {
+        GetClusterNodesEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetClusterNodesEvent {
+        static instance: GetClusterNodesEvent = GetClusterNodesEvent {
+            message_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetClusterNodesEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetClusterNodesEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetClusterNodesEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetClusterNodesEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.LeaveEvent)
+pub struct LeaveEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.LeaveEvent.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.LeaveEvent.node_id)
+    pub node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.LeaveEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LeaveEvent {
+    fn default() -> &'a LeaveEvent {
+        <LeaveEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LeaveEvent {
+    pub fn new() -> LeaveEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &LeaveEvent| { &m.message_id },
+            |m: &mut LeaveEvent| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &LeaveEvent| { &m.node_id },
+            |m: &mut LeaveEvent| { &mut m.node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaveEvent>(
+            "LeaveEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LeaveEvent {
+    const NAME: &'static str = "LeaveEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                16 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
+        }
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        if self.node_id != 0 {
+            os.write_uint64(2, self.node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LeaveEvent {
+        LeaveEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LeaveEvent {
+        static instance: LeaveEvent = LeaveEvent {
+            message_id: ::std::string::String::new(),
+            node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LeaveEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaveEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LeaveEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaveEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.ClusterNodes)
+pub struct ClusterNodes {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.ClusterNodes.nodes)
+    pub nodes: ::std::vec::Vec<RemoteNode>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.ClusterNodes.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ClusterNodes {
+    fn default() -> &'a ClusterNodes {
+        <ClusterNodes as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ClusterNodes {
+    pub fn new() -> ClusterNodes {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "nodes",
+            |m: &ClusterNodes| { &m.nodes },
+            |m: &mut ClusterNodes| { &mut m.nodes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ClusterNodes>(
+            "ClusterNodes",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ClusterNodes {
+    const NAME: &'static str = "ClusterNodes";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.nodes.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.nodes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ClusterNodes {
+        ClusterNodes::new()
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ClusterNodes {
+        static instance: ClusterNodes = ClusterNodes {
+            nodes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ClusterNodes {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ClusterNodes").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ClusterNodes {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClusterNodes {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.CompressedEvent)
+pub struct CompressedEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.CompressedEvent.compression)
+    pub compression: ::protobuf::EnumOrUnknown<CompressionType>,
+    // @@protoc_insertion_point(field:coerce.network.CompressedEvent.uncompressed_length)
+    pub uncompressed_length: u32,
+    // @@protoc_insertion_point(field:coerce.network.CompressedEvent.payload)
+    pub payload: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.CompressedEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CompressedEvent {
+    fn default() -> &'a CompressedEvent {
+        <CompressedEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompressedEvent {
+    pub fn new() -> CompressedEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "compression",
+            |m: &CompressedEvent| { &m.compression },
+            |m: &mut CompressedEvent| { &mut m.compression },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "uncompressed_length",
+            |m: &CompressedEvent| { &m.uncompressed_length },
+            |m: &mut CompressedEvent| { &mut m.uncompressed_length },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "payload",
+            |m: &CompressedEvent| { &m.payload },
+            |m: &mut CompressedEvent| { &mut m.payload },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CompressedEvent>(
+            "CompressedEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CompressedEvent {
+    const NAME: &'static str = "CompressedEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.compression = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.uncompressed_length = is.read_uint32()?;
+                },
+                26 => {
+                    self.payload = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.compression != ::protobuf::EnumOrUnknown::new(CompressionType::Uncompressed) {
+            my_size += ::protobuf::rt::int32_size(1, self.compression.value());
+        }
+        if self.uncompressed_length != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.uncompressed_length);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.payload);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.compression != ::protobuf::EnumOrUnknown::new(CompressionType::Uncompressed) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.compression))?;
+        }
+        if self.uncompressed_length != 0 {
+            os.write_uint32(2, self.uncompressed_length)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_bytes(3, &self.payload)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CompressedEvent {
+        CompressedEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.compression = ::protobuf::EnumOrUnknown::new(CompressionType::Uncompressed);
+        self.uncompressed_length = 0;
+        self.payload.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CompressedEvent {
+        static instance: CompressedEvent = CompressedEvent {
+            compression: ::protobuf::EnumOrUnknown::from_i32(0),
+            uncompressed_length: 0,
+            payload: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CompressedEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CompressedEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CompressedEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompressedEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.CreateActorEvent)
+pub struct CreateActorEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.actor_id)
+    pub actor_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.actor_type)
+    pub actor_type: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.recipe)
+    pub recipe: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.CreateActorEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.CreateActorEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CreateActorEvent {
+    fn default() -> &'a CreateActorEvent {
+        <CreateActorEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateActorEvent {
+    pub fn new() -> CreateActorEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &CreateActorEvent| { &m.message_id },
+            |m: &mut CreateActorEvent| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actor_id",
+            |m: &CreateActorEvent| { &m.actor_id },
+            |m: &mut CreateActorEvent| { &mut m.actor_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actor_type",
+            |m: &CreateActorEvent| { &m.actor_type },
+            |m: &mut CreateActorEvent| { &mut m.actor_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "recipe",
+            |m: &CreateActorEvent| { &m.recipe },
+            |m: &mut CreateActorEvent| { &mut m.recipe },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &CreateActorEvent| { &m.trace_id },
+            |m: &mut CreateActorEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CreateActorEvent>(
+            "CreateActorEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CreateActorEvent {
+    const NAME: &'static str = "CreateActorEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                18 => {
+                    self.actor_id = is.read_string()?;
+                },
+                26 => {
+                    self.actor_type = is.read_string()?;
+                },
+                34 => {
+                    self.recipe = is.read_bytes()?;
+                },
+                42 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
+        }
+        if !self.actor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.actor_id);
+        }
+        if !self.actor_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.actor_type);
+        }
+        if !self.recipe.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.recipe);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        if !self.actor_id.is_empty() {
+            os.write_string(2, &self.actor_id)?;
+        }
+        if !self.actor_type.is_empty() {
+            os.write_string(3, &self.actor_type)?;
+        }
+        if !self.recipe.is_empty() {
+            os.write_bytes(4, &self.recipe)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(5, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CreateActorEvent {
+        CreateActorEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.actor_id.clear();
+        self.actor_type.clear();
+        self.recipe.clear();
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CreateActorEvent {
+        static instance: CreateActorEvent = CreateActorEvent {
+            message_id: ::std::string::String::new(),
+            actor_id: ::std::string::String::new(),
+            actor_type: ::std::string::String::new(),
+            recipe: ::std::vec::Vec::new(),
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CreateActorEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CreateActorEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CreateActorEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateActorEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.FindActorEvent)
+pub struct FindActorEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.FindActorEvent.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.FindActorEvent.actor_id)
+    pub actor_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.FindActorEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.FindActorEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FindActorEvent {
+    fn default() -> &'a FindActorEvent {
+        <FindActorEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FindActorEvent {
+    pub fn new() -> FindActorEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &FindActorEvent| { &m.message_id },
+            |m: &mut FindActorEvent| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actor_id",
+            |m: &FindActorEvent| { &m.actor_id },
+            |m: &mut FindActorEvent| { &mut m.actor_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &FindActorEvent| { &m.trace_id },
+            |m: &mut FindActorEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FindActorEvent>(
+            "FindActorEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FindActorEvent {
+    const NAME: &'static str = "FindActorEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                18 => {
+                    self.actor_id = is.read_string()?;
+                },
+                26 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
+        }
+        if !self.actor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.actor_id);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        if !self.actor_id.is_empty() {
+            os.write_string(2, &self.actor_id)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(3, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FindActorEvent {
+        FindActorEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.actor_id.clear();
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FindActorEvent {
+        static instance: FindActorEvent = FindActorEvent {
+            message_id: ::std::string::String::new(),
+            actor_id: ::std::string::String::new(),
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FindActorEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FindActorEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FindActorEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindActorEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.ActorAddress)
+pub struct ActorAddress {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.ActorAddress.actor_id)
+    pub actor_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.ActorAddress.node_id)
+    pub node_id: ::protobuf::MessageField<::protobuf::well_known_types::wrappers::UInt64Value>,
+    // @@protoc_insertion_point(field:coerce.network.ActorAddress.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.ActorAddress.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ActorAddress {
+    fn default() -> &'a ActorAddress {
+        <ActorAddress as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ActorAddress {
+    pub fn new() -> ActorAddress {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actor_id",
+            |m: &ActorAddress| { &m.actor_id },
+            |m: &mut ActorAddress| { &mut m.actor_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::wrappers::UInt64Value>(
+            "node_id",
+            |m: &ActorAddress| { &m.node_id },
+            |m: &mut ActorAddress| { &mut m.node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &ActorAddress| { &m.trace_id },
+            |m: &mut ActorAddress| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ActorAddress>(
+            "ActorAddress",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ActorAddress {
+    const NAME: &'static str = "ActorAddress";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.actor_id = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node_id)?;
+                },
+                26 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.actor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.actor_id);
+        }
+        if let Some(v) = self.node_id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.actor_id.is_empty() {
+            os.write_string(1, &self.actor_id)?;
+        }
+        if let Some(v) = self.node_id.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(3, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ActorAddress {
+        ActorAddress::new()
+    }
+
+    fn clear(&mut self) {
+        self.actor_id.clear();
+        self.node_id.clear();
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ActorAddress {
+        static instance: ActorAddress = ActorAddress {
+            actor_id: ::std::string::String::new(),
+            node_id: ::protobuf::MessageField::none(),
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ActorAddress {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ActorAddress").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ActorAddress {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ActorAddress {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.MessageRequest)
+pub struct MessageRequest {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.handler_type)
+    pub handler_type: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.actor_id)
+    pub actor_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.message)
+    pub message: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.trace_id)
+    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.requires_response)
+    pub requires_response: bool,
+    // @@protoc_insertion_point(field:coerce.network.MessageRequest.origin_node_id)
+    pub origin_node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.MessageRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MessageRequest {
+    fn default() -> &'a MessageRequest {
+        <MessageRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MessageRequest {
+    pub fn new() -> MessageRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &MessageRequest| { &m.message_id },
+            |m: &mut MessageRequest| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "handler_type",
+            |m: &MessageRequest| { &m.handler_type },
+            |m: &mut MessageRequest| { &mut m.handler_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "actor_id",
+            |m: &MessageRequest| { &m.actor_id },
+            |m: &mut MessageRequest| { &mut m.actor_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &MessageRequest| { &m.message },
+            |m: &mut MessageRequest| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &MessageRequest| { &m.trace_id },
+            |m: &mut MessageRequest| { &mut m.trace_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "requires_response",
+            |m: &MessageRequest| { &m.requires_response },
+            |m: &mut MessageRequest| { &mut m.requires_response },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "origin_node_id",
+            |m: &MessageRequest| { &m.origin_node_id },
+            |m: &mut MessageRequest| { &mut m.origin_node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MessageRequest>(
+            "MessageRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MessageRequest {
+    const NAME: &'static str = "MessageRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                18 => {
+                    self.handler_type = is.read_string()?;
+                },
+                26 => {
+                    self.actor_id = is.read_string()?;
+                },
+                34 => {
+                    self.message = is.read_bytes()?;
+                },
+                42 => {
+                    self.trace_id = is.read_string()?;
+                },
+                48 => {
+                    self.requires_response = is.read_bool()?;
+                },
+                56 => {
+                    self.origin_node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
+        }
+        if !self.handler_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.handler_type);
+        }
+        if !self.actor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.actor_id);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.message);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.trace_id);
+        }
+        if self.requires_response != false {
+            my_size += 1 + 1;
+        }
+        if self.origin_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.origin_node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        if !self.handler_type.is_empty() {
+            os.write_string(2, &self.handler_type)?;
+        }
+        if !self.actor_id.is_empty() {
+            os.write_string(3, &self.actor_id)?;
+        }
+        if !self.message.is_empty() {
+            os.write_bytes(4, &self.message)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(5, &self.trace_id)?;
+        }
+        if self.requires_response != false {
+            os.write_bool(6, self.requires_response)?;
+        }
+        if self.origin_node_id != 0 {
+            os.write_uint64(7, self.origin_node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MessageRequest {
+        MessageRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.handler_type.clear();
+        self.actor_id.clear();
+        self.message.clear();
+        self.trace_id.clear();
+        self.requires_response = false;
+        self.origin_node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MessageRequest {
+        static instance: MessageRequest = MessageRequest {
+            message_id: ::std::string::String::new(),
+            handler_type: ::std::string::String::new(),
+            actor_id: ::std::string::String::new(),
+            message: ::std::vec::Vec::new(),
+            trace_id: ::std::string::String::new(),
+            requires_response: false,
+            origin_node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MessageRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MessageRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MessageRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MessageRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.SessionHandshake)
+pub struct SessionHandshake {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.nodes)
+    pub nodes: ::std::vec::Vec<RemoteNode>,
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.token)
+    pub token: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.node_tag)
+    pub node_tag: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.client_type)
+    pub client_type: ::protobuf::EnumOrUnknown<ClientType>,
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.trace_id)
+    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.SessionHandshake.protocol_version)
+    pub protocol_version: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.SessionHandshake.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SessionHandshake {
+    fn default() -> &'a SessionHandshake {
+        <SessionHandshake as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SessionHandshake {
+    pub fn new() -> SessionHandshake {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &SessionHandshake| { &m.node_id },
+            |m: &mut SessionHandshake| { &mut m.node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "nodes",
+            |m: &SessionHandshake| { &m.nodes },
+            |m: &mut SessionHandshake| { &mut m.nodes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "token",
+            |m: &SessionHandshake| { &m.token },
+            |m: &mut SessionHandshake| { &mut m.token },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_tag",
+            |m: &SessionHandshake| { &m.node_tag },
+            |m: &mut SessionHandshake| { &mut m.node_tag },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "client_type",
+            |m: &SessionHandshake| { &m.client_type },
+            |m: &mut SessionHandshake| { &mut m.client_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &SessionHandshake| { &m.trace_id },
+            |m: &mut SessionHandshake| { &mut m.trace_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "protocol_version",
+            |m: &SessionHandshake| { &m.protocol_version },
+            |m: &mut SessionHandshake| { &mut m.protocol_version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SessionHandshake>(
+            "SessionHandshake",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SessionHandshake {
+    const NAME: &'static str = "SessionHandshake";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                18 => {
+                    self.nodes.push(is.read_message()?);
+                },
+                26 => {
+                    self.token = is.read_bytes()?;
+                },
+                34 => {
+                    self.node_tag = is.read_string()?;
+                },
+                40 => {
+                    self.client_type = is.read_enum_or_unknown()?;
+                },
+                50 => {
+                    self.trace_id = is.read_string()?;
+                },
+                56 => {
+                    self.protocol_version = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.token);
+        }
+        if !self.node_tag.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.node_tag);
+        }
+        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::Client) {
+            my_size += ::protobuf::rt::int32_size(5, self.client_type.value());
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.trace_id);
+        }
+        if self.protocol_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(7, self.protocol_version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        for v in &self.nodes {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if !self.token.is_empty() {
+            os.write_bytes(3, &self.token)?;
+        }
+        if !self.node_tag.is_empty() {
+            os.write_string(4, &self.node_tag)?;
+        }
+        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::Client) {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.client_type))?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(6, &self.trace_id)?;
+        }
+        if self.protocol_version != 0 {
+            os.write_uint32(7, self.protocol_version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SessionHandshake {
+        SessionHandshake::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.nodes.clear();
+        self.token.clear();
+        self.node_tag.clear();
+        self.client_type = ::protobuf::EnumOrUnknown::new(ClientType::Client);
+        self.trace_id.clear();
+        self.protocol_version = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SessionHandshake {
+        static instance: SessionHandshake = SessionHandshake {
+            node_id: 0,
+            nodes: ::std::vec::Vec::new(),
+            token: ::std::vec::Vec::new(),
+            node_tag: ::std::string::String::new(),
+            client_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            trace_id: ::std::string::String::new(),
+            protocol_version: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SessionHandshake {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SessionHandshake").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SessionHandshake {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SessionHandshake {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.StreamPublishEvent)
+pub struct StreamPublishEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.topic)
+    pub topic: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.key)
+    pub key: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.message)
+    pub message: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.StreamPublishEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a StreamPublishEvent {
+    fn default() -> &'a StreamPublishEvent {
+        <StreamPublishEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StreamPublishEvent {
+    pub fn new() -> StreamPublishEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "topic",
+            |m: &StreamPublishEvent| { &m.topic },
+            |m: &mut StreamPublishEvent| { &mut m.topic },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &StreamPublishEvent| { &m.key },
+            |m: &mut StreamPublishEvent| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &StreamPublishEvent| { &m.message },
+            |m: &mut StreamPublishEvent| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &StreamPublishEvent| { &m.trace_id },
+            |m: &mut StreamPublishEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StreamPublishEvent>(
+            "StreamPublishEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for StreamPublishEvent {
+    const NAME: &'static str = "StreamPublishEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.topic = is.read_string()?;
+                },
+                18 => {
+                    self.key = is.read_string()?;
+                },
+                26 => {
+                    self.message = is.read_bytes()?;
+                },
+                34 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.topic.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.topic);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.key);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.message);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.topic.is_empty() {
+            os.write_string(1, &self.topic)?;
+        }
+        if !self.key.is_empty() {
+            os.write_string(2, &self.key)?;
+        }
+        if !self.message.is_empty() {
+            os.write_bytes(3, &self.message)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(4, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> StreamPublishEvent {
+        StreamPublishEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.topic.clear();
+        self.key.clear();
+        self.message.clear();
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static StreamPublishEvent {
+        static instance: StreamPublishEvent = StreamPublishEvent {
+            topic: ::std::string::String::new(),
+            key: ::std::string::String::new(),
+            message: ::std::vec::Vec::new(),
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for StreamPublishEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("StreamPublishEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for StreamPublishEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StreamPublishEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.NewNodeEvent)
+pub struct NewNodeEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.NewNodeEvent.node)
+    pub node: ::protobuf::MessageField<RemoteNode>,
+    // @@protoc_insertion_point(field:coerce.network.NewNodeEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.NewNodeEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NewNodeEvent {
+    fn default() -> &'a NewNodeEvent {
+        <NewNodeEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NewNodeEvent {
+    pub fn new() -> NewNodeEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RemoteNode>(
+            "node",
+            |m: &NewNodeEvent| { &m.node },
+            |m: &mut NewNodeEvent| { &mut m.node },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &NewNodeEvent| { &m.trace_id },
+            |m: &mut NewNodeEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NewNodeEvent>(
+            "NewNodeEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NewNodeEvent {
+    const NAME: &'static str = "NewNodeEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node)?;
+                },
+                18 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.node.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(2, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NewNodeEvent {
+        NewNodeEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.node.clear();
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NewNodeEvent {
+        static instance: NewNodeEvent = NewNodeEvent {
+            node: ::protobuf::MessageField::none(),
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NewNodeEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NewNodeEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NewNodeEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NewNodeEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.NodeRemovedEvent)
+pub struct NodeRemovedEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.NodeRemovedEvent.node)
+    pub node: ::protobuf::MessageField<RemoteNode>,
+    // @@protoc_insertion_point(field:coerce.network.NodeRemovedEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.NodeRemovedEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NodeRemovedEvent {
+    fn default() -> &'a NodeRemovedEvent {
+        <NodeRemovedEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeRemovedEvent {
+    pub fn new() -> NodeRemovedEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RemoteNode>(
+            "node",
+            |m: &NodeRemovedEvent| { &m.node },
+            |m: &mut NodeRemovedEvent| { &mut m.node },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &NodeRemovedEvent| { &m.trace_id },
+            |m: &mut NodeRemovedEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeRemovedEvent>(
+            "NodeRemovedEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NodeRemovedEvent {
+    const NAME: &'static str = "NodeRemovedEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node)?;
+                },
+                18 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.node.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(2, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NodeRemovedEvent {
+        NodeRemovedEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.node.clear();
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NodeRemovedEvent {
+        static instance: NodeRemovedEvent = NodeRemovedEvent {
+            node: ::protobuf::MessageField::none(),
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NodeRemovedEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NodeRemovedEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NodeRemovedEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeRemovedEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.LeaderChangedEvent)
+pub struct LeaderChangedEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.LeaderChangedEvent.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.LeaderChangedEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.LeaderChangedEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LeaderChangedEvent {
+    fn default() -> &'a LeaderChangedEvent {
+        <LeaderChangedEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LeaderChangedEvent {
+    pub fn new() -> LeaderChangedEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &LeaderChangedEvent| { &m.node_id },
+            |m: &mut LeaderChangedEvent| { &mut m.node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &LeaderChangedEvent| { &m.trace_id },
+            |m: &mut LeaderChangedEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaderChangedEvent>(
+            "LeaderChangedEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LeaderChangedEvent {
+    const NAME: &'static str = "LeaderChangedEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                18 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(2, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LeaderChangedEvent {
+        LeaderChangedEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LeaderChangedEvent {
+        static instance: LeaderChangedEvent = LeaderChangedEvent {
+            node_id: 0,
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LeaderChangedEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaderChangedEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LeaderChangedEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaderChangedEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.NodeDownedEvent)
+pub struct NodeDownedEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.NodeDownedEvent.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.NodeDownedEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.NodeDownedEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NodeDownedEvent {
+    fn default() -> &'a NodeDownedEvent {
+        <NodeDownedEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeDownedEvent {
+    pub fn new() -> NodeDownedEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &NodeDownedEvent| { &m.node_id },
+            |m: &mut NodeDownedEvent| { &mut m.node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &NodeDownedEvent| { &m.trace_id },
+            |m: &mut NodeDownedEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeDownedEvent>(
+            "NodeDownedEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NodeDownedEvent {
+    const NAME: &'static str = "NodeDownedEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                18 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(2, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NodeDownedEvent {
+        NodeDownedEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NodeDownedEvent {
+        static instance: NodeDownedEvent = NodeDownedEvent {
+            node_id: 0,
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NodeDownedEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NodeDownedEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NodeDownedEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeDownedEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.NodeLeavingEvent)
+pub struct NodeLeavingEvent {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.NodeLeavingEvent.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.NodeLeavingEvent.trace_id)
+    pub trace_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.NodeLeavingEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NodeLeavingEvent {
+    fn default() -> &'a NodeLeavingEvent {
+        <NodeLeavingEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeLeavingEvent {
+    pub fn new() -> NodeLeavingEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &NodeLeavingEvent| { &m.node_id },
+            |m: &mut NodeLeavingEvent| { &mut m.node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "trace_id",
+            |m: &NodeLeavingEvent| { &m.trace_id },
+            |m: &mut NodeLeavingEvent| { &mut m.trace_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeLeavingEvent>(
+            "NodeLeavingEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NodeLeavingEvent {
+    const NAME: &'static str = "NodeLeavingEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                18 => {
+                    self.trace_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        if !self.trace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        if !self.trace_id.is_empty() {
+            os.write_string(2, &self.trace_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NodeLeavingEvent {
+        NodeLeavingEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.trace_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NodeLeavingEvent {
+        static instance: NodeLeavingEvent = NodeLeavingEvent {
+            node_id: 0,
+            trace_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NodeLeavingEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NodeLeavingEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NodeLeavingEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeLeavingEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftRequest)
+pub struct RaftRequest {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftRequest.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.RaftRequest.request_type)
+    pub request_type: u32,
+    // @@protoc_insertion_point(field:coerce.network.RaftRequest.payload)
+    pub payload: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftRequest {
+    fn default() -> &'a RaftRequest {
+        <RaftRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftRequest {
+    pub fn new() -> RaftRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &RaftRequest| { &m.message_id },
+            |m: &mut RaftRequest| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "request_type",
+            |m: &RaftRequest| { &m.request_type },
+            |m: &mut RaftRequest| { &mut m.request_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "payload",
+            |m: &RaftRequest| { &m.payload },
+            |m: &mut RaftRequest| { &mut m.payload },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftRequest>(
+            "RaftRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftRequest {
+    const NAME: &'static str = "RaftRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                16 => {
+                    self.request_type = is.read_uint32()?;
+                },
+                26 => {
+                    self.payload = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
+        }
+        if self.request_type != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.request_type);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.payload);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        if self.request_type != 0 {
+            os.write_uint32(2, self.request_type)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_bytes(3, &self.payload)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftRequest {
+        RaftRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.request_type = 0;
+        self.payload.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftRequest {
+        static instance: RaftRequest = RaftRequest {
+            message_id: ::std::string::String::new(),
+            request_type: 0,
+            payload: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftLogEntry)
+pub struct RaftLogEntry {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftLogEntry.index)
+    pub index: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftLogEntry.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftLogEntry.command)
+    pub command: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.RaftLogEntry.configuration)
+    pub configuration: ::protobuf::MessageField<RaftConfiguration>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftLogEntry.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftLogEntry {
+    fn default() -> &'a RaftLogEntry {
+        <RaftLogEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftLogEntry {
+    pub fn new() -> RaftLogEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "index",
+            |m: &RaftLogEntry| { &m.index },
+            |m: &mut RaftLogEntry| { &mut m.index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftLogEntry| { &m.term },
+            |m: &mut RaftLogEntry| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "command",
+            |m: &RaftLogEntry| { &m.command },
+            |m: &mut RaftLogEntry| { &mut m.command },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RaftConfiguration>(
+            "configuration",
+            |m: &RaftLogEntry| { &m.configuration },
+            |m: &mut RaftLogEntry| { &mut m.configuration },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftLogEntry>(
+            "RaftLogEntry",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftLogEntry {
+    const NAME: &'static str = "RaftLogEntry";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.index = is.read_uint64()?;
+                },
+                16 => {
+                    self.term = is.read_uint64()?;
+                },
+                26 => {
+                    self.command = is.read_bytes()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.configuration)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.index);
+        }
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.term);
+        }
+        if !self.command.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.command);
+        }
+        if let Some(v) = self.configuration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.index != 0 {
+            os.write_uint64(1, self.index)?;
+        }
+        if self.term != 0 {
+            os.write_uint64(2, self.term)?;
+        }
+        if !self.command.is_empty() {
+            os.write_bytes(3, &self.command)?;
+        }
+        if let Some(v) = self.configuration.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftLogEntry {
+        RaftLogEntry::new()
+    }
+
+    fn clear(&mut self) {
+        self.index = 0;
+        self.term = 0;
+        self.command.clear();
+        self.configuration.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftLogEntry {
+        static instance: RaftLogEntry = RaftLogEntry {
+            index: 0,
+            term: 0,
+            command: ::std::vec::Vec::new(),
+            configuration: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftLogEntry {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftLogEntry").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftLogEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftLogEntry {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftConfiguration)
+pub struct RaftConfiguration {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftConfiguration.voters)
+    pub voters: ::std::vec::Vec<u64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftConfiguration.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftConfiguration {
+    fn default() -> &'a RaftConfiguration {
+        <RaftConfiguration as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftConfiguration {
+    pub fn new() -> RaftConfiguration {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "voters",
+            |m: &RaftConfiguration| { &m.voters },
+            |m: &mut RaftConfiguration| { &mut m.voters },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftConfiguration>(
+            "RaftConfiguration",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftConfiguration {
+    const NAME: &'static str = "RaftConfiguration";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    is.read_repeated_packed_uint64_into(&mut self.voters)?;
+                },
+                8 => {
+                    self.voters.push(is.read_uint64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.voters {
+            my_size += ::protobuf::rt::uint64_size(1, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.voters {
+            os.write_uint64(1, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftConfiguration {
+        RaftConfiguration::new()
+    }
+
+    fn clear(&mut self) {
+        self.voters.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftConfiguration {
+        static instance: RaftConfiguration = RaftConfiguration {
+            voters: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftConfiguration {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftConfiguration").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftConfiguration {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftConfiguration {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftVote)
+pub struct RaftVote {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftVote.candidate_id)
+    pub candidate_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftVote.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftVote {
+    fn default() -> &'a RaftVote {
+        <RaftVote as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftVote {
+    pub fn new() -> RaftVote {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "candidate_id",
+            |m: &RaftVote| { &m.candidate_id },
+            |m: &mut RaftVote| { &mut m.candidate_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftVote>(
+            "RaftVote",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftVote {
+    const NAME: &'static str = "RaftVote";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.candidate_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.candidate_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.candidate_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.candidate_id != 0 {
+            os.write_uint64(1, self.candidate_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftVote {
+        RaftVote::new()
+    }
+
+    fn clear(&mut self) {
+        self.candidate_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftVote {
+        static instance: RaftVote = RaftVote {
+            candidate_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftVote {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftVote").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftVote {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftVote {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftVoteRequest)
+pub struct RaftVoteRequest {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftVoteRequest.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftVoteRequest.candidate_id)
+    pub candidate_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftVoteRequest.last_log_index)
+    pub last_log_index: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftVoteRequest.last_log_term)
+    pub last_log_term: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftVoteRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftVoteRequest {
+    fn default() -> &'a RaftVoteRequest {
+        <RaftVoteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftVoteRequest {
+    pub fn new() -> RaftVoteRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftVoteRequest| { &m.term },
+            |m: &mut RaftVoteRequest| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "candidate_id",
+            |m: &RaftVoteRequest| { &m.candidate_id },
+            |m: &mut RaftVoteRequest| { &mut m.candidate_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_log_index",
+            |m: &RaftVoteRequest| { &m.last_log_index },
+            |m: &mut RaftVoteRequest| { &mut m.last_log_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_log_term",
+            |m: &RaftVoteRequest| { &m.last_log_term },
+            |m: &mut RaftVoteRequest| { &mut m.last_log_term },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftVoteRequest>(
+            "RaftVoteRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftVoteRequest {
+    const NAME: &'static str = "RaftVoteRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.candidate_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.last_log_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.last_log_term = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.candidate_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.candidate_id);
+        }
+        if self.last_log_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.last_log_index);
+        }
+        if self.last_log_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.last_log_term);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.candidate_id != 0 {
+            os.write_uint64(2, self.candidate_id)?;
+        }
+        if self.last_log_index != 0 {
+            os.write_uint64(3, self.last_log_index)?;
+        }
+        if self.last_log_term != 0 {
+            os.write_uint64(4, self.last_log_term)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftVoteRequest {
+        RaftVoteRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.candidate_id = 0;
+        self.last_log_index = 0;
+        self.last_log_term = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftVoteRequest {
+        static instance: RaftVoteRequest = RaftVoteRequest {
+            term: 0,
+            candidate_id: 0,
+            last_log_index: 0,
+            last_log_term: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftVoteRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftVoteRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftVoteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftVoteRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftVoteResponse)
+pub struct RaftVoteResponse {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftVoteResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftVoteResponse.vote_granted)
+    pub vote_granted: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftVoteResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftVoteResponse {
+    fn default() -> &'a RaftVoteResponse {
+        <RaftVoteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftVoteResponse {
+    pub fn new() -> RaftVoteResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftVoteResponse| { &m.term },
+            |m: &mut RaftVoteResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "vote_granted",
+            |m: &RaftVoteResponse| { &m.vote_granted },
+            |m: &mut RaftVoteResponse| { &mut m.vote_granted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftVoteResponse>(
+            "RaftVoteResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftVoteResponse {
+    const NAME: &'static str = "RaftVoteResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.vote_granted = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.vote_granted != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.vote_granted != false {
+            os.write_bool(2, self.vote_granted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftVoteResponse {
+        RaftVoteResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.vote_granted = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftVoteResponse {
+        static instance: RaftVoteResponse = RaftVoteResponse {
+            term: 0,
+            vote_granted: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftVoteResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftVoteResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftVoteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftVoteResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftAppendEntriesRequest)
+pub struct RaftAppendEntriesRequest {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesRequest.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesRequest.leader_id)
+    pub leader_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesRequest.prev_log_index)
+    pub prev_log_index: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesRequest.prev_log_term)
+    pub prev_log_term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesRequest.entries)
+    pub entries: ::std::vec::Vec<RaftLogEntry>,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesRequest.leader_commit)
+    pub leader_commit: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftAppendEntriesRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftAppendEntriesRequest {
+    fn default() -> &'a RaftAppendEntriesRequest {
+        <RaftAppendEntriesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftAppendEntriesRequest {
+    pub fn new() -> RaftAppendEntriesRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftAppendEntriesRequest| { &m.term },
+            |m: &mut RaftAppendEntriesRequest| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_id",
+            |m: &RaftAppendEntriesRequest| { &m.leader_id },
+            |m: &mut RaftAppendEntriesRequest| { &mut m.leader_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_log_index",
+            |m: &RaftAppendEntriesRequest| { &m.prev_log_index },
+            |m: &mut RaftAppendEntriesRequest| { &mut m.prev_log_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_log_term",
+            |m: &RaftAppendEntriesRequest| { &m.prev_log_term },
+            |m: &mut RaftAppendEntriesRequest| { &mut m.prev_log_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &RaftAppendEntriesRequest| { &m.entries },
+            |m: &mut RaftAppendEntriesRequest| { &mut m.entries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_commit",
+            |m: &RaftAppendEntriesRequest| { &m.leader_commit },
+            |m: &mut RaftAppendEntriesRequest| { &mut m.leader_commit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftAppendEntriesRequest>(
+            "RaftAppendEntriesRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftAppendEntriesRequest {
+    const NAME: &'static str = "RaftAppendEntriesRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.leader_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.prev_log_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.prev_log_term = is.read_uint64()?;
+                },
+                42 => {
+                    self.entries.push(is.read_message()?);
+                },
+                48 => {
+                    self.leader_commit = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.leader_id);
+        }
+        if self.prev_log_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.prev_log_index);
+        }
+        if self.prev_log_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.prev_log_term);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.leader_commit != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.leader_commit);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.leader_id != 0 {
+            os.write_uint64(2, self.leader_id)?;
+        }
+        if self.prev_log_index != 0 {
+            os.write_uint64(3, self.prev_log_index)?;
+        }
+        if self.prev_log_term != 0 {
+            os.write_uint64(4, self.prev_log_term)?;
+        }
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if self.leader_commit != 0 {
+            os.write_uint64(6, self.leader_commit)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftAppendEntriesRequest {
+        RaftAppendEntriesRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.leader_id = 0;
+        self.prev_log_index = 0;
+        self.prev_log_term = 0;
+        self.entries.clear();
+        self.leader_commit = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftAppendEntriesRequest {
+        static instance: RaftAppendEntriesRequest = RaftAppendEntriesRequest {
+            term: 0,
+            leader_id: 0,
+            prev_log_index: 0,
+            prev_log_term: 0,
+            entries: ::std::vec::Vec::new(),
+            leader_commit: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftAppendEntriesRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftAppendEntriesRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftAppendEntriesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftAppendEntriesRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftAppendEntriesResponse)
+pub struct RaftAppendEntriesResponse {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesResponse.success)
+    pub success: bool,
+    // @@protoc_insertion_point(field:coerce.network.RaftAppendEntriesResponse.match_index)
+    pub match_index: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftAppendEntriesResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftAppendEntriesResponse {
+    fn default() -> &'a RaftAppendEntriesResponse {
+        <RaftAppendEntriesResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftAppendEntriesResponse {
+    pub fn new() -> RaftAppendEntriesResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftAppendEntriesResponse| { &m.term },
+            |m: &mut RaftAppendEntriesResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "success",
+            |m: &RaftAppendEntriesResponse| { &m.success },
+            |m: &mut RaftAppendEntriesResponse| { &mut m.success },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "match_index",
+            |m: &RaftAppendEntriesResponse| { &m.match_index },
+            |m: &mut RaftAppendEntriesResponse| { &mut m.match_index },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftAppendEntriesResponse>(
+            "RaftAppendEntriesResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftAppendEntriesResponse {
+    const NAME: &'static str = "RaftAppendEntriesResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.success = is.read_bool()?;
+                },
+                24 => {
+                    self.match_index = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.success != false {
+            my_size += 1 + 1;
+        }
+        if self.match_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.match_index);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.success != false {
+            os.write_bool(2, self.success)?;
+        }
+        if self.match_index != 0 {
+            os.write_uint64(3, self.match_index)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftAppendEntriesResponse {
+        RaftAppendEntriesResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.success = false;
+        self.match_index = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftAppendEntriesResponse {
+        static instance: RaftAppendEntriesResponse = RaftAppendEntriesResponse {
+            term: 0,
+            success: false,
+            match_index: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftAppendEntriesResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftAppendEntriesResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftAppendEntriesResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftAppendEntriesResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftInstallSnapshotRequest)
+pub struct RaftInstallSnapshotRequest {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotRequest.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotRequest.leader_id)
+    pub leader_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotRequest.last_included_index)
+    pub last_included_index: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotRequest.last_included_term)
+    pub last_included_term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotRequest.data)
+    pub data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotRequest.configuration)
+    pub configuration: ::protobuf::MessageField<RaftConfiguration>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftInstallSnapshotRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftInstallSnapshotRequest {
+    fn default() -> &'a RaftInstallSnapshotRequest {
+        <RaftInstallSnapshotRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftInstallSnapshotRequest {
+    pub fn new() -> RaftInstallSnapshotRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftInstallSnapshotRequest| { &m.term },
+            |m: &mut RaftInstallSnapshotRequest| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_id",
+            |m: &RaftInstallSnapshotRequest| { &m.leader_id },
+            |m: &mut RaftInstallSnapshotRequest| { &mut m.leader_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_included_index",
+            |m: &RaftInstallSnapshotRequest| { &m.last_included_index },
+            |m: &mut RaftInstallSnapshotRequest| { &mut m.last_included_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_included_term",
+            |m: &RaftInstallSnapshotRequest| { &m.last_included_term },
+            |m: &mut RaftInstallSnapshotRequest| { &mut m.last_included_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &RaftInstallSnapshotRequest| { &m.data },
+            |m: &mut RaftInstallSnapshotRequest| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RaftConfiguration>(
+            "configuration",
+            |m: &RaftInstallSnapshotRequest| { &m.configuration },
+            |m: &mut RaftInstallSnapshotRequest| { &mut m.configuration },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftInstallSnapshotRequest>(
+            "RaftInstallSnapshotRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftInstallSnapshotRequest {
+    const NAME: &'static str = "RaftInstallSnapshotRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.leader_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.last_included_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.last_included_term = is.read_uint64()?;
+                },
+                42 => {
+                    self.data = is.read_bytes()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.configuration)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.leader_id);
+        }
+        if self.last_included_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.last_included_index);
+        }
+        if self.last_included_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.last_included_term);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.data);
+        }
+        if let Some(v) = self.configuration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.leader_id != 0 {
+            os.write_uint64(2, self.leader_id)?;
+        }
+        if self.last_included_index != 0 {
+            os.write_uint64(3, self.last_included_index)?;
+        }
+        if self.last_included_term != 0 {
+            os.write_uint64(4, self.last_included_term)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(5, &self.data)?;
+        }
+        if let Some(v) = self.configuration.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftInstallSnapshotRequest {
+        RaftInstallSnapshotRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.leader_id = 0;
+        self.last_included_index = 0;
+        self.last_included_term = 0;
+        self.data.clear();
+        self.configuration.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftInstallSnapshotRequest {
+        static instance: RaftInstallSnapshotRequest = RaftInstallSnapshotRequest {
+            term: 0,
+            leader_id: 0,
+            last_included_index: 0,
+            last_included_term: 0,
+            data: ::std::vec::Vec::new(),
+            configuration: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftInstallSnapshotRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftInstallSnapshotRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftInstallSnapshotRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftInstallSnapshotRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftInstallSnapshotResponse)
+pub struct RaftInstallSnapshotResponse {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftInstallSnapshotResponse.success)
+    pub success: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftInstallSnapshotResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftInstallSnapshotResponse {
+    fn default() -> &'a RaftInstallSnapshotResponse {
+        <RaftInstallSnapshotResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftInstallSnapshotResponse {
+    pub fn new() -> RaftInstallSnapshotResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftInstallSnapshotResponse| { &m.term },
+            |m: &mut RaftInstallSnapshotResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "success",
+            |m: &RaftInstallSnapshotResponse| { &m.success },
+            |m: &mut RaftInstallSnapshotResponse| { &mut m.success },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftInstallSnapshotResponse>(
+            "RaftInstallSnapshotResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftInstallSnapshotResponse {
+    const NAME: &'static str = "RaftInstallSnapshotResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.success = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.success != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.success != false {
+            os.write_bool(2, self.success)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftInstallSnapshotResponse {
+        RaftInstallSnapshotResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.success = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftInstallSnapshotResponse {
+        static instance: RaftInstallSnapshotResponse = RaftInstallSnapshotResponse {
+            term: 0,
+            success: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftInstallSnapshotResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftInstallSnapshotResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftInstallSnapshotResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftInstallSnapshotResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftMetadataCommand)
+pub struct RaftMetadataCommand {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftMetadataCommand.key)
+    pub key: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.RaftMetadataCommand.value)
+    pub value: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.RaftMetadataCommand.delete)
+    pub delete: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftMetadataCommand.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftMetadataCommand {
+    fn default() -> &'a RaftMetadataCommand {
+        <RaftMetadataCommand as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftMetadataCommand {
+    pub fn new() -> RaftMetadataCommand {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &RaftMetadataCommand| { &m.key },
+            |m: &mut RaftMetadataCommand| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "value",
+            |m: &RaftMetadataCommand| { &m.value },
+            |m: &mut RaftMetadataCommand| { &mut m.value },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "delete",
+            |m: &RaftMetadataCommand| { &m.delete },
+            |m: &mut RaftMetadataCommand| { &mut m.delete },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftMetadataCommand>(
+            "RaftMetadataCommand",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftMetadataCommand {
+    const NAME: &'static str = "RaftMetadataCommand";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.key = is.read_string()?;
+                },
+                18 => {
+                    self.value = is.read_bytes()?;
+                },
+                24 => {
+                    self.delete = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if self.delete != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if self.delete != false {
+            os.write_bool(3, self.delete)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftMetadataCommand {
+        RaftMetadataCommand::new()
+    }
+
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.delete = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftMetadataCommand {
+        static instance: RaftMetadataCommand = RaftMetadataCommand {
+            key: ::std::string::String::new(),
+            value: ::std::vec::Vec::new(),
+            delete: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftMetadataCommand {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftMetadataCommand").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftMetadataCommand {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftMetadataCommand {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftMetadataSnapshot)
+pub struct RaftMetadataSnapshot {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftMetadataSnapshot.entries)
+    pub entries: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftMetadataSnapshot.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftMetadataSnapshot {
+    fn default() -> &'a RaftMetadataSnapshot {
+        <RaftMetadataSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftMetadataSnapshot {
+    pub fn new() -> RaftMetadataSnapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "entries",
+            |m: &RaftMetadataSnapshot| { &m.entries },
+            |m: &mut RaftMetadataSnapshot| { &mut m.entries },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftMetadataSnapshot>(
+            "RaftMetadataSnapshot",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftMetadataSnapshot {
+    const NAME: &'static str = "RaftMetadataSnapshot";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_bytes()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.entries.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for (k, v) in &self.entries {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for (k, v) in &self.entries {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::bytes_size(2, &v);
+            os.write_raw_varint32(10)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_bytes(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftMetadataSnapshot {
+        RaftMetadataSnapshot::new()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftMetadataSnapshot {
+        static instance: ::protobuf::rt::Lazy<RaftMetadataSnapshot> = ::protobuf::rt::Lazy::new();
+        instance.get(RaftMetadataSnapshot::new)
+    }
+}
+
+impl ::protobuf::MessageFull for RaftMetadataSnapshot {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftMetadataSnapshot").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftMetadataSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftMetadataSnapshot {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftJournalEntry)
+pub struct RaftJournalEntry {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalEntry.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalEntry.voted_for)
+    pub voted_for: ::protobuf::MessageField<RaftVote>,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalEntry.truncate_from)
+    pub truncate_from: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalEntry.entries)
+    pub entries: ::std::vec::Vec<RaftLogEntry>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftJournalEntry.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftJournalEntry {
+    fn default() -> &'a RaftJournalEntry {
+        <RaftJournalEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftJournalEntry {
+    pub fn new() -> RaftJournalEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftJournalEntry| { &m.term },
+            |m: &mut RaftJournalEntry| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RaftVote>(
+            "voted_for",
+            |m: &RaftJournalEntry| { &m.voted_for },
+            |m: &mut RaftJournalEntry| { &mut m.voted_for },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "truncate_from",
+            |m: &RaftJournalEntry| { &m.truncate_from },
+            |m: &mut RaftJournalEntry| { &mut m.truncate_from },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &RaftJournalEntry| { &m.entries },
+            |m: &mut RaftJournalEntry| { &mut m.entries },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftJournalEntry>(
+            "RaftJournalEntry",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftJournalEntry {
+    const NAME: &'static str = "RaftJournalEntry";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.voted_for)?;
+                },
+                24 => {
+                    self.truncate_from = is.read_uint64()?;
+                },
+                34 => {
+                    self.entries.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if let Some(v) = self.voted_for.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.truncate_from != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.truncate_from);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if let Some(v) = self.voted_for.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.truncate_from != 0 {
+            os.write_uint64(3, self.truncate_from)?;
+        }
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftJournalEntry {
+        RaftJournalEntry::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.voted_for.clear();
+        self.truncate_from = 0;
+        self.entries.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftJournalEntry {
+        static instance: RaftJournalEntry = RaftJournalEntry {
+            term: 0,
+            voted_for: ::protobuf::MessageField::none(),
+            truncate_from: 0,
+            entries: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftJournalEntry {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftJournalEntry").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftJournalEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftJournalEntry {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.RaftJournalSnapshot)
+pub struct RaftJournalSnapshot {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.voted_for)
+    pub voted_for: ::protobuf::MessageField<RaftVote>,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.snapshot_index)
+    pub snapshot_index: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.snapshot_term)
+    pub snapshot_term: u64,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.snapshot_data)
+    pub snapshot_data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.snapshot_configuration)
+    pub snapshot_configuration: ::protobuf::MessageField<RaftConfiguration>,
+    // @@protoc_insertion_point(field:coerce.network.RaftJournalSnapshot.entries)
+    pub entries: ::std::vec::Vec<RaftLogEntry>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.RaftJournalSnapshot.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftJournalSnapshot {
+    fn default() -> &'a RaftJournalSnapshot {
+        <RaftJournalSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftJournalSnapshot {
+    pub fn new() -> RaftJournalSnapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftJournalSnapshot| { &m.term },
+            |m: &mut RaftJournalSnapshot| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RaftVote>(
+            "voted_for",
+            |m: &RaftJournalSnapshot| { &m.voted_for },
+            |m: &mut RaftJournalSnapshot| { &mut m.voted_for },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "snapshot_index",
+            |m: &RaftJournalSnapshot| { &m.snapshot_index },
+            |m: &mut RaftJournalSnapshot| { &mut m.snapshot_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "snapshot_term",
+            |m: &RaftJournalSnapshot| { &m.snapshot_term },
+            |m: &mut RaftJournalSnapshot| { &mut m.snapshot_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "snapshot_data",
+            |m: &RaftJournalSnapshot| { &m.snapshot_data },
+            |m: &mut RaftJournalSnapshot| { &mut m.snapshot_data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RaftConfiguration>(
+            "snapshot_configuration",
+            |m: &RaftJournalSnapshot| { &m.snapshot_configuration },
+            |m: &mut RaftJournalSnapshot| { &mut m.snapshot_configuration },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &RaftJournalSnapshot| { &m.entries },
+            |m: &mut RaftJournalSnapshot| { &mut m.entries },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftJournalSnapshot>(
+            "RaftJournalSnapshot",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftJournalSnapshot {
+    const NAME: &'static str = "RaftJournalSnapshot";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.voted_for)?;
+                },
+                24 => {
+                    self.snapshot_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.snapshot_term = is.read_uint64()?;
+                },
+                42 => {
+                    self.snapshot_data = is.read_bytes()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.snapshot_configuration)?;
+                },
+                58 => {
+                    self.entries.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if let Some(v) = self.voted_for.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.snapshot_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.snapshot_index);
+        }
+        if self.snapshot_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.snapshot_term);
+        }
+        if !self.snapshot_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.snapshot_data);
+        }
+        if let Some(v) = self.snapshot_configuration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if let Some(v) = self.voted_for.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.snapshot_index != 0 {
+            os.write_uint64(3, self.snapshot_index)?;
+        }
+        if self.snapshot_term != 0 {
+            os.write_uint64(4, self.snapshot_term)?;
+        }
+        if !self.snapshot_data.is_empty() {
+            os.write_bytes(5, &self.snapshot_data)?;
+        }
+        if let Some(v) = self.snapshot_configuration.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftJournalSnapshot {
+        RaftJournalSnapshot::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.voted_for.clear();
+        self.snapshot_index = 0;
+        self.snapshot_term = 0;
+        self.snapshot_data.clear();
+        self.snapshot_configuration.clear();
+        self.entries.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftJournalSnapshot {
+        static instance: RaftJournalSnapshot = RaftJournalSnapshot {
+            term: 0,
+            voted_for: ::protobuf::MessageField::none(),
+            snapshot_index: 0,
+            snapshot_term: 0,
+            snapshot_data: ::std::vec::Vec::new(),
+            snapshot_configuration: ::protobuf::MessageField::none(),
+            entries: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftJournalSnapshot {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftJournalSnapshot").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftJournalSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftJournalSnapshot {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.GossipRequest)
+pub struct GossipRequest {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.GossipRequest.message_id)
+    pub message_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.GossipRequest.request_type)
+    pub request_type: u32,
+    // @@protoc_insertion_point(field:coerce.network.GossipRequest.payload)
+    pub payload: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.GossipRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GossipRequest {
+    fn default() -> &'a GossipRequest {
+        <GossipRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GossipRequest {
+    pub fn new() -> GossipRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message_id",
+            |m: &GossipRequest| { &m.message_id },
+            |m: &mut GossipRequest| { &mut m.message_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "request_type",
+            |m: &GossipRequest| { &m.request_type },
+            |m: &mut GossipRequest| { &mut m.request_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "payload",
+            |m: &GossipRequest| { &m.payload },
+            |m: &mut GossipRequest| { &mut m.payload },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipRequest>(
+            "GossipRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GossipRequest {
+    const NAME: &'static str = "GossipRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.message_id = is.read_string()?;
+                },
+                16 => {
+                    self.request_type = is.read_uint32()?;
+                },
+                26 => {
+                    self.payload = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.message_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.message_id);
+        }
+        if self.request_type != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.request_type);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.payload);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.message_id.is_empty() {
+            os.write_string(1, &self.message_id)?;
+        }
+        if self.request_type != 0 {
+            os.write_uint32(2, self.request_type)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_bytes(3, &self.payload)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GossipRequest {
+        GossipRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.message_id.clear();
+        self.request_type = 0;
+        self.payload.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GossipRequest {
+        static instance: GossipRequest = GossipRequest {
+            message_id: ::std::string::String::new(),
+            request_type: 0,
+            payload: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GossipRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GossipRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GossipRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.GossipMember)
+pub struct GossipMember {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.GossipMember.node)
+    pub node: ::protobuf::MessageField<RemoteNode>,
+    // @@protoc_insertion_point(field:coerce.network.GossipMember.incarnation)
+    pub incarnation: u64,
+    // @@protoc_insertion_point(field:coerce.network.GossipMember.status)
+    pub status: ::protobuf::EnumOrUnknown<GossipMemberStatus>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.GossipMember.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GossipMember {
+    fn default() -> &'a GossipMember {
+        <GossipMember as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GossipMember {
+    pub fn new() -> GossipMember {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RemoteNode>(
+            "node",
+            |m: &GossipMember| { &m.node },
+            |m: &mut GossipMember| { &mut m.node },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "incarnation",
+            |m: &GossipMember| { &m.incarnation },
+            |m: &mut GossipMember| { &mut m.incarnation },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &GossipMember| { &m.status },
+            |m: &mut GossipMember| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipMember>(
+            "GossipMember",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GossipMember {
+    const NAME: &'static str = "GossipMember";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node)?;
+                },
+                16 => {
+                    self.incarnation = is.read_uint64()?;
+                },
+                24 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.incarnation != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.incarnation);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(GossipMemberStatus::GossipAlive) {
+            my_size += ::protobuf::rt::int32_size(3, self.status.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.node.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.incarnation != 0 {
+            os.write_uint64(2, self.incarnation)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(GossipMemberStatus::GossipAlive) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GossipMember {
+        GossipMember::new()
+    }
+
+    fn clear(&mut self) {
+        self.node.clear();
+        self.incarnation = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(GossipMemberStatus::GossipAlive);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GossipMember {
+        static instance: GossipMember = GossipMember {
+            node: ::protobuf::MessageField::none(),
+            incarnation: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GossipMember {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipMember").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GossipMember {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GossipMember {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.network.GossipPing)
+pub struct GossipPing {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.network.GossipPing.from_node_id)
+    pub from_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.GossipPing.target_node_id)
+    pub target_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.GossipPing.updates)
+    pub updates: ::std::vec::Vec<GossipMember>,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.network.GossipPing.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GossipPing {
+    fn default() -> &'a GossipPing {
+        <GossipPing as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GossipPing {
+    pub fn new() -> GossipPing {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "from_node_id",
+            |m: &GossipPing| { &m.from_node_id },
+            |m: &mut GossipPing| { &mut m.from_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target_node_id",
+            |m: &GossipPing| { &m.target_node_id },
+            |m: &mut GossipPing| { &mut m.target_node_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "updates",
+            |m: &GossipPing| { &m.updates },
+            |m: &mut GossipPing| { &mut m.updates },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipPing>(
+            "GossipPing",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GossipPing {
+    const NAME: &'static str = "GossipPing";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.from_node_id = is.read_uint64()?;
+                },
+                16 => {
+                    self.target_node_id = is.read_uint64()?;
+                },
+                26 => {
+                    self.updates.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.from_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.from_node_id);
         }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(6, &self.trace_id);
+        if self.target_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.target_node_id);
         }
+        for value in &self.updates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.node_id != 0 {
-            os.write_uint64(1, self.node_id)?;
-        }
-        for v in &self.nodes {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        };
-        if !self.token.is_empty() {
-            os.write_bytes(3, &self.token)?;
-        }
-        if !self.node_tag.is_empty() {
-            os.write_string(4, &self.node_tag)?;
-        }
-        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::Client) {
-            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.client_type))?;
+        if self.from_node_id != 0 {
+            os.write_uint64(1, self.from_node_id)?;
         }
-        if !self.trace_id.is_empty() {
-            os.write_string(6, &self.trace_id)?;
+        if self.target_node_id != 0 {
+            os.write_uint64(2, self.target_node_id)?;
         }
+        for v in &self.updates {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2549,112 +7800,92 @@ impl ::protobuf::Message for SessionHandshake {
         &mut self.special_fields
     }
 
-    fn new() -> SessionHandshake {
-        SessionHandshake::new()
+    fn new() -> GossipPing {
+        GossipPing::new()
     }
 
     fn clear(&mut self) {
-        self.node_id = 0;
-        self.nodes.clear();
-        self.token.clear();
-        self.node_tag.clear();
-        self.client_type = ::protobuf::EnumOrUnknown::new(ClientType::Client);
-        self.trace_id.clear();
+        self.from_node_id = 0;
+        self.target_node_id = 0;
+        self.updates.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SessionHandshake {
-        static instance: SessionHandshake = SessionHandshake {
-            node_id: 0,
-            nodes: ::std::vec::Vec::new(),
-            token: ::std::vec::Vec::new(),
-            node_tag: ::std::string::String::new(),
-            client_type: ::protobuf::EnumOrUnknown::from_i32(0),
-            trace_id: ::std::string::String::new(),
+    fn default_instance() -> &'static GossipPing {
+        static instance: GossipPing = GossipPing {
+            from_node_id: 0,
+            target_node_id: 0,
+            updates: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SessionHandshake {
+impl ::protobuf::MessageFull for GossipPing {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SessionHandshake").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipPing").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SessionHandshake {
+impl ::std::fmt::Display for GossipPing {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SessionHandshake {
+impl ::protobuf::reflect::ProtobufValue for GossipPing {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.StreamPublishEvent)
-pub struct StreamPublishEvent {
+// @@protoc_insertion_point(message:coerce.network.GossipAck)
+pub struct GossipAck {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.topic)
-    pub topic: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.key)
-    pub key: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.message)
-    pub message: ::std::vec::Vec<u8>,
-    // @@protoc_insertion_point(field:coerce.network.StreamPublishEvent.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.GossipAck.ack)
+    pub ack: bool,
+    // @@protoc_insertion_point(field:coerce.network.GossipAck.updates)
+    pub updates: ::std::vec::Vec<GossipMember>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.StreamPublishEvent.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.GossipAck.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a StreamPublishEvent {
-    fn default() -> &'a StreamPublishEvent {
-        <StreamPublishEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GossipAck {
+    fn default() -> &'a GossipAck {
+        <GossipAck as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StreamPublishEvent {
-    pub fn new() -> StreamPublishEvent {
+impl GossipAck {
+    pub fn new() -> GossipAck {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "topic",
-            |m: &StreamPublishEvent| { &m.topic },
-            |m: &mut StreamPublishEvent| { &mut m.topic },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "key",
-            |m: &StreamPublishEvent| { &m.key },
-            |m: &mut StreamPublishEvent| { &mut m.key },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message",
-            |m: &StreamPublishEvent| { &m.message },
-            |m: &mut StreamPublishEvent| { &mut m.message },
+            "ack",
+            |m: &GossipAck| { &m.ack },
+            |m: &mut GossipAck| { &mut m.ack },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &StreamPublishEvent| { &m.trace_id },
-            |m: &mut StreamPublishEvent| { &mut m.trace_id },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "updates",
+            |m: &GossipAck| { &m.updates },
+            |m: &mut GossipAck| { &mut m.updates },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<StreamPublishEvent>(
-            "StreamPublishEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipAck>(
+            "GossipAck",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for StreamPublishEvent {
-    const NAME: &'static str = "StreamPublishEvent";
+impl ::protobuf::Message for GossipAck {
+    const NAME: &'static str = "GossipAck";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2663,17 +7894,11 @@ impl ::protobuf::Message for StreamPublishEvent {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.topic = is.read_string()?;
+                8 => {
+                    self.ack = is.read_bool()?;
                 },
                 18 => {
-                    self.key = is.read_string()?;
-                },
-                26 => {
-                    self.message = is.read_bytes()?;
-                },
-                34 => {
-                    self.trace_id = is.read_string()?;
+                    self.updates.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2687,36 +7912,25 @@ impl ::protobuf::Message for StreamPublishEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.topic.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.topic);
-        }
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.key);
-        }
-        if !self.message.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.message);
-        }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.trace_id);
+        if self.ack != false {
+            my_size += 1 + 1;
         }
+        for value in &self.updates {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.topic.is_empty() {
-            os.write_string(1, &self.topic)?;
-        }
-        if !self.key.is_empty() {
-            os.write_string(2, &self.key)?;
-        }
-        if !self.message.is_empty() {
-            os.write_bytes(3, &self.message)?;
-        }
-        if !self.trace_id.is_empty() {
-            os.write_string(4, &self.trace_id)?;
+        if self.ack != false {
+            os.write_bool(1, self.ack)?;
         }
+        for v in &self.updates {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2729,94 +7943,97 @@ impl ::protobuf::Message for StreamPublishEvent {
         &mut self.special_fields
     }
 
-    fn new() -> StreamPublishEvent {
-        StreamPublishEvent::new()
+    fn new() -> GossipAck {
+        GossipAck::new()
     }
 
     fn clear(&mut self) {
-        self.topic.clear();
-        self.key.clear();
-        self.message.clear();
-        self.trace_id.clear();
+        self.ack = false;
+        self.updates.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static StreamPublishEvent {
-        static instance: StreamPublishEvent = StreamPublishEvent {
-            topic: ::std::string::String::new(),
-            key: ::std::string::String::new(),
-            message: ::std::vec::Vec::new(),
-            trace_id: ::std::string::String::new(),
+    fn default_instance() -> &'static GossipAck {
+        static instance: GossipAck = GossipAck {
+            ack: false,
+            updates: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for StreamPublishEvent {
+impl ::protobuf::MessageFull for GossipAck {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("StreamPublishEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipAck").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for StreamPublishEvent {
+impl ::std::fmt::Display for GossipAck {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StreamPublishEvent {
+impl ::protobuf::reflect::ProtobufValue for GossipAck {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.NewNodeEvent)
-pub struct NewNodeEvent {
+// @@protoc_insertion_point(message:coerce.network.GossipDigestEntry)
+pub struct GossipDigestEntry {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.NewNodeEvent.node)
-    pub node: ::protobuf::MessageField<RemoteNode>,
-    // @@protoc_insertion_point(field:coerce.network.NewNodeEvent.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.GossipDigestEntry.node_id)
+    pub node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.GossipDigestEntry.incarnation)
+    pub incarnation: u64,
+    // @@protoc_insertion_point(field:coerce.network.GossipDigestEntry.status)
+    pub status: ::protobuf::EnumOrUnknown<GossipMemberStatus>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.NewNodeEvent.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.GossipDigestEntry.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a NewNodeEvent {
-    fn default() -> &'a NewNodeEvent {
-        <NewNodeEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GossipDigestEntry {
+    fn default() -> &'a GossipDigestEntry {
+        <GossipDigestEntry as ::protobuf::Message>::default_instance()
     }
 }
 
-impl NewNodeEvent {
-    pub fn new() -> NewNodeEvent {
+impl GossipDigestEntry {
+    pub fn new() -> GossipDigestEntry {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RemoteNode>(
-            "node",
-            |m: &NewNodeEvent| { &m.node },
-            |m: &mut NewNodeEvent| { &mut m.node },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &GossipDigestEntry| { &m.node_id },
+            |m: &mut GossipDigestEntry| { &mut m.node_id },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &NewNodeEvent| { &m.trace_id },
-            |m: &mut NewNodeEvent| { &mut m.trace_id },
+            "incarnation",
+            |m: &GossipDigestEntry| { &m.incarnation },
+            |m: &mut GossipDigestEntry| { &mut m.incarnation },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NewNodeEvent>(
-            "NewNodeEvent",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &GossipDigestEntry| { &m.status },
+            |m: &mut GossipDigestEntry| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipDigestEntry>(
+            "GossipDigestEntry",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for NewNodeEvent {
-    const NAME: &'static str = "NewNodeEvent";
+impl ::protobuf::Message for GossipDigestEntry {
+    const NAME: &'static str = "GossipDigestEntry";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2825,11 +8042,14 @@ impl ::protobuf::Message for NewNodeEvent {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node)?;
+                8 => {
+                    self.node_id = is.read_uint64()?;
                 },
-                18 => {
-                    self.trace_id = is.read_string()?;
+                16 => {
+                    self.incarnation = is.read_uint64()?;
+                },
+                24 => {
+                    self.status = is.read_enum_or_unknown()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2843,12 +8063,14 @@ impl ::protobuf::Message for NewNodeEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.node.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
         }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        if self.incarnation != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.incarnation);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(GossipMemberStatus::GossipAlive) {
+            my_size += ::protobuf::rt::int32_size(3, self.status.value());
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2856,11 +8078,14 @@ impl ::protobuf::Message for NewNodeEvent {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.node.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
         }
-        if !self.trace_id.is_empty() {
-            os.write_string(2, &self.trace_id)?;
+        if self.incarnation != 0 {
+            os.write_uint64(2, self.incarnation)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(GossipMemberStatus::GossipAlive) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.status))?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2874,90 +8099,92 @@ impl ::protobuf::Message for NewNodeEvent {
         &mut self.special_fields
     }
 
-    fn new() -> NewNodeEvent {
-        NewNodeEvent::new()
+    fn new() -> GossipDigestEntry {
+        GossipDigestEntry::new()
     }
 
     fn clear(&mut self) {
-        self.node.clear();
-        self.trace_id.clear();
+        self.node_id = 0;
+        self.incarnation = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(GossipMemberStatus::GossipAlive);
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static NewNodeEvent {
-        static instance: NewNodeEvent = NewNodeEvent {
-            node: ::protobuf::MessageField::none(),
-            trace_id: ::std::string::String::new(),
+    fn default_instance() -> &'static GossipDigestEntry {
+        static instance: GossipDigestEntry = GossipDigestEntry {
+            node_id: 0,
+            incarnation: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for NewNodeEvent {
+impl ::protobuf::MessageFull for GossipDigestEntry {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("NewNodeEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipDigestEntry").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for NewNodeEvent {
+impl ::std::fmt::Display for GossipDigestEntry {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for NewNodeEvent {
+impl ::protobuf::reflect::ProtobufValue for GossipDigestEntry {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.NodeRemovedEvent)
-pub struct NodeRemovedEvent {
+// @@protoc_insertion_point(message:coerce.network.GossipSync)
+pub struct GossipSync {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.NodeRemovedEvent.node)
-    pub node: ::protobuf::MessageField<RemoteNode>,
-    // @@protoc_insertion_point(field:coerce.network.NodeRemovedEvent.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.GossipSync.from_node_id)
+    pub from_node_id: u64,
+    // @@protoc_insertion_point(field:coerce.network.GossipSync.digest)
+    pub digest: ::std::vec::Vec<GossipDigestEntry>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.NodeRemovedEvent.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.GossipSync.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a NodeRemovedEvent {
-    fn default() -> &'a NodeRemovedEvent {
-        <NodeRemovedEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GossipSync {
+    fn default() -> &'a GossipSync {
+        <GossipSync as ::protobuf::Message>::default_instance()
     }
 }
 
-impl NodeRemovedEvent {
-    pub fn new() -> NodeRemovedEvent {
+impl GossipSync {
+    pub fn new() -> GossipSync {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RemoteNode>(
-            "node",
-            |m: &NodeRemovedEvent| { &m.node },
-            |m: &mut NodeRemovedEvent| { &mut m.node },
-        ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &NodeRemovedEvent| { &m.trace_id },
-            |m: &mut NodeRemovedEvent| { &mut m.trace_id },
+            "from_node_id",
+            |m: &GossipSync| { &m.from_node_id },
+            |m: &mut GossipSync| { &mut m.from_node_id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NodeRemovedEvent>(
-            "NodeRemovedEvent",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "digest",
+            |m: &GossipSync| { &m.digest },
+            |m: &mut GossipSync| { &mut m.digest },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipSync>(
+            "GossipSync",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for NodeRemovedEvent {
-    const NAME: &'static str = "NodeRemovedEvent";
+impl ::protobuf::Message for GossipSync {
+    const NAME: &'static str = "GossipSync";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2966,11 +8193,11 @@ impl ::protobuf::Message for NodeRemovedEvent {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.node)?;
+                8 => {
+                    self.from_node_id = is.read_uint64()?;
                 },
                 18 => {
-                    self.trace_id = is.read_string()?;
+                    self.digest.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2984,25 +8211,25 @@ impl ::protobuf::Message for NodeRemovedEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.node.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
+        if self.from_node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.from_node_id);
         }
+        for value in &self.digest {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.node.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        }
-        if !self.trace_id.is_empty() {
-            os.write_string(2, &self.trace_id)?;
+        if self.from_node_id != 0 {
+            os.write_uint64(1, self.from_node_id)?;
         }
+        for v in &self.digest {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3015,90 +8242,90 @@ impl ::protobuf::Message for NodeRemovedEvent {
         &mut self.special_fields
     }
 
-    fn new() -> NodeRemovedEvent {
-        NodeRemovedEvent::new()
+    fn new() -> GossipSync {
+        GossipSync::new()
     }
 
     fn clear(&mut self) {
-        self.node.clear();
-        self.trace_id.clear();
+        self.from_node_id = 0;
+        self.digest.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static NodeRemovedEvent {
-        static instance: NodeRemovedEvent = NodeRemovedEvent {
-            node: ::protobuf::MessageField::none(),
-            trace_id: ::std::string::String::new(),
+    fn default_instance() -> &'static GossipSync {
+        static instance: GossipSync = GossipSync {
+            from_node_id: 0,
+            digest: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for NodeRemovedEvent {
+impl ::protobuf::MessageFull for GossipSync {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("NodeRemovedEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipSync").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for NodeRemovedEvent {
+impl ::std::fmt::Display for GossipSync {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for NodeRemovedEvent {
+impl ::protobuf::reflect::ProtobufValue for GossipSync {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.LeaderChangedEvent)
-pub struct LeaderChangedEvent {
+// @@protoc_insertion_point(message:coerce.network.GossipSyncAck)
+pub struct GossipSyncAck {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.LeaderChangedEvent.node_id)
-    pub node_id: u64,
-    // @@protoc_insertion_point(field:coerce.network.LeaderChangedEvent.trace_id)
-    pub trace_id: ::std::string::String,
+    // @@protoc_insertion_point(field:coerce.network.GossipSyncAck.members)
+    pub members: ::std::vec::Vec<GossipMember>,
+    // @@protoc_insertion_point(field:coerce.network.GossipSyncAck.requested)
+    pub requested: ::std::vec::Vec<u64>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.LeaderChangedEvent.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.GossipSyncAck.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a LeaderChangedEvent {
-    fn default() -> &'a LeaderChangedEvent {
-        <LeaderChangedEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GossipSyncAck {
+    fn default() -> &'a GossipSyncAck {
+        <GossipSyncAck as ::protobuf::Message>::default_instance()
     }
 }
 
-impl LeaderChangedEvent {
-    pub fn new() -> LeaderChangedEvent {
+impl GossipSyncAck {
+    pub fn new() -> GossipSyncAck {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(2);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "node_id",
-            |m: &LeaderChangedEvent| { &m.node_id },
-            |m: &mut LeaderChangedEvent| { &mut m.node_id },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "members",
+            |m: &GossipSyncAck| { &m.members },
+            |m: &mut GossipSyncAck| { &mut m.members },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "trace_id",
-            |m: &LeaderChangedEvent| { &m.trace_id },
-            |m: &mut LeaderChangedEvent| { &mut m.trace_id },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "requested",
+            |m: &GossipSyncAck| { &m.requested },
+            |m: &mut GossipSyncAck| { &mut m.requested },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaderChangedEvent>(
-            "LeaderChangedEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipSyncAck>(
+            "GossipSyncAck",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for LeaderChangedEvent {
-    const NAME: &'static str = "LeaderChangedEvent";
+impl ::protobuf::Message for GossipSyncAck {
+    const NAME: &'static str = "GossipSyncAck";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3107,11 +8334,14 @@ impl ::protobuf::Message for LeaderChangedEvent {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.node_id = is.read_uint64()?;
+                10 => {
+                    self.members.push(is.read_message()?);
                 },
                 18 => {
-                    self.trace_id = is.read_string()?;
+                    is.read_repeated_packed_uint64_into(&mut self.requested)?;
+                },
+                16 => {
+                    self.requested.push(is.read_uint64()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3125,24 +8355,25 @@ impl ::protobuf::Message for LeaderChangedEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.node_id != 0 {
-            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
-        }
-        if !self.trace_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.trace_id);
-        }
+        for value in &self.members {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.requested {
+            my_size += ::protobuf::rt::uint64_size(2, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.node_id != 0 {
-            os.write_uint64(1, self.node_id)?;
-        }
-        if !self.trace_id.is_empty() {
-            os.write_string(2, &self.trace_id)?;
-        }
+        for v in &self.members {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        for v in &self.requested {
+            os.write_uint64(2, *v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3155,97 +8386,83 @@ impl ::protobuf::Message for LeaderChangedEvent {
         &mut self.special_fields
     }
 
-    fn new() -> LeaderChangedEvent {
-        LeaderChangedEvent::new()
+    fn new() -> GossipSyncAck {
+        GossipSyncAck::new()
     }
 
     fn clear(&mut self) {
-        self.node_id = 0;
-        self.trace_id.clear();
+        self.members.clear();
+        self.requested.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static LeaderChangedEvent {
-        static instance: LeaderChangedEvent = LeaderChangedEvent {
-            node_id: 0,
-            trace_id: ::std::string::String::new(),
+    fn default_instance() -> &'static GossipSyncAck {
+        static instance: GossipSyncAck = GossipSyncAck {
+            members: ::std::vec::Vec::new(),
+            requested: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for LeaderChangedEvent {
+impl ::protobuf::MessageFull for GossipSyncAck {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaderChangedEvent").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipSyncAck").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for LeaderChangedEvent {
+impl ::std::fmt::Display for GossipSyncAck {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for LeaderChangedEvent {
+impl ::protobuf::reflect::ProtobufValue for GossipSyncAck {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:coerce.network.RaftRequest)
-pub struct RaftRequest {
+// @@protoc_insertion_point(message:coerce.network.GossipPush)
+pub struct GossipPush {
     // message fields
-    // @@protoc_insertion_point(field:coerce.network.RaftRequest.message_id)
-    pub message_id: ::std::string::String,
-    // @@protoc_insertion_point(field:coerce.network.RaftRequest.request_type)
-    pub request_type: u32,
-    // @@protoc_insertion_point(field:coerce.network.RaftRequest.payload)
-    pub payload: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:coerce.network.GossipPush.members)
+    pub members: ::std::vec::Vec<GossipMember>,
     // special fields
-    // @@protoc_insertion_point(special_field:coerce.network.RaftRequest.special_fields)
+    // @@protoc_insertion_point(special_field:coerce.network.GossipPush.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a RaftRequest {
-    fn default() -> &'a RaftRequest {
-        <RaftRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GossipPush {
+    fn default() -> &'a GossipPush {
+        <GossipPush as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RaftRequest {
-    pub fn new() -> RaftRequest {
+impl GossipPush {
+    pub fn new() -> GossipPush {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "message_id",
-            |m: &RaftRequest| { &m.message_id },
-            |m: &mut RaftRequest| { &mut m.message_id },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "request_type",
-            |m: &RaftRequest| { &m.request_type },
-            |m: &mut RaftRequest| { &mut m.request_type },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "payload",
-            |m: &RaftRequest| { &m.payload },
-            |m: &mut RaftRequest| { &mut m.payload },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "members",
+            |m: &GossipPush| { &m.members },
+            |m: &mut GossipPush| { &mut m.members },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftRequest>(
-            "RaftRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GossipPush>(
+            "GossipPush",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for RaftRequest {
-    const NAME: &'static str = "RaftRequest";
+impl ::protobuf::Message for GossipPush {
+    const NAME: &'static str = "GossipPush";
 
     fn is_initialized(&self) -> bool {
         true
@@ -3255,13 +8472,7 @@ impl ::protobuf::Message for RaftRequest {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 10 => {
-                    self.message_id = is.read_string()?;
-                },
-                16 => {
-                    self.request_type = is.read_uint32()?;
-                },
-                26 => {
-                    self.payload = is.read_bytes()?;
+                    self.members.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -3275,30 +8486,19 @@ impl ::protobuf::Message for RaftRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.message_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.message_id);
-        }
-        if self.request_type != 0 {
-            my_size += ::protobuf::rt::uint32_size(2, self.request_type);
-        }
-        if !self.payload.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.payload);
-        }
+        for value in &self.members {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.message_id.is_empty() {
-            os.write_string(1, &self.message_id)?;
-        }
-        if self.request_type != 0 {
-            os.write_uint32(2, self.request_type)?;
-        }
-        if !self.payload.is_empty() {
-            os.write_bytes(3, &self.payload)?;
-        }
+        for v in &self.members {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3311,42 +8511,38 @@ impl ::protobuf::Message for RaftRequest {
         &mut self.special_fields
     }
 
-    fn new() -> RaftRequest {
-        RaftRequest::new()
+    fn new() -> GossipPush {
+        GossipPush::new()
     }
 
     fn clear(&mut self) {
-        self.message_id.clear();
-        self.request_type = 0;
-        self.payload.clear();
+        self.members.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static RaftRequest {
-        static instance: RaftRequest = RaftRequest {
-            message_id: ::std::string::String::new(),
-            request_type: 0,
-            payload: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static GossipPush {
+        static instance: GossipPush = GossipPush {
+            members: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for RaftRequest {
+impl ::protobuf::MessageFull for GossipPush {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GossipPush").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for RaftRequest {
+impl ::std::fmt::Display for GossipPush {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RaftRequest {
+impl ::protobuf::reflect::ProtobufValue for GossipPush {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -3706,6 +8902,22 @@ pub enum Event {
     Raft = 11,
     // @@protoc_insertion_point(enum_value:coerce.network.Event.Identity)
     Identity = 12,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.Batch)
+    Batch = 13,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.Compressed)
+    Compressed = 14,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.WatchActor)
+    WatchActor = 15,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.ActorTerminated)
+    ActorTerminated = 16,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.GetNodeStats)
+    GetNodeStats = 17,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.GetClusterNodes)
+    GetClusterNodes = 18,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.Gossip)
+    Gossip = 19,
+    // @@protoc_insertion_point(enum_value:coerce.network.Event.Leave)
+    Leave = 20,
 }
 
 impl ::protobuf::Enum for Event {
@@ -3730,6 +8942,14 @@ impl ::protobuf::Enum for Event {
             10 => ::std::option::Option::Some(Event::StreamPublish),
             11 => ::std::option::Option::Some(Event::Raft),
             12 => ::std::option::Option::Some(Event::Identity),
+            13 => ::std::option::Option::Some(Event::Batch),
+            14 => ::std::option::Option::Some(Event::Compressed),
+            15 => ::std::option::Option::Some(Event::WatchActor),
+            16 => ::std::option::Option::Some(Event::ActorTerminated),
+            17 => ::std::option::Option::Some(Event::GetNodeStats),
+            18 => ::std::option::Option::Some(Event::GetClusterNodes),
+            19 => ::std::option::Option::Some(Event::Gossip),
+            20 => ::std::option::Option::Some(Event::Leave),
             _ => ::std::option::Option::None
         }
     }
@@ -3748,6 +8968,14 @@ impl ::protobuf::Enum for Event {
         Event::StreamPublish,
         Event::Raft,
         Event::Identity,
+        Event::Batch,
+        Event::Compressed,
+        Event::WatchActor,
+        Event::ActorTerminated,
+        Event::GetNodeStats,
+        Event::GetClusterNodes,
+        Event::Gossip,
+        Event::Leave,
     ];
 }
 
@@ -3829,6 +9057,122 @@ impl ClientType {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:coerce.network.TerminationReason)
+pub enum TerminationReason {
+    // @@protoc_insertion_point(enum_value:coerce.network.TerminationReason.Stopped)
+    Stopped = 0,
+    // @@protoc_insertion_point(enum_value:coerce.network.TerminationReason.NotFound)
+    NotFound = 1,
+    // @@protoc_insertion_point(enum_value:coerce.network.TerminationReason.NodeTerminated)
+    NodeTerminated = 2,
+    // @@protoc_insertion_point(enum_value:coerce.network.TerminationReason.Unreachable)
+    Unreachable = 3,
+}
+
+impl ::protobuf::Enum for TerminationReason {
+    const NAME: &'static str = "TerminationReason";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<TerminationReason> {
+        match value {
+            0 => ::std::option::Option::Some(TerminationReason::Stopped),
+            1 => ::std::option::Option::Some(TerminationReason::NotFound),
+            2 => ::std::option::Option::Some(TerminationReason::NodeTerminated),
+            3 => ::std::option::Option::Some(TerminationReason::Unreachable),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [TerminationReason] = &[
+        TerminationReason::Stopped,
+        TerminationReason::NotFound,
+        TerminationReason::NodeTerminated,
+        TerminationReason::Unreachable,
+    ];
+}
+
+impl ::protobuf::EnumFull for TerminationReason {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("TerminationReason").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for TerminationReason {
+    fn default() -> Self {
+        TerminationReason::Stopped
+    }
+}
+
+impl TerminationReason {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<TerminationReason>("TerminationReason")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:coerce.network.CompressionType)
+pub enum CompressionType {
+    // @@protoc_insertion_point(enum_value:coerce.network.CompressionType.Uncompressed)
+    Uncompressed = 0,
+    // @@protoc_insertion_point(enum_value:coerce.network.CompressionType.Deflate)
+    Deflate = 1,
+}
+
+impl ::protobuf::Enum for CompressionType {
+    const NAME: &'static str = "CompressionType";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CompressionType> {
+        match value {
+            0 => ::std::option::Option::Some(CompressionType::Uncompressed),
+            1 => ::std::option::Option::Some(CompressionType::Deflate),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [CompressionType] = &[
+        CompressionType::Uncompressed,
+        CompressionType::Deflate,
+    ];
+}
+
+impl ::protobuf::EnumFull for CompressionType {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("CompressionType").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for CompressionType {
+    fn default() -> Self {
+        CompressionType::Uncompressed
+    }
+}
+
+impl CompressionType {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<CompressionType>("CompressionType")
+    }
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:coerce.network.SystemEvent)
 pub enum SystemEvent {
@@ -3838,6 +9182,12 @@ pub enum SystemEvent {
     ClusterNodeRemoved = 1,
     // @@protoc_insertion_point(enum_value:coerce.network.SystemEvent.ClusterLeaderChanged)
     ClusterLeaderChanged = 2,
+    // @@protoc_insertion_point(enum_value:coerce.network.SystemEvent.ClusterNodeDowned)
+    ClusterNodeDowned = 3,
+    // @@protoc_insertion_point(enum_value:coerce.network.SystemEvent.ClusterNodeLeaving)
+    ClusterNodeLeaving = 4,
+    // @@protoc_insertion_point(enum_value:coerce.network.SystemEvent.ClusterDatacenterLeaderChanged)
+    ClusterDatacenterLeaderChanged = 5,
 }
 
 impl ::protobuf::Enum for SystemEvent {
@@ -3852,6 +9202,9 @@ impl ::protobuf::Enum for SystemEvent {
             0 => ::std::option::Option::Some(SystemEvent::ClusterNewNode),
             1 => ::std::option::Option::Some(SystemEvent::ClusterNodeRemoved),
             2 => ::std::option::Option::Some(SystemEvent::ClusterLeaderChanged),
+            3 => ::std::option::Option::Some(SystemEvent::ClusterNodeDowned),
+            4 => ::std::option::Option::Some(SystemEvent::ClusterNodeLeaving),
+            5 => ::std::option::Option::Some(SystemEvent::ClusterDatacenterLeaderChanged),
             _ => ::std::option::Option::None
         }
     }
@@ -3860,6 +9213,9 @@ impl ::protobuf::Enum for SystemEvent {
         SystemEvent::ClusterNewNode,
         SystemEvent::ClusterNodeRemoved,
         SystemEvent::ClusterLeaderChanged,
+        SystemEvent::ClusterNodeDowned,
+        SystemEvent::ClusterNodeLeaving,
+        SystemEvent::ClusterDatacenterLeaderChanged,
     ];
 }
 
@@ -3887,6 +9243,64 @@ impl SystemEvent {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:coerce.network.GossipMemberStatus)
+pub enum GossipMemberStatus {
+    // @@protoc_insertion_point(enum_value:coerce.network.GossipMemberStatus.GossipAlive)
+    GossipAlive = 0,
+    // @@protoc_insertion_point(enum_value:coerce.network.GossipMemberStatus.GossipSuspect)
+    GossipSuspect = 1,
+    // @@protoc_insertion_point(enum_value:coerce.network.GossipMemberStatus.GossipDead)
+    GossipDead = 2,
+}
+
+impl ::protobuf::Enum for GossipMemberStatus {
+    const NAME: &'static str = "GossipMemberStatus";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<GossipMemberStatus> {
+        match value {
+            0 => ::std::option::Option::Some(GossipMemberStatus::GossipAlive),
+            1 => ::std::option::Option::Some(GossipMemberStatus::GossipSuspect),
+            2 => ::std::option::Option::Some(GossipMemberStatus::GossipDead),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [GossipMemberStatus] = &[
+        GossipMemberStatus::GossipAlive,
+        GossipMemberStatus::GossipSuspect,
+        GossipMemberStatus::GossipDead,
+    ];
+}
+
+impl ::protobuf::EnumFull for GossipMemberStatus {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("GossipMemberStatus").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for GossipMemberStatus {
+    fn default() -> Self {
+        GossipMemberStatus::GossipAlive
+    }
+}
+
+impl GossipMemberStatus {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<GossipMemberStatus>("GossipMemberStatus")
+    }
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:coerce.network.MessageUnwrapErr)
 pub enum MessageUnwrapErr {
@@ -4005,37 +9419,47 @@ impl MessageWrapErr {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\rnetwork.proto\x12\x0ecoerce.network\x1a\x1egoogle/protobuf/wrappers.\
-    proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\x9a\x02\n\nRemoteNode\x12\
+    proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\xd0\x02\n\nRemoteNode\x12\
     \x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x12\n\x04addr\x18\
     \x02\x20\x01(\tR\x04addr\x12B\n\x0fnode_started_at\x18\x03\x20\x01(\x0b2\
     \x1a.google.protobuf.TimestampR\rnodeStartedAt\x12\x10\n\x03tag\x18\x04\
     \x20\x01(\tR\x03tag\x12J\n\nattributes\x18\x05\x20\x03(\x0b2*.coerce.net\
-    work.RemoteNode.AttributesEntryR\nattributes\x1a=\n\x0fAttributesEntry\
-    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\
-    \x20\x01(\tR\x05value:\x028\x01\"s\n\rIdentifyEvent\x12$\n\x0esource_nod\
-    e_id\x18\x01\x20\x01(\x04R\x0csourceNodeId\x12&\n\x0fsource_node_tag\x18\
-    \x02\x20\x01(\tR\rsourceNodeTag\x12\x14\n\x05token\x18\x03\x20\x01(\tR\
-    \x05token\"\xfd\x03\n\x0cNodeIdentity\x12\x17\n\x07node_id\x18\x01\x20\
-    \x01(\x04R\x06nodeId\x12\x19\n\x08node_tag\x18\x02\x20\x01(\tR\x07nodeTa\
-    g\x12\x12\n\x04addr\x18\x03\x20\x01(\tR\x04addr\x12/\n\x13application_ve\
-    rsion\x18\x04\x20\x01(\tR\x12applicationVersion\x12)\n\x10protocol_versi\
-    on\x18\x05\x20\x01(\tR\x0fprotocolVersion\x12B\n\x0fnode_started_at\x18\
-    \x06\x20\x01(\x0b2\x1a.google.protobuf.TimestampR\rnodeStartedAt\x120\n\
-    \x05peers\x18\x07\x20\x03(\x0b2\x1a.coerce.network.RemoteNodeR\x05peers\
-    \x12F\n\x0ccapabilities\x18\x08\x20\x01(\x0b2\".coerce.network.SystemCap\
-    abilitiesR\x0ccapabilities\x12L\n\nattributes\x18\t\x20\x03(\x0b2,.coerc\
-    e.network.NodeIdentity.AttributesEntryR\nattributes\x1a=\n\x0fAttributes\
-    Entry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\
-    \x02\x20\x01(\tR\x05value:\x028\x01\"H\n\x12SystemCapabilities\x12\x16\n\
-    \x06actors\x18\x01\x20\x03(\tR\x06actors\x12\x1a\n\x08messages\x18\x02\
-    \x20\x03(\tR\x08messages\"\xd6\x01\n\x0fClientHandshake\x12\x17\n\x07nod\
-    e_id\x18\x01\x20\x01(\x04R\x06nodeId\x120\n\x05nodes\x18\x02\x20\x03(\
-    \x0b2\x1a.coerce.network.RemoteNodeR\x05nodes\x12\x19\n\x08node_tag\x18\
-    \x03\x20\x01(\tR\x07nodeTag\x12\x19\n\x08trace_id\x18\x04\x20\x01(\tR\
-    \x07traceId\x12B\n\x0fnode_started_at\x18\x05\x20\x01(\x0b2\x1a.google.p\
-    rotobuf.TimestampR\rnodeStartedAt\"`\n\x0cClientResult\x12\x1d\n\nmessag\
-    e_id\x18\x01\x20\x01(\tR\tmessageId\x12\x16\n\x06result\x18\x02\x20\x01(\
-    \x0cR\x06result\x12\x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"x\
+    work.RemoteNode.AttributesEntryR\nattributes\x12\x14\n\x05roles\x18\x06\
+    \x20\x03(\tR\x05roles\x12\x1e\n\ndatacenter\x18\x07\x20\x01(\tR\ndatacen\
+    ter\x1a=\n\x0fAttributesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03ke\
+    y\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"\xe1\x01\n\
+    \rIdentifyEvent\x12$\n\x0esource_node_id\x18\x01\x20\x01(\x04R\x0csource\
+    NodeId\x12&\n\x0fsource_node_tag\x18\x02\x20\x01(\tR\rsourceNodeTag\x12\
+    \x14\n\x05token\x18\x03\x20\x01(\tR\x05token\x12;\n\x08protocol\x18\x04\
+    \x20\x01(\x0b2\x1f.coerce.network.ProtocolSupportR\x08protocol\x12/\n\
+    \x13application_version\x18\x05\x20\x01(\tR\x12applicationVersion\"\x9c\
+    \x01\n\x0fProtocolSupport\x12\x1f\n\x0bmin_version\x18\x01\x20\x01(\rR\n\
+    minVersion\x12\x1f\n\x0bmax_version\x18\x02\x20\x01(\rR\nmaxVersion\x12\
+    \x1a\n\x08features\x18\x03\x20\x03(\tR\x08features\x12+\n\x11required_fe\
+    atures\x18\x04\x20\x03(\tR\x10requiredFeatures\"\xf0\x04\n\x0cNodeIdenti\
+    ty\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\x08nod\
+    e_tag\x18\x02\x20\x01(\tR\x07nodeTag\x12\x12\n\x04addr\x18\x03\x20\x01(\
+    \tR\x04addr\x12/\n\x13application_version\x18\x04\x20\x01(\tR\x12applica\
+    tionVersion\x12)\n\x10protocol_version\x18\x05\x20\x01(\tR\x0fprotocolVe\
+    rsion\x12B\n\x0fnode_started_at\x18\x06\x20\x01(\x0b2\x1a.google.protobu\
+    f.TimestampR\rnodeStartedAt\x120\n\x05peers\x18\x07\x20\x03(\x0b2\x1a.co\
+    erce.network.RemoteNodeR\x05peers\x12F\n\x0ccapabilities\x18\x08\x20\x01\
+    (\x0b2\".coerce.network.SystemCapabilitiesR\x0ccapabilities\x12L\n\nattr\
+    ibutes\x18\t\x20\x03(\x0b2,.coerce.network.NodeIdentity.AttributesEntryR\
+    \nattributes\x12;\n\x08protocol\x18\n\x20\x01(\x0b2\x1f.coerce.network.P\
+    rotocolSupportR\x08protocol\x12\x14\n\x05roles\x18\x0b\x20\x03(\tR\x05ro\
+    les\x12\x1e\n\ndatacenter\x18\x0c\x20\x01(\tR\ndatacenter\x1a=\n\x0fAttr\
+    ibutesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05val\
+    ue\x18\x02\x20\x01(\tR\x05value:\x028\x01\"H\n\x12SystemCapabilities\x12\
+    \x16\n\x06actors\x18\x01\x20\x03(\tR\x06actors\x12\x1a\n\x08messages\x18\
+    \x02\x20\x03(\tR\x08messages\"\xd6\x01\n\x0fClientHandshake\x12\x17\n\
+    \x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x120\n\x05nodes\x18\x02\x20\
+    \x03(\x0b2\x1a.coerce.network.RemoteNodeR\x05nodes\x12\x19\n\x08node_tag\
+    \x18\x03\x20\x01(\tR\x07nodeTag\x12\x19\n\x08trace_id\x18\x04\x20\x01(\t\
+    R\x07traceId\x12B\n\x0fnode_started_at\x18\x05\x20\x01(\x0b2\x1a.google.\
+    protobuf.TimestampR\rnodeStartedAt\"`\n\x0cClientResult\x12\x1d\n\nmessa\
+    ge_id\x18\x01\x20\x01(\tR\tmessageId\x12\x16\n\x06result\x18\x02\x20\x01\
+    (\x0cR\x06result\x12\x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"x\
     \n\tClientErr\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x121\
     \n\x05error\x18\x02\x20\x01(\x0b2\x1b.coerce.network.ActorRefErrR\x05err\
     or\x12\x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"\x8b\x01\n\tPin\
@@ -4044,44 +9468,133 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x20\x01(\x04R\x06nodeId\x12+\n\x11system_terminated\x18\x04\x20\x01(\
     \x08R\x10systemTerminated\"E\n\tPongEvent\x12\x1d\n\nmessage_id\x18\x01\
     \x20\x01(\tR\tmessageId\x12\x19\n\x08trace_id\x18\x02\x20\x01(\tR\x07tra\
-    ceId\"\x9e\x01\n\x10CreateActorEvent\x12\x1d\n\nmessage_id\x18\x01\x20\
-    \x01(\tR\tmessageId\x12\x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\
-    \x12\x1d\n\nactor_type\x18\x03\x20\x01(\tR\tactorType\x12\x16\n\x06recip\
-    e\x18\x04\x20\x01(\x0cR\x06recipe\x12\x19\n\x08trace_id\x18\x05\x20\x01(\
-    \tR\x07traceId\"e\n\x0eFindActorEvent\x12\x1d\n\nmessage_id\x18\x01\x20\
-    \x01(\tR\tmessageId\x12\x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\
-    \x12\x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"{\n\x0cActorAddre\
-    ss\x12\x19\n\x08actor_id\x18\x01\x20\x01(\tR\x07actorId\x125\n\x07node_i\
-    d\x18\x02\x20\x01(\x0b2\x1c.google.protobuf.UInt64ValueR\x06nodeId\x12\
-    \x19\n\x08trace_id\x18\x03\x20\x01(\tR\x07traceId\"\xf5\x01\n\x0eMessage\
-    Request\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12!\n\x0ch\
-    andler_type\x18\x02\x20\x01(\tR\x0bhandlerType\x12\x19\n\x08actor_id\x18\
-    \x03\x20\x01(\tR\x07actorId\x12\x18\n\x07message\x18\x04\x20\x01(\x0cR\
-    \x07message\x12\x19\n\x08trace_id\x18\x05\x20\x01(\tR\x07traceId\x12+\n\
-    \x11requires_response\x18\x06\x20\x01(\x08R\x10requiresResponse\x12$\n\
-    \x0eorigin_node_id\x18\x07\x20\x01(\x04R\x0coriginNodeId\"\xe6\x01\n\x10\
-    SessionHandshake\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\
-    \x120\n\x05nodes\x18\x02\x20\x03(\x0b2\x1a.coerce.network.RemoteNodeR\
-    \x05nodes\x12\x14\n\x05token\x18\x03\x20\x01(\x0cR\x05token\x12\x19\n\
-    \x08node_tag\x18\x04\x20\x01(\tR\x07nodeTag\x12;\n\x0bclient_type\x18\
-    \x05\x20\x01(\x0e2\x1a.coerce.network.ClientTypeR\nclientType\x12\x19\n\
-    \x08trace_id\x18\x06\x20\x01(\tR\x07traceId\"q\n\x12StreamPublishEvent\
-    \x12\x14\n\x05topic\x18\x01\x20\x01(\tR\x05topic\x12\x10\n\x03key\x18\
-    \x02\x20\x01(\tR\x03key\x12\x18\n\x07message\x18\x03\x20\x01(\x0cR\x07me\
-    ssage\x12\x19\n\x08trace_id\x18\x04\x20\x01(\tR\x07traceId\"Y\n\x0cNewNo\
-    deEvent\x12.\n\x04node\x18\x01\x20\x01(\x0b2\x1a.coerce.network.RemoteNo\
-    deR\x04node\x12\x19\n\x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"]\n\
-    \x10NodeRemovedEvent\x12.\n\x04node\x18\x01\x20\x01(\x0b2\x1a.coerce.net\
-    work.RemoteNodeR\x04node\x12\x19\n\x08trace_id\x18\x02\x20\x01(\tR\x07tr\
-    aceId\"H\n\x12LeaderChangedEvent\x12\x17\n\x07node_id\x18\x01\x20\x01(\
-    \x04R\x06nodeId\x12\x19\n\x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"i\
-    \n\x0bRaftRequest\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\
-    \x12!\n\x0crequest_type\x18\x02\x20\x01(\rR\x0brequestType\x12\x18\n\x07\
-    payload\x18\x03\x20\x01(\x0cR\x07payload\"\xee\x04\n\x0bActorRefErr\x129\
-    \n\x04type\x18\x01\x20\x01(\x0e2%.coerce.network.ActorRefErr.ErrorTypeR\
-    \x04type\x12\x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12!\n\x0c\
-    message_type\x18\x03\x20\x01(\tR\x0bmessageType\x12\x1d\n\nactor_type\
-    \x18\x04\x20\x01(\tR\tactorType\x12*\n\x11time_taken_millis\x18\x05\x20\
+    ceId\"$\n\nBatchEvent\x12\x16\n\x06events\x18\x01\x20\x03(\x0cR\x06event\
+    s\"n\n\x0fWatchActorEvent\x12\x19\n\x08actor_id\x18\x01\x20\x01(\tR\x07a\
+    ctorId\x12&\n\x0fwatcher_node_id\x18\x02\x20\x01(\x04R\rwatcherNodeId\
+    \x12\x18\n\x07unwatch\x18\x03\x20\x01(\x08R\x07unwatch\"\x85\x01\n\x14Ac\
+    torTerminatedEvent\x12\x19\n\x08actor_id\x18\x01\x20\x01(\tR\x07actorId\
+    \x12\x17\n\x07node_id\x18\x02\x20\x01(\x04R\x06nodeId\x129\n\x06reason\
+    \x18\x03\x20\x01(\x0e2!.coerce.network.TerminationReasonR\x06reason\"2\n\
+    \x11GetNodeStatsEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessage\
+    Id\"E\n\tNodeStats\x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\
+    \x12\x1f\n\x0bactor_count\x18\x02\x20\x01(\x04R\nactorCount\"5\n\x14GetC\
+    lusterNodesEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\"D\
+    \n\nLeaveEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\
+    \x17\n\x07node_id\x18\x02\x20\x01(\x04R\x06nodeId\"@\n\x0cClusterNodes\
+    \x120\n\x05nodes\x18\x01\x20\x03(\x0b2\x1a.coerce.network.RemoteNodeR\
+    \x05nodes\"\x9f\x01\n\x0fCompressedEvent\x12A\n\x0bcompression\x18\x01\
+    \x20\x01(\x0e2\x1f.coerce.network.CompressionTypeR\x0bcompression\x12/\n\
+    \x13uncompressed_length\x18\x02\x20\x01(\rR\x12uncompressedLength\x12\
+    \x18\n\x07payload\x18\x03\x20\x01(\x0cR\x07payload\"\x9e\x01\n\x10Create\
+    ActorEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\x19\
+    \n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12\x1d\n\nactor_type\x18\
+    \x03\x20\x01(\tR\tactorType\x12\x16\n\x06recipe\x18\x04\x20\x01(\x0cR\
+    \x06recipe\x12\x19\n\x08trace_id\x18\x05\x20\x01(\tR\x07traceId\"e\n\x0e\
+    FindActorEvent\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmessageId\x12\
+    \x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12\x19\n\x08trace_id\
+    \x18\x03\x20\x01(\tR\x07traceId\"{\n\x0cActorAddress\x12\x19\n\x08actor_\
+    id\x18\x01\x20\x01(\tR\x07actorId\x125\n\x07node_id\x18\x02\x20\x01(\x0b\
+    2\x1c.google.protobuf.UInt64ValueR\x06nodeId\x12\x19\n\x08trace_id\x18\
+    \x03\x20\x01(\tR\x07traceId\"\xf5\x01\n\x0eMessageRequest\x12\x1d\n\nmes\
+    sage_id\x18\x01\x20\x01(\tR\tmessageId\x12!\n\x0chandler_type\x18\x02\
+    \x20\x01(\tR\x0bhandlerType\x12\x19\n\x08actor_id\x18\x03\x20\x01(\tR\
+    \x07actorId\x12\x18\n\x07message\x18\x04\x20\x01(\x0cR\x07message\x12\
+    \x19\n\x08trace_id\x18\x05\x20\x01(\tR\x07traceId\x12+\n\x11requires_res\
+    ponse\x18\x06\x20\x01(\x08R\x10requiresResponse\x12$\n\x0eorigin_node_id\
+    \x18\x07\x20\x01(\x04R\x0coriginNodeId\"\x91\x02\n\x10SessionHandshake\
+    \x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x120\n\x05nodes\
+    \x18\x02\x20\x03(\x0b2\x1a.coerce.network.RemoteNodeR\x05nodes\x12\x14\n\
+    \x05token\x18\x03\x20\x01(\x0cR\x05token\x12\x19\n\x08node_tag\x18\x04\
+    \x20\x01(\tR\x07nodeTag\x12;\n\x0bclient_type\x18\x05\x20\x01(\x0e2\x1a.\
+    coerce.network.ClientTypeR\nclientType\x12\x19\n\x08trace_id\x18\x06\x20\
+    \x01(\tR\x07traceId\x12)\n\x10protocol_version\x18\x07\x20\x01(\rR\x0fpr\
+    otocolVersion\"q\n\x12StreamPublishEvent\x12\x14\n\x05topic\x18\x01\x20\
+    \x01(\tR\x05topic\x12\x10\n\x03key\x18\x02\x20\x01(\tR\x03key\x12\x18\n\
+    \x07message\x18\x03\x20\x01(\x0cR\x07message\x12\x19\n\x08trace_id\x18\
+    \x04\x20\x01(\tR\x07traceId\"Y\n\x0cNewNodeEvent\x12.\n\x04node\x18\x01\
+    \x20\x01(\x0b2\x1a.coerce.network.RemoteNodeR\x04node\x12\x19\n\x08trace\
+    _id\x18\x02\x20\x01(\tR\x07traceId\"]\n\x10NodeRemovedEvent\x12.\n\x04no\
+    de\x18\x01\x20\x01(\x0b2\x1a.coerce.network.RemoteNodeR\x04node\x12\x19\
+    \n\x08trace_id\x18\x02\x20\x01(\tR\x07traceId\"H\n\x12LeaderChangedEvent\
+    \x12\x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\x08trace\
+    _id\x18\x02\x20\x01(\tR\x07traceId\"E\n\x0fNodeDownedEvent\x12\x17\n\x07\
+    node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\x08trace_id\x18\x02\
+    \x20\x01(\tR\x07traceId\"F\n\x10NodeLeavingEvent\x12\x17\n\x07node_id\
+    \x18\x01\x20\x01(\x04R\x06nodeId\x12\x19\n\x08trace_id\x18\x02\x20\x01(\
+    \tR\x07traceId\"i\n\x0bRaftRequest\x12\x1d\n\nmessage_id\x18\x01\x20\x01\
+    (\tR\tmessageId\x12!\n\x0crequest_type\x18\x02\x20\x01(\rR\x0brequestTyp\
+    e\x12\x18\n\x07payload\x18\x03\x20\x01(\x0cR\x07payload\"\x9b\x01\n\x0cR\
+    aftLogEntry\x12\x14\n\x05index\x18\x01\x20\x01(\x04R\x05index\x12\x12\n\
+    \x04term\x18\x02\x20\x01(\x04R\x04term\x12\x18\n\x07command\x18\x03\x20\
+    \x01(\x0cR\x07command\x12G\n\rconfiguration\x18\x04\x20\x01(\x0b2!.coerc\
+    e.network.RaftConfigurationR\rconfiguration\"+\n\x11RaftConfiguration\
+    \x12\x16\n\x06voters\x18\x01\x20\x03(\x04R\x06voters\"-\n\x08RaftVote\
+    \x12!\n\x0ccandidate_id\x18\x01\x20\x01(\x04R\x0bcandidateId\"\x92\x01\n\
+    \x0fRaftVoteRequest\x12\x12\n\x04term\x18\x01\x20\x01(\x04R\x04term\x12!\
+    \n\x0ccandidate_id\x18\x02\x20\x01(\x04R\x0bcandidateId\x12$\n\x0elast_l\
+    og_index\x18\x03\x20\x01(\x04R\x0clastLogIndex\x12\"\n\rlast_log_term\
+    \x18\x04\x20\x01(\x04R\x0blastLogTerm\"I\n\x10RaftVoteResponse\x12\x12\n\
+    \x04term\x18\x01\x20\x01(\x04R\x04term\x12!\n\x0cvote_granted\x18\x02\
+    \x20\x01(\x08R\x0bvoteGranted\"\xf2\x01\n\x18RaftAppendEntriesRequest\
+    \x12\x12\n\x04term\x18\x01\x20\x01(\x04R\x04term\x12\x1b\n\tleader_id\
+    \x18\x02\x20\x01(\x04R\x08leaderId\x12$\n\x0eprev_log_index\x18\x03\x20\
+    \x01(\x04R\x0cprevLogIndex\x12\"\n\rprev_log_term\x18\x04\x20\x01(\x04R\
+    \x0bprevLogTerm\x126\n\x07entries\x18\x05\x20\x03(\x0b2\x1c.coerce.netwo\
+    rk.RaftLogEntryR\x07entries\x12#\n\rleader_commit\x18\x06\x20\x01(\x04R\
+    \x0cleaderCommit\"j\n\x19RaftAppendEntriesResponse\x12\x12\n\x04term\x18\
+    \x01\x20\x01(\x04R\x04term\x12\x18\n\x07success\x18\x02\x20\x01(\x08R\
+    \x07success\x12\x1f\n\x0bmatch_index\x18\x03\x20\x01(\x04R\nmatchIndex\"\
+    \x88\x02\n\x1aRaftInstallSnapshotRequest\x12\x12\n\x04term\x18\x01\x20\
+    \x01(\x04R\x04term\x12\x1b\n\tleader_id\x18\x02\x20\x01(\x04R\x08leaderI\
+    d\x12.\n\x13last_included_index\x18\x03\x20\x01(\x04R\x11lastIncludedInd\
+    ex\x12,\n\x12last_included_term\x18\x04\x20\x01(\x04R\x10lastIncludedTer\
+    m\x12\x12\n\x04data\x18\x05\x20\x01(\x0cR\x04data\x12G\n\rconfiguration\
+    \x18\x06\x20\x01(\x0b2!.coerce.network.RaftConfigurationR\rconfiguration\
+    \"K\n\x1bRaftInstallSnapshotResponse\x12\x12\n\x04term\x18\x01\x20\x01(\
+    \x04R\x04term\x12\x18\n\x07success\x18\x02\x20\x01(\x08R\x07success\"U\n\
+    \x13RaftMetadataCommand\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\
+    \x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\x12\x16\n\x06delete\x18\
+    \x03\x20\x01(\x08R\x06delete\"\x9f\x01\n\x14RaftMetadataSnapshot\x12K\n\
+    \x07entries\x18\x01\x20\x03(\x0b21.coerce.network.RaftMetadataSnapshot.E\
+    ntriesEntryR\x07entries\x1a:\n\x0cEntriesEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value:\
+    \x028\x01\"\xba\x01\n\x10RaftJournalEntry\x12\x12\n\x04term\x18\x01\x20\
+    \x01(\x04R\x04term\x125\n\tvoted_for\x18\x02\x20\x01(\x0b2\x18.coerce.ne\
+    twork.RaftVoteR\x08votedFor\x12#\n\rtruncate_from\x18\x03\x20\x01(\x04R\
+    \x0ctruncateFrom\x126\n\x07entries\x18\x04\x20\x03(\x0b2\x1c.coerce.netw\
+    ork.RaftLogEntryR\x07entries\"\xe3\x02\n\x13RaftJournalSnapshot\x12\x12\
+    \n\x04term\x18\x01\x20\x01(\x04R\x04term\x125\n\tvoted_for\x18\x02\x20\
+    \x01(\x0b2\x18.coerce.network.RaftVoteR\x08votedFor\x12%\n\x0esnapshot_i\
+    ndex\x18\x03\x20\x01(\x04R\rsnapshotIndex\x12#\n\rsnapshot_term\x18\x04\
+    \x20\x01(\x04R\x0csnapshotTerm\x12#\n\rsnapshot_data\x18\x05\x20\x01(\
+    \x0cR\x0csnapshotData\x12X\n\x16snapshot_configuration\x18\x06\x20\x01(\
+    \x0b2!.coerce.network.RaftConfigurationR\x15snapshotConfiguration\x126\n\
+    \x07entries\x18\x07\x20\x03(\x0b2\x1c.coerce.network.RaftLogEntryR\x07en\
+    tries\"k\n\rGossipRequest\x12\x1d\n\nmessage_id\x18\x01\x20\x01(\tR\tmes\
+    sageId\x12!\n\x0crequest_type\x18\x02\x20\x01(\rR\x0brequestType\x12\x18\
+    \n\x07payload\x18\x03\x20\x01(\x0cR\x07payload\"\x9c\x01\n\x0cGossipMemb\
+    er\x12.\n\x04node\x18\x01\x20\x01(\x0b2\x1a.coerce.network.RemoteNodeR\
+    \x04node\x12\x20\n\x0bincarnation\x18\x02\x20\x01(\x04R\x0bincarnation\
+    \x12:\n\x06status\x18\x03\x20\x01(\x0e2\".coerce.network.GossipMemberSta\
+    tusR\x06status\"\x8c\x01\n\nGossipPing\x12\x20\n\x0cfrom_node_id\x18\x01\
+    \x20\x01(\x04R\nfromNodeId\x12$\n\x0etarget_node_id\x18\x02\x20\x01(\x04\
+    R\x0ctargetNodeId\x126\n\x07updates\x18\x03\x20\x03(\x0b2\x1c.coerce.net\
+    work.GossipMemberR\x07updates\"U\n\tGossipAck\x12\x10\n\x03ack\x18\x01\
+    \x20\x01(\x08R\x03ack\x126\n\x07updates\x18\x02\x20\x03(\x0b2\x1c.coerce\
+    .network.GossipMemberR\x07updates\"\x8a\x01\n\x11GossipDigestEntry\x12\
+    \x17\n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\x12\x20\n\x0bincarnati\
+    on\x18\x02\x20\x01(\x04R\x0bincarnation\x12:\n\x06status\x18\x03\x20\x01\
+    (\x0e2\".coerce.network.GossipMemberStatusR\x06status\"i\n\nGossipSync\
+    \x12\x20\n\x0cfrom_node_id\x18\x01\x20\x01(\x04R\nfromNodeId\x129\n\x06d\
+    igest\x18\x02\x20\x03(\x0b2!.coerce.network.GossipDigestEntryR\x06digest\
+    \"e\n\rGossipSyncAck\x126\n\x07members\x18\x01\x20\x03(\x0b2\x1c.coerce.\
+    network.GossipMemberR\x07members\x12\x1c\n\trequested\x18\x02\x20\x03(\
+    \x04R\trequested\"D\n\nGossipPush\x126\n\x07members\x18\x01\x20\x03(\x0b\
+    2\x1c.coerce.network.GossipMemberR\x07members\"\xee\x04\n\x0bActorRefErr\
+    \x129\n\x04type\x18\x01\x20\x01(\x0e2%.coerce.network.ActorRefErr.ErrorT\
+    ypeR\x04type\x12\x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12!\n\
+    \x0cmessage_type\x18\x03\x20\x01(\tR\x0bmessageType\x12\x1d\n\nactor_typ\
+    e\x18\x04\x20\x01(\tR\tactorType\x12*\n\x11time_taken_millis\x18\x05\x20\
     \x01(\x04R\x0ftimeTakenMillis\x12O\n\x13serialization_error\x18\x06\x20\
     \x01(\x0e2\x1e.coerce.network.MessageWrapErrR\x12serializationError\x12U\
     \n\x15deserialization_error\x18\x07\x20\x01(\x0e2\x20.coerce.network.Mes\
@@ -4091,19 +9604,30 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     isation\x10\x04\x12\x0b\n\x07Timeout\x10\x05\x12\x14\n\x10ActorStartFail\
     ed\x10\x06\x12\x0e\n\nInvalidRef\x10\x07\x12\x17\n\x13ResultChannelClose\
     d\x10\x08\x12\x14\n\x10ResultSendFailed\x10\t\x12\x10\n\x0cNotSupported\
-    \x10\n\x12\x12\n\x0eNotImplemented\x10\x0b*\xbc\x01\n\x05Event\x12\x0c\n\
+    \x10\n\x12\x12\n\x0eNotImplemented\x10\x0b*\xba\x02\n\x05Event\x12\x0c\n\
     \x08Identify\x10\0\x12\r\n\tHandshake\x10\x01\x12\n\n\x06Result\x10\x02\
     \x12\x07\n\x03Err\x10\x03\x12\x08\n\x04Ping\x10\x04\x12\x08\n\x04Pong\
     \x10\x05\x12\x0f\n\x0bCreateActor\x10\x06\x12\r\n\tFindActor\x10\x07\x12\
     \x11\n\rRegisterActor\x10\x08\x12\x0f\n\x0bNotifyActor\x10\t\x12\x11\n\r\
     StreamPublish\x10\n\x12\x08\n\x04Raft\x10\x0b\x12\x0c\n\x08Identity\x10\
-    \x0c*$\n\nClientType\x12\n\n\x06Client\x10\0\x12\n\n\x06Worker\x10\x01*S\
-    \n\x0bSystemEvent\x12\x12\n\x0eClusterNewNode\x10\0\x12\x16\n\x12Cluster\
-    NodeRemoved\x10\x01\x12\x18\n\x14ClusterLeaderChanged\x10\x02*W\n\x10Mes\
-    sageUnwrapErr\x12\x14\n\x10UnknownUnwrapErr\x10\0\x12\x15\n\x11UnwrapUns\
-    upported\x10\x01\x12\x16\n\x12DeserializationErr\x10\x02*O\n\x0eMessageW\
-    rapErr\x12\x12\n\x0eUnknownWrapErr\x10\0\x12\x13\n\x0fWrapUnsupported\
-    \x10\x01\x12\x14\n\x10SerializationErr\x10\x02b\x06proto3\
+    \x0c\x12\t\n\x05Batch\x10\r\x12\x0e\n\nCompressed\x10\x0e\x12\x0e\n\nWat\
+    chActor\x10\x0f\x12\x13\n\x0fActorTerminated\x10\x10\x12\x10\n\x0cGetNod\
+    eStats\x10\x11\x12\x13\n\x0fGetClusterNodes\x10\x12\x12\n\n\x06Gossip\
+    \x10\x13\x12\t\n\x05Leave\x10\x14*$\n\nClientType\x12\n\n\x06Client\x10\
+    \0\x12\n\n\x06Worker\x10\x01*S\n\x11TerminationReason\x12\x0b\n\x07Stopp\
+    ed\x10\0\x12\x0c\n\x08NotFound\x10\x01\x12\x12\n\x0eNodeTerminated\x10\
+    \x02\x12\x0f\n\x0bUnreachable\x10\x03*0\n\x0fCompressionType\x12\x10\n\
+    \x0cUncompressed\x10\0\x12\x0b\n\x07Deflate\x10\x01*\xa6\x01\n\x0bSystem\
+    Event\x12\x12\n\x0eClusterNewNode\x10\0\x12\x16\n\x12ClusterNodeRemoved\
+    \x10\x01\x12\x18\n\x14ClusterLeaderChanged\x10\x02\x12\x15\n\x11ClusterN\
+    odeDowned\x10\x03\x12\x16\n\x12ClusterNodeLeaving\x10\x04\x12\"\n\x1eClu\
+    sterDatacenterLeaderChanged\x10\x05*H\n\x12GossipMemberStatus\x12\x0f\n\
+    \x0bGossipAlive\x10\0\x12\x11\n\rGossipSuspect\x10\x01\x12\x0e\n\nGossip\
+    Dead\x10\x02*W\n\x10MessageUnwrapErr\x12\x14\n\x10UnknownUnwrapErr\x10\0\
+    \x12\x15\n\x11UnwrapUnsupported\x10\x01\x12\x16\n\x12DeserializationErr\
+    \x10\x02*O\n\x0eMessageWrapErr\x12\x12\n\x0eUnknownWrapErr\x10\0\x12\x13\
+    \n\x0fWrapUnsupported\x10\x01\x12\x14\n\x10SerializationErr\x10\x02b\x06\
+    proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -4123,9 +9647,10 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             let mut deps = ::std::vec::Vec::with_capacity(2);
             deps.push(::protobuf::well_known_types::wrappers::file_descriptor().clone());
             deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(20);
+            let mut messages = ::std::vec::Vec::with_capacity(53);
             messages.push(RemoteNode::generated_message_descriptor_data());
             messages.push(IdentifyEvent::generated_message_descriptor_data());
+            messages.push(ProtocolSupport::generated_message_descriptor_data());
             messages.push(NodeIdentity::generated_message_descriptor_data());
             messages.push(SystemCapabilities::generated_message_descriptor_data());
             messages.push(ClientHandshake::generated_message_descriptor_data());
@@ -4133,6 +9658,15 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(ClientErr::generated_message_descriptor_data());
             messages.push(PingEvent::generated_message_descriptor_data());
             messages.push(PongEvent::generated_message_descriptor_data());
+            messages.push(BatchEvent::generated_message_descriptor_data());
+            messages.push(WatchActorEvent::generated_message_descriptor_data());
+            messages.push(ActorTerminatedEvent::generated_message_descriptor_data());
+            messages.push(GetNodeStatsEvent::generated_message_descriptor_data());
+            messages.push(NodeStats::generated_message_descriptor_data());
+            messages.push(GetClusterNodesEvent::generated_message_descriptor_data());
+            messages.push(LeaveEvent::generated_message_descriptor_data());
+            messages.push(ClusterNodes::generated_message_descriptor_data());
+            messages.push(CompressedEvent::generated_message_descriptor_data());
             messages.push(CreateActorEvent::generated_message_descriptor_data());
             messages.push(FindActorEvent::generated_message_descriptor_data());
             messages.push(ActorAddress::generated_message_descriptor_data());
@@ -4142,12 +9676,38 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(NewNodeEvent::generated_message_descriptor_data());
             messages.push(NodeRemovedEvent::generated_message_descriptor_data());
             messages.push(LeaderChangedEvent::generated_message_descriptor_data());
+            messages.push(NodeDownedEvent::generated_message_descriptor_data());
+            messages.push(NodeLeavingEvent::generated_message_descriptor_data());
             messages.push(RaftRequest::generated_message_descriptor_data());
+            messages.push(RaftLogEntry::generated_message_descriptor_data());
+            messages.push(RaftConfiguration::generated_message_descriptor_data());
+            messages.push(RaftVote::generated_message_descriptor_data());
+            messages.push(RaftVoteRequest::generated_message_descriptor_data());
+            messages.push(RaftVoteResponse::generated_message_descriptor_data());
+            messages.push(RaftAppendEntriesRequest::generated_message_descriptor_data());
+            messages.push(RaftAppendEntriesResponse::generated_message_descriptor_data());
+            messages.push(RaftInstallSnapshotRequest::generated_message_descriptor_data());
+            messages.push(RaftInstallSnapshotResponse::generated_message_descriptor_data());
+            messages.push(RaftMetadataCommand::generated_message_descriptor_data());
+            messages.push(RaftMetadataSnapshot::generated_message_descriptor_data());
+            messages.push(RaftJournalEntry::generated_message_descriptor_data());
+            messages.push(RaftJournalSnapshot::generated_message_descriptor_data());
+            messages.push(GossipRequest::generated_message_descriptor_data());
+            messages.push(GossipMember::generated_message_descriptor_data());
+            messages.push(GossipPing::generated_message_descriptor_data());
+            messages.push(GossipAck::generated_message_descriptor_data());
+            messages.push(GossipDigestEntry::generated_message_descriptor_data());
+            messages.push(GossipSync::generated_message_descriptor_data());
+            messages.push(GossipSyncAck::generated_message_descriptor_data());
+            messages.push(GossipPush::generated_message_descriptor_data());
             messages.push(ActorRefErr::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(6);
+            let mut enums = ::std::vec::Vec::with_capacity(9);
             enums.push(Event::generated_enum_descriptor_data());
             enums.push(ClientType::generated_enum_descriptor_data());
+            enums.push(TerminationReason::generated_enum_descriptor_data());
+            enums.push(CompressionType::generated_enum_descriptor_data());
             enums.push(SystemEvent::generated_enum_descriptor_data());
+            enums.push(GossipMemberStatus::generated_enum_descriptor_data());
             enums.push(MessageUnwrapErr::generated_enum_descriptor_data());
             enums.push(MessageWrapErr::generated_enum_descriptor_data());
             enums.push(actor_ref_err::ErrorType::generated_enum_descriptor_data());
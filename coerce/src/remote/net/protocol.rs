@@ -0,0 +1,198 @@
+//! Protocol version and feature negotiation between nodes.
+//!
+//! Every node advertises the range of protocol versions it can speak, along with the optional
+//! protocol features it supports, when a connection is established. Both sides of the connection
+//! independently pick the highest version supported by both nodes, and the features supported
+//! by both nodes. When no common version exists, or a peer doesn't support a feature the local
+//! node requires, the connection is rejected.
+//!
+//! Pinning `max_version` to the version spoken by the rest of the cluster allows nodes running
+//! a newer release to be rolled out safely, only raising it once every node has been upgraded.
+
+use crate::remote::net::compression::CompressionCodec;
+use crate::remote::net::proto::network as proto;
+use std::fmt::{Display, Formatter};
+
+/// The newest protocol version spoken by this release.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The oldest protocol version this release can still speak.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// Multiple session events can be coalesced into a single [`SessionEvent::Batch`] frame.
+///
+/// [`SessionEvent::Batch`]: crate::remote::net::message::SessionEvent::Batch
+pub const FEATURE_BATCHING: &str = "batching";
+
+/// Protocol version assumed for nodes that don't advertise any protocol support, which
+/// includes any release prior to protocol negotiation being introduced.
+pub const LEGACY_PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProtocolConfig {
+    pub min_version: u32,
+    pub max_version: u32,
+
+    /// Optional protocol features supported by this node.
+    pub features: Vec<String>,
+
+    /// Features every peer must support, peers missing any of these are rejected.
+    pub required_features: Vec<String>,
+}
+
+/// The protocol support advertised by a peer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PeerProtocol {
+    pub min_version: u32,
+    pub max_version: u32,
+    pub features: Vec<String>,
+    pub required_features: Vec<String>,
+}
+
+/// The protocol version and features agreed between two nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegotiatedProtocol {
+    pub version: u32,
+    pub features: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtocolErr {
+    IncompatibleVersion {
+        local_min_version: u32,
+        local_max_version: u32,
+        peer_min_version: u32,
+        peer_max_version: u32,
+    },
+    MissingFeatures(Vec<String>),
+    UnsupportedFeatures(Vec<String>),
+}
+
+impl ProtocolConfig {
+    pub fn negotiate(&self, peer: &PeerProtocol) -> Result<NegotiatedProtocol, ProtocolErr> {
+        let version = self.max_version.min(peer.max_version);
+        if version < self.min_version || version < peer.min_version {
+            return Err(ProtocolErr::IncompatibleVersion {
+                local_min_version: self.min_version,
+                local_max_version: self.max_version,
+                peer_min_version: peer.min_version,
+                peer_max_version: peer.max_version,
+            });
+        }
+
+        let missing_features = missing(&self.required_features, &peer.features);
+        if !missing_features.is_empty() {
+            return Err(ProtocolErr::MissingFeatures(missing_features));
+        }
+
+        let unsupported_features = missing(&peer.required_features, &self.features);
+        if !unsupported_features.is_empty() {
+            return Err(ProtocolErr::UnsupportedFeatures(unsupported_features));
+        }
+
+        let mut features: Vec<String> = self
+            .features
+            .iter()
+            .filter(|f| peer.features.contains(f))
+            .cloned()
+            .collect();
+
+        features.sort();
+        features.dedup();
+
+        Ok(NegotiatedProtocol { version, features })
+    }
+
+    pub fn supports_version(&self, version: u32) -> bool {
+        version >= self.min_version && version <= self.max_version
+    }
+}
+
+impl NegotiatedProtocol {
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+}
+
+/// Every entry of `features` that is not contained in `supported`.
+fn missing(features: &[String], supported: &[String]) -> Vec<String> {
+    features
+        .iter()
+        .filter(|f| !supported.contains(f))
+        .cloned()
+        .collect()
+}
+
+impl Default for ProtocolConfig {
+    fn default() -> Self {
+        Self {
+            min_version: MIN_PROTOCOL_VERSION,
+            max_version: PROTOCOL_VERSION,
+            features: std::iter::once(FEATURE_BATCHING)
+                .chain(CompressionCodec::supported().iter().map(|c| c.feature()))
+                .map(|feature| feature.to_string())
+                .collect(),
+            required_features: vec![],
+        }
+    }
+}
+
+impl From<&ProtocolConfig> for proto::ProtocolSupport {
+    fn from(config: &ProtocolConfig) -> Self {
+        proto::ProtocolSupport {
+            min_version: config.min_version,
+            max_version: config.max_version,
+            features: config.features.clone(),
+            required_features: config.required_features.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Option<proto::ProtocolSupport>> for PeerProtocol {
+    fn from(protocol: Option<proto::ProtocolSupport>) -> Self {
+        match protocol {
+            Some(protocol) if protocol.max_version > 0 => PeerProtocol {
+                min_version: protocol.min_version,
+                max_version: protocol.max_version,
+                features: protocol.features,
+                required_features: protocol.required_features,
+            },
+            _ => PeerProtocol {
+                min_version: LEGACY_PROTOCOL_VERSION,
+                max_version: LEGACY_PROTOCOL_VERSION,
+                features: vec![],
+                required_features: vec![],
+            },
+        }
+    }
+}
+
+impl Display for ProtocolErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            ProtocolErr::IncompatibleVersion {
+                local_min_version,
+                local_max_version,
+                peer_min_version,
+                peer_max_version,
+            } => write!(
+                f,
+                "incompatible protocol version, local node supports {}..={}, peer supports {}..={}",
+                local_min_version, local_max_version, peer_min_version, peer_max_version
+            ),
+            ProtocolErr::MissingFeatures(features) => write!(
+                f,
+                "peer does not support required protocol features: {}",
+                features.join(", ")
+            ),
+            ProtocolErr::UnsupportedFeatures(features) => write!(
+                f,
+                "peer requires protocol features not supported by the local node: {}",
+                features.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProtocolErr {}
@@ -1,3 +1,6 @@
 pub mod auth;
 
+#[cfg(feature = "remote-tls")]
+pub mod tls;
+
 pub use auth::*;
@@ -0,0 +1,275 @@
+//! TLS transport security for node-to-node connections.
+//!
+//! When a [`TlsConfig`] is provided, every outbound [`RemoteClient`] connection is wrapped in TLS,
+//! verifying the server certificate against the configured CA certificates. [`RemoteServer`] accepts
+//! TLS connections using the node's certificate, and when mutual TLS is enabled, requires every
+//! inbound connection to present a client certificate signed by a trusted CA.
+//!
+//! With mutual TLS, node identity is bound to the certificate: the client certificate must be valid
+//! for a DNS name matching the node tag sent in the `Identify` event, otherwise the session is
+//! rejected.
+//!
+//! TLS is applied by the default [`TcpTransport`], custom transports are responsible for
+//! securing their own connections.
+//!
+//! [`TcpTransport`]: crate::remote::net::transport::TcpTransport
+//! [`RemoteClient`]: crate::remote::net::client::RemoteClient
+//! [`RemoteServer`]: crate::remote::net::server::RemoteServer
+
+use crate::remote::net::transport::Connection;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::{
+    Certificate, ClientConfig, PrivateKey, RootCertStore, ServerConfig, ServerName,
+};
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+
+#[derive(Clone)]
+pub struct TlsConfig {
+    client_config: Arc<ClientConfig>,
+    server_config: Arc<ServerConfig>,
+    server_name: Option<String>,
+    mutual_tls: bool,
+}
+
+#[derive(Default)]
+pub struct TlsConfigBuilder {
+    cert_chain: Option<Vec<u8>>,
+    private_key: Option<Vec<u8>>,
+    ca_certs: Option<Vec<u8>>,
+    server_name: Option<String>,
+    mutual_tls: bool,
+}
+
+#[derive(Debug)]
+pub enum TlsErr {
+    Io(std::io::Error),
+    MissingCertificate,
+    MissingPrivateKey,
+    MissingCaCertificates,
+    InvalidCertificate(String),
+    InvalidServerName(String),
+    Rustls(tokio_rustls::rustls::Error),
+}
+
+impl TlsConfig {
+    pub fn builder() -> TlsConfigBuilder {
+        TlsConfigBuilder::default()
+    }
+
+    pub fn is_mutual_tls(&self) -> bool {
+        self.mutual_tls
+    }
+
+    pub fn acceptor(&self) -> TlsAcceptor {
+        TlsAcceptor::from(self.server_config.clone())
+    }
+
+    pub fn connector(&self) -> TlsConnector {
+        TlsConnector::from(self.client_config.clone())
+    }
+
+    /// The name used to verify the certificate presented by the server at `addr`,
+    /// defaulting to the host part of the address unless explicitly configured.
+    pub fn server_name(&self, addr: &str) -> Result<ServerName, TlsErr> {
+        let name = match &self.server_name {
+            Some(server_name) => server_name.as_str(),
+            None => addr
+                .rsplit_once(':')
+                .map_or(addr, |(host, _port)| host)
+                .trim_start_matches('[')
+                .trim_end_matches(']'),
+        };
+
+        ServerName::try_from(name).map_err(|_| TlsErr::InvalidServerName(name.to_string()))
+    }
+
+    pub async fn connect(&self, addr: &str, stream: TcpStream) -> Result<Connection, TlsErr> {
+        let server_name = self.server_name(addr)?;
+        let stream = self
+            .connector()
+            .connect(server_name, stream)
+            .await
+            .map_err(TlsErr::Io)?;
+
+        let peer_certificate = first_certificate(stream.get_ref().1.peer_certificates());
+        Ok(Connection::new(stream, addr).with_peer_certificate(peer_certificate))
+    }
+
+    pub async fn accept(&self, stream: TcpStream, peer_addr: &str) -> Result<Connection, TlsErr> {
+        let stream = self.acceptor().accept(stream).await.map_err(TlsErr::Io)?;
+
+        let peer_certificate = first_certificate(stream.get_ref().1.peer_certificates());
+        Ok(Connection::new(stream, peer_addr).with_peer_certificate(peer_certificate))
+    }
+
+    /// Checks whether the DER-encoded `certificate` is valid for `node_tag`.
+    pub fn verify_node_identity(&self, certificate: &[u8], node_tag: &str) -> bool {
+        let dns_name = match webpki::DnsNameRef::try_from_ascii_str(node_tag) {
+            Ok(dns_name) => dns_name,
+            Err(_) => return false,
+        };
+
+        match webpki::EndEntityCert::try_from(certificate) {
+            Ok(cert) => cert.verify_is_valid_for_dns_name(dns_name).is_ok(),
+            Err(_) => false,
+        }
+    }
+}
+
+impl TlsConfigBuilder {
+    /// PEM-encoded certificate chain presented by this node, both as a server and as a client.
+    pub fn certificate_pem(mut self, cert_chain: impl Into<Vec<u8>>) -> Self {
+        self.cert_chain = Some(cert_chain.into());
+        self
+    }
+
+    /// PEM-encoded private key (PKCS#8, RSA or SEC1) of the node certificate.
+    pub fn private_key_pem(mut self, private_key: impl Into<Vec<u8>>) -> Self {
+        self.private_key = Some(private_key.into());
+        self
+    }
+
+    /// PEM-encoded CA certificates used to verify the certificates of other nodes.
+    pub fn ca_pem(mut self, ca_certs: impl Into<Vec<u8>>) -> Self {
+        self.ca_certs = Some(ca_certs.into());
+        self
+    }
+
+    pub fn certificate_file(self, path: impl AsRef<Path>) -> Result<Self, TlsErr> {
+        let cert_chain = std::fs::read(path).map_err(TlsErr::Io)?;
+        Ok(self.certificate_pem(cert_chain))
+    }
+
+    pub fn private_key_file(self, path: impl AsRef<Path>) -> Result<Self, TlsErr> {
+        let private_key = std::fs::read(path).map_err(TlsErr::Io)?;
+        Ok(self.private_key_pem(private_key))
+    }
+
+    pub fn ca_file(self, path: impl AsRef<Path>) -> Result<Self, TlsErr> {
+        let ca_certs = std::fs::read(path).map_err(TlsErr::Io)?;
+        Ok(self.ca_pem(ca_certs))
+    }
+
+    /// Overrides the name used to verify server certificates, by default the host
+    /// of the address being connected to is used.
+    pub fn server_name(mut self, server_name: impl ToString) -> Self {
+        self.server_name = Some(server_name.to_string());
+        self
+    }
+
+    /// Requires inbound connections to present a client certificate signed by a trusted CA,
+    /// valid for the node tag of the connecting node.
+    pub fn mutual_tls(mut self, mutual_tls: bool) -> Self {
+        self.mutual_tls = mutual_tls;
+        self
+    }
+
+    pub fn build(self) -> Result<TlsConfig, TlsErr> {
+        let cert_chain = read_certificates(
+            self.cert_chain
+                .as_deref()
+                .ok_or(TlsErr::MissingCertificate)?,
+        )?;
+
+        let private_key = read_private_key(
+            self.private_key
+                .as_deref()
+                .ok_or(TlsErr::MissingPrivateKey)?,
+        )?;
+
+        let mut roots = RootCertStore::empty();
+        let ca_certs = read_certificates(
+            self.ca_certs
+                .as_deref()
+                .ok_or(TlsErr::MissingCaCertificates)?,
+        )?;
+
+        for ca_cert in &ca_certs {
+            roots
+                .add(ca_cert)
+                .map_err(|e| TlsErr::InvalidCertificate(format!("{:?}", e)))?;
+        }
+
+        let client_config = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots.clone())
+            .with_single_cert(cert_chain.clone(), private_key.clone())
+            .map_err(TlsErr::Rustls)?;
+
+        let server_config = ServerConfig::builder().with_safe_defaults();
+        let server_config = if self.mutual_tls {
+            server_config.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots))
+        } else {
+            server_config.with_no_client_auth()
+        };
+
+        let server_config = server_config
+            .with_single_cert(cert_chain, private_key)
+            .map_err(TlsErr::Rustls)?;
+
+        Ok(TlsConfig {
+            client_config: Arc::new(client_config),
+            server_config: Arc::new(server_config),
+            server_name: self.server_name,
+            mutual_tls: self.mutual_tls,
+        })
+    }
+}
+
+fn read_certificates(pem: &[u8]) -> Result<Vec<Certificate>, TlsErr> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(pem)).map_err(TlsErr::Io)?;
+    if certs.is_empty() {
+        Err(TlsErr::MissingCertificate)
+    } else {
+        Ok(certs.into_iter().map(Certificate).collect())
+    }
+}
+
+fn first_certificate(certificates: Option<&[Certificate]>) -> Option<Vec<u8>> {
+    certificates
+        .and_then(|certs| certs.first())
+        .map(|cert| cert.0.clone())
+}
+
+fn read_private_key(pem: &[u8]) -> Result<PrivateKey, TlsErr> {
+    let mut reader = BufReader::new(pem);
+    loop {
+        match rustls_pemfile::read_one(&mut reader).map_err(TlsErr::Io)? {
+            Some(rustls_pemfile::Item::PKCS8Key(key))
+            | Some(rustls_pemfile::Item::RSAKey(key))
+            | Some(rustls_pemfile::Item::ECKey(key)) => return Ok(PrivateKey(key)),
+            Some(_) => continue,
+            None => return Err(TlsErr::MissingPrivateKey),
+        }
+    }
+}
+
+impl Debug for TlsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlsConfig")
+            .field("server_name", &self.server_name)
+            .field("mutual_tls", &self.mutual_tls)
+            .finish()
+    }
+}
+
+impl Display for TlsErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            TlsErr::Io(e) => write!(f, "io error: {}", e),
+            TlsErr::MissingCertificate => write!(f, "no certificate provided"),
+            TlsErr::MissingPrivateKey => write!(f, "no private key provided"),
+            TlsErr::MissingCaCertificates => write!(f, "no CA certificates provided"),
+            TlsErr::InvalidCertificate(e) => write!(f, "invalid certificate: {}", e),
+            TlsErr::InvalidServerName(name) => write!(f, "invalid server name: {}", name),
+            TlsErr::Rustls(e) => write!(f, "tls error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TlsErr {}
@@ -1,11 +1,17 @@
 use crate::actor::{IntoActor, LocalActorRef};
 use crate::remote::net::server::session::store::{NewSession, RemoteSessionStore};
 use crate::remote::net::server::session::RemoteSession;
+use crate::remote::net::transport::{Connection, TransportListener};
 use crate::remote::system::RemoteActorSystem;
-use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
+#[cfg(feature = "remote-tls")]
+use crate::remote::net::security::tls::TlsConfig;
+
+#[cfg(feature = "remote-tls")]
+use crate::remote::net::transport::{TcpTransport, Transport};
+
 pub mod session;
 
 pub struct RemoteServer {
@@ -32,6 +38,11 @@ pub struct RemoteServerConfig {
     /// used by the inbound client, rather than the address provided by
     /// the node via the handshake.
     pub override_incoming_node_addr: bool,
+
+    /// When set, inbound connections must complete a TLS handshake before a session is created.
+    /// Defaults to the TLS configuration of the [`RemoteActorSystem`], if any.
+    #[cfg(feature = "remote-tls")]
+    pub tls: Option<TlsConfig>,
 }
 
 impl RemoteServerConfig {
@@ -44,8 +55,16 @@ impl RemoteServerConfig {
             listen_addr,
             external_node_addr,
             override_incoming_node_addr,
+            #[cfg(feature = "remote-tls")]
+            tls: None,
         }
     }
+
+    #[cfg(feature = "remote-tls")]
+    pub fn with_tls(mut self, tls: Option<TlsConfig>) -> Self {
+        self.tls = tls;
+        self
+    }
 }
 
 impl RemoteServer {
@@ -60,13 +79,30 @@ impl RemoteServer {
         config: RemoteServerConfig,
         system: RemoteActorSystem,
     ) -> Result<(), tokio::io::Error> {
+        let transport = system.config().transport().clone();
+
+        // an explicitly configured TLS config overrides the TLS config of the system transport
+        #[cfg(feature = "remote-tls")]
+        let (config, transport) = match config.tls.clone() {
+            Some(tls) => {
+                let transport: Arc<dyn Transport> =
+                    Arc::new(TcpTransport::new().with_tls(Some(tls)));
+
+                (config, transport)
+            }
+            None => {
+                let tls = system.config().security().tls().cloned();
+                (config.with_tls(tls), transport)
+            }
+        };
+
         debug!(
             "starting remote server (node_id={}), config: {:#?}",
             system.node_id(),
             &config
         );
 
-        let listener = tokio::net::TcpListener::bind(&config.listen_addr).await?;
+        let listener = transport.listen(&config.listen_addr).await?;
 
         let session_store = RemoteSessionStore::new()
             .into_actor(Some("remote-session-store"), &system.actor_system())
@@ -79,6 +115,7 @@ impl RemoteServer {
             session_store,
             self.cancellation_token.clone(),
             remote_server_config,
+            system.config().network_config().max_frame_length,
         ));
 
         Ok(())
@@ -94,9 +131,9 @@ pub async fn cancellation(cancellation_token: CancellationToken) {
 }
 
 pub async fn accept(
-    listener: &tokio::net::TcpListener,
+    listener: &mut Box<dyn TransportListener>,
     cancellation_token: CancellationToken,
-) -> Option<tokio::io::Result<(tokio::net::TcpStream, SocketAddr)>> {
+) -> Option<tokio::io::Result<Connection>> {
     tokio::select! {
         _ = cancellation(cancellation_token) => {
             None
@@ -109,39 +146,47 @@ pub async fn accept(
 }
 
 pub async fn server_loop(
-    listener: tokio::net::TcpListener,
+    mut listener: Box<dyn TransportListener>,
     session_store: LocalActorRef<RemoteSessionStore>,
     cancellation_token: CancellationToken,
     remote_server_config: RemoteServerConfigRef,
+    max_frame_length: usize,
 ) {
     loop {
-        match accept(&listener, cancellation_token.clone()).await {
-            Some(Ok((stream, addr))) => {
-                let remote_server_config = remote_server_config.clone();
-
-                let session_id = uuid::Uuid::new_v4();
-                trace!("client accepted {}, session_id={}", addr, session_id);
-
-                let session = session_store
-                    .send(NewSession(RemoteSession::new(
-                        session_id,
-                        addr,
-                        stream,
-                        remote_server_config,
-                    )))
-                    .await;
-
-                if let Err(e) = session {
-                    error!(
-                        "error creating session actor (session_id={}, addr={}), error: {:?}",
-                        session_id, addr, e
-                    );
-                }
+        match accept(&mut listener, cancellation_token.clone()).await {
+            Some(Ok(mut connection)) => {
+                connection.set_max_frame_length(max_frame_length);
+                new_session(connection, &session_store, remote_server_config.clone()).await;
             }
             Some(Err(e)) => error!("error accepting client: {:?}", e),
             None => break,
         }
     }
 
-    info!("tcp listener {:?} stopped", &listener)
+    info!("listener (addr={}) stopped", listener.local_addr())
+}
+
+async fn new_session(
+    connection: Connection,
+    session_store: &LocalActorRef<RemoteSessionStore>,
+    remote_server_config: RemoteServerConfigRef,
+) {
+    let session_id = uuid::Uuid::new_v4();
+    let addr = connection.peer_addr.clone();
+    trace!("client accepted {}, session_id={}", &addr, session_id);
+
+    let session = session_store
+        .send(NewSession(RemoteSession::new(
+            session_id,
+            connection,
+            remote_server_config,
+        )))
+        .await;
+
+    if let Err(e) = session {
+        error!(
+            "error creating session actor (session_id={}, addr={}), error: {:?}",
+            session_id, addr, e
+        );
+    }
 }
@@ -1,24 +1,35 @@
 use crate::actor::context::{ActorContext, LogContext};
 use crate::actor::message::Handler;
-use crate::actor::{Actor, ActorId, IntoActorId, LocalActorRef};
-use crate::remote::actor::message::NodeTerminated;
+use crate::actor::watch::{ActorTerminated, TerminationReason, Watcher};
+use crate::actor::{Actor, ActorId, CoreActorRef, IntoActorId, LocalActorRef};
+use crate::remote::actor::message::{
+    ClientSessionClosed, ClientSessionConnected, NodeLeaving, NodeTerminated,
+};
 use crate::remote::actor::RemoteResponse;
 use crate::remote::cluster::discovery::{Discover, Seed};
-use crate::remote::cluster::node::{NodeAttributes, RemoteNode};
+use crate::remote::cluster::gossip::detector::GossipRpc;
+use crate::remote::cluster::node::{datacenter_from_proto, NodeAttributes, NodeRoles, RemoteNode};
+use crate::remote::net::client::ClientType;
+use crate::remote::net::compression::FrameCompression;
 use crate::remote::net::message::{
-    datetime_to_timestamp, timestamp_to_datetime, ClientEvent, SessionEvent,
+    datetime_to_timestamp, timestamp_to_datetime, ClientEvent, DecodeErr, SessionEvent,
 };
+use crate::remote::net::metrics::NetworkMetrics;
 use crate::remote::net::proto::network::{
-    ActorAddress, ClientHandshake, ClientResult, CreateActorEvent, MessageRequest, NodeIdentity,
-    PongEvent, RemoteNode as RemoteNodeProto, SessionHandshake, StreamPublishEvent,
-    SystemCapabilities,
+    ActorAddress, ClientHandshake, ClientResult, ClusterNodes, CreateActorEvent, Event,
+    GossipRequest, IdentifyEvent, LeaveEvent, MessageRequest, NodeIdentity, PongEvent, RaftRequest,
+    RemoteNode as RemoteNodeProto, SessionHandshake, StreamPublishEvent, SystemCapabilities,
+    WatchActorEvent,
 };
+use crate::remote::net::protocol::{NegotiatedProtocol, ProtocolConfig, LEGACY_PROTOCOL_VERSION};
 use crate::remote::net::server::session::store::{RemoteSessionStore, SessionClosed, SessionWrite};
 use crate::remote::net::server::RemoteServerConfigRef;
+use crate::remote::net::transport::{Connection, ConnectionReader, ConnectionWriter};
 use crate::remote::net::{receive_loop, StreamData, StreamReceiver};
+use crate::remote::raft::node::RaftRpc;
 use crate::remote::stream::mediator::PublishRaw;
 use crate::remote::system::{NodeId, RemoteActorSystem};
-use crate::CARGO_PKG_VERSION;
+use crate::remote::tracing::set_trace_parent;
 use futures::{SinkExt, StreamExt};
 use protobuf::well_known_types::wrappers::UInt64Value;
 use protobuf::{Message as ProtoMessage, MessageField};
@@ -28,11 +39,9 @@ use std::io::Error;
 use std::net::SocketAddr;
 use std::str::FromStr;
 use std::sync::Arc;
-use tokio::io::{ReadHalf, WriteHalf};
-use tokio::net::TcpStream;
 use tokio::sync::oneshot;
-use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 use uuid::Uuid;
 use valuable::Valuable;
 
@@ -40,31 +49,50 @@ pub mod store;
 
 pub struct RemoteSession {
     id: Uuid,
-    addr: SocketAddr,
-    write: FramedWrite<WriteHalf<TcpStream>, LengthDelimitedCodec>,
-    read: Option<FramedRead<ReadHalf<TcpStream>, LengthDelimitedCodec>>,
+    addr: String,
+    write: ConnectionWriter,
+    read: Option<ConnectionReader>,
     read_cancellation_token: Option<CancellationToken>,
     remote_server_config: RemoteServerConfigRef,
+    compression: Option<FrameCompression>,
+
+    #[cfg(feature = "remote-tls")]
+    peer_certificate: Option<Vec<u8>>,
 }
 
 impl RemoteSession {
     pub fn new(
         id: Uuid,
-        addr: SocketAddr,
-        stream: TcpStream,
+        connection: Connection,
         remote_server_config: RemoteServerConfigRef,
     ) -> RemoteSession {
-        let (read, write) = tokio::io::split(stream);
-        let read = Some(FramedRead::new(read, LengthDelimitedCodec::new()));
-        let write = FramedWrite::new(write, LengthDelimitedCodec::new());
         RemoteSession {
             id,
-            addr,
-            write,
-            read,
+            addr: connection.peer_addr,
+            write: connection.writer,
+            read: Some(connection.reader),
             read_cancellation_token: Some(CancellationToken::new()),
             remote_server_config,
+            compression: None,
+            #[cfg(feature = "remote-tls")]
+            peer_certificate: connection.peer_certificate,
+        }
+    }
+
+    /// When mutual TLS is enabled, ensures the client certificate presented during the TLS handshake
+    /// is valid for the node tag provided by the connecting node.
+    fn verify_peer_identity(&self, identify: &IdentifyEvent) -> bool {
+        #[cfg(feature = "remote-tls")]
+        if let Some(tls) = &self.remote_server_config.tls {
+            if tls.is_mutual_tls() {
+                return self.peer_certificate.as_ref().is_some_and(|certificate| {
+                    tls.verify_node_identity(certificate, &identify.source_node_tag)
+                });
+            }
         }
+
+        let _ = identify;
+        true
     }
 }
 
@@ -79,11 +107,34 @@ impl Actor for RemoteSession {
             "session started (addr={}, session_id={}), validating token", &self.addr, &self.id
         );
 
+        let protocol_config = &system.config().network_config().protocol;
+        let mut protocol = Err(None);
         if let Some(read) = &mut self.read {
-            if !validate_session_token(ctx, log, &system, read).await {
+            let identify = match validate_session_token(ctx, log, &system, read).await {
+                Some(identify) => identify,
+                None => {
+                    ctx.stop(None);
+                    return;
+                }
+            };
+
+            if !self.verify_peer_identity(&identify) {
+                error!(
+                    ctx = ctx.log().as_value(),
+                    "peer certificate is not valid for node (node_id={}, node_tag={}), disconnecting session({})",
+                    identify.source_node_id,
+                    &identify.source_node_tag,
+                    ctx.id()
+                );
+
                 ctx.stop(None);
                 return;
             }
+
+            let peer_protocol = identify.protocol.into_option().into();
+            protocol = protocol_config
+                .negotiate(&peer_protocol)
+                .map_err(|e| Some((e, identify.source_node_id, identify.application_version)));
         }
 
         let peers = system
@@ -103,7 +154,11 @@ impl Actor for RemoteSession {
         self.write(ClientEvent::Identity(NodeIdentity {
             node_id: system.node_id(),
             node_tag: system.node_tag().to_string(),
-            application_version: format!("pkg_version={},protocol_version=1", CARGO_PKG_VERSION),
+            application_version: system.config().node_version().to_string(),
+            protocol_version: protocol
+                .as_ref()
+                .map_or_else(|_| String::new(), |p| p.version.to_string()),
+            protocol: Some(protocol_config.into()).into(),
             addr: self.remote_server_config.external_node_addr.to_string(),
             node_started_at: Some(datetime_to_timestamp(system.started_at())).into(),
             peers: peers.into(),
@@ -114,18 +169,49 @@ impl Actor for RemoteSession {
                 .iter()
                 .map(|(k, v)| (k.to_string(), v.to_string()))
                 .collect(),
+            roles: system
+                .config()
+                .get_roles()
+                .iter()
+                .map(|r| r.to_string())
+                .collect(),
+            datacenter: system.datacenter().to_string(),
             ..Default::default()
         }))
         .await;
 
+        // the identity is still written to incompatible peers, so they're able to
+        // reject the connection with a meaningful error on their side too.
+        let protocol = match protocol {
+            Ok(protocol) => protocol,
+            Err(error) => {
+                if let Some((error, node_id, application_version)) = error {
+                    error!(
+                        ctx = ctx.log().as_value(),
+                        "incompatible peer (addr={}, node_id={}, application_version={}), disconnecting session({}), error: {}",
+                        &self.addr, node_id, &application_version, ctx.id(), error
+                    );
+                }
+
+                ctx.stop(None);
+                return;
+            }
+        };
+
+        self.compression =
+            FrameCompression::negotiate(&system.config().network_config().compression, &protocol);
+
         let _session = tokio::spawn(receive_loop(
             system.clone(),
             self.read.take().unwrap(),
             SessionMessageReceiver::new(
                 self.id,
                 self.actor_ref(ctx),
-                self.addr,
+                self.addr.clone(),
                 self.remote_server_config.clone(),
+                protocol,
+                protocol_config.clone(),
+                system.config().network_config().max_decode_errors,
             ),
         ));
     }
@@ -138,6 +224,12 @@ impl Actor for RemoteSession {
 
         let _ = self.write.close().await;
 
+        let _ = ctx
+            .system()
+            .remote()
+            .client_registry()
+            .notify(ClientSessionClosed(self.id));
+
         if let Some(session_store) = ctx.parent::<RemoteSessionStore>() {
             let _ = session_store.send(SessionClosed(self.id)).await;
         }
@@ -148,18 +240,18 @@ async fn validate_session_token(
     ctx: &mut ActorContext,
     log: LogContext,
     system: &RemoteActorSystem,
-    read: &mut FramedRead<ReadHalf<TcpStream>, LengthDelimitedCodec>,
-) -> bool {
+    read: &mut ConnectionReader,
+) -> Option<IdentifyEvent> {
     let bytes = read.next().await;
     if let Some(Ok(bytes)) = bytes {
         match SessionEvent::read_from_bytes(bytes.to_vec()) {
             Some(SessionEvent::Identify(identify)) => {
-                let token = identify.token;
+                let token = identify.token.as_str();
                 let token_valid = system
                     .config()
                     .security()
                     .client_authentication()
-                    .validate_token(token.as_str());
+                    .validate_token(token);
 
                 if !token_valid {
                     error!(
@@ -174,7 +266,7 @@ async fn validate_session_token(
                         "token ({}) validated - connection accepted", &token,
                     );
 
-                    return true;
+                    return Some(identify);
                 }
             }
 
@@ -193,7 +285,7 @@ async fn validate_session_token(
         );
     }
 
-    false
+    None
 }
 
 #[async_trait]
@@ -208,6 +300,11 @@ impl RemoteSession {
         match message.write_to_bytes() {
             Some(msg) => {
                 trace!("message encoded");
+                let msg = match self.compression.and_then(|c| c.compress(&msg)) {
+                    Some(compressed) => compressed,
+                    None => msg,
+                };
+
                 if self.write.send(Bytes::from(msg)).await.is_ok() {
                     trace!("message sent");
                 } else {
@@ -225,9 +322,13 @@ pub struct SessionMessageReceiver {
     session_id: Uuid,
     session: LocalActorRef<RemoteSession>,
     node_id: Option<NodeId>,
-    addr: SocketAddr,
+    addr: String,
     should_close: bool,
     server_config: RemoteServerConfigRef,
+    protocol: NegotiatedProtocol,
+    protocol_config: ProtocolConfig,
+    decode_errors: usize,
+    max_decode_errors: usize,
 }
 
 #[derive(Debug)]
@@ -240,16 +341,36 @@ impl SessionMessageReceiver {
     pub fn new(
         session_id: Uuid,
         session: LocalActorRef<RemoteSession>,
-        addr: SocketAddr,
+        addr: String,
         server_config: RemoteServerConfigRef,
+        protocol: NegotiatedProtocol,
+        protocol_config: ProtocolConfig,
+        max_decode_errors: usize,
     ) -> SessionMessageReceiver {
         SessionMessageReceiver {
             session_id,
             session,
             addr,
             server_config,
+            protocol,
+            protocol_config,
             node_id: None,
             should_close: false,
+            decode_errors: 0,
+            max_decode_errors,
+        }
+    }
+
+    fn parse_message_id(&self, message_id: &str) -> Option<Uuid> {
+        match Uuid::from_str(message_id) {
+            Ok(message_id) => Some(message_id),
+            Err(e) => {
+                warn!(
+                    "received invalid message_id (addr={}, session_id={}, message_id={}) - error: {}",
+                    &self.addr, &self.session_id, message_id, e
+                );
+                None
+            }
         }
     }
 }
@@ -281,12 +402,29 @@ impl StreamReceiver for SessionMessageReceiver {
                     &msg.client_type
                 );
 
+                let protocol_version = match msg.protocol_version {
+                    0 => LEGACY_PROTOCOL_VERSION,
+                    version => version,
+                };
+
+                if protocol_version != self.protocol.version
+                    && !self.protocol_config.supports_version(protocol_version)
+                {
+                    error!(
+                        "handshake rejected, unsupported protocol version (addr={}, node_id={}, session_id={}, protocol_version={}, negotiated_version={})",
+                        &self.addr, &msg.node_id, &self.session_id, protocol_version, self.protocol.version
+                    );
+
+                    self.should_close = true;
+                    return;
+                }
+
                 tokio::spawn(session_handshake(
                     sys.clone(),
                     msg,
                     self.session_id,
                     self.session.clone(),
-                    self.addr,
+                    self.addr.clone(),
                     self.server_config.clone(),
                 ));
             }
@@ -297,8 +435,13 @@ impl StreamReceiver for SessionMessageReceiver {
                     &self.session_id,
                     &find_actor.actor_id
                 );
+                let message_id = match self.parse_message_id(&find_actor.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
                 tokio::spawn(session_handle_lookup(
-                    Uuid::from_str(&find_actor.message_id).unwrap(),
+                    message_id,
                     find_actor.actor_id.into_actor_id(),
                     self.session_id,
                     sys.clone(),
@@ -306,6 +449,34 @@ impl StreamReceiver for SessionMessageReceiver {
                 ));
             }
 
+            SessionEvent::GetNodeStats(req) => {
+                let message_id = match self.parse_message_id(&req.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                tokio::spawn(session_handle_node_stats(
+                    message_id,
+                    self.session_id,
+                    sys.clone(),
+                    self.session.clone(),
+                ));
+            }
+
+            SessionEvent::GetClusterNodes(req) => {
+                let message_id = match self.parse_message_id(&req.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                tokio::spawn(session_handle_cluster_nodes(
+                    message_id,
+                    self.session_id,
+                    sys.clone(),
+                    self.session.clone(),
+                ));
+            }
+
             SessionEvent::RegisterActor(actor) => {
                 if let Some(node_id) = actor.node_id.into_option() {
                     trace!("register actor {}, {}", &actor.actor_id, &node_id.value);
@@ -372,10 +543,104 @@ impl StreamReceiver for SessionMessageReceiver {
                 tokio::spawn(session_stream_publish(msg, sys.clone()));
             }
 
-            SessionEvent::Raft(_req) => {}
+            SessionEvent::Raft(req) => {
+                let message_id = match self.parse_message_id(&req.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                tokio::spawn(session_handle_raft(
+                    message_id,
+                    req,
+                    self.session_id,
+                    sys.clone(),
+                    self.session.clone(),
+                ));
+            }
+
+            SessionEvent::Gossip(req) => {
+                let message_id = match self.parse_message_id(&req.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                tokio::spawn(session_handle_gossip(
+                    message_id,
+                    req,
+                    self.session_id,
+                    sys.clone(),
+                    self.session.clone(),
+                ));
+            }
+
+            SessionEvent::Leave(leave) => {
+                let message_id = match self.parse_message_id(&leave.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                tokio::spawn(session_handle_leave(
+                    message_id,
+                    leave,
+                    self.session_id,
+                    sys.clone(),
+                    self.session.clone(),
+                ));
+            }
+
+            SessionEvent::WatchActor(watch) => {
+                trace!(
+                    "watch actor (actor_id={}, watcher_node_id={}, unwatch={}, session_id={})",
+                    &watch.actor_id,
+                    watch.watcher_node_id,
+                    watch.unwatch,
+                    &self.session_id
+                );
+
+                tokio::spawn(session_watch_actor(watch, sys.clone()));
+            }
+
+            SessionEvent::ActorTerminated(terminated) => {
+                trace!(
+                    "watched actor terminated (actor_id={}, node_id={}, session_id={})",
+                    &terminated.actor_id,
+                    terminated.node_id,
+                    &self.session_id
+                );
+
+                sys.on_remote_actor_terminated(terminated);
+            }
+
+            SessionEvent::Batch(batch) => {
+                trace!(
+                    "received batch (addr={}, session_id={}, events={})",
+                    &self.addr,
+                    &self.session_id,
+                    batch.events.len()
+                );
+
+                for event in batch.events {
+                    match SessionEvent::try_read_from_bytes(event) {
+                        Ok(SessionEvent::Batch(_)) => {
+                            self.on_deserialisation_failed(DecodeErr::UnexpectedEvent(Event::Batch))
+                        }
+                        Ok(event) => self.on_receive(event, sys).await,
+                        Err(error) => self.on_deserialisation_failed(error),
+                    }
+
+                    if self.should_close {
+                        return;
+                    }
+                }
+            }
 
             SessionEvent::Result(res) => {
-                match sys.pop_request(Uuid::from_str(&res.message_id).unwrap()) {
+                let message_id = match self.parse_message_id(&res.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                match sys.pop_request(message_id) {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Ok(res.result));
                     }
@@ -390,8 +655,13 @@ impl StreamReceiver for SessionMessageReceiver {
                 }
             }
             SessionEvent::Err(err) => {
-                let e = err.error.unwrap().into();
-                match sys.pop_request(Uuid::from_str(&err.message_id).unwrap()) {
+                let message_id = match self.parse_message_id(&err.message_id) {
+                    Some(message_id) => message_id,
+                    None => return,
+                };
+
+                let e = err.error.unwrap_or_default().into();
+                match sys.pop_request(message_id) {
                     Some(res_tx) => {
                         let _ = res_tx.send(RemoteResponse::Err(e));
                     }
@@ -412,11 +682,27 @@ impl StreamReceiver for SessionMessageReceiver {
         let _ = self.session.notify_stop();
     }
 
-    fn on_deserialisation_failed(&mut self) {
+    fn on_deserialisation_failed(&mut self, error: DecodeErr) {
+        NetworkMetrics::incr_decode_errors();
+
+        self.decode_errors += 1;
         warn!(
-            "message serialisation failed (addr={}, session_id={})",
-            &self.addr, &self.session_id
-        )
+            "message deserialisation failed (addr={}, session_id={}, event_type={:?}, decode_errors={}) - error: {}",
+            &self.addr,
+            &self.session_id,
+            error.event_type(),
+            self.decode_errors,
+            error
+        );
+
+        if self.decode_errors >= self.max_decode_errors {
+            error!(
+                "received too many invalid frames (addr={}, session_id={}, decode_errors={}), closing session",
+                &self.addr, &self.session_id, self.decode_errors
+            );
+
+            self.should_close = true;
+        }
     }
 
     fn on_stream_lost(&mut self, error: Error) {
@@ -440,7 +726,7 @@ async fn session_handshake(
     handshake: SessionHandshake,
     session_id: Uuid,
     session: LocalActorRef<RemoteSession>,
-    session_addr: SocketAddr,
+    session_addr: String,
     server_config: RemoteServerConfigRef,
 ) {
     // let mut headers = HashMap::<String, String>::new();
@@ -468,29 +754,63 @@ async fn session_handshake(
 
     let self_id = ctx.node_id();
 
+    // cluster clients don't join the cluster, results of requests made by the client
+    // are written back via the session instead.
+    let client_type: ClientType = handshake
+        .client_type
+        .enum_value()
+        .map_or(ClientType::Worker, |t| t.into());
+
+    if client_type == ClientType::Client {
+        let _ = ctx.client_registry().notify(ClientSessionConnected {
+            node_id: handshake.node_id,
+            session_id,
+            session: session.clone(),
+        });
+    }
+
     let nodes = handshake
         .nodes
         .into_iter()
-        .filter(|n| n.node_id != self_id)
+        .filter(|n| {
+            n.node_id != self_id
+                && (client_type == ClientType::Worker || n.node_id != handshake.node_id)
+        })
         .map(|n| {
             let started_at = n.node_started_at.into_option().map(timestamp_to_datetime);
 
-            let addr =
-                if server_config.override_incoming_node_addr && n.node_id == handshake.node_id {
-                    let host = session_addr.ip();
+            // only connections with an IP address can be overridden, e.g. not unix sockets
+            let session_ip = session_addr
+                .parse::<SocketAddr>()
+                .ok()
+                .map(|addr| addr.ip());
+            let addr = match session_ip {
+                Some(host)
+                    if server_config.override_incoming_node_addr
+                        && n.node_id == handshake.node_id =>
+                {
                     info!("parsing addr={}", &n.addr);
                     let port = n.addr.split(":").last().unwrap();
                     format!("{}:{}", host, port)
-                } else {
-                    n.addr
-                };
+                }
+                _ => n.addr,
+            };
 
             let attributes: NodeAttributes = n
                 .attributes
                 .into_iter()
                 .map(|(k, v)| (k.into(), v.into()))
                 .collect();
-            RemoteNode::new(n.node_id, addr, n.tag, started_at, attributes.into())
+            let roles: NodeRoles = n.roles.into_iter().map(|r| r.into()).collect();
+            RemoteNode::new(
+                n.node_id,
+                addr,
+                n.tag,
+                started_at,
+                attributes.into(),
+                roles.into(),
+                datacenter_from_proto(&n.datacenter),
+            )
         })
         .collect();
 
@@ -531,16 +851,27 @@ async fn session_handle_message(
     ctx: RemoteActorSystem,
     session: LocalActorRef<RemoteSession>,
 ) {
-    // let mut headers = HashMap::<String, String>::new();
-    // headers.insert("traceparent".to_owned(), msg.trace_id);
-    // let span = tracing::trace_span!("RemoteServer::MessageRequest");
-    // span.set_parent(global::get_text_map_propagator(|propagator| {
-    //     propagator.extract(&mut headers)
-    // }));
-    //
-    // let _enter = span.enter();
+    let span = tracing::trace_span!(
+        "RemoteSession::MessageRequest",
+        handler_type = msg.handler_type.as_str(),
+        actor_id = msg.actor_id.as_str(),
+    );
+
+    // the span is active when the message is sent to the actor, so it becomes the parent of
+    // the `actor.recv` span
+    set_trace_parent(&span, &msg.trace_id);
 
     let actor_id = msg.actor_id.into_actor_id();
+    let message_id = match Uuid::from_str(&msg.message_id) {
+        Ok(message_id) => message_id,
+        Err(e) => {
+            warn!(
+                "received message with invalid message_id (session_id={}, message_id={}) - error: {}",
+                &session_id, &msg.message_id, e
+            );
+            return;
+        }
+    };
 
     match ctx
         .handle_message(
@@ -548,18 +879,17 @@ async fn session_handle_message(
             actor_id.clone(),
             msg.message.as_slice(),
         )
+        .instrument(span)
         .await
     {
         Ok(buf) => {
             if msg.requires_response {
-                send_result(msg.message_id.parse().unwrap(), buf, session_id, session).await;
+                send_result(message_id, buf, session_id, session).await;
             }
         }
         Err(e) => {
             error!("[node={}] failed to handle message (handler_type={}, target_actor_id={}), error={:?}", ctx.node_id(), &msg.handler_type, &actor_id, e);
-            let _ = ctx
-                .notify_rpc_err(msg.message_id.parse().unwrap(), e, msg.origin_node_id)
-                .await;
+            let _ = ctx.notify_rpc_err(message_id, e, msg.origin_node_id).await;
         }
     }
 }
@@ -588,13 +918,159 @@ async fn session_handle_lookup(
     }
 }
 
+async fn session_handle_node_stats(
+    msg_id: Uuid,
+    session_id: Uuid,
+    ctx: RemoteActorSystem,
+    session: LocalActorRef<RemoteSession>,
+) {
+    let stats = ctx.local_node_stats().await;
+    trace!("sending node stats: {:?}", &stats);
+
+    match stats.write_to_bytes() {
+        Ok(buf) => send_result(msg_id, buf, session_id, session).await,
+        Err(_) => {
+            error!("failed to encode node stats");
+        }
+    }
+}
+
+async fn session_handle_cluster_nodes(
+    msg_id: Uuid,
+    session_id: Uuid,
+    ctx: RemoteActorSystem,
+    session: LocalActorRef<RemoteSession>,
+) {
+    let nodes = ClusterNodes {
+        nodes: ctx
+            .get_nodes()
+            .await
+            .into_iter()
+            .filter(|n| n.status.is_healthy())
+            .map(|n| n.into())
+            .collect(),
+        ..Default::default()
+    };
+
+    trace!("sending {} cluster node(s)", nodes.nodes.len());
+
+    match nodes.write_to_bytes() {
+        Ok(buf) => send_result(msg_id, buf, session_id, session).await,
+        Err(_) => {
+            error!("failed to encode cluster nodes");
+        }
+    }
+}
+
+async fn session_handle_raft(
+    msg_id: Uuid,
+    request: RaftRequest,
+    session_id: Uuid,
+    ctx: RemoteActorSystem,
+    session: LocalActorRef<RemoteSession>,
+) {
+    let raft = match ctx.raft() {
+        Some(raft) => raft,
+        None => {
+            warn!(
+                "received raft request but raft is not enabled (session_id={})",
+                session_id
+            );
+            return;
+        }
+    };
+
+    match raft.actor_ref().send(RaftRpc(request)).await {
+        Ok(Some(buf)) => send_result(msg_id, buf, session_id, session).await,
+        Ok(None) => {
+            error!("failed to decode raft request (session_id={})", session_id);
+        }
+        Err(_) => {
+            error!("failed to handle raft request, raft actor unavailable");
+        }
+    }
+}
+
+async fn session_handle_gossip(
+    msg_id: Uuid,
+    request: GossipRequest,
+    session_id: Uuid,
+    ctx: RemoteActorSystem,
+    session: LocalActorRef<RemoteSession>,
+) {
+    let gossip = match ctx.gossip() {
+        Some(gossip) => gossip,
+        None => {
+            warn!(
+                "received gossip request but gossip is not enabled (session_id={})",
+                session_id
+            );
+            return;
+        }
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if gossip.actor_ref().notify(GossipRpc(request, tx)).is_err() {
+        error!("failed to handle gossip request, gossip actor unavailable");
+        return;
+    }
+
+    match rx.await {
+        Ok(Some(buf)) => send_result(msg_id, buf, session_id, session).await,
+        Ok(None) => {
+            error!(
+                "failed to decode gossip request (session_id={})",
+                session_id
+            );
+        }
+        Err(_) => {
+            trace!("gossip request dropped (session_id={})", session_id);
+        }
+    }
+}
+
+async fn session_handle_leave(
+    msg_id: Uuid,
+    leave: LeaveEvent,
+    session_id: Uuid,
+    ctx: RemoteActorSystem,
+    session: LocalActorRef<RemoteSession>,
+) {
+    info!(
+        "node_id={} is leaving the cluster (session_id={})",
+        leave.node_id, session_id
+    );
+
+    if ctx
+        .heartbeat()
+        .send(NodeLeaving(leave.node_id))
+        .await
+        .is_err()
+    {
+        error!("failed to handle leave event, heartbeat actor unavailable");
+        return;
+    }
+
+    send_result(msg_id, vec![], session_id, session).await
+}
+
 async fn session_create_actor(
     msg: CreateActorEvent,
     session_id: Uuid,
     ctx: RemoteActorSystem,
     session: LocalActorRef<RemoteSession>,
 ) {
-    let msg_id = msg.message_id.clone();
+    let msg_id = match Uuid::from_str(&msg.message_id) {
+        Ok(msg_id) => msg_id,
+        Err(e) => {
+            warn!(
+                "received create actor request with invalid message_id (session_id={}, message_id={}) - error: {}",
+                &session_id, &msg.message_id, e
+            );
+            return;
+        }
+    };
+
     let actor_id = if msg.actor_id.is_empty() {
         None
     } else {
@@ -612,13 +1088,44 @@ async fn session_create_actor(
         .handle_create_actor(actor_id, msg.actor_type, msg.recipe)
         .await
     {
-        Ok(buf) => send_result(msg_id.parse().unwrap(), buf.to_vec(), session_id, session).await,
+        Ok(buf) => send_result(msg_id, buf.to_vec(), session_id, session).await,
         Err(_) => {
             error!("failed to handle message, todo: send err");
         }
     }
 }
 
+async fn session_watch_actor(msg: WatchActorEvent, sys: RemoteActorSystem) {
+    let actor_id = msg.actor_id.into_actor_id();
+    let watcher = Watcher::Remote(msg.watcher_node_id);
+
+    let actor_ref = sys
+        .actor_system()
+        .get_tracked_boxed_actor(actor_id.clone())
+        .await;
+
+    // if the watched actor stops before the watch is registered, dropping the undelivered `Watch`
+    // notifies the watcher
+    match actor_ref {
+        Some(actor_ref) if msg.unwatch => {
+            let _ = actor_ref.notify_unwatch(watcher);
+        }
+        Some(actor_ref) => {
+            let _ = actor_ref.notify_watch(watcher, sys.actor_system());
+        }
+        None if msg.unwatch => {}
+        None => {
+            sys.notify_watcher_terminated(
+                msg.watcher_node_id,
+                ActorTerminated {
+                    actor_id,
+                    reason: TerminationReason::NotFound,
+                },
+            );
+        }
+    }
+}
+
 async fn session_stream_publish(msg: Arc<StreamPublishEvent>, sys: RemoteActorSystem) {
     // TODO: node should acknowledge the message
     if let Some(mediator) = sys.stream_mediator() {
@@ -0,0 +1,131 @@
+use crate::remote::net::transport::{Connection, Transport, TransportListener};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// In-memory transport, connecting nodes within the same process without touching the network,
+/// useful for deterministic tests.
+///
+/// Only nodes sharing the same [`MemoryTransport`] (or a clone of it) can connect to each other,
+/// addresses are arbitrary strings, for example `node-1`.
+#[derive(Clone)]
+pub struct MemoryTransport {
+    network: Arc<MemoryNetwork>,
+    buffer_size: usize,
+}
+
+#[derive(Default)]
+struct MemoryNetwork {
+    listeners: Mutex<HashMap<String, mpsc::UnboundedSender<Connection>>>,
+    connection_counter: AtomicU64,
+}
+
+impl MemoryTransport {
+    pub fn new() -> MemoryTransport {
+        MemoryTransport {
+            network: Arc::new(MemoryNetwork::default()),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+        }
+    }
+
+    /// Maximum number of bytes buffered in each direction of a connection before writes wait
+    /// for the peer to read.
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// Addresses currently accepting connections.
+    pub fn listening_addrs(&self) -> Vec<String> {
+        let mut addrs: Vec<String> = self.network.listeners.lock().keys().cloned().collect();
+        addrs.sort();
+        addrs
+    }
+}
+
+impl Default for MemoryTransport {
+    fn default() -> Self {
+        MemoryTransport::new()
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn connect(&self, addr: &str) -> io::Result<Connection> {
+        let listener = self.network.listeners.lock().get(addr).cloned();
+        let listener = listener.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("no memory listener bound to {}", addr),
+            )
+        })?;
+
+        let connection_id = self
+            .network
+            .connection_counter
+            .fetch_add(1, Ordering::Relaxed);
+
+        let (client, server) = tokio::io::duplex(self.buffer_size);
+        let peer_addr = format!("memory-{}", connection_id);
+        if listener.send(Connection::new(server, peer_addr)).is_err() {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("memory listener {} closed", addr),
+            ));
+        }
+
+        Ok(Connection::new(client, addr))
+    }
+
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let mut listeners = self.network.listeners.lock();
+        if listeners.contains_key(addr) {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("memory address {} already in use", addr),
+            ));
+        }
+
+        let (connection_tx, connections) = mpsc::unbounded_channel();
+        listeners.insert(addr.to_string(), connection_tx);
+
+        Ok(Box::new(MemoryTransportListener {
+            addr: addr.to_string(),
+            connections,
+            network: self.network.clone(),
+        }))
+    }
+}
+
+pub struct MemoryTransportListener {
+    addr: String,
+    connections: mpsc::UnboundedReceiver<Connection>,
+    network: Arc<MemoryNetwork>,
+}
+
+#[async_trait]
+impl TransportListener for MemoryTransportListener {
+    async fn accept(&mut self) -> io::Result<Connection> {
+        self.connections.recv().await.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotConnected,
+                format!("memory listener {} closed", &self.addr),
+            )
+        })
+    }
+
+    fn local_addr(&self) -> String {
+        self.addr.clone()
+    }
+}
+
+impl Drop for MemoryTransportListener {
+    fn drop(&mut self) {
+        self.network.listeners.lock().remove(&self.addr);
+    }
+}
@@ -0,0 +1,114 @@
+//! Pluggable transports used for node-to-node connections.
+//!
+//! A [`Transport`] is responsible for establishing connections to other nodes, and for listening
+//! for inbound connections. Each connection is exposed as a pair of length-delimited, framed halves,
+//! so the [`RemoteClient`] and [`RemoteSession`] actors never need to know whether they're talking
+//! over TCP, a Unix domain socket or an in-memory pipe.
+//!
+//! By default, [`TcpTransport`] is used, a different transport can be provided via
+//! [`RemoteActorSystemBuilder::with_transport`].
+//!
+//! [`RemoteClient`]: crate::remote::net::client::RemoteClient
+//! [`RemoteSession`]: crate::remote::net::server::session::RemoteSession
+//! [`RemoteActorSystemBuilder::with_transport`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_transport
+
+use std::io;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
+
+pub mod memory;
+pub mod tcp;
+
+#[cfg(unix)]
+pub mod unix;
+
+pub use memory::MemoryTransport;
+pub use tcp::TcpTransport;
+
+#[cfg(unix)]
+pub use unix::UnixTransport;
+
+pub type ConnectionReader =
+    FramedRead<Box<dyn AsyncRead + Send + Sync + Unpin>, LengthDelimitedCodec>;
+
+pub type ConnectionWriter =
+    FramedWrite<Box<dyn AsyncWrite + Send + Sync + Unpin>, LengthDelimitedCodec>;
+
+#[async_trait]
+pub trait Transport: 'static + Send + Sync {
+    /// Opens a connection to the node listening on `addr`.
+    async fn connect(&self, addr: &str) -> io::Result<Connection>;
+
+    /// Starts listening for inbound connections on `addr`.
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>>;
+
+    /// Whether `addr` can currently be resolved, peer discovery waits for the seed address
+    /// to resolve before attempting to connect.
+    async fn resolves(&self, _addr: &str) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+pub trait TransportListener: 'static + Send {
+    /// Waits for the next inbound connection.
+    async fn accept(&mut self) -> io::Result<Connection>;
+
+    /// The address the listener is bound to, used for logging.
+    fn local_addr(&self) -> String;
+}
+
+pub struct Connection {
+    pub reader: ConnectionReader,
+    pub writer: ConnectionWriter,
+    pub peer_addr: String,
+    pub peer_certificate: Option<Vec<u8>>,
+}
+
+impl Connection {
+    /// Splits a bidirectional stream into a pair of framed halves.
+    pub fn new<S>(stream: S, peer_addr: impl ToString) -> Connection
+    where
+        S: 'static + AsyncRead + AsyncWrite + Send + Sync,
+    {
+        let (read, write) = tokio::io::split(stream);
+        Self::from_halves(read, write, peer_addr)
+    }
+
+    /// Creates a connection from separate read and write streams, for transports
+    /// that don't provide a single bidirectional stream.
+    pub fn from_halves<R, W>(read: R, write: W, peer_addr: impl ToString) -> Connection
+    where
+        R: 'static + AsyncRead + Send + Sync + Unpin,
+        W: 'static + AsyncWrite + Send + Sync + Unpin,
+    {
+        let codec = LengthDelimitedCodec::new();
+        let read: Box<dyn AsyncRead + Send + Sync + Unpin> = Box::new(read);
+        let write: Box<dyn AsyncWrite + Send + Sync + Unpin> = Box::new(write);
+
+        Connection {
+            reader: FramedRead::new(read, codec.clone()),
+            writer: FramedWrite::new(write, codec),
+            peer_addr: peer_addr.to_string(),
+            peer_certificate: None,
+        }
+    }
+
+    /// Limits the length of frames read from and written to the connection, reading a larger frame
+    /// results in an error, closing the connection.
+    pub fn set_max_frame_length(&mut self, max_frame_length: usize) {
+        self.reader
+            .decoder_mut()
+            .set_max_frame_length(max_frame_length);
+
+        self.writer
+            .encoder_mut()
+            .set_max_frame_length(max_frame_length);
+    }
+
+    /// The DER-encoded end-entity certificate presented by the peer, if any.
+    pub fn with_peer_certificate(mut self, peer_certificate: Option<Vec<u8>>) -> Self {
+        self.peer_certificate = peer_certificate;
+        self
+    }
+}
@@ -0,0 +1,129 @@
+use crate::remote::net::transport::{Connection, Transport, TransportListener};
+use std::io;
+use tokio::net::{lookup_host, TcpListener, TcpStream};
+
+#[cfg(feature = "remote-tls")]
+use crate::remote::net::security::tls::TlsConfig;
+
+#[cfg(feature = "remote-tls")]
+use tokio::sync::mpsc;
+
+/// TCP transport, optionally secured with TLS.
+#[derive(Clone, Default)]
+pub struct TcpTransport {
+    #[cfg(feature = "remote-tls")]
+    tls: Option<TlsConfig>,
+}
+
+impl TcpTransport {
+    pub fn new() -> TcpTransport {
+        TcpTransport::default()
+    }
+
+    #[cfg(feature = "remote-tls")]
+    pub fn with_tls(mut self, tls: Option<TlsConfig>) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    #[cfg(feature = "remote-tls")]
+    pub fn tls(&self) -> Option<&TlsConfig> {
+        self.tls.as_ref()
+    }
+}
+
+#[async_trait]
+impl Transport for TcpTransport {
+    async fn connect(&self, addr: &str) -> io::Result<Connection> {
+        let stream = TcpStream::connect(addr).await?;
+
+        #[cfg(feature = "remote-tls")]
+        if let Some(tls) = &self.tls {
+            return tls.connect(addr, stream).await.map_err(io::Error::other);
+        }
+
+        Ok(Connection::new(stream, addr))
+    }
+
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let listener = TcpListener::bind(addr).await?;
+
+        #[cfg(feature = "remote-tls")]
+        let (handshake_tx, handshake_rx) = mpsc::channel(64);
+
+        Ok(Box::new(TcpTransportListener {
+            listener,
+            #[cfg(feature = "remote-tls")]
+            tls: self.tls.clone(),
+            #[cfg(feature = "remote-tls")]
+            handshake_tx,
+            #[cfg(feature = "remote-tls")]
+            handshake_rx,
+        }))
+    }
+
+    async fn resolves(&self, addr: &str) -> bool {
+        lookup_host(addr).await.is_ok()
+    }
+}
+
+pub struct TcpTransportListener {
+    listener: TcpListener,
+
+    #[cfg(feature = "remote-tls")]
+    tls: Option<TlsConfig>,
+
+    #[cfg(feature = "remote-tls")]
+    handshake_tx: mpsc::Sender<Connection>,
+
+    #[cfg(feature = "remote-tls")]
+    handshake_rx: mpsc::Receiver<Connection>,
+}
+
+#[async_trait]
+impl TransportListener for TcpTransportListener {
+    #[cfg(not(feature = "remote-tls"))]
+    async fn accept(&mut self) -> io::Result<Connection> {
+        let (stream, addr) = self.listener.accept().await?;
+        Ok(Connection::new(stream, addr))
+    }
+
+    #[cfg(feature = "remote-tls")]
+    async fn accept(&mut self) -> io::Result<Connection> {
+        // TLS handshakes are completed in the background, so a slow (or malicious) peer
+        // can't prevent other connections from being accepted.
+        loop {
+            tokio::select! {
+                res = self.listener.accept() => {
+                    let (stream, addr) = res?;
+                    let tls = match &self.tls {
+                        Some(tls) => tls.clone(),
+                        None => return Ok(Connection::new(stream, addr)),
+                    };
+
+                    let handshake_tx = self.handshake_tx.clone();
+                    tokio::spawn(async move {
+                        match tls.accept(stream, &addr.to_string()).await {
+                            Ok(connection) => {
+                                let _ = handshake_tx.send(connection).await;
+                            }
+                            Err(e) => {
+                                warn!("tls handshake with {} failed, error: {}", addr, e);
+                            }
+                        }
+                    });
+                }
+
+                Some(connection) = self.handshake_rx.recv() => {
+                    return Ok(connection);
+                }
+            }
+        }
+    }
+
+    fn local_addr(&self) -> String {
+        self.listener
+            .local_addr()
+            .map_or_else(|_| "unknown".to_string(), |addr| addr.to_string())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::remote::net::transport::{Connection, Transport, TransportListener};
+use std::io;
+use std::path::PathBuf;
+use tokio::net::{UnixListener, UnixStream};
+
+/// Unix domain socket transport, for nodes running on the same host.
+///
+/// Node addresses are paths to socket files, for example `/var/run/coerce/node-1.sock`.
+#[derive(Clone, Default)]
+pub struct UnixTransport {
+    remove_existing: bool,
+}
+
+impl UnixTransport {
+    pub fn new() -> UnixTransport {
+        UnixTransport::default()
+    }
+
+    /// Removes any existing socket file before binding, which is typically left behind
+    /// if a node wasn't shut down cleanly.
+    pub fn remove_existing(mut self, remove_existing: bool) -> Self {
+        self.remove_existing = remove_existing;
+        self
+    }
+}
+
+#[async_trait]
+impl Transport for UnixTransport {
+    async fn connect(&self, addr: &str) -> io::Result<Connection> {
+        let stream = UnixStream::connect(addr).await?;
+        Ok(Connection::new(stream, addr))
+    }
+
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let path = PathBuf::from(addr);
+        if self.remove_existing && path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        Ok(Box::new(UnixTransportListener { listener, path }))
+    }
+}
+
+pub struct UnixTransportListener {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+#[async_trait]
+impl TransportListener for UnixTransportListener {
+    async fn accept(&mut self) -> io::Result<Connection> {
+        let (stream, _) = self.listener.accept().await?;
+
+        // peers connecting via unix sockets are usually unnamed, so the listener path is used instead.
+        Ok(Connection::new(stream, self.local_addr()))
+    }
+
+    fn local_addr(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+impl Drop for UnixTransportListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::remote::net::proto::network::{RaftConfiguration, RaftLogEntry};
+use crate::remote::system::NodeId;
+use std::collections::{BTreeSet, VecDeque};
+
+/// The entries of the Raft log that haven't yet been compacted into a snapshot.
+///
+/// Log indexes start at 1, an index of 0 refers to the (empty) state before the first entry.
+///
+/// Changes made since the log was last persisted are tracked, so only the entries that have changed
+/// need to be written to storage, see [`RaftStorage`].
+///
+/// [`RaftStorage`]: crate::remote::raft::storage::RaftStorage
+#[derive(Default)]
+pub struct RaftLog {
+    entries: VecDeque<RaftLogEntry>,
+    snapshot: Snapshot,
+    unpersisted_from: Option<u64>,
+    snapshot_unpersisted: bool,
+}
+
+/// The state of the metadata after applying every entry up to, and including, `index`.
+#[derive(Clone, Debug, Default)]
+pub struct Snapshot {
+    pub index: u64,
+    pub term: u64,
+    pub data: Vec<u8>,
+
+    /// The voters as of `index`, or `None` if no configuration had been committed, in which case
+    /// the configured voters are used.
+    pub voters: Option<BTreeSet<NodeId>>,
+}
+
+impl RaftLog {
+    pub fn new() -> RaftLog {
+        RaftLog::default()
+    }
+
+    pub fn last_index(&self) -> u64 {
+        self.snapshot.index + self.entries.len() as u64
+    }
+
+    pub fn last_term(&self) -> u64 {
+        self.entries
+            .back()
+            .map_or(self.snapshot.term, |entry| entry.term)
+    }
+
+    /// The term of the entry at `index`, or `None` if the entry doesn't exist or has been
+    /// compacted into the snapshot.
+    pub fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot.index {
+            Some(self.snapshot.term)
+        } else {
+            self.get(index).map(|entry| entry.term)
+        }
+    }
+
+    pub fn get(&self, index: u64) -> Option<&RaftLogEntry> {
+        if index <= self.snapshot.index {
+            return None;
+        }
+
+        self.entries.get((index - self.snapshot.index - 1) as usize)
+    }
+
+    /// Appends a new entry to the end of the log, returning its index.
+    pub fn append(&mut self, term: u64, command: Vec<u8>) -> u64 {
+        self.push(RaftLogEntry {
+            term,
+            command,
+            ..Default::default()
+        })
+    }
+
+    /// Appends a configuration change to the end of the log, returning its index. The new voters
+    /// take effect as soon as the entry is appended, rather than once it's been committed.
+    pub fn append_configuration(&mut self, term: u64, voters: &BTreeSet<NodeId>) -> u64 {
+        self.push(RaftLogEntry {
+            term,
+            configuration: Some(configuration(voters)).into(),
+            ..Default::default()
+        })
+    }
+
+    fn push(&mut self, mut entry: RaftLogEntry) -> u64 {
+        let index = self.last_index() + 1;
+        entry.index = index;
+
+        self.entries.push_back(entry);
+        self.mark_unpersisted(index);
+
+        index
+    }
+
+    /// Appends entries received from the leader, removing any existing entries that conflict
+    /// with them. Entries that have already been compacted into the snapshot are ignored.
+    pub fn append_entries(&mut self, entries: Vec<RaftLogEntry>) {
+        for entry in entries {
+            if entry.index <= self.snapshot.index {
+                continue;
+            }
+
+            match self.term_at(entry.index) {
+                Some(term) if term == entry.term => continue,
+                Some(_) => self.truncate_from(entry.index),
+                None => {}
+            }
+
+            if entry.index == self.last_index() + 1 {
+                self.mark_unpersisted(entry.index);
+                self.entries.push_back(entry);
+            }
+        }
+    }
+
+    /// Up to `max` entries, starting at `index`.
+    pub fn entries_from(&self, index: u64, max: usize) -> Vec<RaftLogEntry> {
+        if index <= self.snapshot.index {
+            return vec![];
+        }
+
+        let start = (index - self.snapshot.index - 1) as usize;
+        self.entries.iter().skip(start).take(max).cloned().collect()
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// The voters as of the last entry in the log, whether or not it has been committed.
+    pub fn voters(&self) -> Option<BTreeSet<NodeId>> {
+        self.voters_at(self.last_index())
+    }
+
+    /// The index of the last configuration change in the log, or the snapshot's index if the log
+    /// doesn't contain one.
+    pub fn last_configuration_index(&self) -> u64 {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.configuration.is_some())
+            .map_or(self.snapshot.index, |entry| entry.index)
+    }
+
+    fn voters_at(&self, index: u64) -> Option<BTreeSet<NodeId>> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.index <= index)
+            .find_map(|entry| entry.configuration.as_ref())
+            .map(voters)
+            .or_else(|| self.snapshot.voters.clone())
+    }
+
+    /// Discards every entry up to, and including, `index`, replacing them with a snapshot.
+    pub fn compact(&mut self, index: u64, data: Vec<u8>) {
+        let term = match self.term_at(index) {
+            Some(term) if index > self.snapshot.index => term,
+            _ => return,
+        };
+
+        let voters = self.voters_at(index);
+        let compacted = (index - self.snapshot.index) as usize;
+        self.entries.drain(..compacted);
+        self.snapshot = Snapshot {
+            index,
+            term,
+            data,
+            voters,
+        };
+
+        self.snapshot_unpersisted = true;
+    }
+
+    /// Replaces the log with a snapshot received from the leader, entries following the snapshot
+    /// are kept if the log contains the snapshot's last entry.
+    pub fn install_snapshot(&mut self, snapshot: Snapshot) {
+        if self.term_at(snapshot.index) == Some(snapshot.term)
+            && snapshot.index > self.snapshot.index
+        {
+            let compacted = (snapshot.index - self.snapshot.index) as usize;
+            self.entries.drain(..compacted);
+        } else {
+            self.entries.clear();
+        }
+
+        self.snapshot = snapshot;
+        self.snapshot_unpersisted = true;
+    }
+
+    /// Restores the snapshot and entries recovered from storage.
+    pub fn restore(&mut self, snapshot: Snapshot, entries: Vec<RaftLogEntry>) {
+        self.snapshot = snapshot;
+        self.entries = entries.into();
+    }
+
+    /// Restores entries recovered from storage, replacing any entries from `index` onwards.
+    pub fn restore_entries(&mut self, index: u64, entries: Vec<RaftLogEntry>) {
+        self.truncate_from(index);
+        self.entries.extend(entries);
+    }
+
+    /// The index of the first entry that has changed since the log was last persisted.
+    pub fn unpersisted_from(&self) -> Option<u64> {
+        self.unpersisted_from
+    }
+
+    /// Whether the snapshot has changed since the log was last persisted, in which case the whole
+    /// log is written to storage.
+    pub fn is_snapshot_unpersisted(&self) -> bool {
+        self.snapshot_unpersisted
+    }
+
+    pub fn mark_persisted(&mut self) {
+        self.unpersisted_from = None;
+        self.snapshot_unpersisted = false;
+    }
+
+    fn mark_unpersisted(&mut self, index: u64) {
+        self.unpersisted_from = Some(self.unpersisted_from.map_or(index, |i| i.min(index)));
+    }
+
+    fn truncate_from(&mut self, index: u64) {
+        if index > self.snapshot.index {
+            self.entries
+                .truncate((index - self.snapshot.index - 1) as usize);
+        }
+    }
+}
+
+pub(crate) fn configuration(voters: &BTreeSet<NodeId>) -> RaftConfiguration {
+    RaftConfiguration {
+        voters: voters.iter().copied().collect(),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn voters(configuration: &RaftConfiguration) -> BTreeSet<NodeId> {
+    configuration.voters.iter().copied().collect()
+}
@@ -0,0 +1,114 @@
+use crate::remote::net::proto::network::{RaftMetadataCommand, RaftMetadataSnapshot};
+use parking_lot::RwLock;
+use protobuf::Message;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Key-value state replicated by Raft, every node applies the same commands in the same order.
+#[derive(Clone, Default)]
+pub struct ClusterMetadata {
+    entries: Arc<RwLock<BTreeMap<String, Vec<u8>>>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MetadataCommand {
+    Set { key: String, value: Vec<u8> },
+    Delete { key: String },
+}
+
+impl ClusterMetadata {
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        self.entries.read().get(key).cloned()
+    }
+
+    /// All entries with a key starting with `prefix`, ordered by key.
+    pub fn get_prefixed(&self, prefix: &str) -> Vec<(String, Vec<u8>)> {
+        self.entries
+            .read()
+            .range(prefix.to_string()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    pub fn apply(&self, command: MetadataCommand) {
+        let mut entries = self.entries.write();
+        match command {
+            MetadataCommand::Set { key, value } => {
+                entries.insert(key, value);
+            }
+            MetadataCommand::Delete { key } => {
+                entries.remove(&key);
+            }
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<u8> {
+        let snapshot = RaftMetadataSnapshot {
+            entries: self
+                .entries
+                .read()
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            ..Default::default()
+        };
+
+        snapshot
+            .write_to_bytes()
+            .expect("serialise metadata snapshot")
+    }
+
+    /// Replaces every entry with the contents of `snapshot`, returning false if the snapshot
+    /// couldn't be decoded.
+    pub fn restore(&self, snapshot: &[u8]) -> bool {
+        match RaftMetadataSnapshot::parse_from_bytes(snapshot) {
+            Ok(snapshot) => {
+                *self.entries.write() = snapshot.entries.into_iter().collect();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl MetadataCommand {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let command = match self {
+            MetadataCommand::Set { key, value } => RaftMetadataCommand {
+                key: key.clone(),
+                value: value.clone(),
+                ..Default::default()
+            },
+            MetadataCommand::Delete { key } => RaftMetadataCommand {
+                key: key.clone(),
+                delete: true,
+                ..Default::default()
+            },
+        };
+
+        command
+            .write_to_bytes()
+            .expect("serialise metadata command")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<MetadataCommand> {
+        let command = RaftMetadataCommand::parse_from_bytes(bytes).ok()?;
+        Some(if command.delete {
+            MetadataCommand::Delete { key: command.key }
+        } else {
+            MetadataCommand::Set {
+                key: command.key,
+                value: command.value,
+            }
+        })
+    }
+}
@@ -1,232 +1,228 @@
-// use crate::actor::LocalActorRef;
-// use crate::remote::net::message::{ClientEvent, SessionEvent};
-// use crate::remote::net::proto::network::{ClientResult, RaftRequest as ProtoRaftRequest};
-// use crate::remote::net::server::session::{RemoteSessionStore, SessionWrite};
-// use crate::remote::net::StreamData;
-// use crate::remote::system::RemoteActorSystem;
-// use anyhow::Result;
-// use async_raft::raft::{
-//     AppendEntriesRequest, AppendEntriesResponse, InstallSnapshotRequest, InstallSnapshotResponse,
-//     VoteRequest, VoteResponse,
-// };
-// use async_raft::{Config, Raft, RaftNetwork};
-// use memstore::{ClientRequest, ClientResponse, MemStore};
-//
-// use std::sync::Arc;
-// use uuid::Uuid;
-//
-// pub type RaftCore = Raft<ClientRequest, ClientResponse, RaftRouter, MemStore>;
-//
-// pub struct RaftSystem {
-//     raft: RaftCore,
-// }
-//
-// pub struct RaftRouter {
-//     system: RemoteActorSystem,
-// }
-//
-// impl RaftSystem {
-//     pub fn new(system: RemoteActorSystem) -> Arc<RaftSystem> {
-//         let node_id = system.node_id();
-//         let cluster_name = "coerce".to_string();
-//         let config = Config::build(cluster_name)
-//             .heartbeat_interval(1000)
-//             .election_timeout_min(60000 * 30)
-//             .election_timeout_max(60000 * 60)
-//             .validate()
-//             .unwrap();
-//
-//         Arc::new(RaftSystem {
-//             raft: Raft::new(
-//                 node_id,
-//                 Arc::new(config),
-//                 Arc::new(RaftRouter::new(system)),
-//                 Arc::new(MemStore::new(node_id)),
-//             ),
-//         })
-//     }
-//
-//     pub fn core(&self) -> &RaftCore {
-//         &self.raft
-//     }
-// }
-//
-// impl RaftRouter {
-//     pub fn new(system: RemoteActorSystem) -> RaftRouter {
-//         RaftRouter { system }
-//     }
-// }
-//
-// #[derive(Debug)]
-// pub enum RaftRequest {
-//     AppendEntries(AppendEntriesRequest<ClientRequest>),
-//     InstallSnapshot(InstallSnapshotRequest),
-//     Vote(VoteRequest),
-// }
-//
-// impl RaftSystem {
-//     pub async fn inbound_request(
-//         &self,
-//         proto_req: ProtoRaftRequest,
-//         session_id: Uuid,
-//         sessions: &LocalActorRef<RemoteSessionStore>,
-//     ) {
-//         let message_id = Uuid::parse_str(&proto_req.message_id).unwrap();
-//         let req = RaftRequest::from_proto(proto_req);
-//
-//         info!(
-//             "inbound req, session={}, message_id={}",
-//             &session_id, &message_id
-//         );
-//
-//         let result = match req {
-//             RaftRequest::AppendEntries(append_entries) => self
-//                 .raft
-//                 .append_entries(append_entries)
-//                 .await
-//                 .map(|res| res.write_to_bytes()),
-//
-//             RaftRequest::InstallSnapshot(snapshot) => self
-//                 .raft
-//                 .install_snapshot(snapshot)
-//                 .await
-//                 .map(|res| res.write_to_bytes()),
-//
-//             RaftRequest::Vote(vote) => self.raft.vote(vote).await.map(|res| res.write_to_bytes()),
-//         };
-//
-//         match result {
-//             Ok(Some(result)) => {
-//                 let result = ClientEvent::Result(ClientResult {
-//                     message_id: message_id.to_string(),
-//                     result,
-//                     ..Default::default()
-//                 });
-//
-//                 sessions.send(SessionWrite(session_id, result)).await;
-//             }
-//             Err(_e) => {
-//                 // todo: send err
-//             }
-//             Ok(None) => {
-//                 // todo: send serialisation err
-//             }
-//         }
-//     }
-// }
-//
-// impl RaftRequest {
-//     fn request_type(&self) -> u32 {
-//         match &self {
-//             &RaftRequest::AppendEntries(_) => 1,
-//             &RaftRequest::InstallSnapshot(_) => 2,
-//             &RaftRequest::Vote(_) => 3,
-//         }
-//     }
-//
-//     fn write_to_bytes(&self) -> Vec<u8> {
-//         // TODO: protobuf for the raft request (json is temporary)
-//         match self {
-//             RaftRequest::AppendEntries(append_entries) => {
-//                 serde_json::to_vec(&append_entries).expect("serialise append_entries request")
-//             }
-//             RaftRequest::InstallSnapshot(install_snapshot) => {
-//                 serde_json::to_vec(&install_snapshot).expect("serialise install_snapshot request")
-//             }
-//             RaftRequest::Vote(vote) => serde_json::to_vec(&vote).expect("serialise vote request"),
-//         }
-//     }
-//
-//     fn from_proto(raft_req: ProtoRaftRequest) -> Self {
-//         match raft_req.request_type {
-//             1 => RaftRequest::AppendEntries(serde_json::from_slice(&raft_req.payload).unwrap()),
-//             2 => RaftRequest::InstallSnapshot(serde_json::from_slice(&raft_req.payload).unwrap()),
-//             3 => RaftRequest::Vote(serde_json::from_slice(&raft_req.payload).unwrap()),
-//             i => panic!("unexpected payload type: {}", i),
-//         }
-//     }
-//
-//     pub fn to_proto(self, message_id: Uuid) -> ProtoRaftRequest {
-//         let request_type = self.request_type();
-//         let payload = self.write_to_bytes();
-//         let message_id = message_id.to_string();
-//
-//         ProtoRaftRequest {
-//             request_type,
-//             payload,
-//             message_id,
-//             ..Default::default()
-//         }
-//     }
-// }
-//
-// #[async_trait]
-// impl RaftNetwork<ClientRequest> for RaftRouter {
-//     async fn append_entries(
-//         &self,
-//         target: u64,
-//         rpc: AppendEntriesRequest<ClientRequest>,
-//     ) -> Result<AppendEntriesResponse> {
-//         let message_id = Uuid::new_v4();
-//         let event = SessionEvent::Raft(RaftRequest::AppendEntries(rpc).to_proto(message_id));
-//
-//         info!("append_entries rpc, id={}", &message_id);
-//         let res = self.system.node_rpc(message_id, event, target).await?;
-//         info!("append_entries rpc, id={} is complete", &message_id);
-//         Ok(res)
-//     }
-//
-//     async fn install_snapshot(
-//         &self,
-//         target: u64,
-//         rpc: InstallSnapshotRequest,
-//     ) -> Result<InstallSnapshotResponse> {
-//         let message_id = Uuid::new_v4();
-//         let event = SessionEvent::Raft(RaftRequest::InstallSnapshot(rpc).to_proto(message_id));
-//
-//         info!("install_snapshot rpc, id={}", &message_id);
-//         let res = self.system.node_rpc(message_id, event, target).await?;
-//         info!("install_snapshot rpc, id={} is complete", &message_id);
-//         Ok(res)
-//     }
-//
-//     async fn vote(&self, target: u64, rpc: VoteRequest) -> Result<VoteResponse> {
-//         let message_id = Uuid::new_v4();
-//         let event = SessionEvent::Raft(RaftRequest::Vote(rpc).to_proto(message_id));
-//
-//         info!("vote rpc, id={}", &message_id);
-//         let res = self.system.node_rpc(message_id, event, target).await?;
-//         info!("vote rpc, id={} is complete", &message_id);
-//         Ok(res)
-//     }
-// }
-//
-// impl StreamData for InstallSnapshotResponse {
-//     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-//         serde_json::from_slice(&data).map_or(None, |s| s)
-//     }
-//
-//     fn write_to_bytes(&self) -> Option<Vec<u8>> {
-//         serde_json::to_vec(&self).map_or(None, |s| Some(s))
-//     }
-// }
-//
-// impl StreamData for AppendEntriesResponse {
-//     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-//         serde_json::from_slice(&data).map_or(None, |s| s)
-//     }
-//
-//     fn write_to_bytes(&self) -> Option<Vec<u8>> {
-//         serde_json::to_vec(&self).map_or(None, |s| Some(s))
-//     }
-// }
-//
-// impl StreamData for VoteResponse {
-//     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
-//         serde_json::from_slice(&data).map_or(None, |s| s)
-//     }
-//
-//     fn write_to_bytes(&self) -> Option<Vec<u8>> {
-//         serde_json::to_vec(&self).map_or(None, |s| Some(s))
-//     }
-// }
+//! Embedded Raft consensus, for strongly consistent cluster state.
+//!
+//! By default, the cluster leader is the oldest healthy node, as seen by each node independently,
+//! which means that during a network partition, each side of the partition can elect its own leader.
+//! When Raft is enabled via [`RemoteSystemConfigBuilder::raft`], the leader is instead elected
+//! by a majority of the cluster's nodes, and the cluster's metadata (such as the sharding
+//! coordinator's shard allocations) is replicated to every node via the Raft log.
+//!
+//! Raft requests are sent over the existing remote sessions, as [`SessionEvent::Raft`] events.
+//!
+//! The voters of the Raft group are fixed, every node must be configured with the same
+//! [`RaftConfig::voters`], so the quorum never depends on which nodes a node can currently see.
+//! Once the group has started, voters are added or removed one at a time via
+//! [`RaftSystem::add_voter`] and [`RaftSystem::remove_voter`], which append the new voters to the
+//! log, taking effect on each node as soon as it receives the entry. Every other node known by the
+//! [`RemoteRegistry`] is a learner, it receives the log from the leader but doesn't vote, and
+//! isn't counted towards the quorum.
+//!
+//! Each node's term, vote and log are persisted via the actor system's journal storage before it
+//! responds to any Raft request, so the actor system should be made persistent, see
+//! [`ActorSystem::to_persistent`]. Without persistence, the state is held in memory only, and a
+//! restarted node could vote twice in the same term. If the state can't be recovered when the
+//! node starts, the Raft actor is stopped and the node never takes part in the group.
+//!
+//! [`RemoteSystemConfigBuilder::raft`]: crate::remote::system::builder::RemoteSystemConfigBuilder::raft
+//! [`SessionEvent::Raft`]: crate::remote::net::message::SessionEvent::Raft
+//! [`RemoteRegistry`]: crate::remote::actor::registry::RemoteRegistry
+//! [`ActorSystem::to_persistent`]: crate::actor::system::ActorSystem::to_persistent
+
+use crate::actor::system::ActorSystem;
+use crate::actor::{IntoActor, LocalActorRef};
+use crate::remote::raft::metadata::{ClusterMetadata, MetadataCommand};
+use crate::remote::raft::node::{ChangeVoters, GetStatus, Propose, Raft, VoterChange};
+use crate::remote::raft::storage::RaftStorage;
+use crate::remote::system::NodeId;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+pub mod log;
+pub mod metadata;
+pub mod node;
+pub(crate) mod rpc;
+pub mod storage;
+
+#[derive(Clone, Debug)]
+pub struct RaftConfig {
+    /// Minimum amount of time a follower waits without hearing from a leader before
+    /// starting an election.
+    pub election_timeout_min: Duration,
+
+    /// Maximum amount of time a follower waits without hearing from a leader before
+    /// starting an election, the actual timeout is randomised between the min and max.
+    pub election_timeout_max: Duration,
+
+    /// How often the leader replicates its log to (or heartbeats) each follower.
+    pub heartbeat_interval: Duration,
+
+    /// How long to wait for a response to a Raft request before retrying.
+    pub rpc_timeout: Duration,
+
+    /// Number of applied log entries after which the log is compacted into a snapshot.
+    pub snapshot_threshold: u64,
+
+    /// Maximum number of log entries sent to a follower in a single request.
+    pub max_entries_per_request: usize,
+
+    /// The voters of the group when it first starts, every node must be configured with the same
+    /// voters. Once the log contains a change of voters, this is ignored.
+    pub voters: BTreeSet<NodeId>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RaftRole {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+#[derive(Clone, Debug)]
+pub struct RaftStatus {
+    pub node_id: NodeId,
+    pub role: RaftRole,
+    pub term: u64,
+    pub leader_id: Option<NodeId>,
+    pub voters: Vec<NodeId>,
+    pub commit_index: u64,
+    pub last_applied: u64,
+    pub last_log_index: u64,
+    pub snapshot_index: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RaftErr {
+    /// Only the leader can accept proposals, contains the current leader, if one is known.
+    NotLeader(Option<NodeId>),
+
+    /// The previous change of voters, or the leader's election, hasn't been committed yet.
+    MembershipChangeInProgress,
+
+    /// The group must always have at least one voter.
+    LastVoter,
+    Stopped,
+}
+
+/// A handle to the node's Raft actor, and the metadata replicated by it.
+#[derive(Clone)]
+pub struct RaftSystem {
+    actor_ref: LocalActorRef<Raft>,
+    metadata: ClusterMetadata,
+}
+
+impl Default for RaftConfig {
+    fn default() -> Self {
+        Self {
+            election_timeout_min: Duration::from_millis(1500),
+            election_timeout_max: Duration::from_millis(3000),
+            heartbeat_interval: Duration::from_millis(250),
+            rpc_timeout: Duration::from_secs(1),
+            snapshot_threshold: 1000,
+            max_entries_per_request: 256,
+            voters: BTreeSet::new(),
+        }
+    }
+}
+
+impl RaftSystem {
+    pub async fn start(node_id: NodeId, config: RaftConfig, system: &ActorSystem) -> RaftSystem {
+        let storage = RaftStorage::new(node_id, system);
+        let metadata = ClusterMetadata::default();
+        let actor_ref = Raft::new(node_id, config, metadata.clone(), storage)
+            .into_actor(Some("raft"), system)
+            .await
+            .expect("raft actor");
+
+        RaftSystem {
+            actor_ref,
+            metadata,
+        }
+    }
+
+    pub fn actor_ref(&self) -> &LocalActorRef<Raft> {
+        &self.actor_ref
+    }
+
+    /// The metadata applied on this node, which may lag behind the leader.
+    pub fn metadata(&self) -> &ClusterMetadata {
+        &self.metadata
+    }
+
+    /// Sets `key` to `value`, returning once the change has been committed by a majority of nodes.
+    pub async fn set(&self, key: impl ToString, value: Vec<u8>) -> Result<(), RaftErr> {
+        self.propose(MetadataCommand::Set {
+            key: key.to_string(),
+            value,
+        })
+        .await
+    }
+
+    pub async fn delete(&self, key: impl ToString) -> Result<(), RaftErr> {
+        self.propose(MetadataCommand::Delete {
+            key: key.to_string(),
+        })
+        .await
+    }
+
+    pub async fn propose(&self, command: MetadataCommand) -> Result<(), RaftErr> {
+        let (tx, rx) = oneshot::channel();
+        self.actor_ref
+            .notify(Propose(command.to_bytes(), tx))
+            .map_err(|_| RaftErr::Stopped)?;
+
+        rx.await.unwrap_or(Err(RaftErr::Stopped))
+    }
+
+    /// Adds `node_id` to the voters, returning once the change has been committed.
+    pub async fn add_voter(&self, node_id: NodeId) -> Result<(), RaftErr> {
+        self.change_voters(VoterChange::Add(node_id)).await
+    }
+
+    /// Removes `node_id` from the voters, returning once the change has been committed. A leader
+    /// that removes itself steps down once the change has been committed.
+    pub async fn remove_voter(&self, node_id: NodeId) -> Result<(), RaftErr> {
+        self.change_voters(VoterChange::Remove(node_id)).await
+    }
+
+    async fn change_voters(&self, change: VoterChange) -> Result<(), RaftErr> {
+        let (tx, rx) = oneshot::channel();
+        self.actor_ref
+            .notify(ChangeVoters(change, tx))
+            .map_err(|_| RaftErr::Stopped)?;
+
+        rx.await.unwrap_or(Err(RaftErr::Stopped))
+    }
+
+    pub async fn status(&self) -> Result<RaftStatus, RaftErr> {
+        self.actor_ref
+            .send(GetStatus)
+            .await
+            .map_err(|_| RaftErr::Stopped)
+    }
+}
+
+impl RaftRole {
+    pub fn is_leader(&self) -> bool {
+        matches!(self, RaftRole::Leader)
+    }
+}
+
+impl Display for RaftErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaftErr::NotLeader(Some(leader_id)) => {
+                write!(f, "not the raft leader (leader_id={})", leader_id)
+            }
+            RaftErr::NotLeader(None) => write!(f, "not the raft leader (no leader elected)"),
+            RaftErr::MembershipChangeInProgress => {
+                write!(f, "a change of raft voters is already in progress")
+            }
+            RaftErr::LastVoter => write!(f, "cannot remove the last raft voter"),
+            RaftErr::Stopped => write!(f, "raft actor stopped"),
+        }
+    }
+}
+
+impl Error for RaftErr {}
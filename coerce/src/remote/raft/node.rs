@@ -0,0 +1,1080 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::scheduler::timer::{Timer, TimerTick};
+use crate::actor::{Actor, LocalActorRef};
+use crate::remote::actor::message::SetRemote;
+use crate::remote::heartbeat::LeaderElected;
+use crate::remote::net::proto::network::{
+    RaftAppendEntriesRequest, RaftAppendEntriesResponse, RaftInstallSnapshotRequest,
+    RaftInstallSnapshotResponse, RaftRequest, RaftVoteRequest, RaftVoteResponse,
+};
+use crate::remote::raft::log::{self, RaftLog, Snapshot};
+use crate::remote::raft::metadata::{ClusterMetadata, MetadataCommand};
+use crate::remote::raft::rpc::{self, RaftRpcRequest};
+use crate::remote::raft::storage::{RaftStorage, RecoveredState};
+use crate::remote::raft::{RaftConfig, RaftErr, RaftRole, RaftStatus};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use protobuf::Message as ProtoMessage;
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::time::Instant;
+
+/// A single member of the Raft group, see [`crate::remote::raft`].
+pub struct Raft {
+    node_id: NodeId,
+    config: RaftConfig,
+    system: Option<RemoteActorSystem>,
+    tick_timer: Option<Timer>,
+    role: RaftRole,
+    term: u64,
+    voted_for: Option<NodeId>,
+    leader_id: Option<NodeId>,
+    elected_leader: Option<NodeId>,
+    log: RaftLog,
+    commit_index: u64,
+    last_applied: u64,
+    metadata: ClusterMetadata,
+    storage: RaftStorage,
+    voters: BTreeSet<NodeId>,
+    votes: HashSet<NodeId>,
+    peers: HashMap<NodeId, Peer>,
+    pending: BTreeMap<u64, PendingProposal>,
+    election_deadline: Instant,
+    last_leader_contact: Option<Instant>,
+}
+
+/// The leader's view of a follower's log, followers that aren't voters are learners, which receive
+/// the log but aren't counted towards the quorum.
+struct Peer {
+    next_index: u64,
+    match_index: u64,
+    in_flight: bool,
+    last_contact: Instant,
+}
+
+struct PendingProposal {
+    term: u64,
+    result: oneshot::Sender<Result<(), RaftErr>>,
+}
+
+impl Raft {
+    pub fn new(
+        node_id: NodeId,
+        config: RaftConfig,
+        metadata: ClusterMetadata,
+        storage: RaftStorage,
+    ) -> Raft {
+        let voters = config.voters.clone();
+        Raft {
+            node_id,
+            config,
+            system: None,
+            tick_timer: None,
+            role: RaftRole::Follower,
+            term: 0,
+            voted_for: None,
+            leader_id: None,
+            elected_leader: None,
+            log: RaftLog::new(),
+            commit_index: 0,
+            last_applied: 0,
+            metadata,
+            storage,
+            voters,
+            votes: HashSet::new(),
+            peers: HashMap::new(),
+            pending: BTreeMap::new(),
+            election_deadline: Instant::now(),
+            last_leader_contact: None,
+        }
+    }
+}
+
+impl Actor for Raft {}
+
+#[derive(Clone)]
+struct RaftTick;
+
+impl Message for RaftTick {
+    type Result = ();
+}
+
+impl TimerTick for RaftTick {}
+
+pub struct GetStatus;
+
+impl Message for GetStatus {
+    type Result = RaftStatus;
+}
+
+pub(crate) struct Propose(pub Vec<u8>, pub oneshot::Sender<Result<(), RaftErr>>);
+
+impl Message for Propose {
+    type Result = ();
+}
+
+pub(crate) enum VoterChange {
+    Add(NodeId),
+    Remove(NodeId),
+}
+
+pub(crate) struct ChangeVoters(pub VoterChange, pub oneshot::Sender<Result<(), RaftErr>>);
+
+impl Message for ChangeVoters {
+    type Result = ();
+}
+
+/// A Raft request received from another node, the result is the encoded response.
+pub(crate) struct RaftRpc(pub RaftRequest);
+
+impl Message for RaftRpc {
+    type Result = Option<Vec<u8>>;
+}
+
+struct VoteReceived {
+    from: NodeId,
+    term: u64,
+    response: Option<RaftVoteResponse>,
+}
+
+impl Message for VoteReceived {
+    type Result = ();
+}
+
+struct AppendEntriesReceived {
+    from: NodeId,
+    term: u64,
+    response: Option<RaftAppendEntriesResponse>,
+}
+
+impl Message for AppendEntriesReceived {
+    type Result = ();
+}
+
+struct InstallSnapshotReceived {
+    from: NodeId,
+    term: u64,
+    snapshot_index: u64,
+    response: Option<RaftInstallSnapshotResponse>,
+}
+
+impl Message for InstallSnapshotReceived {
+    type Result = ();
+}
+
+#[async_trait]
+impl Handler<SetRemote> for Raft {
+    async fn handle(&mut self, message: SetRemote, ctx: &mut ActorContext) {
+        match self.storage.recover().await {
+            Ok(state) => self.restore(state),
+            Err(e) => {
+                error!(
+                    "[node={}] failed to recover raft state, raft will not be started, e={}",
+                    self.node_id, e
+                );
+
+                ctx.stop(None);
+                return;
+            }
+        }
+
+        debug!(
+            "[node={}] starting raft (heartbeat_interval={} millis, term={}, voters={:?})",
+            self.node_id,
+            self.config.heartbeat_interval.as_millis(),
+            self.term,
+            &self.voters
+        );
+
+        if !self.storage.is_persistent() {
+            warn!(
+                "[node={}] raft state is not persisted, a restarted node may vote twice in the same term",
+                self.node_id
+            );
+        }
+
+        self.system = Some(message.0);
+        self.reset_election_deadline();
+        self.tick_timer = Some(Timer::start(
+            self.actor_ref(ctx),
+            self.config.heartbeat_interval,
+            RaftTick,
+        ));
+    }
+}
+
+#[async_trait]
+impl Handler<RaftTick> for Raft {
+    async fn handle(&mut self, _message: RaftTick, ctx: &mut ActorContext) {
+        match self.role {
+            RaftRole::Leader => {
+                if self.check_quorum() {
+                    self.refresh_learners().await;
+                    self.replicate(ctx);
+                }
+            }
+            RaftRole::Follower | RaftRole::Candidate => {
+                if Instant::now() >= self.election_deadline {
+                    self.start_election(ctx).await;
+                }
+            }
+        }
+
+        self.notify_leader_changed();
+    }
+}
+
+#[async_trait]
+impl Handler<GetStatus> for Raft {
+    async fn handle(&mut self, _message: GetStatus, _ctx: &mut ActorContext) -> RaftStatus {
+        RaftStatus {
+            node_id: self.node_id,
+            role: self.role,
+            term: self.term,
+            leader_id: self.leader_id,
+            voters: self.voters.iter().copied().collect(),
+            commit_index: self.commit_index,
+            last_applied: self.last_applied,
+            last_log_index: self.log.last_index(),
+            snapshot_index: self.log.snapshot().index,
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<Propose> for Raft {
+    async fn handle(&mut self, message: Propose, ctx: &mut ActorContext) {
+        let Propose(command, result) = message;
+        if !self.role.is_leader() {
+            let _ = result.send(Err(RaftErr::NotLeader(self.leader_id)));
+            return;
+        }
+
+        let index = self.log.append(self.term, command);
+        self.pending.insert(
+            index,
+            PendingProposal {
+                term: self.term,
+                result,
+            },
+        );
+
+        if !self.persist().await {
+            self.step_down(self.term);
+            return;
+        }
+
+        self.advance_commit();
+        self.replicate(ctx);
+    }
+}
+
+#[async_trait]
+impl Handler<ChangeVoters> for Raft {
+    async fn handle(&mut self, message: ChangeVoters, ctx: &mut ActorContext) {
+        let ChangeVoters(change, result) = message;
+        if !self.role.is_leader() {
+            let _ = result.send(Err(RaftErr::NotLeader(self.leader_id)));
+            return;
+        }
+
+        // voters are changed one at a time, a change can only be made once the previous change,
+        // and an entry from the leader's own term, have been committed
+        if self.log.last_configuration_index() > self.commit_index
+            || self.log.term_at(self.commit_index) != Some(self.term)
+        {
+            let _ = result.send(Err(RaftErr::MembershipChangeInProgress));
+            return;
+        }
+
+        let mut voters = self.voters.clone();
+        let changed = match change {
+            VoterChange::Add(node_id) => voters.insert(node_id),
+            VoterChange::Remove(node_id) => voters.remove(&node_id),
+        };
+
+        if !changed {
+            let _ = result.send(Ok(()));
+            return;
+        }
+
+        if voters.is_empty() {
+            let _ = result.send(Err(RaftErr::LastVoter));
+            return;
+        }
+
+        info!(
+            "[node={}] changing raft voters (term={}, voters={:?})",
+            self.node_id, self.term, &voters
+        );
+
+        let index = self.log.append_configuration(self.term, &voters);
+        self.pending.insert(
+            index,
+            PendingProposal {
+                term: self.term,
+                result,
+            },
+        );
+
+        self.update_voters();
+        if !self.persist().await {
+            self.step_down(self.term);
+            return;
+        }
+
+        self.add_peers(self.voters.clone());
+        self.advance_commit();
+        self.replicate(ctx);
+    }
+}
+
+#[async_trait]
+impl Handler<RaftRpc> for Raft {
+    async fn handle(&mut self, message: RaftRpc, _ctx: &mut ActorContext) -> Option<Vec<u8>> {
+        let response = match RaftRpcRequest::from_proto(&message.0)? {
+            RaftRpcRequest::Vote(request) => self.on_vote_request(request).write_to_bytes(),
+            RaftRpcRequest::AppendEntries(request) => {
+                self.on_append_entries(request).write_to_bytes()
+            }
+            RaftRpcRequest::InstallSnapshot(request) => {
+                self.on_install_snapshot(request).write_to_bytes()
+            }
+        };
+
+        // the term, vote and log must be durable before the response is sent, if they can't be
+        // persisted, no response is sent and the sender will retry
+        if !self.persist().await {
+            return None;
+        }
+
+        response.ok()
+    }
+}
+
+#[async_trait]
+impl Handler<VoteReceived> for Raft {
+    async fn handle(&mut self, message: VoteReceived, ctx: &mut ActorContext) {
+        let response = match message.response {
+            Some(response) => response,
+            None => return,
+        };
+
+        if response.term > self.term {
+            self.step_down(response.term);
+            return;
+        }
+
+        if self.role != RaftRole::Candidate || message.term != self.term || !response.vote_granted {
+            return;
+        }
+
+        self.votes.insert(message.from);
+
+        let votes = self
+            .votes
+            .iter()
+            .filter(|id| self.voters.contains(id))
+            .count();
+
+        if self.has_quorum(votes) {
+            self.become_leader(ctx).await;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<AppendEntriesReceived> for Raft {
+    async fn handle(&mut self, message: AppendEntriesReceived, ctx: &mut ActorContext) {
+        let peer = match self.peers.get_mut(&message.from) {
+            Some(peer) => peer,
+            None => return,
+        };
+
+        peer.in_flight = false;
+
+        let response = match message.response {
+            Some(response) => response,
+            None => return,
+        };
+
+        if response.term > self.term {
+            self.step_down(response.term);
+            return;
+        }
+
+        if message.term != self.term {
+            return;
+        }
+
+        peer.last_contact = Instant::now();
+        if response.success {
+            peer.match_index = peer.match_index.max(response.match_index);
+            peer.next_index = peer.match_index + 1;
+        } else {
+            // the follower's log doesn't contain the entry preceding the ones we sent,
+            // step back and try again
+            peer.next_index = (peer.next_index - 1).min(response.match_index + 1).max(1);
+        }
+
+        let next_index = peer.next_index;
+
+        self.advance_commit();
+        if next_index <= self.log.last_index() {
+            self.send_to_peer(message.from, ctx);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<InstallSnapshotReceived> for Raft {
+    async fn handle(&mut self, message: InstallSnapshotReceived, ctx: &mut ActorContext) {
+        let peer = match self.peers.get_mut(&message.from) {
+            Some(peer) => peer,
+            None => return,
+        };
+
+        peer.in_flight = false;
+
+        let response = match message.response {
+            Some(response) => response,
+            None => return,
+        };
+
+        if response.term > self.term {
+            self.step_down(response.term);
+            return;
+        }
+
+        if message.term != self.term {
+            return;
+        }
+
+        // the snapshot is sent again on the next tick
+        peer.last_contact = Instant::now();
+        if !response.success {
+            return;
+        }
+
+        peer.match_index = peer.match_index.max(message.snapshot_index);
+        peer.next_index = peer.match_index + 1;
+
+        let next_index = peer.next_index;
+
+        self.advance_commit();
+        if next_index <= self.log.last_index() {
+            self.send_to_peer(message.from, ctx);
+        }
+    }
+}
+
+impl Raft {
+    fn restore(&mut self, state: RecoveredState) {
+        let RecoveredState {
+            term,
+            voted_for,
+            log,
+        } = state;
+
+        // the snapshot only contains committed entries, the remaining entries are applied once
+        // they've been committed by the leader again
+        let snapshot_index = log.snapshot().index;
+        if snapshot_index > 0 && !self.metadata.restore(&log.snapshot().data) {
+            warn!(
+                "[node={}] failed to decode recovered raft snapshot (index={})",
+                self.node_id, snapshot_index
+            );
+        }
+
+        self.term = term;
+        self.voted_for = voted_for;
+        self.log = log;
+        self.commit_index = snapshot_index;
+        self.last_applied = snapshot_index;
+        self.update_voters();
+    }
+
+    /// Replicates the log to every node known by the registry, nodes that aren't voters are
+    /// learners, which aren't counted towards the quorum.
+    async fn refresh_learners(&mut self) {
+        let system = match &self.system {
+            Some(system) => system,
+            None => return,
+        };
+
+        let mut nodes: BTreeSet<NodeId> = system
+            .get_nodes()
+            .await
+            .into_iter()
+            .map(|node| node.id)
+            .collect();
+
+        nodes.extend(self.voters.iter().copied());
+        nodes.remove(&self.node_id);
+
+        self.peers.retain(|id, _| nodes.contains(id));
+        self.add_peers(nodes);
+    }
+
+    fn add_peers(&mut self, nodes: BTreeSet<NodeId>) {
+        let next_index = self.log.last_index() + 1;
+        let now = Instant::now();
+        for node_id in nodes {
+            if node_id != self.node_id {
+                self.peers.entry(node_id).or_insert(Peer {
+                    next_index,
+                    match_index: 0,
+                    in_flight: false,
+                    last_contact: now,
+                });
+            }
+        }
+    }
+
+    /// Whether the leader has heard from a majority of voters within the election timeout,
+    /// stepping down if it hasn't, so a leader that has been partitioned from the majority
+    /// doesn't carry on acting as the leader once the majority has elected a new one.
+    fn check_quorum(&mut self) -> bool {
+        let timeout = self.config.election_timeout_min;
+        let active = self
+            .voters
+            .iter()
+            .filter(|id| {
+                **id == self.node_id
+                    || self
+                        .peers
+                        .get(id)
+                        .is_some_and(|peer| peer.last_contact.elapsed() < timeout)
+            })
+            .count();
+
+        if self.has_quorum(active) {
+            return true;
+        }
+
+        warn!(
+            "[node={}] raft leader hasn't heard from a majority of voters (term={}, voters={:?}), stepping down",
+            self.node_id, self.term, &self.voters
+        );
+
+        self.step_down(self.term);
+        false
+    }
+
+    /// The voters are the ones in the latest configuration in the log, whether or not it has
+    /// been committed, or the configured voters if the log doesn't contain a configuration.
+    fn update_voters(&mut self) {
+        let voters = self
+            .log
+            .voters()
+            .unwrap_or_else(|| self.config.voters.clone());
+
+        if voters != self.voters {
+            debug!(
+                "[node={}] raft voters changed, voters={:?}",
+                self.node_id, &voters
+            );
+
+            self.voters = voters;
+        }
+    }
+
+    async fn start_election(&mut self, ctx: &ActorContext) {
+        self.reset_election_deadline();
+
+        // the leader hasn't been heard from within the election timeout
+        self.leader_id = None;
+
+        // learners receive the log, but never stand for election
+        if !self.voters.contains(&self.node_id) {
+            return;
+        }
+
+        // nodes without any of the configured leader roles vote, but never stand for election
+        if let Some(system) = &self.system {
+            let config = system.config();
+            if !config.is_leader_eligible(config.get_roles()) {
+                return;
+            }
+        }
+
+        self.term += 1;
+        self.role = RaftRole::Candidate;
+        self.voted_for = Some(self.node_id);
+        self.leader_id = None;
+        self.votes = HashSet::from([self.node_id]);
+
+        debug!(
+            "[node={}] starting raft election (term={}, voters={:?})",
+            self.node_id, self.term, &self.voters
+        );
+
+        // the vote for ourselves must be durable before asking for votes, otherwise after a
+        // restart we could vote for another candidate in the same term
+        if !self.persist().await {
+            return;
+        }
+
+        if self.has_quorum(self.votes.len()) {
+            self.become_leader(ctx).await;
+            return;
+        }
+
+        let request = RaftVoteRequest {
+            term: self.term,
+            candidate_id: self.node_id,
+            last_log_index: self.log.last_index(),
+            last_log_term: self.log.last_term(),
+            ..Default::default()
+        };
+
+        for voter in self.voters.iter().filter(|id| **id != self.node_id) {
+            let from = *voter;
+            let term = self.term;
+            let request = RaftRpcRequest::Vote(request.clone());
+
+            self.spawn_rpc(ctx, from, request, move |response| VoteReceived {
+                from,
+                term,
+                response,
+            });
+        }
+    }
+
+    async fn become_leader(&mut self, ctx: &ActorContext) {
+        info!(
+            "[node={}] elected raft leader (term={}, voters={:?})",
+            self.node_id, self.term, &self.voters
+        );
+
+        self.role = RaftRole::Leader;
+        self.leader_id = Some(self.node_id);
+        self.peers.clear();
+        self.add_peers(self.voters.clone());
+
+        // entries from previous terms can only be committed once an entry from the
+        // current term has been committed
+        self.log.append(self.term, vec![]);
+        if !self.persist().await {
+            self.step_down(self.term);
+            return;
+        }
+
+        self.on_leader_contact();
+        self.advance_commit();
+        self.replicate(ctx);
+    }
+
+    fn step_down(&mut self, term: u64) {
+        if term > self.term {
+            self.term = term;
+            self.voted_for = None;
+        }
+
+        if self.role.is_leader() {
+            info!(
+                "[node={}] stepping down as raft leader (term={})",
+                self.node_id, self.term
+            );
+        }
+
+        for (_, proposal) in std::mem::take(&mut self.pending) {
+            let _ = proposal.result.send(Err(RaftErr::NotLeader(None)));
+        }
+
+        self.role = RaftRole::Follower;
+        self.leader_id = None;
+        self.peers.clear();
+        self.votes.clear();
+        self.reset_election_deadline();
+    }
+
+    /// Records that a leader has been heard from, notifying the heartbeat actor if the leader
+    /// has changed.
+    fn on_leader_contact(&mut self) {
+        if !self.role.is_leader() {
+            self.last_leader_contact = Some(Instant::now());
+            self.reset_election_deadline();
+        }
+
+        self.notify_leader_changed();
+    }
+
+    /// Notifies the heartbeat actor when the leader changes, including when there's no longer
+    /// a known leader, so a leader that has stepped down stops being published as the leader.
+    fn notify_leader_changed(&mut self) {
+        if self.leader_id == self.elected_leader {
+            return;
+        }
+
+        self.elected_leader = self.leader_id;
+        if let Some(system) = &self.system {
+            let _ = system.heartbeat().notify(LeaderElected(self.leader_id));
+        }
+    }
+
+    fn replicate(&mut self, ctx: &ActorContext) {
+        let peers: Vec<NodeId> = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| !peer.in_flight)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for peer in peers {
+            self.send_to_peer(peer, ctx);
+        }
+    }
+
+    fn send_to_peer(&mut self, peer_id: NodeId, ctx: &ActorContext) {
+        let peer = match self.peers.get_mut(&peer_id) {
+            Some(peer) => peer,
+            None => return,
+        };
+
+        peer.in_flight = true;
+
+        let term = self.term;
+        let snapshot = self.log.snapshot();
+        if peer.next_index <= snapshot.index {
+            let snapshot_index = snapshot.index;
+            let request = RaftRpcRequest::InstallSnapshot(RaftInstallSnapshotRequest {
+                term,
+                leader_id: self.node_id,
+                last_included_index: snapshot.index,
+                last_included_term: snapshot.term,
+                data: snapshot.data.clone(),
+                configuration: snapshot.voters.as_ref().map(log::configuration).into(),
+                ..Default::default()
+            });
+
+            self.spawn_rpc(ctx, peer_id, request, move |response| {
+                InstallSnapshotReceived {
+                    from: peer_id,
+                    term,
+                    snapshot_index,
+                    response,
+                }
+            });
+        } else {
+            let prev_log_index = peer.next_index - 1;
+            let request = RaftRpcRequest::AppendEntries(RaftAppendEntriesRequest {
+                term,
+                leader_id: self.node_id,
+                prev_log_index,
+                prev_log_term: self.log.term_at(prev_log_index).unwrap_or(0),
+                entries: self
+                    .log
+                    .entries_from(peer.next_index, self.config.max_entries_per_request),
+                leader_commit: self.commit_index,
+                ..Default::default()
+            });
+
+            self.spawn_rpc(ctx, peer_id, request, move |response| {
+                AppendEntriesReceived {
+                    from: peer_id,
+                    term,
+                    response,
+                }
+            });
+        }
+    }
+
+    /// Sends `request` to `node_id` without blocking the actor, the response is sent back to the
+    /// actor as the message created by `on_response`.
+    fn spawn_rpc<Res, M, F>(
+        &self,
+        ctx: &ActorContext,
+        node_id: NodeId,
+        request: RaftRpcRequest,
+        on_response: F,
+    ) where
+        Res: ProtoMessage,
+        M: Message<Result = ()>,
+        Raft: Handler<M>,
+        F: 'static + Send + FnOnce(Option<Res>) -> M,
+    {
+        let system = match &self.system {
+            Some(system) => system.clone(),
+            None => return,
+        };
+
+        let actor_ref: LocalActorRef<Raft> = self.actor_ref(ctx);
+        let timeout = self.config.rpc_timeout;
+        tokio::spawn(async move {
+            let response = rpc::send::<Res>(&system, node_id, request, timeout).await;
+            let _ = actor_ref.notify(on_response(response));
+        });
+    }
+
+    /// Commits the latest entry from the current term that has been replicated to a majority
+    /// of voters.
+    fn advance_commit(&mut self) {
+        if !self.role.is_leader() {
+            return;
+        }
+
+        let mut index = self.log.last_index();
+        while index > self.commit_index && self.log.term_at(index) == Some(self.term) {
+            let replicated = self
+                .voters
+                .iter()
+                .filter(|id| {
+                    **id == self.node_id
+                        || self
+                            .peers
+                            .get(id)
+                            .is_some_and(|peer| peer.match_index >= index)
+                })
+                .count();
+
+            if self.has_quorum(replicated) {
+                self.commit_index = index;
+                break;
+            }
+
+            index -= 1;
+        }
+
+        self.apply();
+
+        // a leader that has been removed from the voters steps down once the change is committed
+        if !self.voters.contains(&self.node_id)
+            && self.commit_index >= self.log.last_configuration_index()
+        {
+            self.step_down(self.term);
+        }
+    }
+
+    fn apply(&mut self) {
+        while self.last_applied < self.commit_index {
+            let index = self.last_applied + 1;
+            let entry = match self.log.get(index) {
+                Some(entry) => entry,
+                None => break,
+            };
+
+            let term = entry.term;
+            if !entry.command.is_empty() {
+                match MetadataCommand::from_bytes(&entry.command) {
+                    Some(command) => self.metadata.apply(command),
+                    None => warn!(
+                        "[node={}] failed to decode raft log entry (index={})",
+                        self.node_id, index
+                    ),
+                }
+            }
+
+            self.last_applied = index;
+
+            if let Some(proposal) = self.pending.remove(&index) {
+                let _ = proposal.result.send(if proposal.term == term {
+                    Ok(())
+                } else {
+                    Err(RaftErr::NotLeader(self.leader_id))
+                });
+            }
+        }
+
+        if self.last_applied - self.log.snapshot().index >= self.config.snapshot_threshold {
+            debug!(
+                "[node={}] compacting raft log (last_applied={})",
+                self.node_id, self.last_applied
+            );
+
+            self.log
+                .compact(self.last_applied, self.metadata.snapshot());
+        }
+    }
+
+    fn on_vote_request(&mut self, request: RaftVoteRequest) -> RaftVoteResponse {
+        // nodes that have recently heard from a leader ignore vote requests, so nodes that have
+        // been partitioned from the leader can't disrupt the cluster once they rejoin
+        let leader_alive = match self.role {
+            RaftRole::Leader => true,
+            RaftRole::Candidate => false,
+            RaftRole::Follower => self
+                .last_leader_contact
+                .is_some_and(|t| t.elapsed() < self.config.election_timeout_min),
+        };
+
+        if leader_alive {
+            return RaftVoteResponse {
+                term: self.term,
+                vote_granted: false,
+                ..Default::default()
+            };
+        }
+
+        if request.term > self.term {
+            self.step_down(request.term);
+        }
+
+        let log_up_to_date = (request.last_log_term, request.last_log_index)
+            >= (self.log.last_term(), self.log.last_index());
+
+        let can_vote = match self.voted_for {
+            Some(voted_for) => voted_for == request.candidate_id,
+            None => true,
+        };
+
+        let vote_granted = request.term == self.term && can_vote && log_up_to_date;
+
+        if vote_granted {
+            self.voted_for = Some(request.candidate_id);
+            self.reset_election_deadline();
+        }
+
+        RaftVoteResponse {
+            term: self.term,
+            vote_granted,
+            ..Default::default()
+        }
+    }
+
+    fn on_append_entries(
+        &mut self,
+        request: RaftAppendEntriesRequest,
+    ) -> RaftAppendEntriesResponse {
+        let reject = |term, match_index| RaftAppendEntriesResponse {
+            term,
+            success: false,
+            match_index,
+            ..Default::default()
+        };
+
+        if !self.accept_leader(request.term, request.leader_id) {
+            return reject(self.term, self.log.last_index());
+        }
+
+        let prev_log_index = request.prev_log_index;
+        if prev_log_index > self.log.last_index() {
+            return reject(self.term, self.log.last_index());
+        }
+
+        if prev_log_index >= self.log.snapshot().index
+            && self.log.term_at(prev_log_index) != Some(request.prev_log_term)
+        {
+            return reject(self.term, prev_log_index.saturating_sub(1));
+        }
+
+        let last_new_index = prev_log_index + request.entries.len() as u64;
+        self.log.append_entries(request.entries);
+        self.update_voters();
+
+        if request.leader_commit > self.commit_index {
+            self.commit_index = self
+                .commit_index
+                .max(request.leader_commit.min(last_new_index));
+            self.apply();
+        }
+
+        RaftAppendEntriesResponse {
+            term: self.term,
+            success: true,
+            match_index: last_new_index,
+            ..Default::default()
+        }
+    }
+
+    fn on_install_snapshot(
+        &mut self,
+        request: RaftInstallSnapshotRequest,
+    ) -> RaftInstallSnapshotResponse {
+        let reject = |term| RaftInstallSnapshotResponse {
+            term,
+            success: false,
+            ..Default::default()
+        };
+
+        if !self.accept_leader(request.term, request.leader_id) {
+            return reject(self.term);
+        }
+
+        // the snapshot only contains committed entries, if they've already been committed here,
+        // there's nothing to install
+        if request.last_included_index > self.commit_index {
+            if self.metadata.restore(&request.data) {
+                debug!(
+                    "[node={}] installed raft snapshot (index={}, term={})",
+                    self.node_id, request.last_included_index, request.last_included_term
+                );
+
+                self.log.install_snapshot(Snapshot {
+                    index: request.last_included_index,
+                    term: request.last_included_term,
+                    data: request.data,
+                    voters: request.configuration.as_ref().map(log::voters),
+                });
+
+                self.update_voters();
+
+                self.commit_index = request.last_included_index;
+                self.last_applied = request.last_included_index;
+            } else {
+                warn!(
+                    "[node={}] failed to decode raft snapshot (index={})",
+                    self.node_id, request.last_included_index
+                );
+
+                return reject(self.term);
+            }
+        }
+
+        RaftInstallSnapshotResponse {
+            term: self.term,
+            success: true,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a request from a leader in `term` should be accepted, stepping down if the leader
+    /// is in a newer term.
+    fn accept_leader(&mut self, term: u64, leader_id: NodeId) -> bool {
+        if term < self.term {
+            return false;
+        }
+
+        if term > self.term || self.role != RaftRole::Follower {
+            self.step_down(term);
+        }
+
+        self.leader_id = Some(leader_id);
+        self.on_leader_contact();
+        true
+    }
+
+    /// Writes any changes to the term, vote or log to storage, returning false if they
+    /// couldn't be persisted.
+    async fn persist(&mut self) -> bool {
+        match self
+            .storage
+            .persist(self.term, self.voted_for, &mut self.log)
+            .await
+        {
+            Ok(()) => true,
+            Err(e) => {
+                error!(
+                    "[node={}] failed to persist raft state (term={}), e={}",
+                    self.node_id, self.term, e
+                );
+
+                false
+            }
+        }
+    }
+
+    fn has_quorum(&self, count: usize) -> bool {
+        count > self.voters.len() / 2
+    }
+
+    fn reset_election_deadline(&mut self) {
+        let min = self.config.election_timeout_min;
+        let max = self.config.election_timeout_max.max(min);
+        let timeout = rand::thread_rng().gen_range(min.as_millis()..=max.as_millis());
+
+        self.election_deadline = Instant::now() + Duration::from_millis(timeout as u64);
+    }
+}
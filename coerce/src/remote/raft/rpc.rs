@@ -0,0 +1,93 @@
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::{
+    RaftAppendEntriesRequest, RaftInstallSnapshotRequest, RaftRequest, RaftVoteRequest,
+};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use protobuf::Message;
+use std::time::Duration;
+use uuid::Uuid;
+
+const APPEND_ENTRIES: u32 = 1;
+const INSTALL_SNAPSHOT: u32 = 2;
+const VOTE: u32 = 3;
+
+#[derive(Debug)]
+pub(crate) enum RaftRpcRequest {
+    AppendEntries(RaftAppendEntriesRequest),
+    InstallSnapshot(RaftInstallSnapshotRequest),
+    Vote(RaftVoteRequest),
+}
+
+impl RaftRpcRequest {
+    fn request_type(&self) -> u32 {
+        match self {
+            RaftRpcRequest::AppendEntries(_) => APPEND_ENTRIES,
+            RaftRpcRequest::InstallSnapshot(_) => INSTALL_SNAPSHOT,
+            RaftRpcRequest::Vote(_) => VOTE,
+        }
+    }
+
+    fn write_to_bytes(&self) -> Vec<u8> {
+        let bytes = match self {
+            RaftRpcRequest::AppendEntries(request) => request.write_to_bytes(),
+            RaftRpcRequest::InstallSnapshot(request) => request.write_to_bytes(),
+            RaftRpcRequest::Vote(request) => request.write_to_bytes(),
+        };
+
+        bytes.expect("serialise raft request")
+    }
+
+    pub fn from_proto(request: &RaftRequest) -> Option<RaftRpcRequest> {
+        let payload = request.payload.as_slice();
+        match request.request_type {
+            APPEND_ENTRIES => RaftAppendEntriesRequest::parse_from_bytes(payload)
+                .ok()
+                .map(RaftRpcRequest::AppendEntries),
+            INSTALL_SNAPSHOT => RaftInstallSnapshotRequest::parse_from_bytes(payload)
+                .ok()
+                .map(RaftRpcRequest::InstallSnapshot),
+            VOTE => RaftVoteRequest::parse_from_bytes(payload)
+                .ok()
+                .map(RaftRpcRequest::Vote),
+            _ => None,
+        }
+    }
+
+    fn into_proto(self, message_id: Uuid) -> RaftRequest {
+        RaftRequest {
+            message_id: message_id.to_string(),
+            request_type: self.request_type(),
+            payload: self.write_to_bytes(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Sends a Raft request to `node_id`, returning `None` if no response was received within `timeout`.
+pub(crate) async fn send<Res: Message>(
+    system: &RemoteActorSystem,
+    node_id: NodeId,
+    request: RaftRpcRequest,
+    timeout: Duration,
+) -> Option<Res> {
+    let message_id = Uuid::new_v4();
+    let event = SessionEvent::Raft(request.into_proto(message_id));
+
+    match tokio::time::timeout(
+        timeout,
+        system.node_rpc_proto::<Res>(message_id, event, node_id),
+    )
+    .await
+    {
+        Ok(Ok(response)) => Some(response),
+        Ok(Err(e)) => {
+            debug!("raft request to node_id={} failed, e={:?}", node_id, e);
+            None
+        }
+        Err(_) => {
+            trace!("raft request to node_id={} timed out", node_id);
+            system.pop_request(message_id);
+            None
+        }
+    }
+}
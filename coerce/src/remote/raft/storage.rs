@@ -0,0 +1,249 @@
+use crate::actor::system::ActorSystem;
+use crate::remote::raft::log::RaftLog;
+use crate::remote::system::NodeId;
+
+#[cfg(feature = "persistence")]
+use crate::{
+    persistent::journal::storage::{JournalEntry, JournalStorageRef},
+    remote::net::proto::network::{RaftJournalEntry, RaftJournalSnapshot, RaftLogEntry, RaftVote},
+    remote::raft::log::{self, Snapshot},
+    remote::raft::node::Raft,
+};
+
+#[cfg(feature = "persistence")]
+use {protobuf::Message, std::any::TypeId, std::sync::Arc};
+
+#[cfg(feature = "persistence")]
+const JOURNAL_ENTRY_TYPE: &str = "coerce.raft.journal-entry";
+
+#[cfg(feature = "persistence")]
+const JOURNAL_SNAPSHOT_TYPE: &str = "coerce.raft.journal-snapshot";
+
+#[cfg(feature = "persistence")]
+pub type RaftStorageErr = anyhow::Error;
+
+#[cfg(not(feature = "persistence"))]
+pub type RaftStorageErr = std::convert::Infallible;
+
+/// Persists a node's term, vote and log to the actor system's [`JournalStorage`], so a node that
+/// restarts can't vote twice in the same term, or forget entries it has acknowledged.
+///
+/// Each change is appended to the journal as a [`RaftJournalEntry`], containing the current term
+/// and vote, and the entries that have changed since the last write. Whenever the log is compacted,
+/// the whole state is written as a [`RaftJournalSnapshot`] and the previous entries are deleted.
+///
+/// When the actor system has no persistence configured, or the `persistence` feature isn't
+/// enabled, nothing is persisted.
+///
+/// [`JournalStorage`]: crate::persistent::journal::storage::JournalStorage
+pub struct RaftStorage {
+    #[cfg(feature = "persistence")]
+    persistence_id: String,
+
+    #[cfg(feature = "persistence")]
+    journal: Option<JournalStorageRef>,
+
+    #[cfg(feature = "persistence")]
+    sequence: i64,
+
+    #[cfg(feature = "persistence")]
+    term: u64,
+
+    #[cfg(feature = "persistence")]
+    voted_for: Option<NodeId>,
+}
+
+/// The state recovered from storage when the node starts.
+#[derive(Default)]
+pub struct RecoveredState {
+    pub term: u64,
+    pub voted_for: Option<NodeId>,
+    pub log: RaftLog,
+}
+
+#[cfg(not(feature = "persistence"))]
+impl RaftStorage {
+    pub fn new(_node_id: NodeId, _system: &ActorSystem) -> RaftStorage {
+        RaftStorage {}
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        false
+    }
+
+    pub async fn recover(&mut self) -> Result<RecoveredState, RaftStorageErr> {
+        Ok(RecoveredState::default())
+    }
+
+    pub async fn persist(
+        &mut self,
+        _term: u64,
+        _voted_for: Option<NodeId>,
+        log: &mut RaftLog,
+    ) -> Result<(), RaftStorageErr> {
+        log.mark_persisted();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl RaftStorage {
+    pub fn new(node_id: NodeId, system: &ActorSystem) -> RaftStorage {
+        let journal = system
+            .persistence()
+            .and_then(|p| p.provider(TypeId::of::<Raft>()).journal_storage());
+
+        RaftStorage {
+            persistence_id: format!("coerce-raft-{}", node_id),
+            journal,
+            sequence: 0,
+            term: 0,
+            voted_for: None,
+        }
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.journal.is_some()
+    }
+
+    pub async fn recover(&mut self) -> Result<RecoveredState, RaftStorageErr> {
+        let mut state = RecoveredState::default();
+        let journal = match &self.journal {
+            Some(journal) => journal,
+            None => return Ok(state),
+        };
+
+        if let Some(entry) = journal.read_latest_snapshot(&self.persistence_id).await? {
+            let snapshot = RaftJournalSnapshot::parse_from_bytes(&entry.bytes)?;
+
+            state.term = snapshot.term;
+            state.voted_for = snapshot.voted_for.as_ref().map(|v| v.candidate_id);
+            state.log.restore(
+                Snapshot {
+                    index: snapshot.snapshot_index,
+                    term: snapshot.snapshot_term,
+                    data: snapshot.snapshot_data,
+                    voters: snapshot.snapshot_configuration.as_ref().map(log::voters),
+                },
+                snapshot.entries,
+            );
+
+            self.sequence = entry.sequence;
+        }
+
+        let entries = journal
+            .read_latest_messages(&self.persistence_id, self.sequence)
+            .await?
+            .unwrap_or_default();
+
+        for entry in entries {
+            let journal_entry = RaftJournalEntry::parse_from_bytes(&entry.bytes)?;
+
+            state.term = journal_entry.term;
+            state.voted_for = journal_entry.voted_for.as_ref().map(|v| v.candidate_id);
+            if journal_entry.truncate_from > 0 {
+                state
+                    .log
+                    .restore_entries(journal_entry.truncate_from, journal_entry.entries);
+            }
+
+            self.sequence = entry.sequence;
+        }
+
+        self.term = state.term;
+        self.voted_for = state.voted_for;
+        Ok(state)
+    }
+
+    /// Writes any changes to the term, vote or log since they were last persisted, the log's
+    /// changes are only marked as persisted once they've been written successfully.
+    pub async fn persist(
+        &mut self,
+        term: u64,
+        voted_for: Option<NodeId>,
+        log: &mut RaftLog,
+    ) -> Result<(), RaftStorageErr> {
+        let journal = match &self.journal {
+            Some(journal) => journal.clone(),
+            None => {
+                log.mark_persisted();
+                return Ok(());
+            }
+        };
+
+        let voted_for_proto = voted_for.map(|candidate_id| RaftVote {
+            candidate_id,
+            ..Default::default()
+        });
+
+        if log.is_snapshot_unpersisted() {
+            let snapshot = log.snapshot();
+            let record = RaftJournalSnapshot {
+                term,
+                voted_for: voted_for_proto.into(),
+                snapshot_index: snapshot.index,
+                snapshot_term: snapshot.term,
+                snapshot_data: snapshot.data.clone(),
+                snapshot_configuration: snapshot.voters.as_ref().map(log::configuration).into(),
+                entries: log.entries_from(snapshot.index + 1, usize::MAX),
+                ..Default::default()
+            };
+
+            self.sequence = self
+                .write(&journal, JOURNAL_SNAPSHOT_TYPE, record, true)
+                .await?;
+            journal
+                .delete_messages_to(&self.persistence_id, self.sequence)
+                .await?;
+        } else if term != self.term
+            || voted_for != self.voted_for
+            || log.unpersisted_from().is_some()
+        {
+            let truncate_from = log.unpersisted_from().unwrap_or(0);
+            let entries: Vec<RaftLogEntry> = match truncate_from {
+                0 => vec![],
+                index => log.entries_from(index, usize::MAX),
+            };
+
+            let record = RaftJournalEntry {
+                term,
+                voted_for: voted_for_proto.into(),
+                truncate_from,
+                entries,
+                ..Default::default()
+            };
+
+            self.sequence = self
+                .write(&journal, JOURNAL_ENTRY_TYPE, record, false)
+                .await?;
+        }
+
+        self.term = term;
+        self.voted_for = voted_for;
+        log.mark_persisted();
+        Ok(())
+    }
+
+    async fn write<M: Message>(
+        &self,
+        journal: &JournalStorageRef,
+        payload_type: &str,
+        record: M,
+        snapshot: bool,
+    ) -> Result<i64, RaftStorageErr> {
+        let sequence = self.sequence + 1;
+        let entry = JournalEntry {
+            sequence,
+            payload_type: payload_type.into(),
+            bytes: Arc::new(record.write_to_bytes()?),
+        };
+
+        if snapshot {
+            journal.write_snapshot(&self.persistence_id, entry).await?;
+        } else {
+            journal.write_message(&self.persistence_id, entry).await?;
+        }
+
+        Ok(sequence)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::remote::actor::message::NodeLeaving;
+use crate::remote::cluster::node::NodeStatus;
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::LeaveEvent;
+use crate::remote::system::RemoteActorSystem;
+use futures::future::join_all;
+use std::time::Duration;
+use uuid::Uuid;
+
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Notifies every other node that this node is leaving the cluster, and waits up to `timeout`
+/// for them to acknowledge it.
+pub(crate) async fn announce_leaving(system: &RemoteActorSystem, timeout: Duration) {
+    let node_id = system.node_id();
+
+    let nodes = system.get_nodes().await;
+    let acknowledgements = nodes
+        .into_iter()
+        .filter(|n| n.id != node_id && n.status != NodeStatus::Terminated)
+        .map(|n| {
+            let system = system.clone();
+            async move {
+                let message_id = Uuid::new_v4();
+                let event = SessionEvent::Leave(LeaveEvent {
+                    message_id: message_id.to_string(),
+                    node_id,
+                    ..Default::default()
+                });
+
+                match tokio::time::timeout(timeout, system.node_rpc_raw(message_id, event, n.id))
+                    .await
+                {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => warn!(
+                        "[node={}] failed to notify node_id={} that the node is leaving, e={:?}",
+                        node_id, n.id, e
+                    ),
+                    Err(_) => {
+                        warn!(
+                            "[node={}] node_id={} did not acknowledge that the node is leaving",
+                            node_id, n.id
+                        );
+
+                        system.pop_request(message_id);
+                    }
+                }
+            }
+        });
+
+    join_all(acknowledgements).await;
+
+    let _ = system.heartbeat().send(NodeLeaving(node_id)).await;
+}
+
+/// Waits up to `timeout` for every in-flight remote request to complete.
+pub(crate) async fn drain_requests(system: &RemoteActorSystem, timeout: Duration) {
+    let drained = async {
+        while system.inflight_remote_request_count() > 0 {
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    };
+
+    if tokio::time::timeout(timeout, drained).await.is_err() {
+        warn!(
+            "[node={}] timed out waiting for {} in-flight remote requests to complete",
+            system.node_id(),
+            system.inflight_remote_request_count()
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Coordinated shutdown, allowing a node to gracefully leave the cluster.
+//!
+//! [`RemoteActorSystem::shutdown`] stops the node immediately, other nodes only notice once
+//! their pings to it fail, and any shards it was hosting are only re-allocated once the node
+//! has been marked as terminated. [`RemoteActorSystem::leave`] instead runs the node's
+//! [`CoordinatedShutdown`], which runs through the following phases, in order:
+//!
+//! 1. [`ShutdownPhase::StopAcceptingEntities`]: shard hosts on the node stop being allocated
+//!    new shards.
+//! 2. [`ShutdownPhase::HandOffShards`]: shards hosted on the node are stopped and re-allocated
+//!    to the other hosts.
+//! 3. [`ShutdownPhase::PassivateEntities`]: any remaining entities are passivated, and the
+//!    shard hosts are stopped.
+//! 4. [`ShutdownPhase::AnnounceLeaving`]: the node is marked as [`NodeStatus::Leaving`], and the
+//!    other nodes are notified, which publish [`ClusterEvent::NodeLeaving`]. A leaving node is
+//!    no longer eligible to become the leader.
+//! 5. [`ShutdownPhase::DrainConnections`]: waits for in-flight remote requests to complete.
+//! 6. [`ShutdownPhase::StopActorSystem`]: the actor system is stopped, which notifies the other
+//!    nodes that the node has terminated.
+//!
+//! Tasks can be added to any phase via [`CoordinatedShutdown::add_task`]. The tasks of a phase
+//! run concurrently, and the next phase starts once every task has completed, or once the
+//! phase's timeout has elapsed, see [`CoordinatedShutdownConfig`].
+//!
+//! [`RemoteActorSystem::shutdown`]: crate::remote::system::RemoteActorSystem::shutdown
+//! [`RemoteActorSystem::leave`]: crate::remote::system::RemoteActorSystem::leave
+//! [`NodeStatus::Leaving`]: crate::remote::cluster::node::NodeStatus::Leaving
+//! [`ClusterEvent::NodeLeaving`]: crate::remote::stream::system::ClusterEvent::NodeLeaving
+
+use crate::remote::system::RemoteActorSystem;
+use futures::future::{join_all, BoxFuture};
+use futures::FutureExt;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub(crate) mod leave;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ShutdownPhase {
+    /// Shard hosts on the node stop being allocated new shards.
+    StopAcceptingEntities,
+
+    /// Shards hosted on the node are handed off to the other hosts.
+    HandOffShards,
+
+    /// Remaining entities are passivated and the shard hosts are stopped.
+    PassivateEntities,
+
+    /// The node is marked as leaving, and the other nodes in the cluster are notified.
+    AnnounceLeaving,
+
+    /// Waits for in-flight remote requests to complete.
+    DrainConnections,
+
+    /// The actor system is stopped.
+    StopActorSystem,
+}
+
+impl ShutdownPhase {
+    /// Every phase, in the order they're run.
+    pub const ALL: [ShutdownPhase; 6] = [
+        ShutdownPhase::StopAcceptingEntities,
+        ShutdownPhase::HandOffShards,
+        ShutdownPhase::PassivateEntities,
+        ShutdownPhase::AnnounceLeaving,
+        ShutdownPhase::DrainConnections,
+        ShutdownPhase::StopActorSystem,
+    ];
+}
+
+#[derive(Clone, Debug)]
+pub struct CoordinatedShutdownConfig {
+    /// How long the tasks of a phase are given to complete, unless overridden for the phase.
+    pub default_phase_timeout: Duration,
+
+    /// Per-phase overrides of `default_phase_timeout`.
+    pub phase_timeouts: HashMap<ShutdownPhase, Duration>,
+}
+
+impl Default for CoordinatedShutdownConfig {
+    fn default() -> Self {
+        Self {
+            default_phase_timeout: Duration::from_secs(10),
+            phase_timeouts: HashMap::new(),
+        }
+    }
+}
+
+impl CoordinatedShutdownConfig {
+    pub fn phase_timeout(mut self, phase: ShutdownPhase, timeout: Duration) -> Self {
+        self.phase_timeouts.insert(phase, timeout);
+        self
+    }
+
+    pub fn timeout(&self, phase: ShutdownPhase) -> Duration {
+        self.phase_timeouts
+            .get(&phase)
+            .copied()
+            .unwrap_or(self.default_phase_timeout)
+    }
+}
+
+type ShutdownTask = Box<dyn FnOnce(RemoteActorSystem) -> BoxFuture<'static, ()> + Send>;
+
+/// The node's coordinated shutdown, and the tasks registered with each of its phases.
+#[derive(Clone)]
+pub struct CoordinatedShutdown {
+    inner: Arc<CoordinatedShutdownState>,
+}
+
+struct CoordinatedShutdownState {
+    config: CoordinatedShutdownConfig,
+    tasks: parking_lot::Mutex<HashMap<ShutdownPhase, Vec<(String, ShutdownTask)>>>,
+    leaving: AtomicBool,
+    completed: tokio::sync::Mutex<bool>,
+}
+
+impl CoordinatedShutdown {
+    pub fn new(config: CoordinatedShutdownConfig) -> Self {
+        Self {
+            inner: Arc::new(CoordinatedShutdownState {
+                config,
+                tasks: Default::default(),
+                leaving: AtomicBool::new(false),
+                completed: tokio::sync::Mutex::new(false),
+            }),
+        }
+    }
+
+    pub fn config(&self) -> &CoordinatedShutdownConfig {
+        &self.inner.config
+    }
+
+    /// Adds a task to `phase`, which is run when the node leaves the cluster. Tasks added once
+    /// the phase has started are not run.
+    pub fn add_task<F, Fut>(&self, phase: ShutdownPhase, name: impl ToString, task: F)
+    where
+        F: 'static + FnOnce(RemoteActorSystem) -> Fut + Send,
+        Fut: 'static + Future<Output = ()> + Send,
+    {
+        let task: ShutdownTask = Box::new(move |system| task(system).boxed());
+
+        self.inner
+            .tasks
+            .lock()
+            .entry(phase)
+            .or_default()
+            .push((name.to_string(), task));
+    }
+
+    /// Whether the node has announced that it's leaving the cluster.
+    pub fn is_leaving(&self) -> bool {
+        self.inner.leaving.load(Ordering::Relaxed)
+    }
+
+    /// Runs every phase, in order. The shutdown is only ever run once, subsequent calls wait for
+    /// the initial run to complete.
+    pub(crate) async fn run(&self, system: &RemoteActorSystem) {
+        let mut completed = self.inner.completed.lock().await;
+        if *completed {
+            return;
+        }
+
+        let node_id = system.node_id();
+        info!("[node={}] coordinated shutdown started", node_id);
+
+        for phase in ShutdownPhase::ALL {
+            self.run_phase(phase, system).await;
+        }
+
+        *completed = true;
+        info!("[node={}] coordinated shutdown complete", node_id);
+    }
+
+    async fn run_phase(&self, phase: ShutdownPhase, system: &RemoteActorSystem) {
+        let timeout = self.inner.config.timeout(phase);
+        let deadline = Instant::now() + timeout;
+        let node_id = system.node_id();
+
+        let tasks = self.inner.tasks.lock().remove(&phase).unwrap_or_default();
+        debug!(
+            "[node={}] running shutdown phase={:?}, tasks={}",
+            node_id,
+            phase,
+            tasks.len()
+        );
+
+        let (names, tasks): (Vec<String>, Vec<_>) = tasks
+            .into_iter()
+            .map(|(name, task)| (name, task(system.clone())))
+            .unzip();
+
+        if tokio::time::timeout_at(deadline, join_all(tasks))
+            .await
+            .is_err()
+        {
+            warn!(
+                "[node={}] shutdown phase={:?} timed out after {:?}, tasks={:?}",
+                node_id, phase, timeout, names
+            );
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match phase {
+            ShutdownPhase::AnnounceLeaving => {
+                self.inner.leaving.store(true, Ordering::Relaxed);
+                leave::announce_leaving(system, remaining).await;
+            }
+
+            ShutdownPhase::DrainConnections => leave::drain_requests(system, remaining).await,
+
+            // the actor system is always stopped, regardless of the phase timeout
+            ShutdownPhase::StopActorSystem => system.actor_system().shutdown().await,
+
+            _ => {}
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::actor::context::ActorContext;
 use crate::actor::message::{Handler, Message};
 use crate::actor::{Actor, LocalActorRef};
 use crate::remote::actor::message::SetRemote;
+use crate::remote::cluster::node::RemoteNode;
 use crate::remote::heartbeat::Heartbeat;
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network::StreamPublishEvent;
@@ -12,7 +13,7 @@ use crate::remote::stream::pubsub::{
 use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
 use crate::remote::system::{NodeId, RemoteActorSystem};
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct MediatorTopic(Box<dyn TopicEmitter>);
@@ -20,7 +21,7 @@ pub struct MediatorTopic(Box<dyn TopicEmitter>);
 #[derive(Default)]
 pub struct StreamMediator {
     remote: Option<RemoteActorSystem>,
-    nodes: HashSet<NodeId>,
+    nodes: HashMap<NodeId, Arc<RemoteNode>>,
     topics: HashMap<String, MediatorTopic>,
     system_subscription: Option<Subscription>,
 }
@@ -66,6 +67,18 @@ pub enum PublishErr {
 pub enum Reach {
     Local,
     Cluster,
+
+    /// Only nodes with the role receive the message, see
+    /// [`RemoteActorSystemBuilder::with_roles`].
+    ///
+    /// [`RemoteActorSystemBuilder::with_roles`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_roles
+    Role(String),
+
+    /// Only nodes within the datacenter receive the message, see
+    /// [`RemoteActorSystemBuilder::with_datacenter`].
+    ///
+    /// [`RemoteActorSystemBuilder::with_datacenter`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_datacenter
+    Datacenter(String),
 }
 
 pub struct Publish<T: Topic> {
@@ -152,7 +165,7 @@ impl Handler<Receive<SystemTopic>> for StreamMediator {
             SystemEvent::Cluster(cluster_event) => match cluster_event {
                 ClusterEvent::NodeAdded(new_node) => {
                     if new_node.id != self.remote().node_id() {
-                        self.nodes.insert(new_node.id);
+                        self.nodes.insert(new_node.id, new_node.clone());
                     }
 
                     info!("node added (node_id={})", new_node.id);
@@ -183,17 +196,35 @@ impl<T: Topic> Handler<Publish<T>> for StreamMediator {
         _ctx: &mut ActorContext,
     ) -> Result<(), PublishErr> {
         let msg = Arc::new(message.message);
-        if let Some(topic) = self.topics.get(T::topic_name()) {
-            topic.0.emit(&message.topic.key(), msg.clone()).await;
+        let reach = message.reach;
+        let local_publish = match &reach {
+            Reach::Role(role) => self.remote().config().get_roles().contains(role.as_str()),
+            Reach::Datacenter(datacenter) => self.remote().datacenter() == datacenter,
+            _ => true,
+        };
+
+        if local_publish {
+            if let Some(topic) = self.topics.get(T::topic_name()) {
+                topic.0.emit(&message.topic.key(), msg.clone()).await;
+            }
         }
 
-        if message.reach.remote_publish() && !self.nodes.is_empty() {
+        let nodes: Vec<NodeId> = if reach.remote_publish() {
+            self.nodes
+                .values()
+                .filter(|n| reach.includes(n))
+                .map(|n| n.id)
+                .collect()
+        } else {
+            vec![]
+        };
+
+        if !nodes.is_empty() {
             match msg.write_to_bytes() {
                 Some(bytes) => {
                     let remote = self.remote().clone();
                     let topic = T::topic_name().to_string();
                     let key = message.topic.key();
-                    let nodes: Vec<NodeId> = self.nodes.iter().copied().collect();
 
                     tokio::spawn(async move {
                         let message = bytes;
@@ -279,9 +310,19 @@ where
 
 impl Reach {
     pub fn remote_publish(&self) -> bool {
+        match &self {
+            Self::Local => false,
+            Self::Cluster | Self::Role(_) | Self::Datacenter(_) => true,
+        }
+    }
+
+    /// Whether `node` is within reach of the published message.
+    pub fn includes(&self, node: &RemoteNode) -> bool {
         match &self {
             Self::Local => false,
             Self::Cluster => true,
+            Self::Role(role) => node.has_role(role),
+            Self::Datacenter(datacenter) => node.in_datacenter(datacenter),
         }
     }
 }
@@ -73,6 +73,53 @@ impl PubSub {
         }
     }
 
+    /// Publishes the message to subscribers on nodes with the provided `role`, including this
+    /// node if it has the role.
+    pub async fn publish_to_role<T: Topic>(
+        topic: T,
+        message: T::Message,
+        role: impl ToString,
+        system: &RemoteActorSystem,
+    ) {
+        if let Some(mediator) = system.stream_mediator() {
+            let reach = Reach::Role(role.to_string());
+
+            let _ = mediator
+                .send(Publish {
+                    topic,
+                    message,
+                    reach,
+                })
+                .await
+                .unwrap();
+        } else {
+            panic!("no stream mediator found, system not setup for distributed streams")
+        }
+    }
+
+    /// Publishes the message to subscribers on nodes within the same datacenter as this node,
+    /// including this node.
+    pub async fn publish_to_datacenter<T: Topic>(
+        topic: T,
+        message: T::Message,
+        system: &RemoteActorSystem,
+    ) {
+        if let Some(mediator) = system.stream_mediator() {
+            let reach = Reach::Datacenter(system.datacenter().to_string());
+
+            let _ = mediator
+                .send(Publish {
+                    topic,
+                    message,
+                    reach,
+                })
+                .await
+                .unwrap();
+        } else {
+            panic!("no stream mediator found, system not setup for distributed streams")
+        }
+    }
+
     pub async fn publish_locally<T: Topic>(
         topic: T,
         message: T::Message,
@@ -209,6 +256,13 @@ impl Drop for Subscription {
 }
 
 impl Subscription {
+    /// Creates a subscription that is cancelled by aborting the provided task.
+    pub(crate) fn from_task(task_handle: JoinHandle<()>) -> Subscription {
+        Subscription {
+            task_handle: Some(task_handle),
+        }
+    }
+
     pub(crate) fn new<A: Actor, T: Topic>(
         topic_receiver: broadcast::Receiver<Receive<T>>,
         receiver_ref: LocalActorRef<A>,
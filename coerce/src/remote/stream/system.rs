@@ -1,5 +1,6 @@
 use crate::remote::net::proto::network::{
-    LeaderChangedEvent, NewNodeEvent, NodeRemovedEvent, SystemEvent as SysEvent,
+    LeaderChangedEvent, NewNodeEvent, NodeDownedEvent, NodeLeavingEvent, NodeRemovedEvent,
+    SystemEvent as SysEvent,
 };
 use crate::remote::net::StreamData;
 use crate::remote::stream::pubsub::Topic;
@@ -17,6 +18,20 @@ pub enum ClusterEvent {
     NodeAdded(Arc<RemoteNode>),
     NodeRemoved(Arc<RemoteNode>),
     LeaderChanged(NodeId),
+
+    /// The leader of the current node's datacenter changed, see
+    /// [`RemoteActorSystem::datacenter_leader`].
+    ///
+    /// [`RemoteActorSystem::datacenter_leader`]: crate::remote::system::RemoteActorSystem::datacenter_leader
+    DatacenterLeaderChanged(NodeId),
+
+    /// The node was downed by the split-brain resolver, if this is the current node, the node
+    /// is no longer part of the cluster.
+    NodeDowned(NodeId),
+
+    /// The node announced that it's gracefully leaving the cluster, as part of a
+    /// coordinated shutdown.
+    NodeLeaving(NodeId),
 }
 
 #[derive(Debug)]
@@ -34,7 +49,7 @@ impl Topic for SystemTopic {
 
 impl From<NewNodeEvent> for SystemEvent {
     fn from(message: NewNodeEvent) -> Self {
-        let node = message.node.unwrap();
+        let node = message.node.unwrap_or_default();
 
         SystemEvent::Cluster(ClusterEvent::NodeAdded(Arc::new(node.into())))
     }
@@ -42,7 +57,7 @@ impl From<NewNodeEvent> for SystemEvent {
 
 impl From<NodeRemovedEvent> for SystemEvent {
     fn from(message: NodeRemovedEvent) -> Self {
-        let node = message.node.unwrap();
+        let node = message.node.unwrap_or_default();
 
         SystemEvent::Cluster(ClusterEvent::NodeRemoved(Arc::new(node.into())))
     }
@@ -53,21 +68,44 @@ impl From<LeaderChangedEvent> for SystemEvent {
     }
 }
 
+impl From<NodeDownedEvent> for SystemEvent {
+    fn from(message: NodeDownedEvent) -> Self {
+        SystemEvent::Cluster(ClusterEvent::NodeDowned(message.node_id))
+    }
+}
+
+impl From<NodeLeavingEvent> for SystemEvent {
+    fn from(message: NodeLeavingEvent) -> Self {
+        SystemEvent::Cluster(ClusterEvent::NodeLeaving(message.node_id))
+    }
+}
+
 impl StreamData for SystemEvent {
     fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
         match data.split_first() {
             Some((event, message)) => match SysEvent::from_i32(*event as i32) {
-                Some(SysEvent::ClusterNodeRemoved) => {
-                    Some(NodeRemovedEvent::parse_from_bytes(message).unwrap().into())
+                Some(SysEvent::ClusterNodeRemoved) => NodeRemovedEvent::parse_from_bytes(message)
+                    .ok()
+                    .map(|e| e.into()),
+                Some(SysEvent::ClusterNewNode) => NewNodeEvent::parse_from_bytes(message)
+                    .ok()
+                    .map(|e| e.into()),
+                Some(SysEvent::ClusterLeaderChanged) => {
+                    LeaderChangedEvent::parse_from_bytes(message)
+                        .ok()
+                        .map(|e| e.into())
                 }
-                Some(SysEvent::ClusterNewNode) => {
-                    Some(NewNodeEvent::parse_from_bytes(message).unwrap().into())
+                Some(SysEvent::ClusterDatacenterLeaderChanged) => {
+                    LeaderChangedEvent::parse_from_bytes(message).ok().map(|e| {
+                        SystemEvent::Cluster(ClusterEvent::DatacenterLeaderChanged(e.node_id))
+                    })
                 }
-                Some(SysEvent::ClusterLeaderChanged) => Some(
-                    LeaderChangedEvent::parse_from_bytes(message)
-                        .unwrap()
-                        .into(),
-                ),
+                Some(SysEvent::ClusterNodeDowned) => NodeDownedEvent::parse_from_bytes(message)
+                    .ok()
+                    .map(|e| e.into()),
+                Some(SysEvent::ClusterNodeLeaving) => NodeLeavingEvent::parse_from_bytes(message)
+                    .ok()
+                    .map(|e| e.into()),
                 None => None,
             },
             None => None,
@@ -101,6 +139,33 @@ impl StreamData for SystemEvent {
 
                     write_event(SysEvent::ClusterLeaderChanged, event.write_to_bytes())
                 }
+                ClusterEvent::DatacenterLeaderChanged(node_id) => {
+                    let event = LeaderChangedEvent {
+                        node_id: *node_id,
+                        ..LeaderChangedEvent::default()
+                    };
+
+                    write_event(
+                        SysEvent::ClusterDatacenterLeaderChanged,
+                        event.write_to_bytes(),
+                    )
+                }
+                ClusterEvent::NodeDowned(node_id) => {
+                    let event = NodeDownedEvent {
+                        node_id: *node_id,
+                        ..NodeDownedEvent::default()
+                    };
+
+                    write_event(SysEvent::ClusterNodeDowned, event.write_to_bytes())
+                }
+                ClusterEvent::NodeLeaving(node_id) => {
+                    let event = NodeLeavingEvent {
+                        node_id: *node_id,
+                        ..NodeLeavingEvent::default()
+                    };
+
+                    write_event(SysEvent::ClusterNodeLeaving, event.write_to_bytes())
+                }
             },
         }
     }
@@ -3,6 +3,7 @@ use crate::actor::{
     new_actor_id, Actor, ActorFactory, ActorId, ActorRecipe, ActorRef, CoreActorRef, IntoActorId,
 };
 use crate::remote::actor::message::{GetActorNode, RegisterActor};
+use crate::remote::cluster::placement::Placement;
 use crate::remote::handler::send_proto_result;
 use crate::remote::net::message::SessionEvent;
 use crate::remote::net::proto::network::{ActorAddress, CreateActorEvent};
@@ -21,6 +22,7 @@ pub enum RemoteActorErr {
     MessageSerializationErr,
     ResultSerializationErr,
     ActorNotSupported,
+    NoAvailableNode,
     NodeErr(NodeRpcErr),
 }
 
@@ -178,6 +180,33 @@ impl RemoteActorSystem {
         }
     }
 
+    /// Spawns an actor on the node selected by the [`Placement`] strategy.
+    pub async fn spawn_remote<F: ActorFactory>(
+        &self,
+        id: Option<ActorId>,
+        recipe: F::Recipe,
+        placement: Placement,
+    ) -> Result<ActorRef<F::Actor>, RemoteActorErr> {
+        let actor_type = F::Actor::type_name();
+        let node_id = match self.select_node(&placement, actor_type).await {
+            Some(node_id) => node_id,
+            None => {
+                warn!(
+                    "no node available to spawn actor (actor_type={}, placement={:?})",
+                    actor_type, &placement
+                );
+                return Err(RemoteActorErr::NoAvailableNode);
+            }
+        };
+
+        debug!(
+            "spawning actor (actor_type={}, node_id={}, placement={:?})",
+            actor_type, node_id, &placement
+        );
+
+        self.deploy_actor::<F>(id, recipe, Some(node_id)).await
+    }
+
     pub async fn handle_create_actor(
         &self,
         actor_id: Option<ActorId>,
@@ -6,24 +6,33 @@ use crate::remote::actor::{
     clients::RemoteClientRegistry, registry::RemoteRegistry, BoxedActorHandler,
     BoxedMessageHandler, RemoteHandler,
 };
+use crate::remote::cluster::gossip::{GossipConfig, GossipSystem};
+use crate::remote::cluster::split_brain::SplitBrainResolverConfig;
 use crate::remote::handler::{RemoteActorHandler, RemoteActorMessageHandler};
 use crate::remote::heartbeat::{Heartbeat, HeartbeatConfig};
+use crate::remote::net::config::NetworkConfig;
+use crate::remote::raft::{RaftConfig, RaftSystem};
+use crate::remote::shutdown::{CoordinatedShutdown, CoordinatedShutdownConfig};
 use crate::remote::stream::mediator::StreamMediator;
 use crate::remote::system::{AtomicNodeId, NodeId, RemoteActorSystem, RemoteSystemCore};
 
 use rand::RngCore;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::actor::scheduler::ActorType;
 use crate::remote::cluster::discovery::NodeDiscovery;
 
-use crate::remote::cluster::node::NodeAttributes;
+use crate::remote::cluster::node::{NodeAttributes, NodeRoles, DEFAULT_DATACENTER};
 use crate::remote::config::{RemoteSystemConfig, RemoteSystemSecurity};
 
 use crate::remote::net::security::ClientAuth;
+use crate::remote::net::transport::{TcpTransport, Transport};
+
+#[cfg(feature = "remote-tls")]
+use crate::remote::net::security::tls::TlsConfig;
 use chrono::Utc;
 use uuid::Uuid;
 
@@ -35,8 +44,13 @@ pub struct RemoteActorSystemBuilder {
     config_builders: Vec<ConfigBuilderFn>,
     mediator: Option<StreamMediator>,
     client_auth: Option<ClientAuth>,
+    #[cfg(feature = "remote-tls")]
+    tls: Option<TlsConfig>,
+    transport: Option<Arc<dyn Transport>>,
     single_node_cluster: bool,
     node_attributes: HashMap<String, String>,
+    node_roles: HashSet<String>,
+    node_datacenter: Option<String>,
 }
 
 impl RemoteActorSystemBuilder {
@@ -55,7 +69,12 @@ impl RemoteActorSystemBuilder {
             mediator: Some(mediator),
             single_node_cluster: false,
             client_auth: None,
+            #[cfg(feature = "remote-tls")]
+            tls: None,
+            transport: None,
             node_attributes: Default::default(),
+            node_roles: Default::default(),
+            node_datacenter: None,
         }
     }
 
@@ -124,12 +143,61 @@ impl RemoteActorSystemBuilder {
         self
     }
 
+    /// Enables TLS for all connections between this node and other nodes in the cluster.
+    #[cfg(feature = "remote-tls")]
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Overrides the transport used for connections between nodes, by default [`TcpTransport`] is used.
+    ///
+    /// Every node in the cluster must use a compatible transport. TLS, when enabled via `with_tls`,
+    /// only applies to the default [`TcpTransport`].
+    pub fn with_transport(mut self, transport: impl Transport) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn attribute<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
         self.node_attributes
             .insert(key.to_string(), value.to_string());
         self
     }
 
+    /// Assigns roles to the node, which are exchanged with every other node in the cluster.
+    ///
+    /// Roles can be used to restrict which nodes host sharded entities (see
+    /// [`ShardingBuilder::with_role`]), which nodes are eligible to become the cluster leader
+    /// (see [`RemoteSystemConfigBuilder::leader_roles`]), and which nodes receive messages
+    /// published via [`PubSub::publish_to_role`].
+    ///
+    /// [`ShardingBuilder::with_role`]: crate::sharding::builder::ShardingBuilder::with_role
+    /// [`PubSub::publish_to_role`]: crate::remote::stream::pubsub::PubSub::publish_to_role
+    pub fn with_roles<R: ToString>(mut self, roles: impl IntoIterator<Item = R>) -> Self {
+        self.node_roles
+            .extend(roles.into_iter().map(|r| r.to_string()));
+        self
+    }
+
+    /// Assigns the node to a datacenter (or availability zone), which is exchanged with every
+    /// other node in the cluster. Nodes that aren't assigned a datacenter are part of
+    /// [`DEFAULT_DATACENTER`].
+    ///
+    /// Each datacenter has its own leader (see [`RemoteActorSystem::datacenter_leader`]),
+    /// shards are preferably allocated to nodes within the datacenter of the node requesting
+    /// the shard, heartbeat timeouts for nodes in other datacenters can be configured via
+    /// [`HeartbeatConfig::cross_datacenter`], and messages can be published to the node's
+    /// datacenter only via [`PubSub::publish_to_datacenter`].
+    ///
+    /// [`DEFAULT_DATACENTER`]: crate::remote::cluster::node::DEFAULT_DATACENTER
+    /// [`HeartbeatConfig::cross_datacenter`]: crate::remote::heartbeat::HeartbeatConfig::cross_datacenter
+    /// [`PubSub::publish_to_datacenter`]: crate::remote::stream::pubsub::PubSub::publish_to_datacenter
+    pub fn with_datacenter(mut self, datacenter: impl ToString) -> Self {
+        self.node_datacenter = Some(datacenter.to_string());
+        self
+    }
+
     pub async fn build(self) -> RemoteActorSystem {
         // TODO: This needs cleaning up!
 
@@ -139,6 +207,7 @@ impl RemoteActorSystemBuilder {
         };
 
         let mut config_builder = RemoteSystemConfigBuilder::new(inner.clone());
+        config_builder.datacenter = self.node_datacenter;
 
         self.config_builders.into_iter().for_each(|h| {
             h(&mut config_builder);
@@ -154,11 +223,27 @@ impl RemoteActorSystemBuilder {
             .node_tag
             .clone()
             .unwrap_or_else(|| inner.system_name().to_string());
+        let security = RemoteSystemSecurity::new(self.client_auth.unwrap_or_default());
+
+        #[cfg(feature = "remote-tls")]
+        let security = security.with_tls(self.tls.clone());
+
+        let transport = self.transport.unwrap_or_else(|| {
+            let transport = TcpTransport::new();
+
+            #[cfg(feature = "remote-tls")]
+            let transport = transport.with_tls(self.tls);
+
+            Arc::new(transport)
+        });
+
         let config = config_builder.build(
             Some(system_tag.clone()),
             self.node_version,
-            self.client_auth,
+            security,
+            transport,
             self.node_attributes,
+            self.node_roles,
         );
 
         let handler_ref = Arc::new(parking_lot::Mutex::new(RemoteHandler::new()));
@@ -173,6 +258,23 @@ impl RemoteActorSystemBuilder {
 
         let heartbeat_ref = Heartbeat::start(&inner).await;
 
+        let raft = match config.raft_config() {
+            Some(raft_config) => {
+                Some(RaftSystem::start(node_id, raft_config.clone(), &inner).await)
+            }
+            None => None,
+        };
+
+        let gossip = match config.gossip_config() {
+            Some(gossip_config) => {
+                Some(GossipSystem::start(node_id, gossip_config.clone(), &inner).await)
+            }
+            None => None,
+        };
+
+        let coordinated_shutdown =
+            CoordinatedShutdown::new(config.coordinated_shutdown_config().clone());
+
         let mediator_ref = if let Some(mediator) = self.mediator {
             trace!("mediator set");
             Some(
@@ -195,6 +297,9 @@ impl RemoteActorSystemBuilder {
             mediator_ref,
             discovery_ref,
             heartbeat_ref,
+            raft,
+            gossip,
+            coordinated_shutdown,
             started_at: Utc::now(),
             config,
             current_leader: Arc::new(AtomicNodeId::new(if self.single_node_cluster {
@@ -202,6 +307,14 @@ impl RemoteActorSystemBuilder {
             } else {
                 -1
             })),
+            current_datacenter_leader: Arc::new(AtomicNodeId::new(if self.single_node_cluster {
+                node_id as i64
+            } else {
+                -1
+            })),
+            node_capabilities: Default::default(),
+            remote_watches: Default::default(),
+            cluster_client: Default::default(),
         };
 
         let inner = Arc::new(core.clone());
@@ -218,6 +331,12 @@ impl RemoteActorSystemBuilder {
             .await
             .expect("no system set");
 
+        system
+            .client_registry()
+            .send(SetRemote(system.clone()))
+            .await
+            .expect("no system set");
+
         system
             .heartbeat()
             .send(SetRemote(system.clone()))
@@ -237,6 +356,21 @@ impl RemoteActorSystemBuilder {
                 .expect("no system set");
         }
 
+        if let Some(raft) = system.raft() {
+            raft.actor_ref()
+                .send(SetRemote(system.clone()))
+                .await
+                .expect("no system set");
+        }
+
+        if let Some(gossip) = system.gossip() {
+            gossip
+                .actor_ref()
+                .send(SetRemote(system.clone()))
+                .await
+                .expect("no system set");
+        }
+
         system
             .actor_system()
             .scheduler()
@@ -254,6 +388,13 @@ pub(crate) type ConfigBuilderFn =
 pub struct RemoteSystemConfigBuilder {
     system: ActorSystem,
     heartbeat: Option<HeartbeatConfig>,
+    split_brain_resolver: Option<SplitBrainResolverConfig>,
+    raft: Option<RaftConfig>,
+    gossip: Option<GossipConfig>,
+    network: Option<NetworkConfig>,
+    coordinated_shutdown: Option<CoordinatedShutdownConfig>,
+    leader_roles: HashSet<String>,
+    datacenter: Option<String>,
     actors: HashMap<String, BoxedActorHandler>,
     handlers: HashMap<String, BoxedMessageHandler>,
 }
@@ -265,6 +406,13 @@ impl RemoteSystemConfigBuilder {
             handlers: HashMap::new(),
             system,
             heartbeat: None,
+            split_brain_resolver: None,
+            raft: None,
+            gossip: None,
+            network: None,
+            coordinated_shutdown: None,
+            leader_roles: HashSet::new(),
+            datacenter: None,
         }
     }
 
@@ -294,12 +442,63 @@ impl RemoteSystemConfigBuilder {
         self
     }
 
+    /// Enables split-brain resolution, downing one side of a network partition once the
+    /// partition has been stable for long enough, see [`split_brain`].
+    ///
+    /// [`split_brain`]: crate::remote::cluster::split_brain
+    pub fn split_brain_resolver(&mut self, config: SplitBrainResolverConfig) -> &mut Self {
+        self.split_brain_resolver = Some(config);
+        self
+    }
+
+    /// Enables Raft, electing the cluster leader by majority vote and replicating the cluster's
+    /// metadata to every node, see [`raft`].
+    ///
+    /// [`raft`]: crate::remote::raft
+    pub fn raft(&mut self, raft_config: RaftConfig) -> &mut Self {
+        self.raft = Some(raft_config);
+        self
+    }
+
+    /// Enables gossip-based membership with SWIM-style failure detection, replacing the pings
+    /// sent to every node on each heartbeat, see [`gossip`].
+    ///
+    /// [`gossip`]: crate::remote::cluster::gossip
+    pub fn gossip(&mut self, gossip_config: GossipConfig) -> &mut Self {
+        self.gossip = Some(gossip_config);
+        self
+    }
+
+    pub fn network(&mut self, network_config: NetworkConfig) -> &mut Self {
+        self.network = Some(network_config);
+        self
+    }
+
+    /// Configures the phase timeouts of the coordinated shutdown run when the node leaves the
+    /// cluster, see [`shutdown`].
+    ///
+    /// [`shutdown`]: crate::remote::shutdown
+    pub fn coordinated_shutdown(&mut self, config: CoordinatedShutdownConfig) -> &mut Self {
+        self.coordinated_shutdown = Some(config);
+        self
+    }
+
+    /// Restricts leadership of the cluster to nodes with at least one of the provided roles.
+    /// Every node in the cluster should be configured with the same leader roles.
+    pub fn leader_roles<R: ToString>(&mut self, roles: impl IntoIterator<Item = R>) -> &mut Self {
+        self.leader_roles
+            .extend(roles.into_iter().map(|r| r.to_string()));
+        self
+    }
+
     pub fn build(
         self,
         tag: Option<String>,
         version: Option<String>,
-        client_auth: Option<ClientAuth>,
+        security: RemoteSystemSecurity,
+        transport: Arc<dyn Transport>,
         attributes: HashMap<String, String>,
+        roles: HashSet<String>,
     ) -> Arc<RemoteSystemConfig> {
         let mut handler_types = HashMap::new();
         let mut actor_types = HashMap::new();
@@ -320,16 +519,43 @@ impl RemoteSystemConfigBuilder {
             .collect::<NodeAttributes>()
             .into();
 
-        Arc::new(RemoteSystemConfig::new(
-            node_tag,
-            node_version,
-            actor_types,
-            handler_types,
-            self.handlers,
-            self.actors,
-            self.heartbeat.unwrap_or_default(),
-            attributes,
-            RemoteSystemSecurity::new(client_auth.unwrap_or_default()),
-        ))
+        let roles = roles
+            .into_iter()
+            .map(|r| r.into())
+            .collect::<NodeRoles>()
+            .into();
+
+        let leader_roles = self
+            .leader_roles
+            .into_iter()
+            .map(|r| r.into())
+            .collect::<NodeRoles>();
+
+        let datacenter = self
+            .datacenter
+            .filter(|d| !d.is_empty())
+            .map_or_else(|| DEFAULT_DATACENTER.into(), |d| d.into());
+
+        Arc::new(
+            RemoteSystemConfig::new(
+                node_tag,
+                node_version,
+                actor_types,
+                handler_types,
+                self.handlers,
+                self.actors,
+                self.heartbeat.unwrap_or_default(),
+                attributes,
+                security,
+            )
+            .with_split_brain_resolver(self.split_brain_resolver)
+            .with_raft(self.raft)
+            .with_gossip(self.gossip)
+            .with_network(self.network.unwrap_or_default())
+            .with_coordinated_shutdown(self.coordinated_shutdown.unwrap_or_default())
+            .with_roles(roles, leader_roles)
+            .with_datacenter(datacenter)
+            .with_transport(transport),
+        )
     }
 }
@@ -1,9 +1,13 @@
 use crate::remote::actor::message::{ClientWrite, GetNodes, NewClient, RegisterNode, UpdateNodes};
 use crate::remote::cluster::node::{RemoteNode, RemoteNodeState};
+use crate::remote::config::SystemCapabilities;
 use crate::remote::net::client::{ClientType, RemoteClientRef};
 use crate::remote::net::message::SessionEvent;
-use crate::remote::system::{NodeId, RemoteActorSystem};
+use crate::remote::net::proto::network::{ClusterNodes, GetClusterNodesEvent};
+use crate::remote::system::{NodeId, NodeRpcErr, RemoteActorSystem};
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use uuid::Uuid;
 
 impl RemoteActorSystem {
     pub async fn register_node(&self, node: RemoteNode) {
@@ -22,6 +26,41 @@ impl RemoteActorSystem {
         self.inner.registry_ref.send(GetNodes).await.unwrap()
     }
 
+    /// The healthy nodes of the cluster, as seen by the node `node_id`.
+    pub async fn cluster_nodes(&self, node_id: NodeId) -> Result<Vec<RemoteNode>, NodeRpcErr> {
+        let message_id = Uuid::new_v4();
+        let nodes = self
+            .node_rpc_proto::<ClusterNodes>(
+                message_id,
+                SessionEvent::GetClusterNodes(GetClusterNodesEvent {
+                    message_id: message_id.to_string(),
+                    ..Default::default()
+                }),
+                node_id,
+            )
+            .await?;
+
+        Ok(nodes.nodes.into_iter().map(|n| n.into()).collect())
+    }
+
+    /// Whether this system was started as a cluster client, which connects to the cluster
+    /// without becoming a member.
+    pub fn is_cluster_client(&self) -> bool {
+        self.inner.cluster_client.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_cluster_client(&self) {
+        self.inner.cluster_client.store(true, Ordering::Relaxed);
+    }
+
+    pub fn client_type(&self) -> ClientType {
+        if self.is_cluster_client() {
+            ClientType::Client
+        } else {
+            ClientType::Worker
+        }
+    }
+
     pub async fn update_nodes(&self, nodes: Vec<RemoteNodeState>) {
         self.inner
             .registry_ref
@@ -38,6 +77,36 @@ impl RemoteActorSystem {
             .unwrap()
     }
 
+    /// The actors and message handlers registered on the node, if the node has been identified.
+    pub fn node_capabilities(&self, node_id: NodeId) -> Option<Arc<SystemCapabilities>> {
+        self.inner.node_capabilities.read().get(&node_id).cloned()
+    }
+
+    pub fn update_node_capabilities(&self, node_id: NodeId, capabilities: SystemCapabilities) {
+        self.inner
+            .node_capabilities
+            .write()
+            .insert(node_id, Arc::new(capabilities));
+    }
+
+    /// Whether the node can handle messages with the given handler type, nodes that haven't
+    /// been identified yet are assumed to support every handler.
+    pub fn node_supports_handler(&self, node_id: NodeId, handler_type: &str) -> bool {
+        match self.inner.node_capabilities.read().get(&node_id) {
+            Some(capabilities) => capabilities.messages.iter().any(|m| m == handler_type),
+            None => true,
+        }
+    }
+
+    /// Whether the node can create actors of the given type, nodes that haven't
+    /// been identified yet are assumed to support every actor.
+    pub fn node_supports_actor(&self, node_id: NodeId, actor_type: &str) -> bool {
+        match self.inner.node_capabilities.read().get(&node_id) {
+            Some(capabilities) => capabilities.actors.iter().any(|a| a == actor_type),
+            None => true,
+        }
+    }
+
     pub fn current_leader(&self) -> Option<NodeId> {
         let n = self.inner.current_leader.load(Ordering::SeqCst);
         if n >= 0 {
@@ -59,11 +128,46 @@ impl RemoteActorSystem {
         }
     }
 
+    /// Forgets the current leader, until a new leader has been chosen, returning the previous
+    /// leader, if any.
+    pub(crate) fn clear_leader(&self) -> Option<NodeId> {
+        let n = self.inner.current_leader.swap(-1, Ordering::SeqCst);
+        if n >= 0 {
+            Some(n as NodeId)
+        } else {
+            None
+        }
+    }
+
+    /// The leader of the current node's datacenter, the oldest healthy node within the
+    /// datacenter that's eligible to become leader. When every node is within the same
+    /// datacenter, this is the same node as the cluster leader, unless raft is enabled.
+    pub fn datacenter_leader(&self) -> Option<NodeId> {
+        let n = self.inner.current_datacenter_leader.load(Ordering::SeqCst);
+        if n >= 0 {
+            Some(n as NodeId)
+        } else {
+            None
+        }
+    }
+
+    pub fn update_datacenter_leader(&self, new_leader: NodeId) -> Option<NodeId> {
+        let n = self
+            .inner
+            .current_datacenter_leader
+            .swap(new_leader as i64, Ordering::SeqCst);
+        if n >= 0 {
+            Some(n as NodeId)
+        } else {
+            None
+        }
+    }
+
     pub async fn get_remote_client(&self, addr: String) -> Option<RemoteClientRef> {
         self.client_registry()
             .send(NewClient {
                 addr,
-                client_type: ClientType::Worker,
+                client_type: self.client_type(),
                 system: self.clone(),
             })
             .await
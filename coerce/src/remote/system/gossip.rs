@@ -0,0 +1,9 @@
+use crate::remote::cluster::gossip::GossipSystem;
+use crate::remote::system::RemoteActorSystem;
+
+impl RemoteActorSystem {
+    /// The node's gossip system, if gossip-based membership is enabled.
+    pub fn gossip(&self) -> Option<&GossipSystem> {
+        self.inner.gossip.as_ref()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::actor::message::Handler;
+use crate::actor::{ActorRefErr, LocalActorRef};
+use crate::remote::actor::message::SubscribeMembership;
+use crate::remote::cluster::events::MemberEvent;
+use crate::remote::stream::pubsub::Subscription;
+use crate::remote::system::RemoteActorSystem;
+use tokio::sync::broadcast::error::RecvError;
+
+impl RemoteActorSystem {
+    /// Subscribes the actor to the cluster's membership events, see [`events`].
+    ///
+    /// The actor first receives [`MemberEvent::CurrentMembers`], followed by every membership
+    /// change, until the returned [`Subscription`] is dropped or unsubscribed.
+    ///
+    /// [`events`]: crate::remote::cluster::events
+    pub async fn subscribe_membership<A>(
+        &self,
+        actor_ref: LocalActorRef<A>,
+    ) -> Result<Subscription, ActorRefErr>
+    where
+        A: Handler<MemberEvent>,
+    {
+        let (current_members, mut receiver) =
+            self.inner.registry_ref.send(SubscribeMembership).await?;
+
+        let task_handle = tokio::spawn(async move {
+            if actor_ref.notify(current_members).is_err() {
+                return;
+            }
+
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        if actor_ref.notify(event).is_err() {
+                            break;
+                        }
+                    }
+
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(
+                            "membership subscriber (actor_id={}) lagged, skipped {} events",
+                            actor_ref.actor_id(),
+                            skipped
+                        );
+                    }
+
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        Ok(Subscription::from_task(task_handle))
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
-use std::sync::atomic::AtomicI64;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64};
 use std::sync::Arc;
 
 use crate::actor::system::ActorSystem;
@@ -10,17 +11,26 @@ use crate::remote::actor::{
 use crate::remote::cluster::builder::client::ClusterClientBuilder;
 use crate::remote::cluster::builder::worker::ClusterWorkerBuilder;
 use crate::remote::cluster::discovery::NodeDiscovery;
+use crate::remote::cluster::gossip::GossipSystem;
+use crate::remote::cluster::node::NodeRolesRef;
 use crate::remote::heartbeat::Heartbeat;
+use crate::remote::raft::RaftSystem;
+use crate::remote::shutdown::CoordinatedShutdown;
 use crate::remote::stream::mediator::StreamMediator;
 use crate::remote::system::builder::RemoteActorSystemBuilder;
+use crate::remote::system::watch::RemoteWatches;
 
 pub mod actor;
 pub mod builder;
 pub mod cluster;
+pub mod gossip;
+pub mod membership;
 pub mod raft;
 pub mod rpc;
+pub mod shutdown;
+pub mod watch;
 
-use crate::remote::config::RemoteSystemConfig;
+use crate::remote::config::{RemoteSystemConfig, SystemCapabilities};
 pub use actor::*;
 pub use cluster::*;
 pub use rpc::*;
@@ -43,9 +53,16 @@ pub struct RemoteSystemCore {
     clients_ref: LocalActorRef<RemoteClientRegistry>,
     discovery_ref: LocalActorRef<NodeDiscovery>,
     heartbeat_ref: LocalActorRef<Heartbeat>,
+    raft: Option<RaftSystem>,
+    gossip: Option<GossipSystem>,
+    coordinated_shutdown: CoordinatedShutdown,
     mediator_ref: Option<LocalActorRef<StreamMediator>>,
     config: Arc<RemoteSystemConfig>,
     current_leader: Arc<AtomicNodeId>,
+    current_datacenter_leader: Arc<AtomicNodeId>,
+    node_capabilities: Arc<parking_lot::RwLock<HashMap<NodeId, Arc<SystemCapabilities>>>>,
+    remote_watches: Arc<parking_lot::Mutex<RemoteWatches>>,
+    cluster_client: Arc<AtomicBool>,
 }
 
 impl RemoteActorSystem {
@@ -57,6 +74,15 @@ impl RemoteActorSystem {
 impl RemoteSystemCore {
     pub async fn shutdown(&self) {
         let _ = self.heartbeat_ref.stop().await;
+
+        if let Some(raft) = self.raft.as_ref() {
+            let _ = raft.actor_ref().stop().await;
+        }
+
+        if let Some(gossip) = self.gossip.as_ref() {
+            let _ = gossip.actor_ref().stop().await;
+        }
+
         let _ = self.clients_ref.stop().await;
 
         if let Some(mediator_ref) = self.mediator_ref.as_ref() {
@@ -91,6 +117,18 @@ impl RemoteActorSystem {
         self.inner.config.node_tag()
     }
 
+    pub fn node_roles(&self) -> &NodeRolesRef {
+        self.inner.config.get_roles()
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.node_roles().contains(role)
+    }
+
+    pub fn datacenter(&self) -> &str {
+        self.inner.config.get_datacenter()
+    }
+
     pub fn node_version(&self) -> &str {
         self.inner.config.node_version()
     }
@@ -1,8 +1,9 @@
-// use crate::remote::raft::RaftSystem;
-// use crate::remote::system::RemoteActorSystem;
-//
-// impl RemoteActorSystem {
-//     pub fn raft(&self) -> Option<&RaftSystem> {
-//         self.inner.raft.as_ref().map(|s| s.as_ref())
-//     }
-// }
+use crate::remote::raft::RaftSystem;
+use crate::remote::system::RemoteActorSystem;
+
+impl RemoteActorSystem {
+    /// The node's Raft system, if Raft is enabled.
+    pub fn raft(&self) -> Option<&RaftSystem> {
+        self.inner.raft.as_ref()
+    }
+}
@@ -0,0 +1,22 @@
+use crate::remote::shutdown::CoordinatedShutdown;
+use crate::remote::system::RemoteActorSystem;
+
+impl RemoteActorSystem {
+    /// The node's coordinated shutdown, which tasks can be added to, see [`shutdown`].
+    ///
+    /// [`shutdown`]: crate::remote::shutdown
+    pub fn coordinated_shutdown(&self) -> &CoordinatedShutdown {
+        &self.inner.coordinated_shutdown
+    }
+
+    /// Gracefully leaves the cluster by running the node's coordinated shutdown, which stops
+    /// the actor system once complete.
+    pub async fn leave(&self) {
+        self.inner.coordinated_shutdown.run(self).await;
+    }
+
+    /// Whether the node has announced that it's leaving the cluster.
+    pub fn is_leaving(&self) -> bool {
+        self.inner.coordinated_shutdown.is_leaving()
+    }
+}
@@ -0,0 +1,212 @@
+use crate::actor::watch::{ActorTerminated, TerminationReason};
+use crate::actor::{ActorId, BoxedActorRef, CoreActorRef, IntoActorId};
+use crate::remote::actor::message::ClientWrite;
+use crate::remote::net::message::SessionEvent;
+use crate::remote::net::proto::network::{self as proto, ActorTerminatedEvent, WatchActorEvent};
+use crate::remote::system::{NodeId, RemoteActorSystem};
+use protobuf::EnumOrUnknown;
+use std::collections::HashMap;
+
+/// Local actors watching actors hosted by other nodes, indexed by the node hosting the watched actor.
+#[derive(Default)]
+pub struct RemoteWatches {
+    nodes: HashMap<NodeId, HashMap<ActorId, Vec<BoxedActorRef>>>,
+}
+
+impl RemoteWatches {
+    /// Returns true if this is the first watcher of the actor.
+    fn add(&mut self, node_id: NodeId, actor_id: ActorId, watcher: BoxedActorRef) -> bool {
+        let watchers = self
+            .nodes
+            .entry(node_id)
+            .or_default()
+            .entry(actor_id)
+            .or_default();
+
+        if !watchers.iter().any(|w| w.actor_id() == watcher.actor_id()) {
+            watchers.push(watcher);
+        }
+
+        watchers.len() == 1
+    }
+
+    /// Returns true if there are no watchers of the actor remaining.
+    fn remove(&mut self, node_id: NodeId, actor_id: &ActorId, watcher_id: &ActorId) -> bool {
+        let actors = match self.nodes.get_mut(&node_id) {
+            Some(actors) => actors,
+            None => return false,
+        };
+
+        let watchers = match actors.get_mut(actor_id) {
+            Some(watchers) => watchers,
+            None => return false,
+        };
+
+        watchers.retain(|w| w.actor_id() != watcher_id);
+        if !watchers.is_empty() {
+            return false;
+        }
+
+        actors.remove(actor_id);
+        if actors.is_empty() {
+            self.nodes.remove(&node_id);
+        }
+
+        true
+    }
+
+    fn remove_actor(&mut self, node_id: NodeId, actor_id: &ActorId) -> Vec<BoxedActorRef> {
+        let actors = match self.nodes.get_mut(&node_id) {
+            Some(actors) => actors,
+            None => return vec![],
+        };
+
+        let watchers = actors.remove(actor_id).unwrap_or_default();
+        if actors.is_empty() {
+            self.nodes.remove(&node_id);
+        }
+
+        watchers
+    }
+
+    fn remove_node(&mut self, node_id: NodeId) -> HashMap<ActorId, Vec<BoxedActorRef>> {
+        self.nodes.remove(&node_id).unwrap_or_default()
+    }
+}
+
+impl RemoteActorSystem {
+    pub fn watch_remote_actor(&self, node_id: NodeId, actor_id: ActorId, watcher: BoxedActorRef) {
+        let is_first_watcher =
+            self.inner
+                .remote_watches
+                .lock()
+                .add(node_id, actor_id.clone(), watcher);
+
+        if is_first_watcher {
+            self.write_watch_event(node_id, &actor_id, false);
+        }
+    }
+
+    pub fn unwatch_remote_actor(&self, node_id: NodeId, actor_id: &ActorId, watcher_id: &ActorId) {
+        let no_watchers_remaining = self
+            .inner
+            .remote_watches
+            .lock()
+            .remove(node_id, actor_id, watcher_id);
+
+        if no_watchers_remaining {
+            self.write_watch_event(node_id, actor_id, true);
+        }
+    }
+
+    /// Notifies a node watching an actor hosted by this node that the actor has terminated.
+    pub fn notify_watcher_terminated(&self, node_id: NodeId, terminated: ActorTerminated) {
+        let event = SessionEvent::ActorTerminated(ActorTerminatedEvent {
+            actor_id: terminated.actor_id.to_string(),
+            node_id: self.node_id(),
+            reason: EnumOrUnknown::new(terminated.reason.into()),
+            ..Default::default()
+        });
+
+        let _ = self.inner.clients_ref.notify(ClientWrite(node_id, event));
+    }
+
+    pub(crate) fn on_remote_actor_terminated(&self, event: ActorTerminatedEvent) {
+        let actor_id = event.actor_id.into_actor_id();
+        let reason = event
+            .reason
+            .enum_value()
+            .map_or(TerminationReason::Stopped, |r| r.into());
+
+        let watchers = self
+            .inner
+            .remote_watches
+            .lock()
+            .remove_actor(event.node_id, &actor_id);
+
+        for watcher in watchers {
+            let _ = watcher.notify_actor_terminated(ActorTerminated {
+                actor_id: actor_id.clone(),
+                reason,
+            });
+        }
+    }
+
+    pub(crate) fn on_watched_node_terminated(&self, node_id: NodeId) {
+        let actors = self.inner.remote_watches.lock().remove_node(node_id);
+
+        for (actor_id, watchers) in actors {
+            debug!(
+                "node_id={} terminated, notifying {} watcher(s) of actor_id={}",
+                node_id,
+                watchers.len(),
+                &actor_id
+            );
+
+            for watcher in watchers {
+                let _ = watcher.notify_actor_terminated(ActorTerminated {
+                    actor_id: actor_id.clone(),
+                    reason: TerminationReason::NodeTerminated,
+                });
+            }
+        }
+    }
+
+    /// Called when a watch couldn't be written to the node hosting the watched actor, the
+    /// watchers are notified immediately rather than waiting for a notification that would
+    /// never arrive.
+    pub(crate) fn on_watched_node_unreachable(&self, node_id: NodeId, actor_id: ActorId) {
+        let watchers = self
+            .inner
+            .remote_watches
+            .lock()
+            .remove_actor(node_id, &actor_id);
+
+        debug!(
+            "unable to write watch to node_id={}, notifying {} watcher(s) of actor_id={}",
+            node_id,
+            watchers.len(),
+            &actor_id
+        );
+
+        for watcher in watchers {
+            let _ = watcher.notify_actor_terminated(ActorTerminated {
+                actor_id: actor_id.clone(),
+                reason: TerminationReason::Unreachable,
+            });
+        }
+    }
+
+    fn write_watch_event(&self, node_id: NodeId, actor_id: &ActorId, unwatch: bool) {
+        let event = SessionEvent::WatchActor(WatchActorEvent {
+            actor_id: actor_id.to_string(),
+            watcher_node_id: self.node_id(),
+            unwatch,
+            ..Default::default()
+        });
+
+        let _ = self.inner.clients_ref.notify(ClientWrite(node_id, event));
+    }
+}
+
+impl From<TerminationReason> for proto::TerminationReason {
+    fn from(reason: TerminationReason) -> Self {
+        match reason {
+            TerminationReason::Stopped => proto::TerminationReason::Stopped,
+            TerminationReason::NotFound => proto::TerminationReason::NotFound,
+            TerminationReason::NodeTerminated => proto::TerminationReason::NodeTerminated,
+            TerminationReason::Unreachable => proto::TerminationReason::Unreachable,
+        }
+    }
+}
+
+impl From<proto::TerminationReason> for TerminationReason {
+    fn from(reason: proto::TerminationReason) -> Self {
+        match reason {
+            proto::TerminationReason::Stopped => TerminationReason::Stopped,
+            proto::TerminationReason::NotFound => TerminationReason::NotFound,
+            proto::TerminationReason::NodeTerminated => TerminationReason::NodeTerminated,
+            proto::TerminationReason::Unreachable => TerminationReason::Unreachable,
+        }
+    }
+}
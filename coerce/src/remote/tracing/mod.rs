@@ -1,17 +1,51 @@
-// use opentelemetry::trace::{TraceContextExt, TraceFlags};
-// use tracing::Span;
-// use tracing_opentelemetry::OpenTelemetrySpanExt;
-//
-// #[inline]
-// pub fn extract_trace_identifier(span: &Span) -> String {
-//     let context = span.context();
-//     let span = context.span();
-//     let span_context = span.span_context();
-//     format!(
-//         "{:02x}-{:032x}-{:016x}-{:02x}",
-//         0,
-//         span_context.trace_id(),
-//         span_context.span_id(),
-//         span_context.trace_flags() & TraceFlags::SAMPLED
-//     )
-// }
+//! Propagation of trace context between nodes.
+//!
+//! When the `opentelemetry` feature is enabled, the context of the sending span is serialised as a
+//! [W3C traceparent](https://www.w3.org/TR/trace-context/#traceparent-header) into the `trace_id`
+//! field of outgoing messages, and restored as the parent of the span used to handle the message
+//! on the receiving node. Without the feature, no trace context is sent and any received trace
+//! context is ignored.
+
+use ::tracing::Span;
+
+#[cfg(feature = "opentelemetry")]
+use {
+    opentelemetry::propagation::TextMapPropagator,
+    opentelemetry::sdk::propagation::TraceContextPropagator, std::collections::HashMap,
+    tracing_opentelemetry::OpenTelemetrySpanExt,
+};
+
+#[cfg(feature = "opentelemetry")]
+const TRACEPARENT: &str = "traceparent";
+
+/// Serialises the context of `span` as a W3C traceparent, an empty string is returned when
+/// the span has no valid trace context.
+#[cfg(feature = "opentelemetry")]
+pub fn extract_trace_identifier(span: &Span) -> String {
+    let mut headers = HashMap::new();
+    TraceContextPropagator::new().inject_context(&span.context(), &mut headers);
+
+    headers.remove(TRACEPARENT).unwrap_or_default()
+}
+
+/// Sets the parent of `span` to the trace context received from another node,
+/// ignoring empty or invalid trace identifiers.
+#[cfg(feature = "opentelemetry")]
+pub fn set_trace_parent(span: &Span, trace_id: &str) {
+    if trace_id.is_empty() {
+        return;
+    }
+
+    let mut headers = HashMap::new();
+    headers.insert(TRACEPARENT.to_string(), trace_id.to_string());
+
+    span.set_parent(TraceContextPropagator::new().extract(&headers));
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+pub fn extract_trace_identifier(_span: &Span) -> String {
+    String::new()
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+pub fn set_trace_parent(_span: &Span, _trace_id: &str) {}
@@ -8,6 +8,7 @@ pub struct ShardingBuilder<A: ActorFactory> {
     shard_allocator: Option<Box<dyn ShardAllocator>>,
     shard_entity: Option<String>,
     system: Option<RemoteActorSystem>,
+    role: Option<String>,
     _a: PhantomData<A>,
 }
 
@@ -17,6 +18,7 @@ impl<A: ActorFactory> Sharding<A> {
             shard_allocator: None,
             shard_entity: None,
             system: Some(system),
+            role: None,
             _a: PhantomData,
         }
     }
@@ -33,6 +35,16 @@ impl<A: ActorFactory> ShardingBuilder<A> {
         self
     }
 
+    /// Only hosts entities on nodes with the provided role, see
+    /// [`RemoteActorSystemBuilder::with_roles`]. Nodes without the role can still send messages
+    /// to sharded entities.
+    ///
+    /// [`RemoteActorSystemBuilder::with_roles`]: crate::remote::system::builder::RemoteActorSystemBuilder::with_roles
+    pub fn with_role<S: ToString>(&mut self, role: S) -> &mut Self {
+        self.role = Some(role.to_string());
+        self
+    }
+
     pub async fn build(&mut self) -> Sharding<A> {
         Sharding::start(
             self.shard_entity
@@ -40,6 +52,7 @@ impl<A: ActorFactory> ShardingBuilder<A> {
                 .unwrap_or_else(|| A::Actor::type_name().to_string()),
             self.system.take().unwrap(),
             self.shard_allocator.take(),
+            self.role.take(),
         )
         .await
     }
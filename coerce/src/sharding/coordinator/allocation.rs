@@ -8,7 +8,7 @@ use crate::sharding::host::{ShardAllocated, ShardAllocator, ShardHost, ShardReal
 use crate::sharding::proto::sharding as proto;
 use futures::future::join_all;
 use protobuf::Message as ProtoMessage;
-use std::collections::hash_map::{DefaultHasher, Entry, VacantEntry};
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Display, Formatter};
 
 use crate::sharding::proto::sharding::allocate_shard_result;
@@ -17,6 +17,10 @@ use std::hash::{Hash, Hasher};
 pub struct AllocateShard {
     pub shard_id: ShardId,
     pub rebalancing: bool,
+
+    /// The datacenter the shard is preferably allocated within, typically the datacenter of the
+    /// node requesting the shard.
+    pub datacenter: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -40,24 +44,88 @@ impl ShardCoordinator {
             .map(|n| n.shards.iter().copied().collect())
     }
 
+    /// Allocates the shard, preferring hosts within `datacenter`, if provided.
     pub async fn allocate_shard(
         &mut self,
         shard_id: ShardId,
-        _ctx: &mut ActorContext,
+        datacenter: Option<&str>,
+        ctx: &mut ActorContext,
     ) -> AllocateShardResult {
-        let shard_entry = self.shards.entry(shard_id);
+        self.allocate_shard_to(shard_id, None, datacenter, ctx)
+            .await
+    }
 
-        match shard_entry {
-            Entry::Occupied(node) => AllocateShardResult::AlreadyAllocated(shard_id, *node.get()),
-            Entry::Vacant(vacant) => {
-                allocate(
-                    shard_id,
-                    self.hosts.values_mut().filter(|n| n.is_ready()).collect(),
-                    vacant,
-                )
-                .await
+    /// Allocates the shard, preferring `preferred_node` if it's available, followed by hosts
+    /// within `preferred_datacenter`. When raft is enabled, the allocation is only recorded once
+    /// it has been committed to the cluster metadata, and is rejected if the commit fails.
+    async fn allocate_shard_to(
+        &mut self,
+        shard_id: ShardId,
+        preferred_node: Option<NodeId>,
+        preferred_datacenter: Option<&str>,
+        ctx: &mut ActorContext,
+    ) -> AllocateShardResult {
+        if let Some(node_id) = self.shards.get(&shard_id) {
+            return AllocateShardResult::AlreadyAllocated(shard_id, *node_id);
+        }
+
+        let node_id = match select_host(
+            shard_id,
+            self.hosts.values().filter(|n| n.is_ready()).collect(),
+            preferred_node,
+            preferred_datacenter,
+        ) {
+            Some(node_id) => node_id,
+            None => return AllocateShardResult::NotAllocated,
+        };
+
+        if let Some(raft) = ctx.system().remote().raft() {
+            let allocation_key = shard_allocation_key(&self.shard_entity, shard_id);
+            let value = node_id.to_be_bytes().to_vec();
+
+            if raft.metadata().get(&allocation_key).as_ref() != Some(&value) {
+                if let Err(e) = raft.set(&allocation_key, value).await {
+                    warn!(
+                        "failed to store shard allocation (key={}, node_id={}), err={}",
+                        &allocation_key, node_id, e
+                    );
+
+                    return AllocateShardResult::Err(AllocateShardErr::Persistence);
+                }
             }
         }
+
+        self.shards.insert(shard_id, node_id);
+
+        let newly_allocated = self
+            .hosts
+            .get_mut(&node_id)
+            .is_some_and(|host| host.shards.insert(shard_id));
+
+        if newly_allocated {
+            let hosts = self
+                .hosts
+                .values()
+                .filter(|h| h.is_ready())
+                .map(|h| h.actor.clone())
+                .collect();
+
+            tokio::spawn(async move {
+                broadcast_allocation(shard_id, node_id, hosts).await;
+            });
+        }
+
+        AllocateShardResult::Allocated(shard_id, node_id)
+    }
+
+    /// The node the shard was last allocated to, as stored in the cluster metadata.
+    fn last_allocated_node(&self, shard_id: ShardId, ctx: &ActorContext) -> Option<NodeId> {
+        let raft = ctx.system().remote().raft()?;
+        let node_id = raft
+            .metadata()
+            .get(&shard_allocation_key(&self.shard_entity, shard_id))?;
+
+        node_id.try_into().ok().map(NodeId::from_be_bytes)
     }
 }
 
@@ -72,12 +140,13 @@ impl Handler<AllocateShard> for ShardCoordinator {
             return AllocateShardResult::AlreadyAllocated(message.shard_id, *entry);
         }
 
+        let datacenter = message.datacenter.as_deref();
         if message.rebalancing {
-            return self.allocate_shard(message.shard_id, ctx).await;
+            return self.allocate_shard(message.shard_id, datacenter, ctx).await;
         }
 
         match self.persist(&message, ctx).await {
-            Ok(_) => self.allocate_shard(message.shard_id, ctx).await,
+            Ok(_) => self.allocate_shard(message.shard_id, datacenter, ctx).await,
             Err(e) => {
                 warn!(
                     "error persisting a `AllocateShard`, shard_id={}, err={}",
@@ -94,42 +163,52 @@ impl Recover<AllocateShard> for ShardCoordinator {
     async fn recover(&mut self, message: AllocateShard, ctx: &mut ActorContext) {
         trace!("recovered `AllocateShard`, shard_id={}", message.shard_id);
 
-        self.allocate_shard(message.shard_id, ctx).await;
+        // when raft is enabled, recovered shards are allocated back to the node they were last
+        // allocated to, if it's still available
+        let last_allocated_node = self.last_allocated_node(message.shard_id, ctx);
+        self.allocate_shard_to(
+            message.shard_id,
+            last_allocated_node,
+            message.datacenter.as_deref(),
+            ctx,
+        )
+        .await;
     }
 }
 
-async fn allocate(
+/// The key of a shard's allocation within the cluster metadata replicated by raft.
+pub fn shard_allocation_key(shard_entity: &str, shard_id: ShardId) -> String {
+    format!("sharding/{}/shards/{}", shard_entity, shard_id)
+}
+
+/// Selects the host the shard should be allocated to, or `None` if there are no hosts available.
+fn select_host(
     shard_id: ShardId,
-    mut hosts: Vec<&mut ShardHostState>,
-    shard_entry: VacantEntry<'_, ShardId, NodeId>,
-) -> AllocateShardResult {
+    mut hosts: Vec<&ShardHostState>,
+    preferred_node: Option<NodeId>,
+    preferred_datacenter: Option<&str>,
+) -> Option<NodeId> {
     // TODO: weighted ordering - shards with more entities should have a higher weight, the more shards*
 
-    hosts.sort_by(|h1, h2| h1.shards.len().cmp(&h2.shards.len()));
+    // hosts in other datacenters are only used when there are no hosts available within the
+    // preferred datacenter, keeping traffic between datacenters to a minimum
+    hosts.sort_by_key(|h| {
+        (
+            Some(h.node_id) != preferred_node,
+            preferred_datacenter.is_some_and(|dc| h.datacenter.as_ref() != dc),
+            h.shards.len(),
+        )
+    });
 
     debug!(
         "shard#{} allocating - available nodes={:#?}",
         shard_id, &hosts
     );
 
-    if let Some(host) = hosts.first_mut() {
-        let node_id = host.node_id;
-
-        trace!("shard#{} allocated, target_node={}", shard_id, node_id);
-
-        shard_entry.insert(node_id);
-        if host.shards.insert(shard_id) {
-            let node_id = host.node_id;
-            let hosts = hosts.iter().map(|h| h.actor.clone()).collect();
-            tokio::spawn(async move {
-                broadcast_allocation(shard_id, node_id, hosts).await;
-            });
-        }
+    let node_id = hosts.first()?.node_id;
 
-        AllocateShardResult::Allocated(shard_id, node_id)
-    } else {
-        AllocateShardResult::NotAllocated
-    }
+    trace!("shard#{} selected target_node={}", shard_id, node_id);
+    Some(node_id)
 }
 
 pub async fn broadcast_allocation(
@@ -219,6 +298,7 @@ impl Message for AllocateShard {
         proto::AllocateShard {
             shard_id: self.shard_id,
             rebalancing: self.rebalancing,
+            datacenter: self.datacenter.clone().unwrap_or_default(),
             ..Default::default()
         }
         .write_to_bytes()
@@ -232,6 +312,8 @@ impl Message for AllocateShard {
                 Ok(AllocateShard {
                     shard_id: allocate_shard.shard_id,
                     rebalancing: allocate_shard.rebalancing,
+                    datacenter: Some(allocate_shard.datacenter)
+                        .filter(|datacenter| !datacenter.is_empty()),
                 })
             },
         )
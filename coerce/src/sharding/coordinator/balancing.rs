@@ -99,6 +99,9 @@ impl ShardCoordinator {
                 let shard_host = self.hosts.get_mut(&shard_node_id).unwrap();
                 let _ = shard_host.shards.remove(&shard);
 
+                // the shard is kept within the datacenter it was previously allocated within
+                let datacenter = Some(shard_host.datacenter.to_string());
+
                 let self_ref = self_ref.clone();
                 let shard_host_actor = shard_host.actor.clone();
 
@@ -118,7 +121,7 @@ impl ShardCoordinator {
                                 let result = ShardStopped::from_bytes(result.into_result().unwrap());
                                 match result {
                                     Ok(_res) => {
-                                        let _ = self_ref.send(AllocateShard { shard_id: shard, rebalancing: true }).await;
+                                        let _ = self_ref.send(AllocateShard { shard_id: shard, rebalancing: true, datacenter }).await;
                                     },
                                     Err(e) => {
                                         error!("deserialization error: {}", e);
@@ -148,7 +151,7 @@ impl ShardCoordinator {
     pub async fn rebalance_unavailable_node(&mut self, node_id: NodeId, ctx: &mut ActorContext) {
         debug!("beginning re-balance of shards hosted on node={}", node_id);
 
-        let shards_to_rebalance = {
+        let (shards_to_rebalance, datacenter) = {
             let mut shard_host_state = match self.hosts.get_mut(&node_id) {
                 None => return,
                 Some(shard_host_state) => shard_host_state,
@@ -158,13 +161,16 @@ impl ShardCoordinator {
             // The node can be made available again, once confirmed
             shard_host_state.status = ShardHostStatus::Unavailable;
 
-            mem::take(&mut shard_host_state.shards)
+            (
+                mem::take(&mut shard_host_state.shards),
+                shard_host_state.datacenter.clone(),
+            )
         };
 
         let shard_count = shards_to_rebalance.len();
         for shard in shards_to_rebalance {
             self.shards.remove(&shard);
-            let _ = self.allocate_shard(shard, ctx).await;
+            let _ = self.allocate_shard(shard, Some(&datacenter), ctx).await;
         }
 
         debug!(
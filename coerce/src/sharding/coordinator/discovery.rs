@@ -24,6 +24,15 @@ impl Message for NodeForgotten {
 impl Handler<NodeDiscovered> for ShardCoordinator {
     async fn handle(&mut self, message: NodeDiscovered, ctx: &mut ActorContext) {
         let new_node = message.0;
+        if !self.is_host_role(&new_node.roles) {
+            trace!(
+                "node_id={} does not have the shard host role, ignoring (shard_entity={})",
+                new_node.id,
+                &self.shard_entity
+            );
+            return;
+        }
+
         match self.hosts.entry(new_node.id) {
             Entry::Occupied(mut node) => {
                 let node = node.get_mut();
@@ -43,6 +52,7 @@ impl Handler<NodeDiscovered> for ShardCoordinator {
                 vacant_entry.insert(ShardHostState {
                     node_id: new_node.id,
                     node_tag: new_node.tag.clone(),
+                    datacenter: new_node.datacenter.clone(),
                     shards: Default::default(),
                     actor: ShardHost::remote_ref(&self.shard_entity, new_node.id, &remote),
                     status: ShardHostStatus::Ready/*TODO: shard hosts may not be immediately ready*/,
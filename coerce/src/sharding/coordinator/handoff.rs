@@ -0,0 +1,114 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message, MessageUnwrapErr, MessageWrapErr};
+use crate::remote::system::NodeId;
+use crate::sharding::coordinator::{ShardCoordinator, ShardHostStatus, ShardId};
+use crate::sharding::proto::sharding as proto;
+use protobuf::Message as ProtoMessage;
+
+/// The shard host on `node_id` is leaving the cluster, and is excluded from shard allocation.
+pub struct ShardHostLeaving(pub NodeId);
+
+/// Hands off every shard hosted on `node_id` to the other available hosts. Each shard is stopped
+/// before it's re-allocated.
+pub struct HandOffShards(pub NodeId);
+
+#[async_trait]
+impl Handler<ShardHostLeaving> for ShardCoordinator {
+    async fn handle(&mut self, message: ShardHostLeaving, _ctx: &mut ActorContext) {
+        let node_id = message.0;
+        if let Some(host) = self.hosts.get_mut(&node_id) {
+            if host.status != ShardHostStatus::Unavailable {
+                info!(
+                    "shard host on node_id={} is leaving, excluding from shard allocation (shard_entity={})",
+                    node_id, &self.shard_entity
+                );
+            }
+
+            host.status = ShardHostStatus::Unavailable;
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<HandOffShards> for ShardCoordinator {
+    async fn handle(&mut self, message: HandOffShards, ctx: &mut ActorContext) -> usize {
+        let node_id = message.0;
+        let shards: Vec<ShardId> = match self.hosts.get_mut(&node_id) {
+            Some(host) => {
+                host.status = ShardHostStatus::Unavailable;
+                host.shards.iter().copied().collect()
+            }
+            None => return 0,
+        };
+
+        info!(
+            "handing off {} shards from node_id={} (shard_entity={})",
+            shards.len(),
+            node_id,
+            &self.shard_entity
+        );
+
+        let shard_count = shards.len();
+        let self_ref = ctx.actor_ref();
+        self.rebalance_shards(shards, self_ref, ctx.system().remote())
+            .await;
+
+        shard_count
+    }
+}
+
+impl Message for ShardHostLeaving {
+    type Result = ();
+
+    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+        proto::ShardHostLeaving {
+            node_id: self.0,
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .map_err(|_| MessageWrapErr::SerializationErr)
+    }
+
+    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+        proto::ShardHostLeaving::parse_from_bytes(&b)
+            .map(|r| Self(r.node_id))
+            .map_err(|_| MessageUnwrapErr::DeserializationErr)
+    }
+
+    fn read_remote_result(_: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
+        Ok(())
+    }
+
+    fn write_remote_result(_res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
+        Ok(vec![])
+    }
+}
+
+impl Message for HandOffShards {
+    type Result = usize;
+
+    fn as_bytes(&self) -> Result<Vec<u8>, MessageWrapErr> {
+        proto::HandOffShards {
+            node_id: self.0,
+            ..Default::default()
+        }
+        .write_to_bytes()
+        .map_err(|_| MessageWrapErr::SerializationErr)
+    }
+
+    fn from_bytes(b: Vec<u8>) -> Result<Self, MessageUnwrapErr> {
+        proto::HandOffShards::parse_from_bytes(&b)
+            .map(|r| Self(r.node_id))
+            .map_err(|_| MessageUnwrapErr::DeserializationErr)
+    }
+
+    fn read_remote_result(b: Vec<u8>) -> Result<Self::Result, MessageUnwrapErr> {
+        b.try_into()
+            .map(|b| u64::from_le_bytes(b) as usize)
+            .map_err(|_| MessageUnwrapErr::DeserializationErr)
+    }
+
+    fn write_remote_result(res: Self::Result) -> Result<Vec<u8>, MessageWrapErr> {
+        Ok((res as u64).to_le_bytes().to_vec())
+    }
+}
@@ -9,6 +9,7 @@ use crate::sharding::host::ShardHost;
 use crate::remote::system::NodeId;
 
 use crate::actor::message::Handler;
+use crate::remote::cluster::node::NodeRoles;
 use crate::remote::cluster::node::NodeStatus::{Healthy, Joining};
 use crate::remote::heartbeat::Heartbeat;
 use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
@@ -16,11 +17,13 @@ use crate::remote::stream::system::SystemTopic;
 
 use crate::sharding::coordinator::balancing::Rebalance;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Duration;
 
 pub mod allocation;
 pub mod balancing;
 pub mod discovery;
+pub mod handoff;
 pub mod spawner;
 pub mod stats;
 pub mod stream;
@@ -31,6 +34,7 @@ pub type ShardId = u32;
 pub struct ShardHostState {
     pub node_id: NodeId,
     pub node_tag: String,
+    pub datacenter: Arc<str>,
     pub shards: HashSet<ShardId>,
     pub actor: ActorRef<ShardHost>,
     pub status: ShardHostStatus,
@@ -59,6 +63,7 @@ pub struct ShardCoordinator {
     scheduled_rebalance: Option<ScheduledRebalance>,
     self_node_id: Option<NodeId>,
     system_event_subscription: Option<Subscription>,
+    role: Option<String>,
 }
 
 type ScheduledRebalance = ScheduledNotify<ShardCoordinator, Rebalance>;
@@ -86,13 +91,16 @@ impl PersistentActor for ShardCoordinator {
         let node_tag = remote.node_tag().to_string();
 
         self.self_node_id = Some(node_id);
-        self.add_host(ShardHostState {
-            node_id,
-            node_tag,
-            shards: Default::default(),
-            actor: self.local_shard_host.clone().into(),
-            status: ShardHostStatus::Ready,
-        });
+        if self.is_host_role(remote.config().get_roles()) {
+            self.add_host(ShardHostState {
+                node_id,
+                node_tag,
+                datacenter: remote.config().get_datacenter().clone(),
+                shards: Default::default(),
+                actor: self.local_shard_host.clone().into(),
+                status: ShardHostStatus::Ready,
+            });
+        }
 
         // TODO: start a healthcheck actor/timer checking all allocated shards ensuring they're up,
         //       or rebalance/rehydrate if necessary
@@ -110,10 +118,11 @@ impl PersistentActor for ShardCoordinator {
 
         let potential_hosts = remote.get_nodes().await;
         for host in potential_hosts {
-            if host.id != node_id {
+            if host.id != node_id && self.is_host_role(&host.roles) {
                 self.add_host(ShardHostState {
                     node_id: host.id,
                     node_tag: String::default(),
+                    datacenter: host.datacenter.clone(),
                     shards: HashSet::new(),
                     actor: ShardHost::remote_ref(&self.shard_entity, host.id, remote),
                     status: if host.status == Healthy || host.status == Joining {
@@ -145,6 +154,20 @@ impl ShardCoordinator {
             scheduled_rebalance: None,
             self_node_id: None,
             system_event_subscription: None,
+            role: None,
+        }
+    }
+
+    /// Only allocates shards to nodes with the provided role, when set.
+    pub fn with_role(mut self, role: Option<String>) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn is_host_role(&self, roles: &NodeRoles) -> bool {
+        match &self.role {
+            Some(role) => roles.contains(role.as_str()),
+            None => true,
         }
     }
 
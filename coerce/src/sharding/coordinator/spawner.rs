@@ -5,6 +5,7 @@ use crate::remote::stream::pubsub::{PubSub, Receive, Subscription};
 use crate::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
 use crate::remote::system::NodeId;
 use crate::sharding::coordinator::discovery::{NodeDiscovered, NodeForgotten};
+use crate::sharding::coordinator::handoff::ShardHostLeaving;
 use crate::sharding::coordinator::ShardCoordinator;
 use crate::sharding::host::{LeaderAllocated, ShardHost};
 
@@ -15,6 +16,7 @@ pub struct CoordinatorSpawner {
     local_shard_host: LocalActorRef<ShardHost>,
     system_event_subscription: Option<Subscription>,
     coordinator: Option<LocalActorRef<ShardCoordinator>>,
+    role: Option<String>,
 }
 
 impl CoordinatorSpawner {
@@ -22,8 +24,10 @@ impl CoordinatorSpawner {
         node_id: NodeId,
         shard_entity: String,
         local_shard_host: LocalActorRef<ShardHost>,
+        role: Option<String>,
     ) -> CoordinatorSpawner {
         Self {
+            role,
             node_id,
             shard_entity,
             local_shard_host,
@@ -36,6 +40,7 @@ impl CoordinatorSpawner {
     pub async fn start_coordinator(&mut self, ctx: &mut ActorContext) {
         let coordinator =
             ShardCoordinator::new(self.shard_entity.clone(), self.local_shard_host.clone())
+                .with_role(self.role.clone())
                 .into_actor(
                     Some(format!("shard-coordinator-{}", &self.shard_entity)),
                     &ctx.system(),
@@ -115,6 +120,12 @@ impl Handler<Receive<SystemTopic>> for CoordinatorSpawner {
                     }
                 }
 
+                ClusterEvent::NodeLeaving(node_id) => {
+                    if let Some(coordinator) = &self.coordinator {
+                        let _ = coordinator.notify(ShardHostLeaving(*node_id));
+                    }
+                }
+
                 ClusterEvent::LeaderChanged(leader_node_id) => {
                     let leader_node_id = *leader_node_id;
                     debug!(
@@ -135,6 +146,23 @@ impl Handler<Receive<SystemTopic>> for CoordinatorSpawner {
                         );
                     }
                 }
+
+                // the coordinator is always hosted by the cluster leader
+                ClusterEvent::DatacenterLeaderChanged(_) => {}
+
+                ClusterEvent::NodeDowned(node_id) => {
+                    if *node_id != self.node_id {
+                        return;
+                    }
+
+                    warn!(
+                        "[node={}] node was downed, stopping shard host (entity={})",
+                        self.node_id, &self.shard_entity
+                    );
+
+                    self.stop_coordinator().await;
+                    let _ = self.local_shard_host.stop().await;
+                }
             },
         }
     }
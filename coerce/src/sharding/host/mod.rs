@@ -18,6 +18,7 @@ use std::collections::{HashMap, VecDeque};
 use uuid::Uuid;
 
 pub mod request;
+pub mod shutdown;
 pub mod stats;
 
 pub enum ShardState {
@@ -82,6 +82,7 @@ impl Handler<EntityRequest> for ShardHost {
             ));
         } else if ctx.system().remote().current_leader().is_some() {
             let leader = self.get_coordinator(&ctx).await;
+            let datacenter = ctx.system().remote().datacenter().to_string();
 
             let buffered_requests = self.requests_pending_shard_allocation.entry(shard_id);
             let buffered_requests = buffered_requests.or_insert_with(|| vec![]);
@@ -97,6 +98,7 @@ impl Handler<EntityRequest> for ShardHost {
                     .send(AllocateShard {
                         shard_id,
                         rebalancing: false,
+                        datacenter: Some(datacenter),
                     })
                     .await;
                 match allocation {
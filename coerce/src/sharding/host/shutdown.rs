@@ -0,0 +1,145 @@
+use crate::actor::context::ActorContext;
+use crate::actor::message::{Handler, Message};
+use crate::actor::{Actor, ActorRef, LocalActorRef};
+use crate::remote::shutdown::ShutdownPhase;
+use crate::remote::system::RemoteActorSystem;
+use crate::sharding::coordinator::handoff::{HandOffShards, ShardHostLeaving};
+use crate::sharding::coordinator::ShardCoordinator;
+use crate::sharding::host::{
+    GetCoordinator, GetShards, HostedShards, ShardHost, ShardState, ShardStopped,
+};
+use futures::future::join_all;
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+const HAND_OFF_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Stops every shard that's hosted locally, passivating their entities.
+pub struct StopHostedShards;
+
+impl Message for StopHostedShards {
+    type Result = oneshot::Receiver<()>;
+}
+
+#[async_trait]
+impl Handler<GetShards> for ShardHost {
+    async fn handle(&mut self, _message: GetShards, _ctx: &mut ActorContext) -> HostedShards {
+        HostedShards {
+            shards: self.hosted_shards.keys().copied().collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<StopHostedShards> for ShardHost {
+    async fn handle(
+        &mut self,
+        _message: StopHostedShards,
+        ctx: &mut ActorContext,
+    ) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+        let shards: Vec<_> = self
+            .hosted_shards
+            .iter_mut()
+            .filter_map(|(shard_id, state)| match state.actor_ref() {
+                Some(actor_ref) => {
+                    *state = ShardState::Stopping;
+                    Some((*shard_id, actor_ref))
+                }
+                None => None,
+            })
+            .collect();
+
+        debug!(
+            "stopping {} hosted shards (shard_entity={})",
+            shards.len(),
+            &self.shard_entity
+        );
+
+        let shard_host = self.actor_ref(ctx);
+        tokio::spawn(async move {
+            join_all(shards.into_iter().map(|(shard_id, actor_ref)| {
+                let shard_host = shard_host.clone();
+                async move {
+                    let result = actor_ref.stop().await;
+                    let _ = shard_host.notify(ShardStopped {
+                        shard_id,
+                        stopped_successfully: result.is_ok(),
+                    });
+                }
+            }))
+            .await;
+
+            let _ = tx.send(());
+        });
+
+        rx
+    }
+}
+
+/// Adds the shard host's tasks to the node's coordinated shutdown, so that its shards are handed
+/// off to the other hosts when the node leaves the cluster.
+pub(crate) fn add_shutdown_tasks(
+    shard_host: &LocalActorRef<ShardHost>,
+    system: &RemoteActorSystem,
+) {
+    let coordinated_shutdown = system.coordinated_shutdown();
+    let shard_entity = &shard_host.actor_id();
+
+    let host = shard_host.clone();
+    coordinated_shutdown.add_task(
+        ShutdownPhase::StopAcceptingEntities,
+        format!("{}-stop-accepting-shards", shard_entity),
+        |system| async move {
+            if let Some(coordinator) = get_coordinator(&host, &system).await {
+                let _ = coordinator.send(ShardHostLeaving(system.node_id())).await;
+            }
+        },
+    );
+
+    let host = shard_host.clone();
+    coordinated_shutdown.add_task(
+        ShutdownPhase::HandOffShards,
+        format!("{}-hand-off-shards", shard_entity),
+        |system| async move {
+            let coordinator = match get_coordinator(&host, &system).await {
+                Some(coordinator) => coordinator,
+                None => return,
+            };
+
+            if let Err(e) = coordinator.send(HandOffShards(system.node_id())).await {
+                warn!("failed to hand off shards, e={}", e);
+                return;
+            }
+
+            while let Ok(hosted_shards) = host.send(GetShards).await {
+                if hosted_shards.shards.is_empty() {
+                    break;
+                }
+
+                tokio::time::sleep(HAND_OFF_POLL_INTERVAL).await;
+            }
+        },
+    );
+
+    let host = shard_host.clone();
+    coordinated_shutdown.add_task(
+        ShutdownPhase::PassivateEntities,
+        format!("{}-passivate-entities", shard_entity),
+        |_system| async move {
+            if let Ok(shards_stopped) = host.send(StopHostedShards).await {
+                let _ = shards_stopped.await;
+            }
+
+            let _ = host.stop().await;
+        },
+    );
+}
+
+async fn get_coordinator(
+    host: &LocalActorRef<ShardHost>,
+    system: &RemoteActorSystem,
+) -> Option<ActorRef<ShardCoordinator>> {
+    system.current_leader()?;
+    host.send(GetCoordinator).await.ok()
+}
@@ -8,10 +8,12 @@ use crate::actor::{
 use crate::remote::system::builder::RemoteSystemConfigBuilder;
 use crate::remote::system::RemoteActorSystem;
 use crate::sharding::coordinator::allocation::AllocateShard;
+use crate::sharding::coordinator::handoff::{HandOffShards, ShardHostLeaving};
 use crate::sharding::coordinator::spawner::CoordinatorSpawner;
 use crate::sharding::coordinator::stats::GetShardingStats;
 use crate::sharding::coordinator::ShardCoordinator;
 use crate::sharding::host::request::{EntityRequest, RemoteEntityRequest};
+use crate::sharding::host::shutdown::add_shutdown_tasks;
 use crate::sharding::host::{
     ShardAllocated, ShardAllocator, ShardHost, ShardReallocating, StopShard,
 };
@@ -52,6 +54,7 @@ impl<A: ActorFactory> Sharding<A> {
         shard_entity: String,
         system: RemoteActorSystem,
         allocator: Option<Box<dyn ShardAllocator>>,
+        role: Option<String>,
     ) -> Self {
         let actor_handler = match system
             .config()
@@ -68,8 +71,10 @@ impl<A: ActorFactory> Sharding<A> {
             .await
             .expect("create ShardHost actor");
 
+        add_shutdown_tasks(&host, &system);
+
         let coordinator_spawner =
-            CoordinatorSpawner::new(system.node_id(), shard_entity.clone(), host.clone())
+            CoordinatorSpawner::new(system.node_id(), shard_entity.clone(), host.clone(), role)
                 .into_actor(
                     Some(format!("shard-coordinator-spawner-{}", &shard_entity).into_actor_id()),
                     system.actor_system(),
@@ -183,6 +188,8 @@ pub fn sharding(builder: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemCon
     builder
         .with_handler::<ShardCoordinator, AllocateShard>("ShardCoordinator.AllocateShard")
         .with_handler::<ShardCoordinator, GetShardingStats>("ShardCoordinator.GetShardingStats")
+        .with_handler::<ShardCoordinator, ShardHostLeaving>("ShardCoordinator.ShardHostLeaving")
+        .with_handler::<ShardCoordinator, HandOffShards>("ShardCoordinator.HandOffShards")
         .with_handler::<ShardHost, ShardAllocated>("ShardHost.ShardAllocated")
         .with_handler::<ShardHost, ShardReallocating>("ShardHost.ShardReallocating")
         .with_handler::<ShardHost, StopShard>("ShardHost.StopShard")
@@ -33,6 +33,8 @@ pub struct AllocateShard {
     pub shard_id: u32,
     // @@protoc_insertion_point(field:coerce.sharding.AllocateShard.rebalancing)
     pub rebalancing: bool,
+    // @@protoc_insertion_point(field:coerce.sharding.AllocateShard.datacenter)
+    pub datacenter: ::std::string::String,
     // special fields
     // @@protoc_insertion_point(special_field:coerce.sharding.AllocateShard.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -50,7 +52,7 @@ impl AllocateShard {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "shard_id",
@@ -62,6 +64,11 @@ impl AllocateShard {
             |m: &AllocateShard| { &m.rebalancing },
             |m: &mut AllocateShard| { &mut m.rebalancing },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "datacenter",
+            |m: &AllocateShard| { &m.datacenter },
+            |m: &mut AllocateShard| { &mut m.datacenter },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AllocateShard>(
             "AllocateShard",
             fields,
@@ -86,6 +93,9 @@ impl ::protobuf::Message for AllocateShard {
                 16 => {
                     self.rebalancing = is.read_bool()?;
                 },
+                26 => {
+                    self.datacenter = is.read_string()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -104,6 +114,9 @@ impl ::protobuf::Message for AllocateShard {
         if self.rebalancing != false {
             my_size += 1 + 1;
         }
+        if !self.datacenter.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.datacenter);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -116,6 +129,9 @@ impl ::protobuf::Message for AllocateShard {
         if self.rebalancing != false {
             os.write_bool(2, self.rebalancing)?;
         }
+        if !self.datacenter.is_empty() {
+            os.write_string(3, &self.datacenter)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -135,6 +151,7 @@ impl ::protobuf::Message for AllocateShard {
     fn clear(&mut self) {
         self.shard_id = 0;
         self.rebalancing = false;
+        self.datacenter.clear();
         self.special_fields.clear();
     }
 
@@ -142,6 +159,7 @@ impl ::protobuf::Message for AllocateShard {
         static instance: AllocateShard = AllocateShard {
             shard_id: 0,
             rebalancing: false,
+            datacenter: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -1144,6 +1162,250 @@ pub mod allocate_shard_result {
     }
 }
 
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.sharding.ShardHostLeaving)
+pub struct ShardHostLeaving {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.sharding.ShardHostLeaving.node_id)
+    pub node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.sharding.ShardHostLeaving.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ShardHostLeaving {
+    fn default() -> &'a ShardHostLeaving {
+        <ShardHostLeaving as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShardHostLeaving {
+    pub fn new() -> ShardHostLeaving {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &ShardHostLeaving| { &m.node_id },
+            |m: &mut ShardHostLeaving| { &mut m.node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ShardHostLeaving>(
+            "ShardHostLeaving",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ShardHostLeaving {
+    const NAME: &'static str = "ShardHostLeaving";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ShardHostLeaving {
+        ShardHostLeaving::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ShardHostLeaving {
+        static instance: ShardHostLeaving = ShardHostLeaving {
+            node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ShardHostLeaving {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ShardHostLeaving").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ShardHostLeaving {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShardHostLeaving {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:coerce.sharding.HandOffShards)
+pub struct HandOffShards {
+    // message fields
+    // @@protoc_insertion_point(field:coerce.sharding.HandOffShards.node_id)
+    pub node_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:coerce.sharding.HandOffShards.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HandOffShards {
+    fn default() -> &'a HandOffShards {
+        <HandOffShards as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HandOffShards {
+    pub fn new() -> HandOffShards {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "node_id",
+            |m: &HandOffShards| { &m.node_id },
+            |m: &mut HandOffShards| { &mut m.node_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HandOffShards>(
+            "HandOffShards",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HandOffShards {
+    const NAME: &'static str = "HandOffShards";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.node_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.node_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.node_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.node_id != 0 {
+            os.write_uint64(1, self.node_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HandOffShards {
+        HandOffShards::new()
+    }
+
+    fn clear(&mut self) {
+        self.node_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HandOffShards {
+        static instance: HandOffShards = HandOffShards {
+            node_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HandOffShards {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HandOffShards").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HandOffShards {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HandOffShards {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default,Debug)]
 // @@protoc_insertion_point(message:coerce.sharding.RemoteEntityRequest)
 pub struct RemoteEntityRequest {
@@ -3007,31 +3269,34 @@ impl ShardHostStatus {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0esharding.proto\x12\x0fcoerce.sharding\"L\n\rAllocateShard\x12\x19\
+    \n\x0esharding.proto\x12\x0fcoerce.sharding\"l\n\rAllocateShard\x12\x19\
     \n\x08shard_id\x18\x01\x20\x01(\rR\x07shardId\x12\x20\n\x0brebalancing\
-    \x18\x02\x20\x01(\x08R\x0brebalancing\"A\n\x0bRemoteShard\x12\x19\n\x08s\
-    hard_id\x18\x01\x20\x01(\rR\x07shardId\x12\x17\n\x07node_id\x18\x02\x20\
-    \x01(\x04R\x06nodeId\"D\n\x0eShardAllocated\x122\n\x05shard\x18\x01\x20\
-    \x01(\x0b2\x1c.coerce.sharding.RemoteShardR\x05shard\".\n\x11ShardReallo\
-    cating\x12\x19\n\x08shard_id\x18\x01\x20\x01(\rR\x07shardId\"k\n\tStopSh\
-    ard\x12\x19\n\x08shard_id\x18\x01\x20\x01(\rR\x07shardId\x12$\n\x0eorigi\
-    n_node_id\x18\x02\x20\x01(\x04R\x0coriginNodeId\x12\x1d\n\nrequest_id\
-    \x18\x03\x20\x01(\tR\trequestId\"t\n\x0cShardStopped\x12\x19\n\x08shard_\
-    id\x18\x01\x20\x01(\rR\x07shardId\x12$\n\x0eorigin_node_id\x18\x02\x20\
-    \x01(\x04R\x0coriginNodeId\x12#\n\ris_successful\x18\x03\x20\x01(\x08R\
-    \x0cisSuccessful\"\xe4\x02\n\x13AllocateShardResult\x12J\n\x0bresult_typ\
-    e\x18\x01\x20\x01(\x0e2).coerce.sharding.AllocateShardResult.TypeR\nresu\
-    ltType\x12<\n\nallocation\x18\x02\x20\x01(\x0b2\x1c.coerce.sharding.Remo\
-    teShardR\nallocation\x12G\n\x03err\x18\x03\x20\x01(\x0e25.coerce.shardin\
-    g.AllocateShardResult.AllocateShardErrR\x03err\"H\n\x04Type\x12\r\n\tALL\
-    OCATED\x10\0\x12\x15\n\x11ALREADY_ALLOCATED\x10\x01\x12\x11\n\rNOT_ALLOC\
-    ATED\x10\x02\x12\x07\n\x03ERR\x10\x03\"0\n\x10AllocateShardErr\x12\x0b\n\
-    \x07UNKNOWN\x10\0\x12\x0f\n\x0bPERSISTENCE\x10\x01\"\x94\x02\n\x13Remote\
-    EntityRequest\x12\x1d\n\nrequest_id\x18\x01\x20\x01(\tR\trequestId\x12\
-    \x19\n\x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12!\n\x0cmessage_type\
-    \x18\x03\x20\x01(\tR\x0bmessageType\x12\x18\n\x07message\x18\x04\x20\x01\
-    (\x0cR\x07message\x12C\n\x06recipe\x18\x05\x20\x01(\x0b2+.coerce.shardin\
-    g.RemoteEntityRequest.RecipeR\x06recipe\x12\x1f\n\x0borigin_node\x18\x06\
+    \x18\x02\x20\x01(\x08R\x0brebalancing\x12\x1e\n\ndatacenter\x18\x03\x20\
+    \x01(\tR\ndatacenter\"A\n\x0bRemoteShard\x12\x19\n\x08shard_id\x18\x01\
+    \x20\x01(\rR\x07shardId\x12\x17\n\x07node_id\x18\x02\x20\x01(\x04R\x06no\
+    deId\"D\n\x0eShardAllocated\x122\n\x05shard\x18\x01\x20\x01(\x0b2\x1c.co\
+    erce.sharding.RemoteShardR\x05shard\".\n\x11ShardReallocating\x12\x19\n\
+    \x08shard_id\x18\x01\x20\x01(\rR\x07shardId\"k\n\tStopShard\x12\x19\n\
+    \x08shard_id\x18\x01\x20\x01(\rR\x07shardId\x12$\n\x0eorigin_node_id\x18\
+    \x02\x20\x01(\x04R\x0coriginNodeId\x12\x1d\n\nrequest_id\x18\x03\x20\x01\
+    (\tR\trequestId\"t\n\x0cShardStopped\x12\x19\n\x08shard_id\x18\x01\x20\
+    \x01(\rR\x07shardId\x12$\n\x0eorigin_node_id\x18\x02\x20\x01(\x04R\x0cor\
+    iginNodeId\x12#\n\ris_successful\x18\x03\x20\x01(\x08R\x0cisSuccessful\"\
+    \xe4\x02\n\x13AllocateShardResult\x12J\n\x0bresult_type\x18\x01\x20\x01(\
+    \x0e2).coerce.sharding.AllocateShardResult.TypeR\nresultType\x12<\n\nall\
+    ocation\x18\x02\x20\x01(\x0b2\x1c.coerce.sharding.RemoteShardR\nallocati\
+    on\x12G\n\x03err\x18\x03\x20\x01(\x0e25.coerce.sharding.AllocateShardRes\
+    ult.AllocateShardErrR\x03err\"H\n\x04Type\x12\r\n\tALLOCATED\x10\0\x12\
+    \x15\n\x11ALREADY_ALLOCATED\x10\x01\x12\x11\n\rNOT_ALLOCATED\x10\x02\x12\
+    \x07\n\x03ERR\x10\x03\"0\n\x10AllocateShardErr\x12\x0b\n\x07UNKNOWN\x10\
+    \0\x12\x0f\n\x0bPERSISTENCE\x10\x01\"+\n\x10ShardHostLeaving\x12\x17\n\
+    \x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\"(\n\rHandOffShards\x12\x17\
+    \n\x07node_id\x18\x01\x20\x01(\x04R\x06nodeId\"\x94\x02\n\x13RemoteEntit\
+    yRequest\x12\x1d\n\nrequest_id\x18\x01\x20\x01(\tR\trequestId\x12\x19\n\
+    \x08actor_id\x18\x02\x20\x01(\tR\x07actorId\x12!\n\x0cmessage_type\x18\
+    \x03\x20\x01(\tR\x0bmessageType\x12\x18\n\x07message\x18\x04\x20\x01(\
+    \x0cR\x07message\x12C\n\x06recipe\x18\x05\x20\x01(\x0b2+.coerce.sharding\
+    .RemoteEntityRequest.RecipeR\x06recipe\x12\x1f\n\x0borigin_node\x18\x06\
     \x20\x01(\x04R\noriginNode\x1a\x20\n\x06Recipe\x12\x16\n\x06recipe\x18\
     \x01\x20\x01(\x0cR\x06recipe\"@\n\x0bStartEntity\x12\x19\n\x08actor_id\
     \x18\x01\x20\x01(\tR\x07actorId\x12\x16\n\x06recipe\x18\x02\x20\x01(\x0c\
@@ -3075,7 +3340,7 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(19);
+            let mut messages = ::std::vec::Vec::with_capacity(21);
             messages.push(AllocateShard::generated_message_descriptor_data());
             messages.push(RemoteShard::generated_message_descriptor_data());
             messages.push(ShardAllocated::generated_message_descriptor_data());
@@ -3083,6 +3348,8 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(StopShard::generated_message_descriptor_data());
             messages.push(ShardStopped::generated_message_descriptor_data());
             messages.push(AllocateShardResult::generated_message_descriptor_data());
+            messages.push(ShardHostLeaving::generated_message_descriptor_data());
+            messages.push(HandOffShards::generated_message_descriptor_data());
             messages.push(RemoteEntityRequest::generated_message_descriptor_data());
             messages.push(StartEntity::generated_message_descriptor_data());
             messages.push(PassivateEntity::generated_message_descriptor_data());
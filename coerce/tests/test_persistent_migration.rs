@@ -0,0 +1,147 @@
+use coerce::persistent::journal::migration::{
+    export_journals, import_journals, migrate_journals, verify_journal, ExportedJournal,
+    ImportOptions, MigrationErr, MigrationSummary,
+};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::journal::provider::StorageProvider;
+use coerce::persistent::journal::storage::{JournalEntry, JournalStorageRef};
+use std::sync::Arc;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+pub mod util;
+
+fn entry(sequence: i64, payload_type: &str) -> JournalEntry {
+    JournalEntry {
+        sequence,
+        payload_type: payload_type.into(),
+        bytes: Arc::new(format!("{}-{}", payload_type, sequence).into_bytes()),
+    }
+}
+
+async fn create_source_storage() -> JournalStorageRef {
+    let storage = InMemoryStorageProvider::new().journal_storage().unwrap();
+
+    storage
+        .write_snapshot("actor-1", entry(2, "snapshot"))
+        .await
+        .unwrap();
+
+    storage
+        .write_message_batch(
+            "actor-1",
+            vec![
+                entry(1, "message"),
+                entry(2, "message"),
+                entry(3, "message"),
+            ],
+        )
+        .await
+        .unwrap();
+
+    storage
+        .write_message("actor-2", entry(1, "message"))
+        .await
+        .unwrap();
+
+    storage
+        .write_snapshot("actor-3", entry(10, "snapshot"))
+        .await
+        .unwrap();
+
+    storage
+}
+
+#[tokio::test]
+pub async fn test_journal_export_import_roundtrip() {
+    util::create_trace_logger();
+
+    let source = create_source_storage().await;
+    let mut exported = vec![];
+    let export_summary = export_journals(&source, &mut exported).await.unwrap();
+
+    let expected_summary = MigrationSummary {
+        journals: 3,
+        snapshots: 2,
+        messages: 4,
+    };
+
+    assert_eq!(export_summary, expected_summary);
+
+    let target = InMemoryStorageProvider::new().journal_storage().unwrap();
+    let import_summary =
+        import_journals(&mut exported.as_slice(), &target, ImportOptions::default())
+            .await
+            .unwrap();
+
+    assert_eq!(import_summary, expected_summary);
+    assert_eq!(
+        target.read_persistence_ids().await.unwrap(),
+        vec!["actor-1", "actor-2", "actor-3"]
+    );
+
+    let messages = target
+        .read_latest_messages("actor-1", 0)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        messages.iter().map(|m| m.sequence).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let snapshot = target.read_latest_snapshot("actor-3").await.unwrap();
+    assert_eq!(snapshot.unwrap().bytes.as_slice(), b"snapshot-10");
+}
+
+#[tokio::test]
+pub async fn test_journal_migrate_between_providers() {
+    util::create_trace_logger();
+
+    let source = create_source_storage().await;
+    let target = InMemoryStorageProvider::new().journal_storage().unwrap();
+
+    let summary = migrate_journals(&source, &target, ImportOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.journals, 3);
+    assert_eq!(
+        source.read_persistence_ids().await.unwrap(),
+        target.read_persistence_ids().await.unwrap()
+    );
+}
+
+#[tokio::test]
+pub async fn test_journal_import_verification_failure() {
+    util::create_trace_logger();
+
+    let target = InMemoryStorageProvider::new().journal_storage().unwrap();
+    target
+        .write_message("actor-1", entry(1, "existing-message"))
+        .await
+        .unwrap();
+
+    let journal = ExportedJournal {
+        persistence_id: "actor-1".to_string(),
+        snapshot: None,
+        messages: vec![entry(1, "message")],
+    };
+
+    let result = verify_journal(&journal, &target).await;
+    assert!(matches!(result, Err(MigrationErr::Verification { .. })));
+
+    let source = create_source_storage().await;
+    let options = ImportOptions {
+        verify: true,
+        replace_existing: true,
+    };
+
+    let summary = migrate_journals(&source, &target, options).await;
+    assert!(summary.is_ok());
+}
@@ -0,0 +1,195 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use crate::util::{GetStatusRequest, GetStatusResponse, TestActor};
+use bytes::Bytes;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{ActorRef, ToActorId};
+use coerce::remote::net::config::{BatchingConfig, NetworkConfig};
+use coerce::remote::net::message::{ClientEvent, SessionEvent};
+use coerce::remote::net::proto::network::{BatchEvent, IdentifyEvent, PingEvent};
+use coerce::remote::net::protocol::ProtocolConfig;
+use coerce::remote::net::transport::{MemoryTransport, Transport};
+use coerce::remote::net::StreamData;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use futures::future::join_all;
+use futures::{SinkExt, StreamExt};
+use std::time::Duration;
+
+#[tokio::test]
+pub async fn test_remote_session_decodes_batch() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let _node = create_node(1, &transport, None, batching(), ProtocolConfig::default()).await;
+
+    let mut connection = transport.connect("node-1").await.unwrap();
+    let identify = SessionEvent::Identify(IdentifyEvent {
+        source_node_id: 2,
+        source_node_tag: "node-2".to_string(),
+        protocol: Some((&ProtocolConfig::default()).into()).into(),
+        ..Default::default()
+    });
+
+    let write = |event: SessionEvent| Bytes::from(event.write_to_bytes().unwrap());
+
+    connection.writer.send(write(identify)).await.unwrap();
+    let _identity = connection.reader.next().await.unwrap().unwrap();
+
+    let ping = |message_id: &str| {
+        SessionEvent::Ping(PingEvent {
+            message_id: message_id.to_string(),
+            node_id: 2,
+            ..Default::default()
+        })
+        .write_to_bytes()
+        .unwrap()
+    };
+
+    let batch = SessionEvent::Batch(BatchEvent {
+        events: vec![ping("ping-1"), ping("ping-2")],
+        ..Default::default()
+    });
+
+    connection.writer.send(write(batch)).await.unwrap();
+
+    let mut pongs = vec![];
+    for _ in 0..2 {
+        let frame = tokio::time::timeout(Duration::from_secs(1), connection.reader.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        match ClientEvent::read_from_bytes(frame.to_vec()) {
+            Some(ClientEvent::Pong(pong)) => pongs.push(pong.message_id),
+            _ => panic!("expected pong"),
+        }
+    }
+
+    assert_eq!(pongs, vec!["ping-1", "ping-2"]);
+}
+
+#[tokio::test]
+pub async fn test_remote_batched_requests() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None, batching(), ProtocolConfig::default()).await;
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        batching(),
+        ProtocolConfig::default(),
+    )
+    .await;
+
+    assert_batched_requests_complete(&node_1, &node_2).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_batching_unsupported_by_peer() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(
+        1,
+        &transport,
+        None,
+        BatchingConfig::default(),
+        ProtocolConfig {
+            features: vec![],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        batching(),
+        ProtocolConfig::default(),
+    )
+    .await;
+
+    assert_batched_requests_complete(&node_1, &node_2).await;
+}
+
+async fn assert_batched_requests_complete(node_1: &RemoteActorSystem, node_2: &RemoteActorSystem) {
+    let actor_id = "test-actor".to_actor_id();
+    let _ = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<TestActor>::new(
+        actor_id,
+        node_1.node_id(),
+        node_2.clone(),
+    ));
+
+    let results = join_all((0..250).map(|_| actor_ref.send(GetStatusRequest))).await;
+
+    assert_eq!(results.len(), 250);
+    assert!(results
+        .into_iter()
+        .all(|res| res == Ok(GetStatusResponse::None)));
+}
+
+fn batching() -> BatchingConfig {
+    BatchingConfig {
+        enabled: true,
+        linger: Duration::from_millis(5),
+        max_batch_messages: 64,
+        ..Default::default()
+    }
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+    batching: BatchingConfig,
+    protocol: ProtocolConfig,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .with_handlers(|handlers| {
+            handlers.with_handler::<TestActor, GetStatusRequest>("TestActor.GetStatusRequest")
+        })
+        .configure(|config| {
+            config.network(NetworkConfig {
+                batching,
+                protocol,
+                ..Default::default()
+            })
+        })
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
@@ -0,0 +1,187 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{ActorRefErr, LocalActorRef};
+use coerce::remote::actor::RemoteResponse;
+use coerce::remote::net::client::send::Write;
+use coerce::remote::net::client::{ClientType, RemoteClient};
+use coerce::remote::net::config::{BufferOverflowPolicy, NetworkConfig, WriteBufferConfig};
+use coerce::remote::net::message::SessionEvent;
+use coerce::remote::net::proto::network::MessageRequest;
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::system::RemoteActorSystem;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+#[tokio::test]
+pub async fn test_remote_client_buffer_reject_new() {
+    util::create_trace_logger();
+
+    let remote = create_system(WriteBufferConfig {
+        max_messages: 1,
+        overflow_policy: BufferOverflowPolicy::RejectNew,
+        ..Default::default()
+    })
+    .await;
+
+    let client = unreachable_client(&remote).await;
+
+    let mut first = send_request(&remote, &client).await;
+    let second = send_request(&remote, &client).await;
+
+    assert_eq!(
+        response_err(second).await,
+        Some(ActorRefErr::ActorUnavailable)
+    );
+    assert!(first.try_recv().is_err());
+}
+
+#[tokio::test]
+pub async fn test_remote_client_buffer_drop_oldest() {
+    util::create_trace_logger();
+
+    let remote = create_system(WriteBufferConfig {
+        max_messages: 1,
+        overflow_policy: BufferOverflowPolicy::DropOldest,
+        ..Default::default()
+    })
+    .await;
+
+    let client = unreachable_client(&remote).await;
+
+    let first = send_request(&remote, &client).await;
+    let mut second = send_request(&remote, &client).await;
+
+    assert_eq!(
+        response_err(first).await,
+        Some(ActorRefErr::ActorUnavailable)
+    );
+    assert!(second.try_recv().is_err());
+}
+
+#[tokio::test]
+pub async fn test_remote_client_buffer_fail_pending() {
+    util::create_trace_logger();
+
+    let remote = create_system(WriteBufferConfig {
+        max_bytes: 256,
+        overflow_policy: BufferOverflowPolicy::FailPending,
+        ..Default::default()
+    })
+    .await;
+
+    let client = unreachable_client(&remote).await;
+
+    let first = send_request(&remote, &client).await;
+    let second = send_request(&remote, &client).await;
+    let mut large = send_request_with_payload(&remote, &client, vec![0; 128]).await;
+
+    assert_eq!(
+        response_err(first).await,
+        Some(ActorRefErr::ActorUnavailable)
+    );
+    assert_eq!(
+        response_err(second).await,
+        Some(ActorRefErr::ActorUnavailable)
+    );
+    assert!(large.try_recv().is_err());
+
+    // messages larger than the entire buffer are always rejected
+    let oversized = send_request_with_payload(&remote, &client, vec![0; 512]).await;
+    assert_eq!(
+        response_err(oversized).await,
+        Some(ActorRefErr::ActorUnavailable)
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_client_buffer_ttl() {
+    util::create_trace_logger();
+
+    let remote = create_system(WriteBufferConfig {
+        ttl: Some(Duration::from_millis(100)),
+        ..Default::default()
+    })
+    .await;
+
+    let client = unreachable_client(&remote).await;
+    let request = send_request(&remote, &client).await;
+
+    // expired messages are discarded when the client next fails to reconnect
+    let res = tokio::time::timeout(Duration::from_secs(3), request)
+        .await
+        .expect("expired request should fail");
+
+    assert!(matches!(
+        res,
+        Ok(RemoteResponse::Err(ActorRefErr::Timeout { .. }))
+    ));
+}
+
+async fn create_system(write_buffer: WriteBufferConfig) -> RemoteActorSystem {
+    RemoteActorSystem::builder()
+        .with_tag("node-1")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(MemoryTransport::new())
+        .configure(|config| {
+            config.network(NetworkConfig {
+                write_buffer,
+                ..Default::default()
+            })
+        })
+        .build()
+        .await
+}
+
+async fn unreachable_client(remote: &RemoteActorSystem) -> LocalActorRef<RemoteClient> {
+    RemoteClient::new(
+        "unreachable".to_string(),
+        remote.clone(),
+        ClientType::Worker,
+    )
+    .await
+}
+
+async fn send_request(
+    remote: &RemoteActorSystem,
+    client: &LocalActorRef<RemoteClient>,
+) -> oneshot::Receiver<RemoteResponse> {
+    send_request_with_payload(remote, client, vec![]).await
+}
+
+async fn send_request_with_payload(
+    remote: &RemoteActorSystem,
+    client: &LocalActorRef<RemoteClient>,
+    message: Vec<u8>,
+) -> oneshot::Receiver<RemoteResponse> {
+    let message_id = Uuid::new_v4();
+    let (res_tx, res_rx) = oneshot::channel();
+    remote.push_request(message_id, res_tx);
+
+    let request = SessionEvent::NotifyActor(MessageRequest {
+        message_id: message_id.to_string(),
+        handler_type: "TestActor.GetStatusRequest".to_string(),
+        actor_id: "test-actor".to_string(),
+        message,
+        requires_response: true,
+        ..Default::default()
+    });
+
+    client.send(Write(request)).await.unwrap().unwrap();
+    res_rx
+}
+
+async fn response_err(res_rx: oneshot::Receiver<RemoteResponse>) -> Option<ActorRefErr> {
+    match tokio::time::timeout(Duration::from_secs(1), res_rx).await {
+        Ok(Ok(RemoteResponse::Err(e))) => Some(e),
+        _ => None,
+    }
+}
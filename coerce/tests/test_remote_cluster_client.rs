@@ -9,10 +9,18 @@ extern crate async_trait;
 #[macro_use]
 extern crate coerce_macros;
 
+use coerce::actor::scheduler::ActorType::Tracked;
 use coerce::actor::system::ActorSystem;
-use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe};
-
-use coerce::remote::system::RemoteActorSystem;
+use coerce::actor::{ActorCreationErr, ActorFactory, ActorRecipe, ActorRef, ToActorId};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::cluster::client::RemoteClusterClient;
+use coerce::remote::net::server::RemoteServer;
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use coerce::sharding::Sharding;
+use std::time::Duration;
 use util::*;
 
 #[derive(Serialize, Deserialize)]
@@ -23,7 +31,7 @@ struct TestActorFactory;
 
 impl ActorRecipe for TestActorRecipe {
     fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
-        serde_json::from_slice(bytes).unwrap()
+        serde_json::from_slice(bytes).ok()
     }
 
     fn write_to_bytes(&self) -> Option<Vec<u8>> {
@@ -66,24 +74,176 @@ pub async fn test_remote_get_actor() {
     assert_eq!(actor.is_some(), true);
 }
 
-//
-// #[coerce_test]
-// pub async fn test_remote_cluster_client_create_actor() {
-//     let mut system = ActorSystem::new();
-//     let _actor = system.new_tracked_actor(TestActor::new()).await.unwrap();
-//     let remote = RemoteActorSystem::builder()
-//         .with_actor_system(system)
-//         .with_handlers(|builder| {
-//             builder.with_actor::<TestActorFactory>("TestActor", TestActorFactory {})
-//         })
-//         .build()
-//         .await;
-//
-//     let mut client = remote.cluster_client().build();
-//
-//     let actor = client
-//         .create_actor::<TestActorFactory>(TestActorRecipe {}, Some(format!("TestActor")))
-//         .await;
-//
-//     assert_eq!(actor.is_some(), false);
-// }
+#[tokio::test]
+pub async fn test_cluster_client_does_not_join_cluster() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let (node_1, _server_1) = create_node(1, &transport, None).await;
+    let (node_2, _server_2) = create_node(2, &transport, Some("node-1")).await;
+    let client = create_client(3, &transport, &["node-1"]).await;
+
+    assert_eq!(client.contact_node().await, Some(1));
+    assert!(client.system().is_cluster_client());
+    assert_eq!(node_ids(client.system()).await, vec![1, 2]);
+
+    // members of the cluster never learn of the client
+    assert_eq!(node_ids(&node_1).await, vec![1, 2]);
+    assert_eq!(node_ids(&node_2).await, vec![1, 2]);
+
+    let actor_id = "cluster-client-actor".to_actor_id();
+    let _ = node_2
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = client
+        .actor_ref::<TestActor>(actor_id)
+        .await
+        .expect("locate actor");
+
+    assert_eq!(actor_ref.node_id(), Some(2));
+
+    let _ = actor_ref
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await
+        .unwrap();
+
+    let status = actor_ref.send(GetStatusRequest).await.unwrap();
+    assert_eq!(status, GetStatusResponse::Ok(TestActorStatus::Active));
+}
+
+#[tokio::test]
+pub async fn test_cluster_client_sharded_entity() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let (node_1, _server_1) = create_node(1, &transport, None).await;
+    let (node_2, _server_2) = create_node(2, &transport, Some("node-1")).await;
+    let client = create_client(3, &transport, &["node-1"]).await;
+
+    let _sharding_1 = Sharding::<TestActorFactory>::builder(node_1).build().await;
+    let _sharding_2 = Sharding::<TestActorFactory>::builder(node_2).build().await;
+    let sharding = Sharding::<TestActorFactory>::builder(client.system().clone())
+        .build()
+        .await;
+
+    let actor_count = client.system().actor_system().actor_count().await;
+    let sharded_actor = sharding.get("sharded-entity", Some(TestActorRecipe));
+    let _ = sharded_actor
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await
+        .unwrap();
+
+    let status = sharded_actor.send(GetStatusRequest).await.unwrap();
+    assert_eq!(status, GetStatusResponse::Ok(TestActorStatus::Active));
+
+    // shards are never allocated to the client
+    assert_eq!(
+        client.system().actor_system().actor_count().await,
+        actor_count
+    );
+}
+
+#[tokio::test]
+pub async fn test_cluster_client_failover() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let (node_1, server_1) = create_node(1, &transport, None).await;
+    let (node_2, _server_2) = create_node(2, &transport, Some("node-1")).await;
+
+    // the first seed is unreachable
+    let client = create_client(3, &transport, &["node-4", "node-1", "node-2"]).await;
+    assert_eq!(client.contact_node().await, Some(1));
+
+    server_1.stop();
+    node_1.actor_system().shutdown().await;
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client.refresh().await != Some(2) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("client should fail over to node 2");
+
+    let actor_id = "cluster-client-actor".to_actor_id();
+    let _ = node_2
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<TestActor>::new(
+        actor_id,
+        2,
+        client.system().clone(),
+    ));
+    let status = actor_ref.send(GetStatusRequest).await.unwrap();
+    assert_eq!(status, GetStatusResponse::None);
+}
+
+async fn node_ids(system: &RemoteActorSystem) -> Vec<NodeId> {
+    let mut node_ids: Vec<NodeId> = system.get_nodes().await.iter().map(|n| n.id).collect();
+    node_ids.sort();
+    node_ids
+}
+
+async fn create_system(node_id: NodeId, transport: &MemoryTransport) -> RemoteActorSystem {
+    let system =
+        ActorSystem::new().to_persistent(Persistence::from(InMemoryStorageProvider::new()));
+    RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(system)
+        .with_transport(transport.clone())
+        .with_actors(|actors| {
+            actors
+                .with_actor(TestActorFactory)
+                .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+                .with_handler::<TestActor, SetStatusRequest>("SetStatusRequest")
+        })
+        .build()
+        .await
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+) -> (RemoteActorSystem, RemoteServer) {
+    let remote = create_system(node_id, transport).await;
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    let server = worker.start().await;
+    (remote, server)
+}
+
+async fn create_client(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addrs: &[&str],
+) -> RemoteClusterClient {
+    let remote = create_system(node_id, transport).await;
+    let client = seed_addrs
+        .iter()
+        .fold(remote.cluster_client(), |client, seed_addr| {
+            client.with_seed_addr(seed_addr)
+        });
+
+    client.start().await
+}
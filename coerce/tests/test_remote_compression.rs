@@ -0,0 +1,282 @@
+#![cfg(feature = "remote-compression")]
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use bytes::Bytes;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, ToActorId};
+use coerce::remote::net::compression::{decompress_frame, FrameCompression};
+use coerce::remote::net::config::{CompressionConfig, NetworkConfig};
+use coerce::remote::net::message::{ClientEvent, DecodeErr, SessionEvent};
+use coerce::remote::net::proto::network::{Event, IdentifyEvent, PingEvent};
+use coerce::remote::net::protocol::{PeerProtocol, ProtocolConfig};
+use coerce::remote::net::transport::{MemoryTransport, Transport};
+use coerce::remote::net::StreamData;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use futures::{SinkExt, StreamExt};
+use std::time::Duration;
+
+const MAX_FRAME_LENGTH: usize = 64 * 1024;
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+struct Echo {
+    payload: String,
+}
+
+struct EchoActor;
+
+impl Actor for EchoActor {}
+
+#[async_trait]
+impl Handler<Echo> for EchoActor {
+    async fn handle(&mut self, message: Echo, _ctx: &mut ActorContext) -> String {
+        message.payload
+    }
+}
+
+#[test]
+pub fn test_remote_compress_frames() {
+    let compression = frame_compression(enabled(), &ProtocolConfig::default());
+
+    let frame = vec![Event::Ping as u8; 1024];
+    let compressed = compression
+        .compress(&frame)
+        .expect("frame should be compressed");
+    assert!(compressed.len() < frame.len());
+    assert_eq!(compressed[0], Event::Compressed as u8);
+    assert_eq!(
+        decompress_frame(compressed, MAX_FRAME_LENGTH).unwrap(),
+        frame
+    );
+
+    // frames below the threshold aren't compressed
+    assert!(compression.compress(&frame[..32]).is_none());
+
+    // uncompressed frames are returned as-is
+    assert_eq!(
+        decompress_frame(frame.clone(), MAX_FRAME_LENGTH).unwrap(),
+        frame
+    );
+
+    // frames that decompress to more than the max frame length are rejected
+    let oversized = compression
+        .compress(&vec![0; MAX_FRAME_LENGTH * 2])
+        .unwrap();
+    assert!(matches!(
+        decompress_frame(oversized, MAX_FRAME_LENGTH),
+        Err(DecodeErr::Decompression(_))
+    ));
+
+    assert!(matches!(
+        decompress_frame(vec![Event::Compressed as u8, 0xff, 0xff], MAX_FRAME_LENGTH),
+        Err(DecodeErr::InvalidMessage { .. })
+    ));
+}
+
+#[test]
+pub fn test_remote_compression_negotiation() {
+    let compression_unsupported = ProtocolConfig {
+        features: vec![],
+        ..Default::default()
+    };
+
+    let local = ProtocolConfig::default();
+    let negotiate = |config: CompressionConfig, peer: &ProtocolConfig| {
+        let peer = PeerProtocol::from(Some(peer.into()));
+        FrameCompression::negotiate(&config, &local.negotiate(&peer).unwrap())
+    };
+
+    assert!(negotiate(enabled(), &local).is_some());
+    assert!(negotiate(enabled(), &compression_unsupported).is_none());
+    assert!(negotiate(CompressionConfig::default(), &local).is_none());
+}
+
+#[tokio::test]
+pub async fn test_remote_session_decodes_compressed_frames() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let _node = create_node(1, &transport, None, enabled(), ProtocolConfig::default()).await;
+
+    let mut connection = transport.connect("node-1").await.unwrap();
+    let identify = SessionEvent::Identify(IdentifyEvent {
+        source_node_id: 2,
+        source_node_tag: "node-2".to_string(),
+        protocol: Some((&ProtocolConfig::default()).into()).into(),
+        ..Default::default()
+    });
+
+    connection
+        .writer
+        .send(Bytes::from(identify.write_to_bytes().unwrap()))
+        .await
+        .unwrap();
+
+    let _identity = connection.reader.next().await.unwrap().unwrap();
+
+    let message_id = "a".repeat(512);
+    let ping = SessionEvent::Ping(PingEvent {
+        message_id: message_id.clone(),
+        node_id: 2,
+        ..Default::default()
+    })
+    .write_to_bytes()
+    .unwrap();
+
+    let compression = frame_compression(enabled(), &ProtocolConfig::default());
+    let compressed = compression.compress(&ping).unwrap();
+
+    connection
+        .writer
+        .send(Bytes::from(compressed))
+        .await
+        .unwrap();
+
+    let frame = tokio::time::timeout(Duration::from_secs(1), connection.reader.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    // the pong is larger than the threshold, so it's compressed by the session too
+    let frame = decompress_frame(frame.to_vec(), MAX_FRAME_LENGTH).unwrap();
+    match ClientEvent::read_from_bytes(frame) {
+        Some(ClientEvent::Pong(pong)) => assert_eq!(pong.message_id, message_id),
+        _ => panic!("expected pong"),
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_compressed_requests() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None, enabled(), ProtocolConfig::default()).await;
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        enabled(),
+        ProtocolConfig::default(),
+    )
+    .await;
+
+    assert_echo(&node_1, &node_2).await;
+}
+
+#[tokio::test]
+pub async fn test_remote_compression_unsupported_by_peer() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(
+        1,
+        &transport,
+        None,
+        CompressionConfig::default(),
+        ProtocolConfig {
+            features: vec![],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        enabled(),
+        ProtocolConfig::default(),
+    )
+    .await;
+
+    assert_echo(&node_1, &node_2).await;
+}
+
+async fn assert_echo(node_1: &RemoteActorSystem, node_2: &RemoteActorSystem) {
+    let actor_id = "echo-actor".to_actor_id();
+    let _ = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), EchoActor, Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<EchoActor>::new(
+        actor_id,
+        node_1.node_id(),
+        node_2.clone(),
+    ));
+
+    let payload = "coerce".repeat(1024);
+    let res = actor_ref
+        .send(Echo {
+            payload: payload.clone(),
+        })
+        .await;
+
+    assert_eq!(res, Ok(payload));
+}
+
+fn enabled() -> CompressionConfig {
+    CompressionConfig {
+        enabled: true,
+        threshold: 256,
+        ..Default::default()
+    }
+}
+
+fn frame_compression(config: CompressionConfig, protocol: &ProtocolConfig) -> FrameCompression {
+    let peer = PeerProtocol::from(Some(protocol.into()));
+    let negotiated = protocol.negotiate(&peer).unwrap();
+    FrameCompression::negotiate(&config, &negotiated).unwrap()
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+    compression: CompressionConfig,
+    protocol: ProtocolConfig,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .with_handlers(|handlers| handlers.with_handler::<EchoActor, Echo>("EchoActor.Echo"))
+        .configure(|config| {
+            config.network(NetworkConfig {
+                compression,
+                protocol,
+                max_frame_length: MAX_FRAME_LENGTH,
+                ..Default::default()
+            })
+        })
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
@@ -0,0 +1,308 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use crate::util::TestActor;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::watch::{ActorTerminated, TerminationReason};
+use coerce::actor::{Actor, ActorRef, LocalActorRef, ToActorId};
+use coerce::remote::actor::message::NodeTerminated;
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+struct DeathWatcher {
+    terminated: mpsc::UnboundedSender<ActorTerminated>,
+}
+
+struct WatchActor(ActorRef<TestActor>);
+
+struct UnwatchActor(ActorRef<TestActor>);
+
+impl Message for WatchActor {
+    type Result = ();
+}
+
+impl Message for UnwatchActor {
+    type Result = ();
+}
+
+#[async_trait]
+impl Actor for DeathWatcher {
+    async fn on_actor_terminated(&mut self, terminated: &ActorTerminated, _ctx: &mut ActorContext) {
+        let _ = self.terminated.send(terminated.clone());
+    }
+}
+
+#[async_trait]
+impl Handler<WatchActor> for DeathWatcher {
+    async fn handle(&mut self, message: WatchActor, ctx: &mut ActorContext) {
+        ctx.watch(&message.0);
+    }
+}
+
+#[async_trait]
+impl Handler<UnwatchActor> for DeathWatcher {
+    async fn handle(&mut self, message: UnwatchActor, ctx: &mut ActorContext) {
+        ctx.unwatch(&message.0);
+    }
+}
+
+#[tokio::test]
+pub async fn test_local_death_watch() {
+    util::create_trace_logger();
+
+    let system = ActorSystem::new();
+    let (watcher, mut terminated) = death_watcher(&system).await;
+
+    let actor_ref = system.new_anon_actor(TestActor::new()).await.unwrap();
+    watcher
+        .send(WatchActor(ActorRef::from(actor_ref.clone())))
+        .await
+        .unwrap();
+
+    actor_ref.stop().await.unwrap();
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(&notification.actor_id, actor_ref.actor_id());
+    assert_eq!(notification.reason, TerminationReason::Stopped);
+}
+
+#[tokio::test]
+pub async fn test_local_death_watch_stopped_actor() {
+    let system = ActorSystem::new();
+    let (watcher, mut terminated) = death_watcher(&system).await;
+
+    let actor_ref = system.new_anon_actor(TestActor::new()).await.unwrap();
+    actor_ref.stop().await.unwrap();
+
+    watcher
+        .send(WatchActor(ActorRef::from(actor_ref.clone())))
+        .await
+        .unwrap();
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(notification.reason, TerminationReason::NotFound);
+}
+
+#[tokio::test]
+pub async fn test_local_death_watch_stopping_actor() {
+    let system = ActorSystem::new();
+    let (watcher, mut terminated) = death_watcher(&system).await;
+
+    let actor_ref = system.new_anon_actor(TestActor::new()).await.unwrap();
+
+    // the watch is queued behind the stop request, so is never handled by the watched actor
+    actor_ref.notify_stop().unwrap();
+    watcher
+        .send(WatchActor(ActorRef::from(actor_ref.clone())))
+        .await
+        .unwrap();
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(&notification.actor_id, actor_ref.actor_id());
+    assert_eq!(notification.reason, TerminationReason::NotFound);
+}
+
+#[tokio::test]
+pub async fn test_local_unwatch() {
+    let system = ActorSystem::new();
+    let (watcher, mut terminated) = death_watcher(&system).await;
+
+    let local_ref = system.new_anon_actor(TestActor::new()).await.unwrap();
+    let actor_ref = ActorRef::from(local_ref.clone());
+    watcher.send(WatchActor(actor_ref.clone())).await.unwrap();
+    watcher.send(UnwatchActor(actor_ref)).await.unwrap();
+
+    local_ref.stop().await.unwrap();
+    assert!(next_terminated(&mut terminated).await.is_none());
+}
+
+#[tokio::test]
+pub async fn test_remote_death_watch() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+    let node_2 = create_node(2, &transport, Some("node-1")).await;
+
+    let actor_id = "watched-actor".to_actor_id();
+    let local_ref = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let (watcher, mut terminated) = death_watcher(node_2.actor_system()).await;
+    watcher
+        .send(WatchActor(remote_ref(&actor_id, &node_1, &node_2)))
+        .await
+        .unwrap();
+
+    // give the watch time to be registered on the hosting node
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    local_ref.stop().await.unwrap();
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(notification.actor_id, actor_id);
+    assert_eq!(notification.reason, TerminationReason::Stopped);
+
+    // watching an actor that doesn't exist on the remote node
+    let missing_actor_id = "missing-actor".to_actor_id();
+    watcher
+        .send(WatchActor(remote_ref(&missing_actor_id, &node_1, &node_2)))
+        .await
+        .unwrap();
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(notification.actor_id, missing_actor_id);
+    assert_eq!(notification.reason, TerminationReason::NotFound);
+}
+
+#[tokio::test]
+pub async fn test_remote_death_watch_unreachable_node() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+
+    let actor_id = "watched-actor".to_actor_id();
+    let actor_ref = ActorRef::from(RemoteActorRef::<TestActor>::new(
+        actor_id.clone(),
+        2,
+        node_1.clone(),
+    ));
+
+    let (watcher, mut terminated) = death_watcher(node_1.actor_system()).await;
+    watcher.send(WatchActor(actor_ref)).await.unwrap();
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(notification.actor_id, actor_id);
+    assert_eq!(notification.reason, TerminationReason::Unreachable);
+}
+
+#[tokio::test]
+pub async fn test_remote_death_watch_node_terminated() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+    let node_2 = create_node(2, &transport, Some("node-1")).await;
+
+    let actor_id = "watched-actor".to_actor_id();
+    let _ = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let (watcher, mut terminated) = death_watcher(node_2.actor_system()).await;
+    watcher
+        .send(WatchActor(remote_ref(&actor_id, &node_1, &node_2)))
+        .await
+        .unwrap();
+
+    let _ = node_2.heartbeat().notify(NodeTerminated(node_1.node_id()));
+
+    let notification = next_terminated(&mut terminated).await.unwrap();
+    assert_eq!(notification.actor_id, actor_id);
+    assert_eq!(notification.reason, TerminationReason::NodeTerminated);
+}
+
+#[tokio::test]
+pub async fn test_remote_unwatch() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+    let node_2 = create_node(2, &transport, Some("node-1")).await;
+
+    let actor_id = "watched-actor".to_actor_id();
+    let local_ref = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let (watcher, mut terminated) = death_watcher(node_2.actor_system()).await;
+    let actor_ref = remote_ref(&actor_id, &node_1, &node_2);
+    watcher.send(WatchActor(actor_ref.clone())).await.unwrap();
+    watcher.send(UnwatchActor(actor_ref)).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    local_ref.stop().await.unwrap();
+
+    assert!(next_terminated(&mut terminated).await.is_none());
+}
+
+async fn death_watcher(
+    system: &ActorSystem,
+) -> (
+    LocalActorRef<DeathWatcher>,
+    mpsc::UnboundedReceiver<ActorTerminated>,
+) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let watcher = system
+        .new_anon_actor(DeathWatcher { terminated: tx })
+        .await
+        .unwrap();
+
+    (watcher, rx)
+}
+
+async fn next_terminated(
+    terminated: &mut mpsc::UnboundedReceiver<ActorTerminated>,
+) -> Option<ActorTerminated> {
+    tokio::time::timeout(Duration::from_millis(500), terminated.recv())
+        .await
+        .ok()
+        .flatten()
+}
+
+fn remote_ref(
+    actor_id: &coerce::actor::ActorId,
+    host: &RemoteActorSystem,
+    system: &RemoteActorSystem,
+) -> ActorRef<TestActor> {
+    ActorRef::from(RemoteActorRef::<TestActor>::new(
+        actor_id.clone(),
+        host.node_id(),
+        system.clone(),
+    ))
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
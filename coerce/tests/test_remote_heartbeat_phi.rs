@@ -0,0 +1,174 @@
+pub mod util;
+
+#[macro_use]
+extern crate coerce_macros;
+
+#[macro_use]
+extern crate async_trait;
+
+#[macro_use]
+extern crate serde;
+
+use coerce::actor::system::ActorSystem;
+use coerce::remote::cluster::node::NodeStatus::{Healthy, Terminated};
+use coerce::remote::heartbeat::phi::{PhiAccrualConfig, PhiAccrualDetector};
+use coerce::remote::heartbeat::{FailureDetector, HeartbeatConfig};
+use coerce::remote::system::builder::RemoteSystemConfigBuilder;
+use coerce::remote::system::RemoteActorSystem;
+use std::time::Duration;
+use tokio::time;
+use tokio::time::Instant;
+
+const INTERVAL: Duration = Duration::from_millis(500);
+
+#[test]
+pub fn test_phi_accrual_steady_heartbeats() {
+    let config = PhiAccrualConfig {
+        acceptable_heartbeat_pause: Duration::ZERO,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let mut detector = PhiAccrualDetector::new(config, INTERVAL);
+    let last_heartbeat = heartbeats(&mut detector, start, &[500; 20]);
+
+    assert!(detector.phi(at(last_heartbeat, 500)) < 1.0);
+    assert!(detector.is_available(at(last_heartbeat, 700)));
+    assert!(!detector.is_available(at(last_heartbeat, 2000)));
+
+    // φ increases the longer the node goes without a heartbeat
+    let phi = [600, 800, 1000].map(|ms| detector.phi(at(last_heartbeat, ms)));
+    assert!(phi[0] < phi[1] && phi[1] < phi[2]);
+}
+
+#[test]
+pub fn test_phi_accrual_adapts_to_jitter() {
+    let config = PhiAccrualConfig {
+        acceptable_heartbeat_pause: Duration::ZERO,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let mut steady = PhiAccrualDetector::new(config.clone(), INTERVAL);
+    let mut noisy = PhiAccrualDetector::new(config, INTERVAL);
+
+    let steady_last_heartbeat = heartbeats(&mut steady, start, &[500; 20]);
+    let noisy_last_heartbeat = heartbeats(&mut noisy, start, &[200, 800, 300, 900, 250].repeat(4));
+
+    // the same delay is far less suspicious when the intervals have been noisy
+    assert!(!steady.is_available(at(steady_last_heartbeat, 1500)));
+    assert!(noisy.is_available(at(noisy_last_heartbeat, 1500)));
+}
+
+#[test]
+pub fn test_phi_accrual_acceptable_heartbeat_pause() {
+    let start = Instant::now();
+    let mut detector = PhiAccrualDetector::new(
+        PhiAccrualConfig {
+            acceptable_heartbeat_pause: Duration::from_secs(3),
+            ..Default::default()
+        },
+        INTERVAL,
+    );
+
+    let last_heartbeat = heartbeats(&mut detector, start, &[500; 20]);
+
+    assert!(detector.is_available(at(last_heartbeat, 3000)));
+    assert!(!detector.is_available(at(last_heartbeat, 6000)));
+}
+
+#[test]
+pub fn test_phi_accrual_no_heartbeats() {
+    let detector = PhiAccrualDetector::new(PhiAccrualConfig::default(), INTERVAL);
+
+    assert_eq!(detector.phi(Instant::now()), 0.0);
+    assert!(detector.last_heartbeat().is_none());
+}
+
+#[coerce_test]
+pub async fn test_remote_cluster_heartbeat_phi_accrual() {
+    util::create_trace_logger();
+    fn configure_sys(c: &mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder {
+        c.heartbeat(HeartbeatConfig {
+            interval: Duration::from_millis(250),
+            ping_timeout: Duration::from_millis(10),
+            terminated_node_heartbeat_timeout: Duration::from_millis(1000),
+            failure_detector: FailureDetector::PhiAccrual(PhiAccrualConfig {
+                acceptable_heartbeat_pause: Duration::from_millis(250),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    let remote = RemoteActorSystem::builder()
+        .with_tag("remote-1")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .configure(configure_sys)
+        .build()
+        .await;
+
+    let remote_2 = RemoteActorSystem::builder()
+        .with_tag("remote-2")
+        .with_id(2)
+        .with_actor_system(ActorSystem::new())
+        .configure(configure_sys)
+        .build()
+        .await;
+
+    let server = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30111")
+        .start()
+        .await;
+
+    let _server_2 = remote_2
+        .clone()
+        .cluster_worker()
+        .listen_addr("localhost:30112")
+        .with_seed_addr("localhost:30111")
+        .start()
+        .await;
+
+    time::sleep(Duration::from_millis(1500)).await;
+
+    for nodes in [remote.get_nodes().await, remote_2.get_nodes().await] {
+        assert_eq!(nodes.len(), 2);
+        assert!(nodes.iter().all(|n| n.status == Healthy));
+    }
+
+    {
+        let server = server;
+        let remote = remote;
+        server.stop();
+        remote.actor_system().shutdown().await;
+    }
+
+    time::sleep(Duration::from_secs(2)).await;
+
+    let nodes = remote_2.get_nodes().await;
+    let node_1 = nodes.iter().find(|n| n.id == 1).cloned().unwrap();
+    let node_2 = nodes.iter().find(|n| n.id == 2).cloned().unwrap();
+
+    assert_eq!(node_1.status, Terminated);
+    assert_eq!(node_2.status, Healthy);
+}
+
+/// Records heartbeats separated by `intervals` (in millis), returning the time of the last one.
+fn heartbeats(detector: &mut PhiAccrualDetector, start: Instant, intervals: &[u64]) -> Instant {
+    let mut now = start;
+    detector.heartbeat(now);
+
+    for interval in intervals {
+        now = at(now, *interval);
+        detector.heartbeat(now);
+    }
+
+    now
+}
+
+fn at(time: Instant, millis: u64) -> Instant {
+    time + Duration::from_millis(millis)
+}
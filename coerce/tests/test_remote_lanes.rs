@@ -0,0 +1,221 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use crate::util::{GetStatusRequest, GetStatusResponse, TestActor};
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, ToActorId};
+use coerce::remote::net::client::lane::ConnectionLane;
+use coerce::remote::net::client::RemoteClient;
+use coerce::remote::net::config::{ConnectionLanesConfig, NetworkConfig};
+use coerce::remote::net::message::SessionEvent;
+use coerce::remote::net::proto::network::{
+    ActorAddress, ActorTerminatedEvent, MessageRequest, PingEvent, StreamPublishEvent,
+    WatchActorEvent,
+};
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::net::StreamData;
+use coerce::remote::stream::pubsub::{PubSub, Receive, Subscription, Topic};
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use futures::future::join_all;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+pub struct Greetings;
+
+impl Topic for Greetings {
+    type Message = Greeting;
+
+    fn topic_name() -> &'static str {
+        "greetings"
+    }
+}
+
+pub struct Greeting(String);
+
+impl StreamData for Greeting {
+    fn read_from_bytes(data: Vec<u8>) -> Option<Self> {
+        String::from_utf8(data).ok().map(Greeting)
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(self.0.clone().into_bytes())
+    }
+}
+
+pub struct GreetingConsumer {
+    subscription: Option<Subscription>,
+    on_received: Option<oneshot::Sender<String>>,
+}
+
+#[async_trait]
+impl Actor for GreetingConsumer {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.subscription = Some(
+            PubSub::subscribe::<Self, Greetings>(Greetings, ctx)
+                .await
+                .unwrap(),
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<Greetings>> for GreetingConsumer {
+    async fn handle(&mut self, message: Receive<Greetings>, _ctx: &mut ActorContext) {
+        if let Some(on_received) = self.on_received.take() {
+            let _ = on_received.send(message.0 .0.clone());
+        }
+    }
+}
+
+#[test]
+pub fn test_remote_session_event_lanes() {
+    let notify = SessionEvent::NotifyActor(MessageRequest::default());
+    let ping = SessionEvent::Ping(PingEvent::default());
+    let publish = SessionEvent::StreamPublish(Arc::new(StreamPublishEvent::default()));
+
+    assert_eq!(notify.lane(), ConnectionLane::Request);
+    assert_eq!(ping.lane(), ConnectionLane::Control);
+    assert_eq!(publish.lane(), ConnectionLane::Streaming);
+
+    // events addressed to an actor share a lane, so they can't be reordered across connections
+    let register = SessionEvent::RegisterActor(ActorAddress::default());
+    let watch = SessionEvent::WatchActor(WatchActorEvent::default());
+    let terminated = SessionEvent::ActorTerminated(ActorTerminatedEvent::default());
+
+    assert_eq!(register.lane(), ConnectionLane::Request);
+    assert_eq!(watch.lane(), ConnectionLane::Request);
+    assert_eq!(terminated.lane(), ConnectionLane::Request);
+}
+
+#[tokio::test]
+pub async fn test_remote_request_lanes() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+    let node_2 = create_node(2, &transport, Some("node-1")).await;
+
+    let actor_refs = join_all((0..8).map(|i| {
+        let node_1 = node_1.clone();
+        let node_2 = node_2.clone();
+        async move {
+            let actor_id = format!("test-actor-{}", i).to_actor_id();
+            let _ = node_1
+                .actor_system()
+                .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+                .await
+                .unwrap();
+
+            ActorRef::from(RemoteActorRef::<TestActor>::new(
+                actor_id,
+                node_1.node_id(),
+                node_2,
+            ))
+        }
+    }))
+    .await;
+
+    let results = join_all(
+        actor_refs
+            .iter()
+            .flat_map(|actor_ref| (0..10).map(move |_| actor_ref.send(GetStatusRequest))),
+    )
+    .await;
+
+    assert_eq!(results.len(), 80);
+    assert!(results
+        .into_iter()
+        .all(|res| res == Ok(GetStatusResponse::None)));
+
+    for lane_client in [
+        "remote-client-node-1-request-0",
+        "remote-client-node-1-request-1",
+        "remote-client-node-1-streaming-0",
+    ] {
+        assert!(node_2
+            .actor_system()
+            .get_tracked_actor::<RemoteClient>(lane_client.to_actor_id())
+            .await
+            .is_some());
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_streaming_lane() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+    let node_2 = create_node(2, &transport, Some("node-1")).await;
+
+    let (tx, rx) = oneshot::channel();
+    let _consumer = node_1
+        .actor_system()
+        .new_anon_actor(GreetingConsumer {
+            subscription: None,
+            on_received: Some(tx),
+        })
+        .await
+        .unwrap();
+
+    // give the subscription time to propagate before publishing
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    PubSub::publish(Greetings, Greeting("hello".to_string()), &node_2).await;
+
+    let greeting = tokio::time::timeout(Duration::from_secs(3), rx)
+        .await
+        .expect("greeting should be received")
+        .unwrap();
+
+    assert_eq!(greeting, "hello");
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .with_handlers(|handlers| {
+            handlers.with_handler::<TestActor, GetStatusRequest>("TestActor.GetStatusRequest")
+        })
+        .configure(|config| {
+            config.network(NetworkConfig {
+                lanes: ConnectionLanesConfig {
+                    enabled: true,
+                    request_connections: 2,
+                    streaming_connections: 1,
+                },
+                ..Default::default()
+            })
+        })
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
@@ -0,0 +1,135 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use bytes::Bytes;
+use coerce::actor::system::ActorSystem;
+use coerce::remote::net::config::NetworkConfig;
+use coerce::remote::net::message::{ClientEvent, DecodeErr, SessionEvent};
+use coerce::remote::net::proto::network::{Event, IdentifyEvent};
+use coerce::remote::net::transport::{Connection, MemoryTransport, Transport};
+use coerce::remote::net::StreamData;
+use coerce::remote::system::RemoteActorSystem;
+use futures::{SinkExt, StreamExt};
+use std::time::Duration;
+
+const MAX_FRAME_LENGTH: usize = 1024;
+const MAX_DECODE_ERRORS: usize = 3;
+
+#[test]
+pub fn test_remote_decode_malformed_frames() {
+    assert!(matches!(
+        SessionEvent::try_read_from_bytes(vec![]),
+        Err(DecodeErr::EmptyFrame)
+    ));
+
+    assert!(matches!(
+        SessionEvent::try_read_from_bytes(vec![250, 1, 2, 3]),
+        Err(DecodeErr::UnknownEvent(250))
+    ));
+
+    let err = SessionEvent::try_read_from_bytes(vec![Event::Identify as u8, 0xff, 0xff, 0xff])
+        .unwrap_err();
+    assert!(matches!(err, DecodeErr::InvalidMessage { .. }));
+    assert_eq!(err.event_type(), Some(Event::Identify));
+
+    assert!(matches!(
+        ClientEvent::try_read_from_bytes(vec![Event::CreateActor as u8]),
+        Err(DecodeErr::UnexpectedEvent(Event::CreateActor))
+    ));
+
+    assert!(SessionEvent::read_from_bytes(vec![Event::Ping as u8, 0xff]).is_none());
+}
+
+#[tokio::test]
+pub async fn test_remote_session_closed_after_max_decode_errors() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let _node = create_node(transport.clone()).await;
+    let mut connection = identify(&transport).await;
+
+    for _ in 0..MAX_DECODE_ERRORS - 1 {
+        send_frame(&mut connection, vec![Event::Ping as u8, 0xff, 0xff]).await;
+    }
+
+    assert!(!is_closed(&mut connection).await);
+
+    send_frame(&mut connection, vec![250]).await;
+    assert!(is_closed(&mut connection).await);
+}
+
+#[tokio::test]
+pub async fn test_remote_session_closed_after_oversized_frame() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let _node = create_node(transport.clone()).await;
+    let mut connection = identify(&transport).await;
+
+    send_frame(
+        &mut connection,
+        vec![Event::Ping as u8; MAX_FRAME_LENGTH + 1],
+    )
+    .await;
+    assert!(is_closed(&mut connection).await);
+}
+
+async fn create_node(transport: MemoryTransport) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag("node-1")
+        .with_id(1)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport)
+        .configure(|config| {
+            config.network(NetworkConfig {
+                max_frame_length: MAX_FRAME_LENGTH,
+                max_decode_errors: MAX_DECODE_ERRORS,
+                ..Default::default()
+            })
+        })
+        .build()
+        .await;
+
+    remote
+        .clone()
+        .cluster_worker()
+        .listen_addr("node-1")
+        .start()
+        .await;
+    remote
+}
+
+async fn identify(transport: &MemoryTransport) -> Connection {
+    let mut connection = transport.connect("node-1").await.unwrap();
+    let identify = SessionEvent::Identify(IdentifyEvent {
+        source_node_id: 2,
+        source_node_tag: "node-2".to_string(),
+        ..Default::default()
+    });
+
+    send_frame(&mut connection, identify.write_to_bytes().unwrap()).await;
+
+    let identity = connection.reader.next().await.unwrap().unwrap();
+    assert!(matches!(
+        ClientEvent::read_from_bytes(identity.to_vec()),
+        Some(ClientEvent::Identity(_))
+    ));
+
+    connection
+}
+
+async fn send_frame(connection: &mut Connection, frame: Vec<u8>) {
+    connection.writer.send(Bytes::from(frame)).await.unwrap();
+}
+
+async fn is_closed(connection: &mut Connection) -> bool {
+    match tokio::time::timeout(Duration::from_millis(500), connection.reader.next()).await {
+        Ok(None) | Ok(Some(Err(_))) => true,
+        Ok(Some(Ok(_))) | Err(_) => false,
+    }
+}
@@ -0,0 +1,170 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorCreationErr, ActorFactory, ActorRecipe, IntoActor};
+use coerce::remote::cluster::placement::Placement;
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::system::{NodeId, RemoteActorErr, RemoteActorSystem};
+use std::time::Duration;
+
+pub struct PlacedActor;
+
+impl Actor for PlacedActor {}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlacedActorRecipe;
+
+impl ActorRecipe for PlacedActorRecipe {
+    fn read_from_bytes(bytes: &Vec<u8>) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(&self).ok()
+    }
+}
+
+#[derive(Clone)]
+pub struct PlacedActorFactory;
+
+#[async_trait]
+impl ActorFactory for PlacedActorFactory {
+    type Actor = PlacedActor;
+    type Recipe = PlacedActorRecipe;
+
+    async fn create(&self, _recipe: Self::Recipe) -> Result<PlacedActor, ActorCreationErr> {
+        Ok(PlacedActor)
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_spawn_placement() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, "a", &transport, None).await;
+    let _node_2 = create_node(2, "a", &transport, Some("node-1")).await;
+    let _node_3 = create_node(3, "b", &transport, Some("node-1")).await;
+
+    wait_for_healthy_nodes(&node_1, 3).await;
+
+    let actor_ref = node_1
+        .spawn_remote::<PlacedActorFactory>(None, PlacedActorRecipe, Placement::Node(2))
+        .await
+        .unwrap();
+
+    assert_eq!(actor_ref.node_id(), Some(2));
+
+    let actor_ref = node_1
+        .spawn_remote::<PlacedActorFactory>(
+            None,
+            PlacedActorRecipe,
+            Placement::attribute("zone", "b"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(actor_ref.node_id(), Some(3));
+
+    let actor_ref = node_1
+        .spawn_remote::<PlacedActorFactory>(None, PlacedActorRecipe, Placement::Random)
+        .await
+        .unwrap();
+
+    assert!(matches!(actor_ref.node_id(), Some(1..=3)));
+
+    let no_node = node_1
+        .spawn_remote::<PlacedActorFactory>(
+            None,
+            PlacedActorRecipe,
+            Placement::attribute("zone", "c"),
+        )
+        .await;
+
+    assert_eq!(no_node.err(), Some(RemoteActorErr::NoAvailableNode));
+}
+
+#[tokio::test]
+pub async fn test_remote_spawn_least_loaded() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, "a", &transport, None).await;
+    let _node_2 = create_node(2, "a", &transport, Some("node-1")).await;
+    let node_3 = create_node(3, "a", &transport, Some("node-1")).await;
+
+    wait_for_healthy_nodes(&node_1, 3).await;
+
+    for node in [&node_1, &node_3] {
+        for i in 0..5 {
+            let _ = PlacedActor
+                .into_actor(Some(format!("existing-actor-{}", i)), node.actor_system())
+                .await
+                .unwrap();
+        }
+    }
+
+    let stats = node_1.node_stats(3).await.unwrap();
+    assert_eq!(
+        stats.actor_count as usize,
+        node_3.actor_system().actor_count().await
+    );
+
+    let actor_ref = node_1
+        .spawn_remote::<PlacedActorFactory>(None, PlacedActorRecipe, Placement::LeastLoaded)
+        .await
+        .unwrap();
+
+    assert_eq!(actor_ref.node_id(), Some(2));
+}
+
+async fn wait_for_healthy_nodes(system: &RemoteActorSystem, count: usize) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let nodes = system.get_nodes().await;
+            if nodes.iter().filter(|n| n.status.is_healthy()).count() >= count {
+                break;
+            }
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("nodes should become healthy");
+}
+
+async fn create_node(
+    node_id: NodeId,
+    zone: &str,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .with_actors(|actors| actors.with_actor(PlacedActorFactory))
+        .attribute("zone", zone)
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
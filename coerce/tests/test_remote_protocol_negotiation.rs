@@ -0,0 +1,242 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use crate::util::{GetStatusRequest, SetStatusRequest, TestActor, TestActorStatus};
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{ActorRef, ActorRefErr, ToActorId};
+use coerce::remote::net::config::NetworkConfig;
+use coerce::remote::net::protocol::{PeerProtocol, ProtocolConfig, ProtocolErr};
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::system::builder::RemoteSystemConfigBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::RemoteActorRef;
+use std::time::Duration;
+
+#[test]
+pub fn test_remote_protocol_negotiate() {
+    let local = ProtocolConfig {
+        min_version: 1,
+        max_version: 3,
+        features: vec!["compression".to_string(), "batching".to_string()],
+        required_features: vec![],
+    };
+
+    let peer = PeerProtocol {
+        min_version: 2,
+        max_version: 5,
+        features: vec!["batching".to_string()],
+        required_features: vec![],
+    };
+
+    let negotiated = local.negotiate(&peer).unwrap();
+    assert_eq!(negotiated.version, 3);
+    assert_eq!(negotiated.features, vec!["batching".to_string()]);
+    assert!(negotiated.supports("batching"));
+    assert!(!negotiated.supports("compression"));
+
+    let legacy_peer = PeerProtocol::from(None);
+    assert_eq!(local.negotiate(&legacy_peer).unwrap().version, 1);
+
+    let newer_peer = PeerProtocol {
+        min_version: 4,
+        max_version: 5,
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        local.negotiate(&newer_peer),
+        Err(ProtocolErr::IncompatibleVersion { .. })
+    ));
+
+    let strict_local = ProtocolConfig {
+        required_features: vec!["compression".to_string()],
+        ..local.clone()
+    };
+
+    assert_eq!(
+        strict_local.negotiate(&peer),
+        Err(ProtocolErr::MissingFeatures(
+            vec!["compression".to_string()]
+        ))
+    );
+
+    let strict_peer = PeerProtocol {
+        required_features: vec!["tracing".to_string()],
+        ..peer
+    };
+
+    assert_eq!(
+        local.negotiate(&strict_peer),
+        Err(ProtocolErr::UnsupportedFeatures(
+            vec!["tracing".to_string()]
+        ))
+    );
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_compatible_nodes() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None, ProtocolConfig::default()).await;
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        ProtocolConfig {
+            max_version: 2,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 2);
+    assert_eq!(node_2.get_nodes().await.len(), 2);
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_incompatible_version_rejected() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None, ProtocolConfig::default()).await;
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        ProtocolConfig {
+            min_version: 2,
+            max_version: 2,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 1);
+    assert_eq!(node_2.get_nodes().await.len(), 1);
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_missing_required_feature_rejected() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None, ProtocolConfig::default()).await;
+    let node_2 = create_node(
+        2,
+        &transport,
+        Some("node-1"),
+        ProtocolConfig {
+            required_features: vec!["compression".to_string()],
+            ..Default::default()
+        },
+    )
+    .await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 1);
+    assert_eq!(node_2.get_nodes().await.len(), 1);
+}
+
+#[tokio::test]
+pub async fn test_remote_protocol_unsupported_handler_fails_fast() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 =
+        create_node_with_handlers(1, &transport, None, ProtocolConfig::default(), |handlers| {
+            handlers.with_handler::<TestActor, GetStatusRequest>("TestActor.GetStatusRequest")
+        })
+        .await;
+
+    let node_2 = create_node(2, &transport, Some("node-1"), ProtocolConfig::default()).await;
+
+    let capabilities = node_2.node_capabilities(1).expect("node=1 capabilities");
+    assert!(!capabilities
+        .messages
+        .contains(&"TestActor.SetStatusRequest".to_string()));
+
+    let actor_id = "test-actor".to_actor_id();
+    let _ = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<TestActor>::new(
+        actor_id,
+        node_1.node_id(),
+        node_2.clone(),
+    ));
+
+    assert!(actor_ref.send(GetStatusRequest).await.is_ok());
+
+    let res = tokio::time::timeout(
+        Duration::from_millis(100),
+        actor_ref.send(SetStatusRequest {
+            status: TestActorStatus::Inactive,
+        }),
+    )
+    .await
+    .expect("unsupported message should fail without waiting for node=1");
+
+    assert!(matches!(res, Err(ActorRefErr::NotSupported { .. })));
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+    protocol: ProtocolConfig,
+) -> RemoteActorSystem {
+    create_node_with_handlers(node_id, transport, seed_addr, protocol, |handlers| {
+        handlers
+            .with_handler::<TestActor, GetStatusRequest>("TestActor.GetStatusRequest")
+            .with_handler::<TestActor, SetStatusRequest>("TestActor.SetStatusRequest")
+    })
+    .await
+}
+
+async fn create_node_with_handlers<F>(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+    protocol: ProtocolConfig,
+    handlers: F,
+) -> RemoteActorSystem
+where
+    F: 'static + (FnOnce(&mut RemoteSystemConfigBuilder) -> &mut RemoteSystemConfigBuilder),
+{
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .with_handlers(handlers)
+        .configure(|config| {
+            config.network(NetworkConfig {
+                protocol,
+                ..Default::default()
+            })
+        })
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
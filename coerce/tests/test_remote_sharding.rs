@@ -73,6 +73,7 @@ async fn create_shard_coordinator<T: Actor>(
     shard_coordinator.add_host(ShardHostState {
         node_id,
         node_tag,
+        datacenter: remote.datacenter().into(),
         shards: Default::default(),
         actor: shard_host,
         status: ShardHostStatus::Ready,
@@ -125,6 +126,7 @@ pub async fn test_shard_coordinator_shard_allocation() {
         .send(AllocateShard {
             shard_id: SHARD_ID,
             rebalancing: false,
+            datacenter: None,
         })
         .await;
 
@@ -162,6 +164,7 @@ pub async fn test_shard_coordinator_shard_allocation() {
         .send(AllocateShard {
             shard_id: SHARD_ID,
             rebalancing: false,
+            datacenter: None,
         })
         .await;
 
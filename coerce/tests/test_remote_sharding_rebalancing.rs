@@ -79,6 +79,7 @@ async fn create_shard_coordinator<T: Actor>(
     shard_coordinator.add_host(ShardHostState {
         node_id,
         node_tag,
+        datacenter: remote.datacenter().into(),
         shards: Default::default(),
         actor: shard_host,
         status: ShardHostStatus::Ready,
@@ -113,6 +114,7 @@ async fn create_system(
                 ping_timeout: Duration::from_millis(10),
                 unhealthy_node_heartbeat_timeout: Duration::from_millis(750),
                 terminated_node_heartbeat_timeout: Duration::from_millis(1000),
+                ..Default::default()
             })
         })
         .with_id(node_id)
@@ -0,0 +1,152 @@
+use chrono::{Duration, Utc};
+use coerce::remote::cluster::node::{NodeStatus, RemoteNode, RemoteNodeState, DEFAULT_DATACENTER};
+use coerce::remote::cluster::split_brain::{ClusterView, SplitBrainDecision, SplitBrainStrategy};
+use coerce::remote::system::NodeId;
+
+const DOWN_UNREACHABLE: SplitBrainDecision = SplitBrainDecision::DownUnreachable;
+const DOWN_SELF: SplitBrainDecision = SplitBrainDecision::DownSelf;
+
+#[test]
+pub fn test_keep_majority() {
+    let strategy = SplitBrainStrategy::KeepMajority;
+
+    // 3 nodes partitioned into {1, 2} and {3}
+    let nodes = cluster(&[1, 2], &[3]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_UNREACHABLE);
+    assert_eq!(decide(&strategy, 2, &nodes), DOWN_UNREACHABLE);
+
+    let nodes = cluster(&[3], &[1, 2]);
+    assert_eq!(decide(&strategy, 3, &nodes), DOWN_SELF);
+
+    // equal sides, the side with the lowest node id is kept
+    let nodes = cluster(&[1, 4], &[2, 3]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_UNREACHABLE);
+
+    let nodes = cluster(&[2, 3], &[1, 4]);
+    assert_eq!(decide(&strategy, 2, &nodes), DOWN_SELF);
+}
+
+#[test]
+pub fn test_keep_majority_ignores_joining_nodes() {
+    let strategy = SplitBrainStrategy::KeepMajority;
+
+    let mut nodes = cluster(&[1], &[2]);
+    nodes.push(node(3, NodeStatus::Joining, 3));
+    nodes.push(node(4, NodeStatus::Joining, 4));
+
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_UNREACHABLE);
+}
+
+#[test]
+pub fn test_static_quorum() {
+    let strategy = SplitBrainStrategy::StaticQuorum { quorum_size: 3 };
+
+    let nodes = cluster(&[1, 2, 3], &[4, 5]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_UNREACHABLE);
+
+    let nodes = cluster(&[4, 5], &[1, 2, 3]);
+    assert_eq!(decide(&strategy, 4, &nodes), DOWN_SELF);
+
+    // neither side has a quorum, both sides are downed
+    let nodes = cluster(&[1, 2], &[3, 4]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_SELF);
+
+    let nodes = cluster(&[3, 4], &[1, 2]);
+    assert_eq!(decide(&strategy, 3, &nodes), DOWN_SELF);
+}
+
+#[test]
+pub fn test_keep_oldest() {
+    let strategy = SplitBrainStrategy::KeepOldest {
+        down_if_alone: false,
+    };
+
+    // node 1 is the oldest node
+    let nodes = cluster(&[1], &[2, 3]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_UNREACHABLE);
+
+    let nodes = cluster(&[2, 3], &[1]);
+    assert_eq!(decide(&strategy, 2, &nodes), DOWN_SELF);
+}
+
+#[test]
+pub fn test_keep_oldest_down_if_alone() {
+    let strategy = SplitBrainStrategy::KeepOldest {
+        down_if_alone: true,
+    };
+
+    let nodes = cluster(&[1], &[2, 3]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_SELF);
+
+    let nodes = cluster(&[2, 3], &[1]);
+    assert_eq!(decide(&strategy, 2, &nodes), DOWN_UNREACHABLE);
+
+    // the oldest node isn't alone, so its side is kept
+    let nodes = cluster(&[1, 2], &[3]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_UNREACHABLE);
+
+    let nodes = cluster(&[3], &[1, 2]);
+    assert_eq!(decide(&strategy, 3, &nodes), DOWN_SELF);
+}
+
+#[test]
+pub fn test_keep_referee() {
+    let strategy = SplitBrainStrategy::KeepReferee {
+        referee_addr: addr(3),
+        down_all_if_less_than_nodes: 2,
+    };
+
+    let nodes = cluster(&[2, 3], &[1]);
+    assert_eq!(decide(&strategy, 2, &nodes), DOWN_UNREACHABLE);
+
+    let nodes = cluster(&[1], &[2, 3]);
+    assert_eq!(decide(&strategy, 1, &nodes), DOWN_SELF);
+
+    // the referee is reachable, but there aren't enough nodes left
+    let nodes = cluster(&[3], &[1, 2]);
+    assert_eq!(decide(&strategy, 3, &nodes), DOWN_SELF);
+}
+
+fn decide(
+    strategy: &SplitBrainStrategy,
+    node_id: NodeId,
+    nodes: &[RemoteNodeState],
+) -> SplitBrainDecision {
+    let view = ClusterView::new(node_id, nodes);
+    assert!(view.is_partitioned());
+
+    strategy.decide(&view)
+}
+
+/// Nodes are started in order of their id, so the node with the lowest id is the oldest.
+fn cluster(reachable: &[NodeId], unreachable: &[NodeId]) -> Vec<RemoteNodeState> {
+    let reachable = reachable
+        .iter()
+        .map(|id| node(*id, NodeStatus::Healthy, *id as i64));
+
+    let unreachable = unreachable
+        .iter()
+        .map(|id| node(*id, NodeStatus::Terminated, *id as i64));
+
+    reachable.chain(unreachable).collect()
+}
+
+fn node(id: NodeId, status: NodeStatus, start_order: i64) -> RemoteNodeState {
+    let started_at = Utc::now() - Duration::minutes(100) + Duration::minutes(start_order);
+    let mut node = RemoteNodeState::new(RemoteNode::new(
+        id,
+        addr(id),
+        "test".to_string(),
+        Some(started_at),
+        Default::default(),
+        Default::default(),
+        DEFAULT_DATACENTER.into(),
+    ));
+
+    node.status = status;
+    node
+}
+
+fn addr(id: NodeId) -> String {
+    format!("localhost:{}", 31100 + id)
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "remote-tls")]
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use coerce::actor::system::ActorSystem;
+use coerce::remote::net::security::tls::TlsConfig;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+
+mod util;
+
+const CA_CERT: &str = include_str!("certs/ca.pem");
+
+fn tls_config(cert_name: &str, mutual_tls: bool) -> TlsConfig {
+    let cert_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/certs");
+
+    TlsConfig::builder()
+        .certificate_file(format!("{}/{}.pem", cert_dir, cert_name))
+        .unwrap()
+        .private_key_file(format!("{}/{}.key", cert_dir, cert_name))
+        .unwrap()
+        .ca_pem(CA_CERT)
+        .mutual_tls(mutual_tls)
+        .build()
+        .expect("tls config")
+}
+
+async fn create_node(
+    node_id: NodeId,
+    node_tag: &str,
+    tls: TlsConfig,
+    listen_addr: &str,
+    seed_addr: Option<&str>,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(node_tag)
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_tls(tls)
+        .build()
+        .await;
+
+    let worker = remote.clone().cluster_worker().listen_addr(listen_addr);
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_tls() {
+    util::create_trace_logger();
+
+    let node_1 = create_node(
+        1,
+        "tls-node-1",
+        tls_config("tls-node-1", false),
+        "localhost:32201",
+        None,
+    )
+    .await;
+
+    let node_2 = create_node(
+        2,
+        "tls-node-2",
+        tls_config("tls-node-2", false),
+        "localhost:32202",
+        Some("localhost:32201"),
+    )
+    .await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 2);
+    assert_eq!(node_2.get_nodes().await.len(), 2);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_mutual_tls() {
+    util::create_trace_logger();
+
+    let node_1 = create_node(
+        1,
+        "tls-node-1",
+        tls_config("tls-node-1", true),
+        "localhost:32211",
+        None,
+    )
+    .await;
+
+    let node_2 = create_node(
+        2,
+        "tls-node-2",
+        tls_config("tls-node-2", true),
+        "localhost:32212",
+        Some("localhost:32211"),
+    )
+    .await;
+
+    let untrusted_node = create_node(
+        3,
+        "untrusted-node",
+        tls_config("untrusted-node", true),
+        "localhost:32213",
+        Some("localhost:32211"),
+    )
+    .await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 2);
+    assert_eq!(node_2.get_nodes().await.len(), 2);
+    assert_eq!(untrusted_node.get_nodes().await.len(), 1);
+}
+
+#[tokio::test]
+pub async fn test_remote_cluster_mutual_tls_node_identity() {
+    util::create_trace_logger();
+
+    let node_1 = create_node(
+        1,
+        "tls-node-1",
+        tls_config("tls-node-1", true),
+        "localhost:32221",
+        None,
+    )
+    .await;
+
+    // trusted certificate, but not issued for the node tag `tls-node-3`
+    let impostor = create_node(
+        3,
+        "tls-node-3",
+        tls_config("tls-node-2", true),
+        "localhost:32223",
+        Some("localhost:32221"),
+    )
+    .await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 1);
+    assert_eq!(impostor.get_nodes().await.len(), 1);
+}
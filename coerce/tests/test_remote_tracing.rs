@@ -0,0 +1,144 @@
+#![cfg(feature = "opentelemetry")]
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::{Actor, ActorRef, ToActorId};
+use coerce::remote::net::transport::MemoryTransport;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::remote::tracing::{extract_trace_identifier, set_trace_parent};
+use coerce::remote::RemoteActorRef;
+use coerce_macros::JsonMessage;
+use opentelemetry::sdk::trace::TracerProvider;
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use tracing::{Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+
+const INVALID_TRACE_ID: &str = "00000000000000000000000000000000";
+
+#[derive(JsonMessage, Serialize, Deserialize)]
+#[result("String")]
+struct GetTraceId;
+
+struct TracedActor;
+
+impl Actor for TracedActor {}
+
+#[async_trait]
+impl Handler<GetTraceId> for TracedActor {
+    async fn handle(&mut self, _message: GetTraceId, _ctx: &mut ActorContext) -> String {
+        trace_id(&Span::current())
+    }
+}
+
+#[tokio::test]
+pub async fn test_remote_trace_identifier() {
+    let (subscriber, _provider) = otel_subscriber();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    assert_eq!(extract_trace_identifier(&Span::none()), "");
+
+    let span = tracing::info_span!("test.send");
+    let traceparent = extract_trace_identifier(&span);
+    let parts: Vec<&str> = traceparent.split('-').collect();
+
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0], "00");
+    assert_eq!(parts[1], trace_id(&span));
+    assert_eq!(parts[2].len(), 16);
+
+    let child = tracing::info_span!(parent: None, "test.recv");
+    set_trace_parent(&child, &traceparent);
+    assert_eq!(trace_id(&child), trace_id(&span));
+
+    // invalid trace identifiers are ignored
+    let root = tracing::info_span!(parent: None, "test.recv");
+    set_trace_parent(&root, "not-a-traceparent");
+    assert_ne!(trace_id(&root), trace_id(&span));
+}
+
+#[tokio::test]
+pub async fn test_remote_trace_context_propagation() {
+    let (subscriber, _provider) = otel_subscriber();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, &transport, None).await;
+    let node_2 = create_node(2, &transport, Some("node-1")).await;
+
+    let actor_id = "traced-actor".to_actor_id();
+    let _ = node_1
+        .actor_system()
+        .new_actor(actor_id.clone(), TracedActor, Tracked)
+        .await
+        .unwrap();
+
+    let actor_ref = ActorRef::from(RemoteActorRef::<TracedActor>::new(
+        actor_id,
+        node_1.node_id(),
+        node_2.clone(),
+    ));
+
+    let span = tracing::info_span!("test.send");
+    let expected_trace_id = trace_id(&span);
+    assert_ne!(expected_trace_id, INVALID_TRACE_ID);
+
+    let received_trace_id = actor_ref.send(GetTraceId).instrument(span).await.unwrap();
+    assert_eq!(received_trace_id, expected_trace_id);
+}
+
+fn trace_id(span: &Span) -> String {
+    format!("{:032x}", span.context().span().span_context().trace_id())
+}
+
+/// The tracer only holds a weak reference to the provider, so the provider is returned
+/// to be kept alive for the duration of the test.
+fn otel_subscriber() -> (impl tracing::Subscriber + Send + Sync, TracerProvider) {
+    let provider = TracerProvider::builder().build();
+    let tracer = provider.tracer("coerce-test");
+
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+    (subscriber, provider)
+}
+
+async fn create_node(
+    node_id: NodeId,
+    transport: &MemoryTransport,
+    seed_addr: Option<&str>,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport.clone())
+        .with_handlers(|handlers| {
+            handlers.with_handler::<TracedActor, GetTraceId>("TracedActor.GetTraceId")
+        })
+        .build()
+        .await;
+
+    let worker = remote
+        .clone()
+        .cluster_worker()
+        .listen_addr(format!("node-{}", node_id));
+
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
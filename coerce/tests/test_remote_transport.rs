@@ -0,0 +1,99 @@
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::IntoActorId;
+use coerce::remote::net::transport::{MemoryTransport, Transport};
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+
+async fn create_node(
+    node_id: NodeId,
+    transport: impl Transport,
+    listen_addr: &str,
+    seed_addr: Option<&str>,
+) -> RemoteActorSystem {
+    let remote = RemoteActorSystem::builder()
+        .with_tag(format!("node-{}", node_id))
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(transport)
+        .build()
+        .await;
+
+    let worker = remote.clone().cluster_worker().listen_addr(listen_addr);
+    let worker = match seed_addr {
+        Some(seed_addr) => worker.with_seed_addr(seed_addr),
+        None => worker,
+    };
+
+    worker.start().await;
+    remote
+}
+
+#[tokio::test]
+pub async fn test_remote_memory_transport_cluster() {
+    util::create_trace_logger();
+
+    let transport = MemoryTransport::new();
+    let node_1 = create_node(1, transport.clone(), "node-1", None).await;
+    let node_2 = create_node(2, transport.clone(), "node-2", Some("node-1")).await;
+    let node_3 = create_node(3, transport.clone(), "node-3", Some("node-2")).await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 3);
+    assert_eq!(node_2.get_nodes().await.len(), 3);
+    assert_eq!(node_3.get_nodes().await.len(), 3);
+    assert_eq!(
+        transport.listening_addrs(),
+        vec!["node-1", "node-2", "node-3"]
+    );
+
+    let _ = node_1
+        .actor_system()
+        .new_actor("test-actor", util::TestActor::new(), Tracked)
+        .await
+        .unwrap();
+
+    let located_node = node_3.locate_actor_node("test-actor".into_actor_id()).await;
+    assert_eq!(located_node, Some(1));
+}
+
+#[tokio::test]
+pub async fn test_remote_memory_transport_unknown_addr() {
+    let transport = MemoryTransport::new();
+    let _listener = transport.listen("node-1").await.unwrap();
+
+    assert!(transport.listen("node-1").await.is_err());
+    assert!(transport.connect("node-2").await.is_err());
+    assert!(transport.connect("node-1").await.is_ok());
+
+    // separate memory transports don't share listeners
+    assert!(MemoryTransport::new().connect("node-1").await.is_err());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+pub async fn test_remote_unix_transport_cluster() {
+    use coerce::remote::net::transport::UnixTransport;
+
+    util::create_trace_logger();
+
+    let socket_dir = std::env::temp_dir().join(format!("coerce-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&socket_dir).unwrap();
+
+    let node_1_addr = socket_dir.join("node-1.sock").display().to_string();
+    let node_2_addr = socket_dir.join("node-2.sock").display().to_string();
+
+    let node_1 = create_node(1, UnixTransport::new(), &node_1_addr, None).await;
+    let node_2 = create_node(2, UnixTransport::new(), &node_2_addr, Some(&node_1_addr)).await;
+
+    assert_eq!(node_1.get_nodes().await.len(), 2);
+    assert_eq!(node_2.get_nodes().await.len(), 2);
+
+    let _ = std::fs::remove_dir_all(&socket_dir);
+}
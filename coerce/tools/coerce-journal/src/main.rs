@@ -0,0 +1,202 @@
+use clap::{arg, ArgMatches, Command};
+use coerce::actor::system::ActorSystem;
+use coerce::persistent::journal::migration::{
+    export_journals, import_journals, migrate_journals, ImportOptions, JournalExportReader,
+    MigrationSummary,
+};
+use coerce::persistent::journal::provider::StorageProvider;
+use coerce::persistent::journal::storage::JournalStorageRef;
+use coerce_redis::journal::{RedisStorageConfig, RedisStorageProvider};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::process::exit;
+
+#[macro_use]
+extern crate tracing;
+
+#[tokio::main]
+pub async fn main() {
+    tracing_subscriber::fmt()
+        .compact()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    let matches = Command::new("coerce-journal")
+        .about("Export, import and migrate Coerce actor journals stored in Redis")
+        .long_about(
+            "Export, import and migrate Coerce actor journals stored in Redis.\n\n\
+             Only redis:// and rediss:// storage URLs are supported, journals held by other \
+             storage providers can be exported and imported using the \
+             `coerce::persistent::journal::migration` API. Journals can't be exported from a \
+             Redis Cluster, since each node only holds a subset of the keys.",
+        )
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("export")
+                .about("Exports every journal from a Redis storage provider to a file")
+                .arg(arg!(--from <STORAGE> "Source Redis storage, e.g. redis://127.0.0.1:6379/"))
+                .arg(arg!(--output <FILE> "The file the journals will be written to"))
+                .args(storage_args()),
+        )
+        .subcommand(
+            Command::new("import")
+                .about("Imports journals from a file into a Redis storage provider")
+                .arg(arg!(--input <FILE> "A file previously created by `export`"))
+                .arg(arg!(--to <STORAGE> "Target Redis storage, e.g. redis://127.0.0.1:6379/"))
+                .args(storage_args())
+                .args(import_args()),
+        )
+        .subcommand(
+            Command::new("migrate")
+                .about("Copies every journal from one Redis storage provider to another")
+                .arg(arg!(--from <STORAGE> "Source Redis storage, e.g. redis://127.0.0.1:6379/"))
+                .arg(arg!(--to <STORAGE> "Target Redis storage, e.g. redis://127.0.0.1:6380/"))
+                .arg(arg!(--target_key_prefix [PREFIX] "Key prefix used by the target storage (default=--key_prefix)"))
+                .args(storage_args())
+                .args(import_args()),
+        )
+        .subcommand(
+            Command::new("inspect")
+                .about("Prints a summary of every journal contained in an export file")
+                .arg(arg!(--input <FILE> "A file previously created by `export`")),
+        )
+        .get_matches();
+
+    let system = ActorSystem::new();
+    let result = match matches.subcommand() {
+        Some(("export", args)) => export(args, &system).await,
+        Some(("import", args)) => import(args, &system).await,
+        Some(("migrate", args)) => migrate(args, &system).await,
+        Some(("inspect", args)) => inspect(args),
+        _ => unreachable!(),
+    };
+
+    match result {
+        Ok(summary) => {
+            info!(
+                "completed (journals={}, snapshots={}, messages={})",
+                summary.journals, summary.snapshots, summary.messages
+            );
+        }
+        Err(e) => {
+            error!("{}", e);
+            exit(1);
+        }
+    }
+}
+
+type CommandResult = Result<MigrationSummary, Box<dyn std::error::Error>>;
+
+fn storage_args() -> Vec<clap::Arg> {
+    vec![
+        arg!(--key_prefix [PREFIX] "Key prefix used by the storage provider (default=\"\")"),
+        arg!(--use_key_hashtags "Whether the storage provider uses redis cluster key hashtags"),
+    ]
+}
+
+fn import_args() -> Vec<clap::Arg> {
+    vec![
+        arg!(--no_verify "Skip reading back and verifying every imported journal"),
+        arg!(--replace_existing "Delete existing journals in the target storage before importing"),
+    ]
+}
+
+async fn export(args: &ArgMatches, system: &ActorSystem) -> CommandResult {
+    let key_prefix = key_prefix(args, "key_prefix");
+    let storage = connect(args, "from", key_prefix, system).await?;
+    let output = args.get_one::<String>("output").unwrap();
+
+    let writer = BufWriter::new(File::create(output)?);
+    Ok(export_journals(&storage, writer).await?)
+}
+
+async fn import(args: &ArgMatches, system: &ActorSystem) -> CommandResult {
+    let key_prefix = key_prefix(args, "key_prefix");
+    let storage = connect(args, "to", key_prefix, system).await?;
+    let input = args.get_one::<String>("input").unwrap();
+
+    let mut reader = BufReader::new(File::open(input)?);
+    Ok(import_journals(&mut reader, &storage, import_options(args)).await?)
+}
+
+async fn migrate(args: &ArgMatches, system: &ActorSystem) -> CommandResult {
+    let source_key_prefix = key_prefix(args, "key_prefix");
+    let target_key_prefix = args
+        .get_one::<String>("target_key_prefix")
+        .cloned()
+        .unwrap_or_else(|| source_key_prefix.clone());
+
+    let source = connect(args, "from", source_key_prefix, system).await?;
+    let target = connect(args, "to", target_key_prefix, system).await?;
+
+    Ok(migrate_journals(&source, &target, import_options(args)).await?)
+}
+
+fn inspect(args: &ArgMatches) -> CommandResult {
+    let input = args.get_one::<String>("input").unwrap();
+    let mut file = BufReader::new(File::open(input)?);
+    let mut reader = JournalExportReader::new(&mut file);
+
+    let mut summary = MigrationSummary::default();
+    while let Some(journal) = reader.next_journal()? {
+        println!(
+            "{} snapshot_sequence={} messages={} last_sequence={}",
+            &journal.persistence_id,
+            journal
+                .snapshot
+                .as_ref()
+                .map_or_else(|| "-".to_string(), |s| s.sequence.to_string()),
+            journal.messages.len(),
+            journal
+                .messages
+                .last()
+                .map_or_else(|| "-".to_string(), |m| m.sequence.to_string()),
+        );
+
+        summary.journals += 1;
+        summary.snapshots += journal.snapshot.is_some() as usize;
+        summary.messages += journal.messages.len();
+    }
+
+    Ok(summary)
+}
+
+fn key_prefix(args: &ArgMatches, id: &str) -> String {
+    args.get_one::<String>(id).cloned().unwrap_or_default()
+}
+
+fn import_options(args: &ArgMatches) -> ImportOptions {
+    ImportOptions {
+        verify: !args.get_flag("no_verify"),
+        replace_existing: args.get_flag("replace_existing"),
+    }
+}
+
+async fn connect(
+    args: &ArgMatches,
+    id: &str,
+    key_prefix: String,
+    system: &ActorSystem,
+) -> Result<JournalStorageRef, Box<dyn std::error::Error>> {
+    let storage = args.get_one::<String>(id).unwrap();
+    if !storage.starts_with("redis://") && !storage.starts_with("rediss://") {
+        return Err(format!(
+            "unsupported storage, only redis:// and rediss:// URLs are supported (storage={})",
+            storage
+        )
+        .into());
+    }
+
+    let provider = RedisStorageProvider::connect(
+        RedisStorageConfig {
+            nodes: vec![storage.clone()],
+            key_prefix,
+            cluster: false,
+            use_key_hashtags: args.get_flag("use_key_hashtags"),
+        },
+        system,
+    )
+    .await;
+
+    Ok(provider.journal_storage().unwrap())
+}
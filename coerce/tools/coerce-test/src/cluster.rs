@@ -0,0 +1,374 @@
+use crate::event::recorder::EventRecorder;
+use crate::event::{EventFilter, EventLog, RecordedEvent};
+use crate::network::SimulatedNetwork;
+use crate::time::{self, WaitTimeout};
+use coerce::actor::system::ActorSystem;
+use coerce::actor::IntoActor;
+use coerce::persistent::Persistence;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::net::server::RemoteServer;
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+const CONVERGENCE_TIMEOUT: Duration = Duration::from_secs(60);
+
+type ConfigureFn = Arc<dyn Fn(RemoteActorSystemBuilder) -> RemoteActorSystemBuilder + Send + Sync>;
+
+type ConfigureNodeFn =
+    Arc<dyn Fn(NodeId, RemoteActorSystemBuilder) -> RemoteActorSystemBuilder + Send + Sync>;
+
+pub struct TestRemoteActorSystem {
+    system: RemoteActorSystem,
+    node_id: NodeId,
+    addr: String,
+    server: RemoteServer,
+    running: bool,
+}
+
+impl TestRemoteActorSystem {
+    pub fn system(&self) -> &RemoteActorSystem {
+        &self.system
+    }
+
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+}
+
+/// A cluster of [`RemoteActorSystem`]s running within the same process, connected via a
+/// [`SimulatedNetwork`].
+///
+/// Node `n` listens on `node-n`, and every node joins the cluster via the first node.
+///
+/// # Example
+/// ```rust,no_run
+/// use coerce_test::event::EventFilter;
+/// use coerce_test::TestCluster;
+/// use std::time::Duration;
+///
+/// #[tokio::test(start_paused = true)]
+/// async fn test_leader_change() {
+///     let mut cluster = TestCluster::builder().nodes(3).build().await;
+///
+///     cluster.kill(1).await;
+///     cluster
+///         .wait_for(
+///             &EventFilter::leader_changed().node(2).observed_by(3),
+///             Duration::from_secs(30),
+///         )
+///         .await
+///         .unwrap();
+/// }
+/// ```
+pub struct TestCluster {
+    systems: HashMap<NodeId, TestRemoteActorSystem>,
+    network: SimulatedNetwork,
+    events: EventLog,
+    persistence: Option<Persistence>,
+    configure: Option<ConfigureFn>,
+    configure_node: Option<ConfigureNodeFn>,
+}
+
+pub struct TestClusterBuilder {
+    nodes: usize,
+    persistence: Option<Persistence>,
+    configure: Option<ConfigureFn>,
+    configure_node: Option<ConfigureNodeFn>,
+}
+
+impl TestClusterBuilder {
+    /// The number of nodes started when the cluster is built, defaults to 3.
+    pub fn nodes(mut self, nodes: usize) -> Self {
+        self.nodes = nodes;
+        self
+    }
+
+    /// Each node's actor system is made persistent, sharing the same persistence backplane,
+    /// which is required when using sharding.
+    pub fn with_persistence(mut self, persistence: Persistence) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
+    /// Configures each node's [`RemoteActorSystemBuilder`], for example to register actors and
+    /// handlers, or to override the heartbeat configuration.
+    pub fn configure<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(RemoteActorSystemBuilder) -> RemoteActorSystemBuilder + Send + Sync,
+    {
+        self.configure = Some(Arc::new(f));
+        self
+    }
+
+    /// Configures a specific node's [`RemoteActorSystemBuilder`], for example to assign roles to
+    /// some nodes only. Applied after [`TestClusterBuilder::configure`].
+    pub fn configure_node<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(NodeId, RemoteActorSystemBuilder) -> RemoteActorSystemBuilder + Send + Sync,
+    {
+        self.configure_node = Some(Arc::new(f));
+        self
+    }
+
+    /// Starts every node, waiting for all nodes to see each other as healthy, and to agree
+    /// on a leader.
+    pub async fn build(self) -> TestCluster {
+        let mut cluster = TestCluster {
+            systems: HashMap::new(),
+            network: SimulatedNetwork::new(),
+            events: EventLog::new(),
+            persistence: self.persistence,
+            configure: self.configure,
+            configure_node: self.configure_node,
+        };
+
+        for _ in 0..self.nodes {
+            cluster.add_node().await;
+        }
+
+        cluster
+            .wait_for_convergence(CONVERGENCE_TIMEOUT)
+            .await
+            .expect("cluster convergence");
+
+        cluster
+    }
+}
+
+impl TestCluster {
+    pub fn builder() -> TestClusterBuilder {
+        TestClusterBuilder {
+            nodes: 3,
+            persistence: None,
+            configure: None,
+            configure_node: None,
+        }
+    }
+
+    /// Starts a new node, joining the cluster via the first node that's still running.
+    pub async fn add_node(&mut self) -> NodeId {
+        let node_id = self.systems.keys().max().map_or(1, |id| id + 1);
+        let addr = node_addr(node_id);
+        let seed_addr = self.running_nodes().first().map(|&id| node_addr(id));
+
+        let actor_system = match &self.persistence {
+            Some(persistence) => ActorSystem::new().to_persistent(persistence.clone()),
+            None => ActorSystem::new(),
+        };
+
+        let mut builder = RemoteActorSystem::builder()
+            .with_tag(addr.clone())
+            .with_id(node_id)
+            .with_actor_system(actor_system)
+            .with_transport(self.network.transport(&addr));
+
+        if let Some(configure) = &self.configure {
+            builder = configure(builder);
+        }
+
+        if let Some(configure_node) = &self.configure_node {
+            builder = configure_node(node_id, builder);
+        }
+
+        let system = builder.build().await;
+
+        EventRecorder::new(node_id, self.events.clone())
+            .into_actor(Some("coerce-test-event-recorder"), system.actor_system())
+            .await
+            .expect("event recorder");
+
+        let mut worker = system.clone().cluster_worker().listen_addr(&addr);
+        if let Some(seed_addr) = seed_addr {
+            worker = worker.with_seed_addr(seed_addr);
+        }
+
+        let server = worker.start().await;
+
+        self.systems.insert(
+            node_id,
+            TestRemoteActorSystem {
+                system,
+                node_id,
+                addr,
+                server,
+                running: true,
+            },
+        );
+
+        node_id
+    }
+
+    pub fn node(&self, node_id: NodeId) -> &TestRemoteActorSystem {
+        self.systems
+            .get(&node_id)
+            .unwrap_or_else(|| panic!("node_id={} does not exist", node_id))
+    }
+
+    pub fn system(&self, node_id: NodeId) -> &RemoteActorSystem {
+        self.node(node_id).system()
+    }
+
+    /// Ids of the nodes that haven't been killed, in ascending order.
+    pub fn running_nodes(&self) -> Vec<NodeId> {
+        let mut nodes: Vec<NodeId> = self
+            .systems
+            .values()
+            .filter(|n| n.running)
+            .map(|n| n.node_id)
+            .collect();
+
+        nodes.sort();
+        nodes
+    }
+
+    pub fn network(&self) -> &SimulatedNetwork {
+        &self.network
+    }
+
+    pub fn events(&self) -> &EventLog {
+        &self.events
+    }
+
+    /// Abruptly stops a node, closing all of its connections and shutting its actor system down.
+    pub async fn kill(&mut self, node_id: NodeId) {
+        let node = self
+            .systems
+            .get_mut(&node_id)
+            .unwrap_or_else(|| panic!("node_id={} does not exist", node_id));
+
+        if !node.running {
+            return;
+        }
+
+        node.running = false;
+        self.network.isolate(&node.addr);
+        node.server.stop();
+        node.system.actor_system().shutdown().await;
+    }
+
+    /// Gracefully removes a node from the cluster by running its coordinated shutdown, see
+    /// [`RemoteActorSystem::leave`].
+    pub async fn leave(&mut self, node_id: NodeId) {
+        let node = self
+            .systems
+            .get_mut(&node_id)
+            .unwrap_or_else(|| panic!("node_id={} does not exist", node_id));
+
+        if !node.running {
+            return;
+        }
+
+        node.running = false;
+        node.system.leave().await;
+        node.server.stop();
+        self.network.isolate(&node.addr);
+    }
+
+    /// Partitions node `a` from node `b`, see [`SimulatedNetwork::partition`].
+    pub fn partition(&self, a: NodeId, b: NodeId) {
+        self.network.partition(&node_addr(a), &node_addr(b));
+    }
+
+    /// Partitions `nodes` from every other node in the cluster.
+    pub fn split(&self, nodes: &[NodeId]) {
+        for &a in nodes {
+            for &b in self.systems.keys().filter(|id| !nodes.contains(id)) {
+                self.partition(a, b);
+            }
+        }
+    }
+
+    pub fn heal(&self, a: NodeId, b: NodeId) {
+        self.network.heal(&node_addr(a), &node_addr(b));
+    }
+
+    /// Removes all network faults, nodes that have been killed remain unreachable.
+    pub fn heal_all(&self) {
+        self.network.heal_all();
+        for node in self.systems.values().filter(|n| !n.running) {
+            self.network.isolate(&node.addr);
+        }
+    }
+
+    pub fn drop_messages(&self, from: NodeId, to: NodeId) {
+        self.network.drop_messages(&node_addr(from), &node_addr(to));
+    }
+
+    pub fn delay_messages(&self, from: NodeId, to: NodeId, delay: Duration) {
+        self.network
+            .delay_messages(&node_addr(from), &node_addr(to), delay);
+    }
+
+    /// Moves the paused clock forward by `duration`, see [`time::advance`].
+    pub async fn advance(&self, duration: Duration) {
+        time::advance(duration).await
+    }
+
+    /// Waits for an event matching `filter` to be emitted by any node.
+    pub async fn wait_for(
+        &self,
+        filter: &EventFilter,
+        timeout: Duration,
+    ) -> Result<RecordedEvent, WaitTimeout> {
+        self.events.wait_for(filter, timeout).await
+    }
+
+    /// The leader, if every running node agrees on one.
+    pub fn leader(&self) -> Option<NodeId> {
+        let mut leaders = self
+            .running_nodes()
+            .into_iter()
+            .map(|id| self.system(id).current_leader());
+
+        let leader = leaders.next()??;
+        leaders.all(|l| l == Some(leader)).then_some(leader)
+    }
+
+    /// Waits for every running node to agree that `leader` is the leader.
+    pub async fn wait_for_leader(
+        &self,
+        leader: NodeId,
+        timeout: Duration,
+    ) -> Result<(), WaitTimeout> {
+        time::wait_until(timeout, || async { self.leader() == Some(leader) }).await
+    }
+
+    /// Waits for every running node to see every other running node as healthy, and for all
+    /// running nodes to agree on a leader.
+    pub async fn wait_for_convergence(&self, timeout: Duration) -> Result<(), WaitTimeout> {
+        time::wait_until(timeout, || self.is_converged()).await
+    }
+
+    async fn is_converged(&self) -> bool {
+        let running_nodes = self.running_nodes();
+        for &node_id in &running_nodes {
+            let nodes = self.system(node_id).get_nodes().await;
+            let all_healthy = running_nodes.iter().all(|peer| {
+                nodes
+                    .iter()
+                    .any(|n| n.id == *peer && n.status == NodeStatus::Healthy)
+            });
+
+            if !all_healthy {
+                return false;
+            }
+        }
+
+        self.leader().is_some()
+    }
+}
+
+pub fn node_addr(node_id: NodeId) -> String {
+    format!("node-{}", node_id)
+}
@@ -1,4 +1,92 @@
-pub struct EventFilter {
+use crate::event::{RecordedEvent, TestEvent};
+use coerce::remote::system::NodeId;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum EventKind {
+    NodeAdded,
+    NodeRemoved,
+    LeaderChanged,
+    DatacenterLeaderChanged,
+    NodeDowned,
+    NodeLeaving,
+}
 
+/// Matches recorded events, an empty filter matches every event.
+///
+/// # Example
+/// ```rust,no_run
+/// use coerce_test::event::EventFilter;
+///
+/// // node 2 observed node 1 leaving the cluster
+/// let filter = EventFilter::node_removed().node(1).observed_by(2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    kind: Option<EventKind>,
+    node_id: Option<NodeId>,
+    observed_by: Option<NodeId>,
 }
 
+impl EventFilter {
+    pub fn any() -> EventFilter {
+        EventFilter::default()
+    }
+
+    pub fn node_added() -> EventFilter {
+        EventFilter::kind(EventKind::NodeAdded)
+    }
+
+    pub fn node_removed() -> EventFilter {
+        EventFilter::kind(EventKind::NodeRemoved)
+    }
+
+    pub fn leader_changed() -> EventFilter {
+        EventFilter::kind(EventKind::LeaderChanged)
+    }
+
+    pub fn datacenter_leader_changed() -> EventFilter {
+        EventFilter::kind(EventKind::DatacenterLeaderChanged)
+    }
+
+    pub fn node_downed() -> EventFilter {
+        EventFilter::kind(EventKind::NodeDowned)
+    }
+
+    pub fn node_leaving() -> EventFilter {
+        EventFilter::kind(EventKind::NodeLeaving)
+    }
+
+    /// Only match events about `node_id`, for example the node that was added, or the new leader.
+    pub fn node(mut self, node_id: NodeId) -> Self {
+        self.node_id = Some(node_id);
+        self
+    }
+
+    /// Only match events emitted by `node_id`.
+    pub fn observed_by(mut self, node_id: NodeId) -> Self {
+        self.observed_by = Some(node_id);
+        self
+    }
+
+    pub fn matches(&self, event: &RecordedEvent) -> bool {
+        let kind = match event.event {
+            TestEvent::NodeAdded(_) => EventKind::NodeAdded,
+            TestEvent::NodeRemoved(_) => EventKind::NodeRemoved,
+            TestEvent::LeaderChanged(_) => EventKind::LeaderChanged,
+            TestEvent::DatacenterLeaderChanged(_) => EventKind::DatacenterLeaderChanged,
+            TestEvent::NodeDowned(_) => EventKind::NodeDowned,
+            TestEvent::NodeLeaving(_) => EventKind::NodeLeaving,
+        };
+
+        self.kind.is_none_or(|k| k == kind)
+            && self.node_id.is_none_or(|n| n == event.event.node_id())
+            && self.observed_by.is_none_or(|n| n == event.observed_by)
+    }
+
+    fn kind(kind: EventKind) -> EventFilter {
+        EventFilter {
+            kind: Some(kind),
+            ..Default::default()
+        }
+    }
+}
@@ -1 +1,115 @@
+//! Recording of the cluster events emitted by each node, so tests can wait for, and assert on,
+//! membership and leadership changes rather than sleeping.
 
+use crate::time::WaitTimeout;
+use coerce::remote::system::NodeId;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+pub mod filter;
+pub mod recorder;
+
+pub use filter::EventFilter;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TestEvent {
+    NodeAdded(NodeId),
+    NodeRemoved(NodeId),
+    LeaderChanged(NodeId),
+    DatacenterLeaderChanged(NodeId),
+    NodeDowned(NodeId),
+    NodeLeaving(NodeId),
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordedEvent {
+    /// The node that emitted the event.
+    pub observed_by: NodeId,
+    pub event: TestEvent,
+    pub recorded_at: Instant,
+}
+
+/// Events recorded across every node in a cluster, in the order they were emitted.
+#[derive(Clone, Default)]
+pub struct EventLog {
+    events: Arc<Mutex<Vec<RecordedEvent>>>,
+    notify: Arc<Notify>,
+}
+
+impl TestEvent {
+    /// The node the event is about.
+    pub fn node_id(&self) -> NodeId {
+        match self {
+            TestEvent::NodeAdded(node_id)
+            | TestEvent::NodeRemoved(node_id)
+            | TestEvent::LeaderChanged(node_id)
+            | TestEvent::DatacenterLeaderChanged(node_id)
+            | TestEvent::NodeDowned(node_id)
+            | TestEvent::NodeLeaving(node_id) => *node_id,
+        }
+    }
+}
+
+impl EventLog {
+    pub fn new() -> EventLog {
+        EventLog::default()
+    }
+
+    pub fn record(&self, observed_by: NodeId, event: TestEvent) {
+        self.events.lock().push(RecordedEvent {
+            observed_by,
+            event,
+            recorded_at: Instant::now(),
+        });
+
+        self.notify.notify_waiters();
+    }
+
+    pub fn events(&self) -> Vec<RecordedEvent> {
+        self.events.lock().clone()
+    }
+
+    pub fn matching(&self, filter: &EventFilter) -> Vec<RecordedEvent> {
+        self.events
+            .lock()
+            .iter()
+            .filter(|e| filter.matches(e))
+            .cloned()
+            .collect()
+    }
+
+    /// Removes all recorded events, allowing a test to only wait for events emitted after
+    /// this point.
+    pub fn clear(&self) {
+        self.events.lock().clear();
+    }
+
+    /// Waits for an event matching `filter` to be recorded, returning immediately if one already
+    /// has been.
+    pub async fn wait_for(
+        &self,
+        filter: &EventFilter,
+        timeout: Duration,
+    ) -> Result<RecordedEvent, WaitTimeout> {
+        let wait = async {
+            loop {
+                let notified = self.notify.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                if let Some(event) = self.matching(filter).into_iter().next() {
+                    return event;
+                }
+
+                notified.await;
+            }
+        };
+
+        tokio::time::timeout(timeout, wait)
+            .await
+            .map_err(|_| WaitTimeout(timeout))
+    }
+}
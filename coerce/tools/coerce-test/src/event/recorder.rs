@@ -0,0 +1,62 @@
+use crate::event::{EventLog, TestEvent};
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::Actor;
+use coerce::remote::stream::pubsub::{PubSub, Receive, Subscription};
+use coerce::remote::stream::system::{ClusterEvent, SystemEvent, SystemTopic};
+use coerce::remote::system::NodeId;
+
+/// Records every [`SystemEvent`] published on a node into the cluster's [`EventLog`].
+pub struct EventRecorder {
+    node_id: NodeId,
+    log: EventLog,
+    subscription: Option<Subscription>,
+}
+
+impl EventRecorder {
+    pub fn new(node_id: NodeId, log: EventLog) -> EventRecorder {
+        EventRecorder {
+            node_id,
+            log,
+            subscription: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for EventRecorder {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.subscription = Some(
+            PubSub::subscribe::<Self, SystemTopic>(SystemTopic, ctx)
+                .await
+                .expect("system topic subscription"),
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<SystemTopic>> for EventRecorder {
+    async fn handle(&mut self, message: Receive<SystemTopic>, _ctx: &mut ActorContext) {
+        let event = match message.0.as_ref() {
+            SystemEvent::Cluster(ClusterEvent::NodeAdded(node)) => TestEvent::NodeAdded(node.id),
+            SystemEvent::Cluster(ClusterEvent::NodeRemoved(node)) => {
+                TestEvent::NodeRemoved(node.id)
+            }
+            SystemEvent::Cluster(ClusterEvent::LeaderChanged(node_id)) => {
+                TestEvent::LeaderChanged(*node_id)
+            }
+            SystemEvent::Cluster(ClusterEvent::DatacenterLeaderChanged(node_id)) => {
+                TestEvent::DatacenterLeaderChanged(*node_id)
+            }
+            SystemEvent::Cluster(ClusterEvent::NodeDowned(node_id)) => {
+                TestEvent::NodeDowned(*node_id)
+            }
+            SystemEvent::Cluster(ClusterEvent::NodeLeaving(node_id)) => {
+                TestEvent::NodeLeaving(*node_id)
+            }
+        };
+
+        trace!("[node={}] recorded {:?}", self.node_id, &event);
+        self.log.record(self.node_id, event);
+    }
+}
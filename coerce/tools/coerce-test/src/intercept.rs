@@ -0,0 +1,117 @@
+//! Interception of the messages sent to a [`LocalActorRef`].
+//!
+//! [`intercept`] creates a new reference to an existing actor, every message sent via the new
+//! reference is recorded before being forwarded to the actor, and messages of a given type can be
+//! blocked from reaching the actor entirely.
+
+use coerce::actor::message::{Message, MessageHandler};
+use coerce::actor::{Actor, LocalActorRef};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+pub struct MessageInterceptor {
+    intercepted: mpsc::UnboundedReceiver<&'static str>,
+    blocked: Arc<Mutex<HashSet<&'static str>>>,
+}
+
+/// Creates a reference to the same actor as `actor_ref`, with every message sent via the returned
+/// reference being recorded by the [`MessageInterceptor`].
+pub fn intercept<A: Actor>(actor_ref: &LocalActorRef<A>) -> (LocalActorRef<A>, MessageInterceptor) {
+    let (message_tx, mut messages) = mpsc::unbounded_channel::<MessageHandler<A>>();
+    let (intercepted_tx, intercepted) = mpsc::unbounded_channel();
+    let blocked = Arc::new(Mutex::new(HashSet::new()));
+
+    let intercepted_ref = LocalActorRef::new(
+        actor_ref.actor_id().clone(),
+        message_tx,
+        actor_ref.actor_path().clone(),
+    );
+
+    let actor_ref = actor_ref.clone();
+    let blocked_messages = blocked.clone();
+    tokio::spawn(async move {
+        while let Some(message) = messages.recv().await {
+            let message_type = message.name();
+            let _ = intercepted_tx.send(message_type);
+
+            if blocked_messages.lock().contains(message_type) {
+                trace!("blocked {} to actor {}", message_type, actor_ref.actor_id());
+                continue;
+            }
+
+            if actor_ref.enqueue(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    (
+        intercepted_ref,
+        MessageInterceptor {
+            intercepted,
+            blocked,
+        },
+    )
+}
+
+impl MessageInterceptor {
+    /// Stops messages of type `M` from reaching the actor, they're still recorded by
+    /// the interceptor.
+    pub fn block<M: Message>(&self) {
+        self.blocked.lock().insert(std::any::type_name::<M>());
+    }
+
+    pub fn unblock<M: Message>(&self) {
+        self.blocked.lock().remove(std::any::type_name::<M>());
+    }
+
+    /// Waits for the next intercepted message, panicking if it isn't an `M`, or if no message is
+    /// intercepted within `timeout`.
+    pub async fn expect_msg<M: Message>(&mut self, timeout: Duration) {
+        let message_type = tokio::time::timeout(timeout, self.intercepted.recv())
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| {
+                panic!(
+                    "timeout ({}ms) while waiting for {}",
+                    timeout.as_millis(),
+                    std::any::type_name::<M>()
+                )
+            });
+
+        assert_eq!(
+            message_type,
+            std::any::type_name::<M>(),
+            "expected {}, intercepted {}",
+            std::any::type_name::<M>(),
+            message_type
+        );
+    }
+
+    /// Panics if any message is intercepted within `duration`.
+    pub async fn expect_no_msg(&mut self, duration: Duration) {
+        if let Ok(Some(message_type)) =
+            tokio::time::timeout(duration, self.intercepted.recv()).await
+        {
+            panic!(
+                "expected no message within {}ms, intercepted {}",
+                duration.as_millis(),
+                message_type
+            );
+        }
+    }
+
+    /// The types of messages intercepted since the last expectation, without waiting.
+    pub fn drain(&mut self) -> Vec<&'static str> {
+        let mut message_types = vec![];
+        while let Ok(message_type) = self.intercepted.try_recv() {
+            message_types.push(message_type);
+        }
+
+        message_types
+    }
+}
@@ -1,11 +1,31 @@
-use coerce::remote::system::{NodeId, RemoteActorSystem};
-use std::collections::HashMap;
+//! In-process cluster test harness for Coerce.
+//!
+//! [`TestCluster`] spins up any number of [`RemoteActorSystem`]s in a single process, connected
+//! via a [`SimulatedNetwork`] that can partition and heal links between nodes, drop or delay
+//! messages, and kill nodes outright. Cluster events emitted by every node are recorded, so tests
+//! can wait for membership and leadership changes, and with a paused tokio clock (see [`time`]),
+//! heartbeats and timeouts elapse without the test actually sleeping.
+//!
+//! For local actors, [`TestProbe`] records the messages an actor receives, and [`intercept`]
+//! records the messages sent to an existing actor, allowing tests to assert on messages and
+//! actor lifecycle events without sleeping.
+//!
+//! [`RemoteActorSystem`]: coerce::remote::system::RemoteActorSystem
+//! [`intercept`]: intercept::intercept
 
-pub struct TestRemoteActorSystem {
-    system: RemoteActorSystem,
-    node_id: NodeId,
-}
+#[macro_use]
+extern crate async_trait;
 
-pub struct TestCluster {
-    systems: HashMap<NodeId, TestRemoteActorSystem>,
-}
+#[macro_use]
+extern crate tracing;
+
+pub mod cluster;
+pub mod event;
+pub mod intercept;
+pub mod network;
+pub mod probe;
+pub mod time;
+
+pub use cluster::{TestCluster, TestClusterBuilder, TestRemoteActorSystem};
+pub use network::{SimulatedNetwork, SimulatedTransport};
+pub use probe::{ProbeMessage, TestProbe};
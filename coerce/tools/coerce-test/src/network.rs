@@ -0,0 +1,401 @@
+//! A simulated network, connecting the nodes of a [`TestCluster`] within the same process.
+//!
+//! Every connection is relayed frame by frame, which allows links between nodes to be
+//! partitioned, or for messages travelling across a link to be dropped or delayed, without the
+//! nodes themselves being aware of it.
+//!
+//! [`TestCluster`]: crate::cluster::TestCluster
+
+use coerce::remote::net::transport::{Connection, Transport, TransportListener};
+use futures::{SinkExt, StreamExt};
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
+use tokio_util::sync::CancellationToken;
+
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Clone, Default)]
+pub struct SimulatedNetwork {
+    state: Arc<NetworkState>,
+}
+
+#[derive(Default)]
+struct NetworkState {
+    listeners: Mutex<HashMap<String, mpsc::UnboundedSender<Connection>>>,
+    links: Mutex<HashMap<(String, String), Link>>,
+    isolated: Mutex<HashSet<String>>,
+    connections: Mutex<HashMap<u64, OpenConnection>>,
+    connection_counter: AtomicU64,
+}
+
+/// Faults applied to messages travelling from one address to another.
+#[derive(Clone, Copy, Debug, Default)]
+struct Link {
+    partitioned: bool,
+    drop_messages: bool,
+    delay: Option<Duration>,
+    dropped_messages: usize,
+}
+
+struct OpenConnection {
+    from: String,
+    to: String,
+    cancellation_token: CancellationToken,
+}
+
+enum Fault {
+    None,
+    Drop,
+    Delay(Duration),
+}
+
+impl SimulatedNetwork {
+    pub fn new() -> SimulatedNetwork {
+        SimulatedNetwork::default()
+    }
+
+    /// Creates a transport for the node bound to `addr`, connections opened via the transport
+    /// are subject to the faults applied to links from `addr`.
+    pub fn transport(&self, addr: impl ToString) -> SimulatedTransport {
+        SimulatedTransport {
+            network: self.clone(),
+            addr: addr.to_string(),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+        }
+    }
+
+    /// Partitions `a` from `b`, closing any open connections between them and refusing new ones
+    /// until the link is healed.
+    pub fn partition(&self, a: &str, b: &str) {
+        {
+            let mut links = self.state.links.lock();
+            links.entry(link_key(a, b)).or_default().partitioned = true;
+            links.entry(link_key(b, a)).or_default().partitioned = true;
+        }
+
+        self.sever(|c| (c.from == a && c.to == b) || (c.from == b && c.to == a));
+    }
+
+    /// Partitions `addr` from every other address, closing all of its open connections.
+    pub fn isolate(&self, addr: &str) {
+        self.state.isolated.lock().insert(addr.to_string());
+        self.sever(|c| c.from == addr || c.to == addr);
+    }
+
+    /// Drops every message sent from `from` to `to`, without closing the connection.
+    pub fn drop_messages(&self, from: &str, to: &str) {
+        self.state
+            .links
+            .lock()
+            .entry(link_key(from, to))
+            .or_default()
+            .drop_messages = true;
+    }
+
+    /// Delays every message sent from `from` to `to` by `delay`, messages are still delivered
+    /// in the order they were sent.
+    pub fn delay_messages(&self, from: &str, to: &str, delay: Duration) {
+        self.state
+            .links
+            .lock()
+            .entry(link_key(from, to))
+            .or_default()
+            .delay = Some(delay);
+    }
+
+    /// Removes all faults between `a` and `b`, in both directions.
+    pub fn heal(&self, a: &str, b: &str) {
+        let mut links = self.state.links.lock();
+        for key in [link_key(a, b), link_key(b, a)] {
+            if let Some(link) = links.get_mut(&key) {
+                link.heal();
+            }
+        }
+    }
+
+    /// Removes all faults, including isolated addresses.
+    pub fn heal_all(&self) {
+        self.state.isolated.lock().clear();
+        self.state.links.lock().values_mut().for_each(Link::heal);
+    }
+
+    /// Whether a connection can currently be opened from `from` to `to`.
+    pub fn is_reachable(&self, from: &str, to: &str) -> bool {
+        {
+            let isolated = self.state.isolated.lock();
+            if isolated.contains(from) || isolated.contains(to) {
+                return false;
+            }
+        }
+
+        let links = self.state.links.lock();
+        let partitioned = |key| links.get(&key).is_some_and(|l: &Link| l.partitioned);
+        !partitioned(link_key(from, to)) && !partitioned(link_key(to, from))
+    }
+
+    /// The number of messages sent from `from` to `to` that have been dropped.
+    pub fn dropped_messages(&self, from: &str, to: &str) -> usize {
+        self.state
+            .links
+            .lock()
+            .get(&link_key(from, to))
+            .map_or(0, |l| l.dropped_messages)
+    }
+
+    /// The number of connections currently open between `a` and `b`, in either direction.
+    pub fn open_connections(&self, a: &str, b: &str) -> usize {
+        self.state
+            .connections
+            .lock()
+            .values()
+            .filter(|c| (c.from == a && c.to == b) || (c.from == b && c.to == a))
+            .count()
+    }
+
+    fn sever(&self, predicate: impl Fn(&OpenConnection) -> bool) {
+        for connection in self.state.connections.lock().values() {
+            if predicate(connection) {
+                connection.cancellation_token.cancel();
+            }
+        }
+    }
+
+    fn fault(&self, from: &str, to: &str) -> Fault {
+        let mut links = self.state.links.lock();
+        let link = match links.get_mut(&link_key(from, to)) {
+            Some(link) => link,
+            None => return Fault::None,
+        };
+
+        if link.partitioned || link.drop_messages {
+            link.dropped_messages += 1;
+            Fault::Drop
+        } else if let Some(delay) = link.delay {
+            Fault::Delay(delay)
+        } else {
+            Fault::None
+        }
+    }
+
+    fn connect(&self, from: &str, to: &str, buffer_size: usize) -> io::Result<Connection> {
+        if !self.is_reachable(from, to) {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("{} is partitioned from {}", from, to),
+            ));
+        }
+
+        let listener = self.state.listeners.lock().get(to).cloned();
+        let listener = listener.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("no simulated listener bound to {}", to),
+            )
+        })?;
+
+        let connection_id = self
+            .state
+            .connection_counter
+            .fetch_add(1, Ordering::Relaxed);
+        let cancellation_token = CancellationToken::new();
+        self.state.connections.lock().insert(
+            connection_id,
+            OpenConnection {
+                from: from.to_string(),
+                to: to.to_string(),
+                cancellation_token: cancellation_token.clone(),
+            },
+        );
+
+        let (client, client_relay) = tokio::io::duplex(buffer_size);
+        let (server_relay, server) = tokio::io::duplex(buffer_size);
+        let (client_relay_read, client_relay_write) = tokio::io::split(client_relay);
+        let (server_relay_read, server_relay_write) = tokio::io::split(server_relay);
+
+        tokio::spawn(self.clone().relay(
+            connection_id,
+            from.to_string(),
+            to.to_string(),
+            client_relay_read,
+            server_relay_write,
+            cancellation_token.clone(),
+        ));
+
+        tokio::spawn(self.clone().relay(
+            connection_id,
+            to.to_string(),
+            from.to_string(),
+            server_relay_read,
+            client_relay_write,
+            cancellation_token.clone(),
+        ));
+
+        if listener.send(Connection::new(server, from)).is_err() {
+            cancellation_token.cancel();
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("simulated listener {} closed", to),
+            ));
+        }
+
+        Ok(Connection::new(client, to))
+    }
+
+    /// Forwards frames read from `from` to `to`, applying any faults on the link, until either
+    /// side closes the connection or the connection is severed.
+    async fn relay<R, W>(
+        self,
+        connection_id: u64,
+        from: String,
+        to: String,
+        read: R,
+        write: W,
+        cancellation_token: CancellationToken,
+    ) where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let codec = LengthDelimitedCodec::builder()
+            .max_frame_length(usize::MAX)
+            .new_codec();
+
+        let mut reader = FramedRead::new(read, codec.clone());
+        let mut writer = FramedWrite::new(write, codec);
+        let (frame_tx, mut frame_rx) = mpsc::unbounded_channel();
+
+        let read_frames = async {
+            while let Some(Ok(frame)) = reader.next().await {
+                let deliver_at = match self.fault(&from, &to) {
+                    Fault::Drop => {
+                        trace!("dropped frame from {} to {}", &from, &to);
+                        continue;
+                    }
+                    Fault::Delay(delay) => Instant::now() + delay,
+                    Fault::None => Instant::now(),
+                };
+
+                if frame_tx.send((deliver_at, frame.freeze())).is_err() {
+                    break;
+                }
+            }
+
+            drop(frame_tx);
+        };
+
+        let write_frames = async {
+            while let Some((deliver_at, frame)) = frame_rx.recv().await {
+                tokio::time::sleep_until(deliver_at).await;
+                if writer.send(frame).await.is_err() {
+                    break;
+                }
+            }
+        };
+
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {},
+            _ = async { tokio::join!(read_frames, write_frames) } => {},
+        }
+
+        cancellation_token.cancel();
+        self.state.connections.lock().remove(&connection_id);
+    }
+}
+
+impl Link {
+    fn heal(&mut self) {
+        self.partitioned = false;
+        self.drop_messages = false;
+        self.delay = None;
+    }
+}
+
+fn link_key(from: &str, to: &str) -> (String, String) {
+    (from.to_string(), to.to_string())
+}
+
+/// A [`Transport`] bound to a single address on a [`SimulatedNetwork`].
+#[derive(Clone)]
+pub struct SimulatedTransport {
+    network: SimulatedNetwork,
+    addr: String,
+    buffer_size: usize,
+}
+
+impl SimulatedTransport {
+    /// Maximum number of bytes buffered in each direction of a connection before writes wait
+    /// for the peer to read.
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    pub fn network(&self) -> &SimulatedNetwork {
+        &self.network
+    }
+}
+
+#[async_trait]
+impl Transport for SimulatedTransport {
+    async fn connect(&self, addr: &str) -> io::Result<Connection> {
+        self.network.connect(&self.addr, addr, self.buffer_size)
+    }
+
+    async fn listen(&self, addr: &str) -> io::Result<Box<dyn TransportListener>> {
+        let mut listeners = self.network.state.listeners.lock();
+        if listeners.contains_key(addr) {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("simulated address {} already in use", addr),
+            ));
+        }
+
+        let (connection_tx, connections) = mpsc::unbounded_channel();
+        listeners.insert(addr.to_string(), connection_tx);
+
+        Ok(Box::new(SimulatedTransportListener {
+            addr: addr.to_string(),
+            connections,
+            network: self.network.clone(),
+        }))
+    }
+}
+
+pub struct SimulatedTransportListener {
+    addr: String,
+    connections: mpsc::UnboundedReceiver<Connection>,
+    network: SimulatedNetwork,
+}
+
+#[async_trait]
+impl TransportListener for SimulatedTransportListener {
+    async fn accept(&mut self) -> io::Result<Connection> {
+        self.connections.recv().await.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotConnected,
+                format!("simulated listener {} closed", &self.addr),
+            )
+        })
+    }
+
+    fn local_addr(&self) -> String {
+        self.addr.clone()
+    }
+}
+
+impl Drop for SimulatedTransportListener {
+    fn drop(&mut self) {
+        self.network.state.listeners.lock().remove(&self.addr);
+    }
+}
@@ -0,0 +1,301 @@
+//! Test probes, actors that record every message they receive so tests can assert on them,
+//! rather than building ad-hoc actors and sleeping until an effect is observed.
+//!
+//! # Example
+//! ```rust,no_run
+//! use coerce::actor::message::Message;
+//! use coerce::actor::system::ActorSystem;
+//! use coerce_test::probe::{ProbeMessage, TestProbe};
+//! use std::time::Duration;
+//!
+//! struct Ping(u32);
+//!
+//! impl Message for Ping {
+//!     type Result = ();
+//! }
+//!
+//! impl ProbeMessage for Ping {}
+//!
+//! # async fn example() {
+//! let system = ActorSystem::new();
+//! let mut probe = TestProbe::new(&system).await;
+//!
+//! probe.actor_ref().notify(Ping(1)).unwrap();
+//!
+//! let ping: Ping = probe.expect_msg(Duration::from_secs(1)).await;
+//! assert_eq!(ping.0, 1);
+//!
+//! probe.expect_no_msg(Duration::from_millis(100)).await;
+//! # }
+//! ```
+
+use crate::time;
+use coerce::actor::context::{ActorContext, ActorStatus};
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::system::ActorSystem;
+use coerce::actor::watch::ActorTerminated;
+use coerce::actor::{Actor, ActorId, ActorRef, IntoActor, LocalActorRef};
+use parking_lot::RwLock;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Marks a message as one that can be sent to a [`Probe`].
+///
+/// Messages with a result other than `()` must have a reply registered via
+/// [`TestProbe::reply_with`] before they're sent to the probe.
+pub trait ProbeMessage: Message {}
+
+/// A message recorded by a [`Probe`].
+pub struct Received {
+    message: Box<dyn Any + Send>,
+    type_name: &'static str,
+}
+
+/// The actor behind a [`TestProbe`].
+pub struct Probe {
+    state: Arc<ProbeState>,
+}
+
+struct ProbeState {
+    received: mpsc::UnboundedSender<Received>,
+    replies: RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>,
+}
+
+type ReplyFn<M> = Box<dyn Fn(&M) -> <M as Message>::Result + Send + Sync>;
+
+pub struct TestProbe {
+    actor_ref: LocalActorRef<Probe>,
+    state: Arc<ProbeState>,
+    received: mpsc::UnboundedReceiver<Received>,
+}
+
+impl TestProbe {
+    pub async fn new(system: &ActorSystem) -> TestProbe {
+        let (received_tx, received) = mpsc::unbounded_channel();
+        let state = Arc::new(ProbeState {
+            received: received_tx,
+            replies: RwLock::new(HashMap::new()),
+        });
+
+        let actor_ref = Probe {
+            state: state.clone(),
+        }
+        .into_actor(Option::<ActorId>::None, system)
+        .await
+        .expect("probe actor");
+
+        TestProbe {
+            actor_ref,
+            state,
+            received,
+        }
+    }
+
+    pub fn actor_ref(&self) -> LocalActorRef<Probe> {
+        self.actor_ref.clone()
+    }
+
+    /// Replies to every `M` received by the probe with the result of `reply`.
+    pub fn reply_with<M: ProbeMessage, F>(&self, reply: F)
+    where
+        F: 'static + Fn(&M) -> M::Result + Send + Sync,
+    {
+        let reply: ReplyFn<M> = Box::new(reply);
+        self.state
+            .replies
+            .write()
+            .insert(TypeId::of::<M>(), Box::new(reply));
+    }
+
+    /// Watches `actor_ref`, once the actor stops, an [`ActorTerminated`] message is received
+    /// by the probe.
+    pub async fn watch<A: Actor>(&self, actor_ref: &ActorRef<A>) {
+        self.actor_ref
+            .send(WatchActor(actor_ref.clone()))
+            .await
+            .expect("probe watch");
+    }
+
+    /// Waits for the next message, panicking if it isn't an `M`, or if no message is received
+    /// within `timeout`.
+    pub async fn expect_msg<M: 'static>(&mut self, timeout: Duration) -> M {
+        let received = self.receive(timeout).await.unwrap_or_else(|| {
+            panic!(
+                "timeout ({}ms) while waiting for {}",
+                timeout.as_millis(),
+                std::any::type_name::<M>()
+            )
+        });
+
+        let type_name = received.type_name;
+        received.downcast().unwrap_or_else(|| {
+            panic!(
+                "expected {}, received {}",
+                std::any::type_name::<M>(),
+                type_name
+            )
+        })
+    }
+
+    /// Panics if any message is received within `duration`.
+    pub async fn expect_no_msg(&mut self, duration: Duration) {
+        if let Some(received) = self.receive(duration).await {
+            panic!(
+                "expected no message within {}ms, received {}",
+                duration.as_millis(),
+                received.type_name
+            );
+        }
+    }
+
+    /// Skips received messages until an `M` matching `predicate` is received, panicking if no
+    /// matching message is received within `timeout`.
+    pub async fn fish_for<M: 'static, F>(&mut self, timeout: Duration, mut predicate: F) -> M
+    where
+        F: FnMut(&M) -> bool,
+    {
+        let fish = async {
+            loop {
+                let received = self.received.recv().await.expect("probe stopped");
+                if let Some(message) = received.downcast::<M>() {
+                    if predicate(&message) {
+                        return message;
+                    }
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, fish)
+            .await
+            .unwrap_or_else(|_| {
+                panic!(
+                    "timeout ({}ms) while fishing for {}",
+                    timeout.as_millis(),
+                    std::any::type_name::<M>()
+                )
+            })
+    }
+
+    /// Waits for the [`ActorTerminated`] message of an actor watched via [`TestProbe::watch`].
+    pub async fn expect_terminated(
+        &mut self,
+        actor_id: &ActorId,
+        timeout: Duration,
+    ) -> ActorTerminated {
+        let terminated: ActorTerminated = self.expect_msg(timeout).await;
+        assert_eq!(
+            &terminated.actor_id, actor_id,
+            "expected actor {} to terminate, but {} terminated",
+            actor_id, &terminated.actor_id
+        );
+
+        terminated
+    }
+
+    /// Waits for `actor_ref` to finish starting, panicking if it hasn't started within `timeout`.
+    pub async fn expect_started<A: Actor>(&self, actor_ref: &LocalActorRef<A>, timeout: Duration) {
+        time::wait_until(timeout, || async {
+            matches!(actor_ref.status().await, Ok(ActorStatus::Started))
+        })
+        .await
+        .unwrap_or_else(|_| {
+            panic!(
+                "actor {} did not start within {}ms",
+                actor_ref.actor_id(),
+                timeout.as_millis()
+            )
+        });
+    }
+
+    /// Waits for `actor_ref` to stop, panicking if it's still running after `timeout`.
+    pub async fn expect_stopped<A: Actor>(&self, actor_ref: &LocalActorRef<A>, timeout: Duration) {
+        time::wait_until(timeout, || async { !actor_ref.is_valid() })
+            .await
+            .unwrap_or_else(|_| {
+                panic!(
+                    "actor {} did not stop within {}ms",
+                    actor_ref.actor_id(),
+                    timeout.as_millis()
+                )
+            });
+    }
+
+    async fn receive(&mut self, timeout: Duration) -> Option<Received> {
+        tokio::time::timeout(timeout, self.received.recv())
+            .await
+            .ok()
+            .flatten()
+    }
+}
+
+impl Received {
+    fn new<M: 'static + Send>(message: M) -> Received {
+        Received {
+            message: Box::new(message),
+            type_name: std::any::type_name::<M>(),
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub fn downcast<M: 'static>(self) -> Option<M> {
+        self.message.downcast().ok().map(|m| *m)
+    }
+}
+
+impl ProbeState {
+    fn reply<M: ProbeMessage>(&self, message: &M) -> M::Result {
+        if let Some(reply) = self.replies.read().get(&TypeId::of::<M>()) {
+            let reply = reply.downcast_ref::<ReplyFn<M>>().unwrap();
+            return reply(message);
+        }
+
+        // messages with a `()` result don't need a reply to be registered
+        let unit: Box<dyn Any> = Box::new(());
+        match unit.downcast::<M::Result>() {
+            Ok(result) => *result,
+            Err(_) => panic!(
+                "no reply registered for {}, see `TestProbe::reply_with`",
+                M::type_name()
+            ),
+        }
+    }
+
+    fn record<M: 'static + Send>(&self, message: M) {
+        let _ = self.received.send(Received::new(message));
+    }
+}
+
+#[async_trait]
+impl Actor for Probe {
+    async fn on_actor_terminated(&mut self, terminated: &ActorTerminated, _ctx: &mut ActorContext) {
+        self.state.record(terminated.clone());
+    }
+}
+
+#[async_trait]
+impl<M: ProbeMessage> Handler<M> for Probe {
+    async fn handle(&mut self, message: M, _ctx: &mut ActorContext) -> M::Result {
+        let result = self.state.reply(&message);
+        self.state.record(message);
+        result
+    }
+}
+
+struct WatchActor<A: Actor>(ActorRef<A>);
+
+impl<A: Actor> Message for WatchActor<A> {
+    type Result = ();
+}
+
+#[async_trait]
+impl<A: Actor> Handler<WatchActor<A>> for Probe {
+    async fn handle(&mut self, message: WatchActor<A>, ctx: &mut ActorContext) {
+        ctx.watch(&message.0);
+    }
+}
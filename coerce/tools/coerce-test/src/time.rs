@@ -0,0 +1,47 @@
+//! Helpers for controlling time within tests.
+//!
+//! Tests using the harness are expected to run with a paused clock, via
+//! `#[tokio::test(start_paused = true)]`. While paused, time only moves forward when it is
+//! explicitly advanced, or when every task in the runtime is waiting on a timer, so heartbeats,
+//! timeouts and message delays are simulated without the test actually sleeping.
+
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Moves the paused clock forward by `duration`, firing any timers that elapse along the way.
+///
+/// Panics if the clock isn't paused.
+pub async fn advance(duration: Duration) {
+    tokio::time::advance(duration).await
+}
+
+/// Polls `condition` until it returns true, or until `timeout` has elapsed.
+pub async fn wait_until<F, Fut>(timeout: Duration, mut condition: F) -> Result<(), WaitTimeout>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let poll = async {
+        while !condition().await {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    };
+
+    tokio::time::timeout(timeout, poll)
+        .await
+        .map_err(|_| WaitTimeout(timeout))
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct WaitTimeout(pub Duration);
+
+impl Display for WaitTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "condition not met within {}ms", self.0.as_millis())
+    }
+}
+
+impl std::error::Error for WaitTimeout {}
@@ -0,0 +1,142 @@
+use crate::util::{with_test_actor, GetStatusRequest, GetStatusResponse, TestActor};
+use coerce::actor::scheduler::ActorType::Tracked;
+use coerce::actor::{ActorRef, ToActorId};
+use coerce::remote::RemoteActorRef;
+use coerce_test::event::{EventFilter, TestEvent};
+use coerce_test::TestCluster;
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_cluster_formation() {
+    let cluster = TestCluster::builder().nodes(3).build().await;
+
+    assert_eq!(cluster.running_nodes(), vec![1, 2, 3]);
+    assert_eq!(cluster.leader(), Some(1));
+
+    for node_id in [2, 3] {
+        let node_added = EventFilter::node_added().node(node_id).observed_by(1);
+        assert!(!cluster.events().matching(&node_added).is_empty());
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_leader_changes_when_leader_is_killed() {
+    let mut cluster = TestCluster::builder().nodes(3).build().await;
+    cluster.events().clear();
+
+    cluster.kill(1).await;
+
+    for node_id in [2, 3] {
+        cluster
+            .wait_for(
+                &EventFilter::leader_changed().node(2).observed_by(node_id),
+                TIMEOUT,
+            )
+            .await
+            .expect("leader change");
+    }
+
+    cluster.wait_for_leader(2, TIMEOUT).await.unwrap();
+    assert_eq!(cluster.running_nodes(), vec![2, 3]);
+
+    let removed = cluster.events().matching(&EventFilter::node_removed());
+    assert!(removed.iter().all(|e| e.event == TestEvent::NodeRemoved(1)));
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_partitioned_node_is_removed() {
+    let cluster = TestCluster::builder().nodes(3).build().await;
+    cluster.events().clear();
+
+    cluster.split(&[3]);
+
+    for node_id in [1, 2] {
+        cluster
+            .wait_for(
+                &EventFilter::node_removed().node(3).observed_by(node_id),
+                TIMEOUT,
+            )
+            .await
+            .expect("partitioned node removed");
+    }
+
+    assert_eq!(cluster.network().open_connections("node-1", "node-3"), 0);
+    assert!(!cluster.network().is_reachable("node-1", "node-3"));
+
+    cluster.heal_all();
+    assert!(cluster.network().is_reachable("node-1", "node-3"));
+    assert_eq!(cluster.leader(), Some(1));
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_dropped_messages() {
+    let cluster = TestCluster::builder()
+        .nodes(2)
+        .configure(with_test_actor)
+        .build()
+        .await;
+
+    let actor_ref = remote_test_actor(&cluster).await;
+    cluster.drop_messages(1, 2);
+
+    let res = tokio::time::timeout(Duration::from_secs(1), actor_ref.send(GetStatusRequest)).await;
+    assert!(res.is_err());
+    assert!(cluster.network().dropped_messages("node-1", "node-2") > 0);
+
+    cluster.heal(1, 2);
+
+    let res = actor_ref.send(GetStatusRequest).await;
+    assert_eq!(res.unwrap(), GetStatusResponse::None);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_delayed_messages() {
+    const DELAY: Duration = Duration::from_secs(5);
+
+    let cluster = TestCluster::builder()
+        .nodes(2)
+        .configure(with_test_actor)
+        .build()
+        .await;
+
+    let actor_ref = remote_test_actor(&cluster).await;
+    cluster.delay_messages(2, 1, DELAY);
+
+    let start = Instant::now();
+    let res = actor_ref.send(GetStatusRequest).await;
+    assert_eq!(res.unwrap(), GetStatusResponse::None);
+    assert!(start.elapsed() >= DELAY);
+
+    cluster.heal(1, 2);
+
+    let start = Instant::now();
+    let _ = actor_ref.send(GetStatusRequest).await.unwrap();
+    assert!(start.elapsed() < DELAY);
+}
+
+async fn remote_test_actor(cluster: &TestCluster) -> ActorRef<TestActor> {
+    let actor_id = "test-actor".to_actor_id();
+    let _ = cluster
+        .system(2)
+        .actor_system()
+        .new_actor(actor_id.clone(), TestActor::default(), Tracked)
+        .await
+        .unwrap();
+
+    ActorRef::from(RemoteActorRef::<TestActor>::new(
+        actor_id,
+        2,
+        cluster.system(1).clone(),
+    ))
+}
@@ -0,0 +1,219 @@
+use crate::util::{
+    with_test_actor, GetStatusRequest, SetStatusRequest, SetStatusResponse, TestActorFactory,
+    TestActorRecipe, TestActorStatus,
+};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::shutdown::{CoordinatedShutdownConfig, ShutdownPhase};
+use coerce::remote::system::NodeId;
+use coerce::sharding::host::stats::GetStats;
+use coerce::sharding::Sharding;
+use coerce_test::event::EventFilter;
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_leave_runs_phases_in_order() {
+    let mut cluster = TestCluster::builder().nodes(3).build().await;
+
+    let phases = Arc::new(Mutex::new(vec![]));
+    for phase in ShutdownPhase::ALL {
+        let phases = phases.clone();
+        cluster.system(3).coordinated_shutdown().add_task(
+            phase,
+            format!("record-{:?}", phase),
+            move |system| async move {
+                phases.lock().unwrap().push((phase, system.is_leaving()));
+            },
+        );
+    }
+
+    cluster.events().clear();
+    cluster.leave(3).await;
+
+    // the node is only marked as leaving once the `AnnounceLeaving` phase's tasks have run
+    assert_eq!(
+        *phases.lock().unwrap(),
+        vec![
+            (ShutdownPhase::StopAcceptingEntities, false),
+            (ShutdownPhase::HandOffShards, false),
+            (ShutdownPhase::PassivateEntities, false),
+            (ShutdownPhase::AnnounceLeaving, false),
+            (ShutdownPhase::DrainConnections, true),
+            (ShutdownPhase::StopActorSystem, true),
+        ]
+    );
+
+    assert!(cluster.system(3).actor_system().is_terminated());
+
+    for node_id in [1, 2] {
+        cluster
+            .wait_for(
+                &EventFilter::node_leaving().node(3).observed_by(node_id),
+                TIMEOUT,
+            )
+            .await
+            .expect("node 3 leaving");
+
+        wait_for_status(&cluster, node_id, 3, NodeStatus::Terminated).await;
+    }
+
+    cluster
+        .wait_for_leader(1, TIMEOUT)
+        .await
+        .expect("leader unchanged");
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_leave_phase_timeout() {
+    let mut cluster = TestCluster::builder()
+        .nodes(2)
+        .configure(|builder| {
+            builder.configure(|config| {
+                config.coordinated_shutdown(
+                    CoordinatedShutdownConfig::default()
+                        .phase_timeout(ShutdownPhase::HandOffShards, Duration::from_secs(2)),
+                )
+            })
+        })
+        .build()
+        .await;
+
+    let completed = Arc::new(Mutex::new(vec![]));
+    let shutdown = cluster.system(2).coordinated_shutdown();
+
+    let hand_off_completed = completed.clone();
+    shutdown.add_task(
+        ShutdownPhase::HandOffShards,
+        "never-completes",
+        |_| async move {
+            futures::future::pending::<()>().await;
+            hand_off_completed.lock().unwrap().push("never-completes");
+        },
+    );
+
+    let passivate_completed = completed.clone();
+    shutdown.add_task(
+        ShutdownPhase::PassivateEntities,
+        "completes",
+        |_| async move {
+            passivate_completed.lock().unwrap().push("completes");
+        },
+    );
+
+    let start = Instant::now();
+    cluster.leave(2).await;
+
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_secs(2));
+    assert!(elapsed < CoordinatedShutdownConfig::default().default_phase_timeout);
+
+    assert_eq!(*completed.lock().unwrap(), vec!["completes"]);
+    assert!(cluster.system(2).actor_system().is_terminated());
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_leave_hands_off_shards() {
+    let mut cluster = TestCluster::builder()
+        .nodes(2)
+        .with_persistence(Persistence::from(InMemoryStorageProvider::new()))
+        .configure(with_test_actor)
+        .build()
+        .await;
+
+    let sharding_1 = Sharding::<TestActorFactory>::builder(cluster.system(1).clone())
+        .build()
+        .await;
+
+    let sharding_2 = Sharding::<TestActorFactory>::builder(cluster.system(2).clone())
+        .build()
+        .await;
+
+    let res = sharding_1
+        .get("leon".to_string(), Some(TestActorRecipe))
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+
+    let shard_count = |sharding: &Sharding<TestActorFactory>| {
+        let host = sharding.shard_host().clone();
+        async move {
+            let stats = host.send(GetStats).await.unwrap().await.unwrap();
+            stats.hosted_shard_count
+        }
+    };
+
+    // the node hosting the entity's shard leaves the cluster
+    let (leaving, remaining, sharding) = if shard_count(&sharding_1).await > 0 {
+        (1, 2, sharding_2)
+    } else {
+        (2, 1, sharding_1)
+    };
+
+    cluster.events().clear();
+    cluster.leave(leaving).await;
+
+    cluster
+        .wait_for(
+            &EventFilter::node_leaving()
+                .node(leaving)
+                .observed_by(remaining),
+            TIMEOUT,
+        )
+        .await
+        .expect("node leaving");
+
+    cluster
+        .wait_for_leader(remaining, TIMEOUT)
+        .await
+        .expect("remaining node becomes leader");
+
+    // without a recipe, the entity is only reachable if its shard was handed off to the
+    // remaining node, which recovers the shard's entities from the shared persistence backplane
+    let res = tokio::time::timeout(
+        TIMEOUT,
+        sharding
+            .get("leon".to_string(), None)
+            .send(GetStatusRequest),
+    )
+    .await
+    .expect("shard handed off");
+
+    assert!(res.is_ok());
+    assert!(shard_count(&sharding).await > 0);
+}
+
+async fn wait_for_status(cluster: &TestCluster, node_id: NodeId, peer: NodeId, status: NodeStatus) {
+    wait_until(TIMEOUT, || async {
+        cluster
+            .system(node_id)
+            .get_nodes()
+            .await
+            .iter()
+            .any(|n| n.id == peer && n.status == status)
+    })
+    .await
+    .unwrap_or_else(|_| {
+        panic!(
+            "node_id={} did not see node_id={} as {:?}",
+            node_id, peer, status
+        )
+    });
+}
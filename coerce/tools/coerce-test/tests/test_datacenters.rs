@@ -0,0 +1,268 @@
+use crate::util::{
+    with_test_actor, SetStatusRequest, SetStatusResponse, TestActorFactory, TestActorRecipe,
+    TestActorStatus,
+};
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::{Actor, IntoActor};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::heartbeat::{CrossDatacenterHeartbeatConfig, HeartbeatConfig};
+use coerce::remote::net::StreamData;
+use coerce::remote::stream::pubsub::{PubSub, Receive, Subscription, Topic};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::NodeId;
+use coerce::sharding::host::stats::GetStats;
+use coerce::sharding::Sharding;
+use coerce_test::event::EventFilter;
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Nodes 1 and 2 are in `dc-a`, every other node is in `dc-b`.
+fn with_datacenters(
+    node_id: NodeId,
+    builder: RemoteActorSystemBuilder,
+) -> RemoteActorSystemBuilder {
+    builder.with_datacenter(datacenter(node_id))
+}
+
+fn datacenter(node_id: NodeId) -> &'static str {
+    if node_id <= 2 {
+        "dc-a"
+    } else {
+        "dc-b"
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_datacenter_exchanged_between_nodes() {
+    let cluster = TestCluster::builder()
+        .nodes(4)
+        .configure_node(with_datacenters)
+        .build()
+        .await;
+
+    for node_id in [1, 2, 3, 4] {
+        assert_eq!(cluster.system(node_id).datacenter(), datacenter(node_id));
+
+        let nodes = cluster.system(node_id).get_nodes().await;
+        for node in nodes {
+            assert_eq!(
+                node.datacenter.as_ref(),
+                datacenter(node.id),
+                "node_id={} datacenter as seen by node_id={}",
+                node.id,
+                node_id
+            );
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_leader_per_datacenter() {
+    let mut cluster = TestCluster::builder()
+        .nodes(4)
+        .configure_node(with_datacenters)
+        .build()
+        .await;
+
+    wait_until(TIMEOUT, || async {
+        [(1, 1), (2, 1), (3, 3), (4, 3)]
+            .into_iter()
+            .all(|(node_id, leader)| cluster.system(node_id).datacenter_leader() == Some(leader))
+    })
+    .await
+    .expect("each datacenter elects a leader");
+
+    assert_eq!(cluster.leader(), Some(1));
+
+    cluster.kill(3).await;
+
+    cluster
+        .wait_for(
+            &EventFilter::datacenter_leader_changed()
+                .node(4)
+                .observed_by(4),
+            TIMEOUT,
+        )
+        .await
+        .expect("node 4 becomes leader of dc-b");
+
+    assert_eq!(cluster.system(4).datacenter_leader(), Some(4));
+    assert_eq!(cluster.system(1).datacenter_leader(), Some(1));
+    assert_eq!(cluster.leader(), Some(1));
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_sharding_prefers_requesting_datacenter() {
+    let cluster = TestCluster::builder()
+        .nodes(4)
+        .with_persistence(Persistence::from(InMemoryStorageProvider::new()))
+        .configure(with_test_actor)
+        .configure_node(with_datacenters)
+        .build()
+        .await;
+
+    let mut shardings = vec![];
+    for node_id in [1, 2, 3, 4] {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(cluster.system(node_id).clone())
+                .build()
+                .await,
+        );
+    }
+
+    // the shard coordinator runs on node 1, within `dc-a`
+    for i in 0..10 {
+        let res = shardings[2]
+            .get(format!("entity-{}", i), Some(TestActorRecipe))
+            .send(SetStatusRequest {
+                status: TestActorStatus::Active,
+            })
+            .await;
+
+        assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+    }
+
+    let mut hosted_shards = vec![];
+    for sharding in &shardings {
+        let stats = sharding
+            .shard_host()
+            .send(GetStats)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        hosted_shards.push(stats.hosted_shard_count);
+    }
+
+    assert_eq!(hosted_shards[0] + hosted_shards[1], 0);
+    assert!(hosted_shards[2] + hosted_shards[3] > 0);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_publish_to_datacenter() {
+    let cluster = TestCluster::builder()
+        .nodes(4)
+        .configure_node(with_datacenters)
+        .build()
+        .await;
+
+    let mut received = vec![];
+    let mut subscribers = vec![];
+    for node_id in [1, 2, 3, 4] {
+        let count = Arc::new(AtomicUsize::new(0));
+        let subscriber = DatacenterSubscriber {
+            received: count.clone(),
+            subscription: None,
+        }
+        .into_anon_actor(None::<String>, cluster.system(node_id).actor_system())
+        .await
+        .unwrap();
+
+        received.push(count);
+        subscribers.push(subscriber);
+    }
+
+    PubSub::publish_to_datacenter(DatacenterTopic, DatacenterEvent, cluster.system(3)).await;
+
+    wait_until(TIMEOUT, || async {
+        received[2].load(Ordering::Relaxed) == 1 && received[3].load(Ordering::Relaxed) == 1
+    })
+    .await
+    .expect("nodes within dc-b receive published messages");
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(received[0].load(Ordering::Relaxed), 0);
+    assert_eq!(received[1].load(Ordering::Relaxed), 0);
+}
+
+#[test]
+pub fn test_cross_datacenter_heartbeat_config() {
+    let config = HeartbeatConfig {
+        cross_datacenter: Some(CrossDatacenterHeartbeatConfig {
+            ping_timeout: Duration::from_secs(20),
+            unhealthy_node_heartbeat_timeout: Duration::from_secs(4),
+            terminated_node_heartbeat_timeout: Duration::from_secs(90),
+        }),
+        ..Default::default()
+    };
+
+    let local = config.for_link("dc-a", "dc-a");
+    assert_eq!(local.ping_timeout, config.ping_timeout);
+    assert_eq!(
+        local.terminated_node_heartbeat_timeout,
+        config.terminated_node_heartbeat_timeout
+    );
+
+    let cross = config.for_link("dc-a", "dc-b");
+    assert_eq!(cross.ping_timeout, Duration::from_secs(20));
+    assert_eq!(
+        cross.unhealthy_node_heartbeat_timeout,
+        Duration::from_secs(4)
+    );
+    assert_eq!(
+        cross.terminated_node_heartbeat_timeout,
+        Duration::from_secs(90)
+    );
+    assert_eq!(cross.interval, config.interval);
+}
+
+pub struct DatacenterTopic;
+
+pub struct DatacenterEvent;
+
+impl Topic for DatacenterTopic {
+    type Message = DatacenterEvent;
+
+    fn topic_name() -> &'static str {
+        "datacenter-topic"
+    }
+}
+
+impl StreamData for DatacenterEvent {
+    fn read_from_bytes(_data: Vec<u8>) -> Option<Self> {
+        Some(DatacenterEvent)
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(vec![])
+    }
+}
+
+pub struct DatacenterSubscriber {
+    received: Arc<AtomicUsize>,
+    subscription: Option<Subscription>,
+}
+
+#[async_trait]
+impl Actor for DatacenterSubscriber {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.subscription = Some(
+            PubSub::subscribe::<Self, DatacenterTopic>(DatacenterTopic, ctx)
+                .await
+                .unwrap(),
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<DatacenterTopic>> for DatacenterSubscriber {
+    async fn handle(&mut self, _message: Receive<DatacenterTopic>, _ctx: &mut ActorContext) {
+        self.received.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,128 @@
+use coerce::actor::system::ActorSystem;
+use coerce::remote::cluster::builder::worker::ClusterWorkerBuilder;
+use coerce::remote::cluster::discovery::file::FileDiscovery;
+use coerce::remote::cluster::discovery::seeds::StaticDiscovery;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::net::server::RemoteServer;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce_test::time::wait_until;
+use coerce_test::SimulatedNetwork;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(300);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_static_discovery() {
+    let network = SimulatedNetwork::new();
+    let addrs: Vec<_> = [1, 2, 3].into_iter().map(node_addr).collect();
+
+    let mut nodes = vec![];
+    for node_id in [1, 2, 3] {
+        let provider = StaticDiscovery::new(addrs.clone());
+        nodes.push(start_node(&network, node_id, |worker| worker.with_discovery(provider)).await);
+    }
+
+    for (system, _) in &nodes {
+        wait_for_healthy(system, &[1, 2, 3]).await;
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_file_discovery_picks_up_changes() {
+    // the refresh interval is long enough that node 3 is only discovered via the file watch
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+
+    let network = SimulatedNetwork::new();
+    let file = DiscoveryFile::new("test_file_discovery_picks_up_changes");
+    file.write(&[1, 2]);
+
+    let provider = || FileDiscovery::new(&file.0).refresh_interval(REFRESH_INTERVAL);
+    let (system_1, _server_1) =
+        start_node(&network, 1, |worker| worker.with_discovery(provider())).await;
+    let (system_2, _server_2) =
+        start_node(&network, 2, |worker| worker.with_discovery(provider())).await;
+
+    wait_for_healthy(&system_1, &[1, 2]).await;
+    wait_for_healthy(&system_2, &[1, 2]).await;
+
+    let (system_3, _server_3) = start_node(&network, 3, |worker| worker).await;
+
+    let start = tokio::time::Instant::now();
+    file.write(&[1, 2, 3]);
+
+    for system in [&system_1, &system_2, &system_3] {
+        wait_for_healthy(system, &[1, 2, 3]).await;
+    }
+
+    assert!(start.elapsed() < REFRESH_INTERVAL);
+}
+
+struct DiscoveryFile(PathBuf);
+
+impl DiscoveryFile {
+    fn new(name: &str) -> DiscoveryFile {
+        let path = std::env::temp_dir().join(format!("coerce-{}-{}.txt", name, std::process::id()));
+
+        DiscoveryFile(path)
+    }
+
+    fn write(&self, node_ids: &[NodeId]) {
+        let mut contents = "# coerce cluster nodes\n".to_string();
+        for node_id in node_ids {
+            contents.push_str(&format!("{}\n", node_addr(*node_id)));
+        }
+
+        std::fs::write(&self.0, contents).unwrap();
+    }
+}
+
+impl Drop for DiscoveryFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn node_addr(node_id: NodeId) -> String {
+    format!("node-{}:30101", node_id)
+}
+
+async fn start_node(
+    network: &SimulatedNetwork,
+    node_id: NodeId,
+    configure: impl FnOnce(ClusterWorkerBuilder) -> ClusterWorkerBuilder,
+) -> (RemoteActorSystem, RemoteServer) {
+    let addr = node_addr(node_id);
+    let system = RemoteActorSystem::builder()
+        .with_tag(addr.clone())
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(network.transport(&addr))
+        .build()
+        .await;
+
+    let server = configure(system.clone().cluster_worker().listen_addr(&addr))
+        .start()
+        .await;
+
+    (system, server)
+}
+
+async fn wait_for_healthy(system: &RemoteActorSystem, node_ids: &[NodeId]) {
+    wait_until(TIMEOUT, || async {
+        let nodes = system.get_nodes().await;
+        node_ids.iter().all(|id| {
+            nodes
+                .iter()
+                .any(|n| n.id == *id && n.status == NodeStatus::Healthy)
+        })
+    })
+    .await
+    .unwrap_or_else(|_| {
+        panic!(
+            "node_id={} did not see nodes {:?} as healthy",
+            system.node_id(),
+            node_ids
+        )
+    });
+}
@@ -0,0 +1,186 @@
+use coerce::actor::system::ActorSystem;
+use coerce::remote::cluster::discovery::dns::{
+    DnsClusterSeed, DnsResolveErr, DnsResolver, SrvRecord,
+};
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::net::server::RemoteServer;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce_test::time::wait_until;
+use coerce_test::SimulatedNetwork;
+use parking_lot::Mutex;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(300);
+const PORT: u16 = 30101;
+const SERVICE_NAME: &str = "_coerce._tcp.coerce.default.svc.cluster.local";
+
+#[tokio::test(start_paused = true)]
+pub async fn test_dns_srv_seed_discovery() {
+    let network = SimulatedNetwork::new();
+    let resolver = StubResolver::default();
+    resolver.set_srv(&[1, 2, 3]);
+
+    let mut nodes = vec![];
+    for node_id in [1, 2, 3] {
+        let addr = format!("node-{}:{}", node_id, PORT);
+        let seed = DnsClusterSeed::srv(SERVICE_NAME).with_resolver(resolver.clone());
+        nodes.push(start_node(&network, node_id, addr, seed).await);
+    }
+
+    for (system, _) in &nodes {
+        wait_for_healthy(system, &[1, 2, 3]).await;
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_dns_a_record_seed_discovery() {
+    let network = SimulatedNetwork::new();
+    let resolver = StubResolver::default();
+    resolver.set_ips(&[1, 2]);
+
+    let mut nodes = vec![];
+    for node_id in [1, 2] {
+        let addr = format!("{}:{}", ip(node_id), PORT);
+        let seed = DnsClusterSeed::a_records(SERVICE_NAME, PORT).with_resolver(resolver.clone());
+        nodes.push(start_node(&network, node_id, addr, seed).await);
+    }
+
+    for (system, _) in &nodes {
+        wait_for_healthy(system, &[1, 2]).await;
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_dns_rediscovery_when_cluster_shrinks() {
+    // the refresh interval is long enough that nodes are only re-discovered once the cluster
+    // shrinks to a single node
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+
+    let network = SimulatedNetwork::new();
+    let resolver = StubResolver::default();
+    resolver.set_srv(&[1, 2]);
+
+    let seed = || {
+        DnsClusterSeed::srv(SERVICE_NAME)
+            .refresh_interval(REFRESH_INTERVAL)
+            .with_resolver(resolver.clone())
+    };
+
+    let (system_1, _server_1) = start_node(&network, 1, node_addr(1), seed()).await;
+    let (system_2, server_2) = start_node(&network, 2, node_addr(2), seed()).await;
+
+    wait_for_healthy(&system_1, &[1, 2]).await;
+
+    // node 3 isn't registered with the service yet, so doesn't discover any other nodes
+    let (system_3, _server_3) = start_node(
+        &network,
+        3,
+        node_addr(3),
+        DnsClusterSeed::srv(SERVICE_NAME)
+            .refresh_interval(REFRESH_INTERVAL)
+            .with_resolver(StubResolver::default()),
+    )
+    .await;
+
+    resolver.set_srv(&[1, 3]);
+
+    network.isolate(&node_addr(2));
+    server_2.stop();
+    system_2.actor_system().shutdown().await;
+
+    let start = tokio::time::Instant::now();
+    wait_for_healthy(&system_1, &[1, 3]).await;
+    wait_for_healthy(&system_3, &[1, 3]).await;
+
+    assert!(start.elapsed() < REFRESH_INTERVAL);
+}
+
+#[derive(Clone, Default)]
+struct StubResolver {
+    srv: Arc<Mutex<Vec<SrvRecord>>>,
+    ips: Arc<Mutex<Vec<IpAddr>>>,
+}
+
+impl StubResolver {
+    fn set_srv(&self, node_ids: &[NodeId]) {
+        *self.srv.lock() = node_ids
+            .iter()
+            .map(|id| SrvRecord {
+                target: format!("node-{}", id),
+                port: PORT,
+            })
+            .collect();
+    }
+
+    fn set_ips(&self, node_ids: &[NodeId]) {
+        *self.ips.lock() = node_ids.iter().map(|id| ip(*id)).collect();
+    }
+}
+
+#[async_trait]
+impl DnsResolver for StubResolver {
+    async fn lookup_srv(&self, _name: &str) -> Result<Vec<SrvRecord>, DnsResolveErr> {
+        Ok(self.srv.lock().clone())
+    }
+
+    async fn lookup_ip(&self, _name: &str) -> Result<Vec<IpAddr>, DnsResolveErr> {
+        Ok(self.ips.lock().clone())
+    }
+}
+
+fn ip(node_id: NodeId) -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(10, 0, 0, node_id as u8))
+}
+
+fn node_addr(node_id: NodeId) -> String {
+    format!("node-{}:{}", node_id, PORT)
+}
+
+async fn start_node(
+    network: &SimulatedNetwork,
+    node_id: NodeId,
+    addr: String,
+    seed: DnsClusterSeed,
+) -> (RemoteActorSystem, RemoteServer) {
+    let system = RemoteActorSystem::builder()
+        .with_tag(addr.clone())
+        .with_id(node_id)
+        .with_actor_system(ActorSystem::new())
+        .with_transport(network.transport(&addr))
+        .build()
+        .await;
+
+    let server = system
+        .clone()
+        .cluster_worker()
+        .listen_addr(&addr)
+        .with_dns_seed(seed)
+        .start()
+        .await;
+
+    (system, server)
+}
+
+async fn wait_for_healthy(system: &RemoteActorSystem, node_ids: &[NodeId]) {
+    wait_until(TIMEOUT, || async {
+        let nodes = system.get_nodes().await;
+        node_ids.iter().all(|id| {
+            nodes
+                .iter()
+                .any(|n| n.id == *id && n.status == NodeStatus::Healthy)
+        })
+    })
+    .await
+    .unwrap_or_else(|_| {
+        panic!(
+            "node_id={} did not see nodes {:?} as healthy",
+            system.node_id(),
+            node_ids
+        )
+    });
+}
@@ -0,0 +1,165 @@
+use coerce::remote::cluster::gossip::membership::MemberStatus;
+use coerce::remote::cluster::gossip::GossipConfig;
+use coerce::remote::cluster::node::NodeStatus;
+use coerce::remote::system::NodeId;
+use coerce_test::event::EventFilter;
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::time::Duration;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_gossip_cluster_formation() {
+    let cluster = gossip_cluster(4, GossipConfig::default()).await;
+
+    for node_id in [1, 2, 3, 4] {
+        let membership = cluster.system(node_id).gossip().unwrap().membership();
+        let members = membership.members();
+
+        assert_eq!(members.len(), 4);
+        assert!(members.iter().all(|m| m.status == MemberStatus::Alive));
+        assert_eq!(membership.incarnation(), 0);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_gossip_dead_node_removed() {
+    let mut cluster = gossip_cluster(3, GossipConfig::default()).await;
+
+    cluster.events().clear();
+    cluster.kill(3).await;
+
+    for node_id in [1, 2] {
+        cluster
+            .wait_for(
+                &EventFilter::node_removed().node(3).observed_by(node_id),
+                TIMEOUT,
+            )
+            .await
+            .expect("dead node removed");
+
+        wait_for_status(&cluster, node_id, 3, NodeStatus::Terminated).await;
+    }
+
+    cluster
+        .wait_for_leader(1, TIMEOUT)
+        .await
+        .expect("leader unchanged");
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_gossip_indirect_probe_prevents_suspicion() {
+    let cluster = gossip_cluster(3, GossipConfig::default()).await;
+
+    // node 1 can no longer reach node 3 directly, but both can still reach node 2
+    cluster.drop_messages(1, 3);
+    cluster.drop_messages(3, 1);
+    cluster.events().clear();
+
+    for _ in 0..30 {
+        cluster.advance(Duration::from_secs(1)).await;
+
+        for node_id in [1, 2, 3] {
+            let nodes = cluster.system(node_id).get_nodes().await;
+            assert!(nodes.iter().all(|n| n.status == NodeStatus::Healthy));
+        }
+    }
+
+    assert!(cluster
+        .events()
+        .matching(&EventFilter::node_removed())
+        .is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_gossip_suspected_node_refutes_suspicion() {
+    let config = GossipConfig {
+        indirect_probes: 0,
+        suspicion_timeout: Duration::from_secs(30),
+        ..Default::default()
+    };
+
+    let cluster = gossip_cluster(3, config).await;
+
+    // without indirect probes, node 1 suspects node 3, which learns about the suspicion
+    // via node 2 and refutes it
+    cluster.drop_messages(1, 3);
+    cluster.events().clear();
+
+    wait_until(TIMEOUT, || async {
+        cluster
+            .system(3)
+            .gossip()
+            .unwrap()
+            .membership()
+            .incarnation()
+            > 0
+    })
+    .await
+    .expect("suspicion refuted");
+
+    cluster.heal_all();
+
+    wait_until(TIMEOUT, || async {
+        let incarnation = cluster
+            .system(3)
+            .gossip()
+            .unwrap()
+            .membership()
+            .incarnation();
+        [1, 2].iter().all(|node_id| {
+            let member = cluster
+                .system(*node_id)
+                .gossip()
+                .unwrap()
+                .membership()
+                .get(3);
+            member.is_some_and(|m| m.status == MemberStatus::Alive && m.incarnation == incarnation)
+        })
+    })
+    .await
+    .expect("refutation disseminated");
+
+    for node_id in [1, 2] {
+        wait_for_status(&cluster, node_id, 3, NodeStatus::Healthy).await;
+    }
+
+    assert!(cluster
+        .events()
+        .matching(&EventFilter::node_removed())
+        .is_empty());
+}
+
+async fn gossip_cluster(nodes: usize, config: GossipConfig) -> TestCluster {
+    TestCluster::builder()
+        .nodes(nodes)
+        .configure(move |b| {
+            let config = config.clone();
+            b.configure(move |c| c.gossip(config.clone()))
+        })
+        .build()
+        .await
+}
+
+async fn wait_for_status(
+    cluster: &TestCluster,
+    observed_by: NodeId,
+    node_id: NodeId,
+    status: NodeStatus,
+) {
+    wait_until(TIMEOUT, || async {
+        let nodes = cluster.system(observed_by).get_nodes().await;
+        nodes.iter().any(|n| n.id == node_id && n.status == status)
+    })
+    .await
+    .expect("node status");
+}
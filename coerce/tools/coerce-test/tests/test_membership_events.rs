@@ -0,0 +1,118 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::{Actor, IntoActor, LocalActorRef};
+use coerce::remote::cluster::events::MemberEvent;
+use coerce::remote::stream::pubsub::Subscription;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_late_subscriber_receives_current_members() {
+    let cluster = TestCluster::builder().nodes(3).build().await;
+
+    let (events, _subscription) = subscribe(cluster.system(1)).await;
+    let events = events.lock().unwrap();
+
+    match events.first() {
+        Some(MemberEvent::CurrentMembers(members)) => {
+            let mut node_ids: Vec<NodeId> = members.iter().map(|n| n.id).collect();
+            node_ids.sort();
+
+            assert_eq!(node_ids, vec![1, 2, 3]);
+        }
+        event => panic!("expected CurrentMembers, received {:?}", event),
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_member_up_and_removed() {
+    let mut cluster = TestCluster::builder().nodes(2).build().await;
+
+    let (events, _subscription) = subscribe(cluster.system(1)).await;
+
+    let node_id = cluster.add_node().await;
+    wait_for_event(
+        &events,
+        |e| matches!(e, MemberEvent::MemberUp(n) if n.id == node_id),
+    )
+    .await;
+
+    cluster.kill(node_id).await;
+    wait_for_event(
+        &events,
+        |e| matches!(e, MemberEvent::MemberRemoved(n) if n.id == node_id),
+    )
+    .await;
+
+    let events = events.lock().unwrap();
+    let joined = events
+        .iter()
+        .position(|e| matches!(e, MemberEvent::MemberJoining(n) if n.id == node_id));
+
+    let up = events
+        .iter()
+        .position(|e| matches!(e, MemberEvent::MemberUp(n) if n.id == node_id));
+
+    assert!(joined < up, "MemberJoining is published before MemberUp");
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_member_leaving() {
+    let mut cluster = TestCluster::builder().nodes(3).build().await;
+
+    let (events, _subscription) = subscribe(cluster.system(1)).await;
+
+    cluster.leave(3).await;
+    wait_for_event(
+        &events,
+        |e| matches!(e, MemberEvent::MemberLeaving(n) if n.id == 3),
+    )
+    .await;
+    wait_for_event(
+        &events,
+        |e| matches!(e, MemberEvent::MemberRemoved(n) if n.id == 3),
+    )
+    .await;
+}
+
+type Events = Arc<Mutex<Vec<MemberEvent>>>;
+
+struct MemberEventCollector(Events);
+
+impl Actor for MemberEventCollector {}
+
+#[async_trait]
+impl Handler<MemberEvent> for MemberEventCollector {
+    async fn handle(&mut self, message: MemberEvent, _ctx: &mut ActorContext) {
+        self.0.lock().unwrap().push(message);
+    }
+}
+
+async fn subscribe(system: &RemoteActorSystem) -> (Events, Subscription) {
+    let events = Events::default();
+    let actor: LocalActorRef<MemberEventCollector> = MemberEventCollector(events.clone())
+        .into_actor(Some("member-events"), system.actor_system())
+        .await
+        .unwrap();
+
+    let subscription = system.subscribe_membership(actor).await.unwrap();
+    wait_for_event(&events, |e| matches!(e, MemberEvent::CurrentMembers(_))).await;
+
+    (events, subscription)
+}
+
+async fn wait_for_event(events: &Events, predicate: impl Fn(&MemberEvent) -> bool) {
+    wait_until(TIMEOUT, || async {
+        events.lock().unwrap().iter().any(&predicate)
+    })
+    .await
+    .expect("membership event");
+}
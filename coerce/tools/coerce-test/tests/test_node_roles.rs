@@ -0,0 +1,218 @@
+use crate::util::{
+    with_test_actor, SetStatusRequest, SetStatusResponse, TestActorFactory, TestActorRecipe,
+    TestActorStatus,
+};
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::{Actor, IntoActor};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::remote::net::StreamData;
+use coerce::remote::stream::pubsub::{PubSub, Receive, Subscription, Topic};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::NodeId;
+use coerce::sharding::host::stats::GetStats;
+use coerce::sharding::Sharding;
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Node 1 is a frontend node, every other node is a backend node.
+fn with_roles(node_id: NodeId, builder: RemoteActorSystemBuilder) -> RemoteActorSystemBuilder {
+    if node_id == 1 {
+        builder.with_roles(["frontend"])
+    } else {
+        builder.with_roles(["backend"])
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_roles_exchanged_between_nodes() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .configure_node(with_roles)
+        .build()
+        .await;
+
+    assert!(cluster.system(1).has_role("frontend"));
+    assert!(!cluster.system(1).has_role("backend"));
+
+    for node_id in [1, 2, 3] {
+        let nodes = cluster.system(node_id).get_nodes().await;
+        for node in nodes {
+            let expected_role = if node.id == 1 { "frontend" } else { "backend" };
+            assert_eq!(
+                node.roles.iter().map(|r| r.as_ref()).collect::<Vec<_>>(),
+                vec![expected_role],
+                "node_id={} roles as seen by node_id={}",
+                node.id,
+                node_id
+            );
+        }
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_leader_restricted_to_leader_roles() {
+    let mut cluster = TestCluster::builder()
+        .nodes(3)
+        .configure(|builder| builder.configure(|config| config.leader_roles(["backend"])))
+        .configure_node(with_roles)
+        .build()
+        .await;
+
+    // node 1 is the oldest node, but it doesn't have the `backend` role
+    cluster
+        .wait_for_leader(2, TIMEOUT)
+        .await
+        .expect("node 2 becomes leader");
+
+    cluster.kill(2).await;
+
+    cluster
+        .wait_for_leader(3, TIMEOUT)
+        .await
+        .expect("node 3 becomes leader");
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_sharding_only_hosted_on_role() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .with_persistence(Persistence::from(InMemoryStorageProvider::new()))
+        .configure(with_test_actor)
+        .configure_node(with_roles)
+        .build()
+        .await;
+
+    let mut shardings = vec![];
+    for node_id in [1, 2, 3] {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(cluster.system(node_id).clone())
+                .with_role("backend")
+                .build()
+                .await,
+        );
+    }
+
+    for i in 0..10 {
+        let res = shardings[0]
+            .get(format!("entity-{}", i), Some(TestActorRecipe))
+            .send(SetStatusRequest {
+                status: TestActorStatus::Active,
+            })
+            .await;
+
+        assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+    }
+
+    let mut hosted_shards = vec![];
+    for sharding in &shardings {
+        let stats = sharding
+            .shard_host()
+            .send(GetStats)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        hosted_shards.push(stats.hosted_shard_count);
+    }
+
+    assert_eq!(hosted_shards[0], 0);
+    assert!(hosted_shards[1] + hosted_shards[2] > 0);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_publish_to_role() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .configure_node(with_roles)
+        .build()
+        .await;
+
+    let mut received = vec![];
+    let mut subscribers = vec![];
+    for node_id in [1, 2, 3] {
+        let count = Arc::new(AtomicUsize::new(0));
+        let subscriber = RoleSubscriber {
+            received: count.clone(),
+            subscription: None,
+        }
+        .into_anon_actor(None::<String>, cluster.system(node_id).actor_system())
+        .await
+        .unwrap();
+
+        received.push(count);
+        subscribers.push(subscriber);
+    }
+
+    PubSub::publish_to_role(RoleTopic, RoleEvent, "backend", cluster.system(1)).await;
+    PubSub::publish_to_role(RoleTopic, RoleEvent, "backend", cluster.system(2)).await;
+
+    wait_until(TIMEOUT, || async {
+        received[1].load(Ordering::Relaxed) == 2 && received[2].load(Ordering::Relaxed) == 2
+    })
+    .await
+    .expect("backend nodes receive published messages");
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(received[0].load(Ordering::Relaxed), 0);
+}
+
+pub struct RoleTopic;
+
+pub struct RoleEvent;
+
+impl Topic for RoleTopic {
+    type Message = RoleEvent;
+
+    fn topic_name() -> &'static str {
+        "role-topic"
+    }
+}
+
+impl StreamData for RoleEvent {
+    fn read_from_bytes(_data: Vec<u8>) -> Option<Self> {
+        Some(RoleEvent)
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(vec![])
+    }
+}
+
+pub struct RoleSubscriber {
+    received: Arc<AtomicUsize>,
+    subscription: Option<Subscription>,
+}
+
+#[async_trait]
+impl Actor for RoleSubscriber {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        self.subscription = Some(
+            PubSub::subscribe::<Self, RoleTopic>(RoleTopic, ctx)
+                .await
+                .unwrap(),
+        );
+    }
+}
+
+#[async_trait]
+impl Handler<Receive<RoleTopic>> for RoleSubscriber {
+    async fn handle(&mut self, _message: Receive<RoleTopic>, _ctx: &mut ActorContext) {
+        self.received.fetch_add(1, Ordering::Relaxed);
+    }
+}
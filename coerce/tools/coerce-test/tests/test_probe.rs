@@ -0,0 +1,187 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::system::ActorSystem;
+use coerce::actor::watch::TerminationReason;
+use coerce::actor::{Actor, ActorRef, IntoActor, LocalActorRef, Receiver};
+use coerce_test::intercept::intercept;
+use coerce_test::{ProbeMessage, TestProbe};
+use std::time::Duration;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+struct Ping(u32);
+
+impl Message for Ping {
+    type Result = ();
+}
+
+impl ProbeMessage for Ping {}
+
+struct GetCount;
+
+impl Message for GetCount {
+    type Result = u32;
+}
+
+impl ProbeMessage for GetCount {}
+
+#[derive(Debug, Eq, PartialEq)]
+struct CountChanged(u32);
+
+impl Message for CountChanged {
+    type Result = ();
+}
+
+impl ProbeMessage for CountChanged {}
+
+struct Increment;
+
+impl Message for Increment {
+    type Result = ();
+}
+
+struct Counter {
+    count: u32,
+    subscriber: Receiver<CountChanged>,
+}
+
+impl Actor for Counter {}
+
+#[async_trait]
+impl Handler<Increment> for Counter {
+    async fn handle(&mut self, _message: Increment, _ctx: &mut ActorContext) {
+        self.count += 1;
+        let _ = self.subscriber.send(CountChanged(self.count)).await;
+    }
+}
+
+async fn counter(system: &ActorSystem, probe: &TestProbe) -> LocalActorRef<Counter> {
+    Counter {
+        count: 0,
+        subscriber: probe.actor_ref().into(),
+    }
+    .into_actor(Some("counter"), system)
+    .await
+    .unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_probe_expect_msg() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+
+    probe.actor_ref().notify(Ping(1)).unwrap();
+    probe.actor_ref().notify(Ping(2)).unwrap();
+
+    assert_eq!(probe.expect_msg::<Ping>(TIMEOUT).await.0, 1);
+    assert_eq!(probe.expect_msg::<Ping>(TIMEOUT).await.0, 2);
+    probe.expect_no_msg(TIMEOUT).await;
+}
+
+#[tokio::test(start_paused = true)]
+#[should_panic(expected = "expected test_probe::GetCount, received test_probe::Ping")]
+pub async fn test_probe_expect_msg_wrong_type() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+
+    probe.actor_ref().notify(Ping(1)).unwrap();
+    probe.expect_msg::<GetCount>(TIMEOUT).await;
+}
+
+#[tokio::test(start_paused = true)]
+#[should_panic(expected = "expected no message within 1000ms, received test_probe::Ping")]
+pub async fn test_probe_expect_no_msg() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+
+    probe.actor_ref().notify(Ping(1)).unwrap();
+    probe.expect_no_msg(TIMEOUT).await;
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_probe_fish_for() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+    let counter = counter(&system, &probe).await;
+
+    for _ in 0..5 {
+        counter.notify(Increment).unwrap();
+    }
+
+    let changed = probe
+        .fish_for::<CountChanged, _>(TIMEOUT, |c| c.0 == 4)
+        .await;
+
+    assert_eq!(changed, CountChanged(4));
+    assert_eq!(
+        probe.expect_msg::<CountChanged>(TIMEOUT).await,
+        CountChanged(5)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_probe_reply_with() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+
+    probe.reply_with(|_: &GetCount| 42);
+
+    let count = probe.actor_ref().send(GetCount).await.unwrap();
+    assert_eq!(count, 42);
+    probe.expect_msg::<GetCount>(TIMEOUT).await;
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_probe_lifecycle() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+    let counter = counter(&system, &probe).await;
+
+    probe.expect_started(&counter, TIMEOUT).await;
+    probe.watch(&ActorRef::from(counter.clone())).await;
+
+    counter.notify_stop().unwrap();
+
+    probe.expect_stopped(&counter, TIMEOUT).await;
+    let terminated = probe.expect_terminated(counter.actor_id(), TIMEOUT).await;
+    assert_eq!(terminated.reason, TerminationReason::Stopped);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_intercept_messages() {
+    let system = ActorSystem::new();
+    let mut probe = TestProbe::new(&system).await;
+    let counter = counter(&system, &probe).await;
+
+    let (intercepted_ref, mut interceptor) = intercept(&counter);
+
+    intercepted_ref.notify(Increment).unwrap();
+    interceptor.expect_msg::<Increment>(TIMEOUT).await;
+    assert_eq!(
+        probe.expect_msg::<CountChanged>(TIMEOUT).await,
+        CountChanged(1)
+    );
+
+    interceptor.block::<Increment>();
+    intercepted_ref.notify(Increment).unwrap();
+    interceptor.expect_msg::<Increment>(TIMEOUT).await;
+    probe.expect_no_msg(TIMEOUT).await;
+
+    interceptor.unblock::<Increment>();
+    intercepted_ref.notify(Increment).unwrap();
+    assert_eq!(
+        probe.expect_msg::<CountChanged>(TIMEOUT).await,
+        CountChanged(2)
+    );
+
+    counter.notify(Increment).unwrap();
+    assert_eq!(
+        probe.expect_msg::<CountChanged>(TIMEOUT).await,
+        CountChanged(3)
+    );
+    interceptor.expect_msg::<Increment>(TIMEOUT).await;
+    interceptor.expect_no_msg(TIMEOUT).await;
+}
@@ -0,0 +1,521 @@
+use crate::util::{
+    with_test_actor, SetStatusRequest, SetStatusResponse, TestActorFactory, TestActorRecipe,
+    TestActorStatus,
+};
+use coerce::actor::system::ActorSystem;
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::journal::provider::StorageProvider;
+use coerce::persistent::journal::storage::{JournalEntry, JournalStorage, JournalStorageRef};
+use coerce::persistent::Persistence;
+use coerce::remote::raft::{RaftConfig, RaftErr, RaftRole, RaftStatus};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce::remote::system::{NodeId, RemoteActorSystem};
+use coerce::sharding::coordinator::allocation::{
+    AllocateShard, AllocateShardErr, AllocateShardResult,
+};
+use coerce::sharding::coordinator::stats::GetShardingStats;
+use coerce::sharding::host::GetCoordinator;
+use coerce::sharding::Sharding;
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::cell::Cell;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_leader_election() {
+    let cluster = raft_cluster(3, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+
+    assert_eq!(cluster.leader(), Some(leader));
+
+    for node_id in [1, 2, 3] {
+        let status = raft_status(&cluster, node_id).await;
+        assert_eq!(status.voters, vec![1, 2, 3]);
+        assert_eq!(status.role.is_leader(), node_id == leader);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_metadata_replication() {
+    let cluster = raft_cluster(3, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+    let follower = [1, 2, 3].into_iter().find(|id| *id != leader).unwrap();
+
+    let raft = cluster.system(leader).raft().unwrap();
+    raft.set("key-1", b"value-1".to_vec()).await.unwrap();
+    raft.set("key-2", b"value-2".to_vec()).await.unwrap();
+    raft.delete("key-1").await.unwrap();
+
+    wait_for_metadata(&cluster, &[1, 2, 3], "key-1", None).await;
+    for node_id in [1, 2, 3] {
+        let metadata = cluster.system(node_id).raft().unwrap().metadata();
+        assert_eq!(metadata.get("key-2"), Some(b"value-2".to_vec()));
+    }
+
+    let follower_raft = cluster.system(follower).raft().unwrap();
+    assert_eq!(
+        follower_raft.set("key-3", vec![]).await,
+        Err(RaftErr::NotLeader(Some(leader)))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_leader_failover() {
+    let mut cluster = raft_cluster(3, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+
+    let raft = cluster.system(leader).raft().unwrap();
+    raft.set("key", b"before".to_vec()).await.unwrap();
+
+    cluster.kill(leader).await;
+
+    let remaining: Vec<NodeId> = cluster.running_nodes();
+    let new_leader = wait_for_raft_leader(&cluster, &remaining).await;
+    assert_ne!(new_leader, leader);
+
+    cluster
+        .wait_for_leader(new_leader, TIMEOUT)
+        .await
+        .expect("heartbeat leader follows the raft leader");
+
+    let raft = cluster.system(new_leader).raft().unwrap();
+    assert_eq!(raft.metadata().get("key"), Some(b"before".to_vec()));
+
+    raft.set("key", b"after".to_vec()).await.unwrap();
+    wait_for_metadata(&cluster, &remaining, "key", Some(b"after".to_vec())).await;
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_minority_cannot_elect_leader() {
+    let cluster = raft_cluster(3, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+    let isolated = [1, 2, 3].into_iter().find(|id| *id != leader).unwrap();
+
+    cluster.split(&[isolated]);
+    tokio::time::sleep(Duration::from_secs(30)).await;
+
+    let status = raft_status(&cluster, isolated).await;
+    assert!(!status.role.is_leader());
+
+    let raft = cluster.system(leader).raft().unwrap();
+    raft.set("key", b"value".to_vec()).await.unwrap();
+
+    let isolated_raft = cluster.system(isolated).raft().unwrap();
+    assert_eq!(isolated_raft.metadata().get("key"), None);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_isolated_leader_steps_down() {
+    let cluster = raft_cluster(3, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+    let majority: Vec<NodeId> = [1, 2, 3].into_iter().filter(|id| *id != leader).collect();
+
+    cluster.split(&[leader]);
+
+    let new_leader = wait_for_raft_leader(&cluster, &majority).await;
+    assert_ne!(new_leader, leader);
+
+    // the old leader can't reach a majority, so it stops acting as the leader
+    wait_until(TIMEOUT, || async {
+        !raft_status(&cluster, leader).await.role.is_leader()
+            && cluster.system(leader).current_leader() != Some(leader)
+    })
+    .await
+    .expect("isolated leader stepped down");
+
+    let raft = cluster.system(leader).raft().unwrap();
+    assert!(matches!(
+        raft.set("key", vec![]).await,
+        Err(RaftErr::NotLeader(_))
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_partitioned_minority_never_elects_leader() {
+    let cluster = raft_cluster(5, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3, 4, 5]).await;
+    let minority: Vec<NodeId> = [1, 2, 3, 4, 5]
+        .into_iter()
+        .filter(|id| *id != leader)
+        .take(2)
+        .collect();
+
+    // the quorum is a majority of the configured voters, however long the partition lasts
+    cluster.split(&minority);
+    tokio::time::sleep(Duration::from_secs(300)).await;
+
+    for node_id in &minority {
+        let status = raft_status(&cluster, *node_id).await;
+        assert!(!status.role.is_leader());
+        assert_eq!(status.voters, vec![1, 2, 3, 4, 5]);
+    }
+
+    let raft = cluster.system(leader).raft().unwrap();
+    raft.set("key", b"value".to_vec()).await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_no_leader_until_quorum_of_voters_started() {
+    let persistence = Persistence::from(InMemoryStorageProvider::new());
+    let builder = RemoteActorSystem::builder()
+        .with_id(1)
+        .with_actor_system(ActorSystem::new().to_persistent(persistence));
+
+    // the only node that has started can't elect itself, it's one of three voters
+    let system = with_raft(builder, 3, RaftConfig::default()).build().await;
+    tokio::time::sleep(Duration::from_secs(60)).await;
+
+    let status = system.raft().unwrap().status().await.unwrap();
+    assert!(!status.role.is_leader());
+    assert_eq!(status.leader_id, None);
+    assert!(status.term > 0);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_snapshot_installed_on_new_node() {
+    let config = RaftConfig {
+        snapshot_threshold: 10,
+        ..Default::default()
+    };
+
+    let mut cluster = raft_cluster(3, config).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+
+    let raft = cluster.system(leader).raft().unwrap();
+    for i in 0..25 {
+        raft.set(format!("key-{}", i), vec![i]).await.unwrap();
+    }
+
+    let status = raft_status(&cluster, leader).await;
+    assert!(status.snapshot_index > 0);
+
+    let new_node = cluster.add_node().await;
+    wait_for_metadata(&cluster, &[new_node], "key-24", Some(vec![24])).await;
+
+    let metadata = cluster.system(new_node).raft().unwrap().metadata();
+    assert_eq!(metadata.get_prefixed("key-").len(), 25);
+
+    // nodes that aren't configured as voters are learners
+    let status = raft_status(&cluster, new_node).await;
+    assert_eq!(status.role, RaftRole::Follower);
+    assert_eq!(status.leader_id, Some(leader));
+    assert_eq!(status.voters, vec![1, 2, 3]);
+    assert!(status.snapshot_index > 0);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_voters_changed_via_log() {
+    let mut cluster = raft_cluster(3, RaftConfig::default()).await;
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+
+    let new_node = cluster.add_node().await;
+    let raft = cluster.system(leader).raft().unwrap().clone();
+    raft.add_voter(new_node).await.unwrap();
+
+    wait_for_voters(&cluster, &[1, 2, 3, new_node], &[1, 2, 3, new_node]).await;
+
+    let removed = [1, 2, 3].into_iter().find(|id| *id != leader).unwrap();
+    raft.remove_voter(removed).await.unwrap();
+
+    let voters: Vec<NodeId> = [1, 2, 3, new_node]
+        .into_iter()
+        .filter(|id| *id != removed)
+        .collect();
+
+    wait_for_voters(&cluster, &[1, 2, 3, new_node], &voters).await;
+
+    // the removed node is now a learner, the remaining voters can still commit without it
+    cluster.kill(removed).await;
+    raft.set("key", b"value".to_vec()).await.unwrap();
+    wait_for_metadata(&cluster, &voters, "key", Some(b"value".to_vec())).await;
+
+    let follower = cluster.system(new_node).raft().unwrap();
+    assert_eq!(
+        follower.add_voter(removed).await,
+        Err(RaftErr::NotLeader(Some(leader)))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_state_recovered_after_restart() {
+    let persistence = Persistence::from(InMemoryStorageProvider::new());
+
+    let system = single_node_raft(persistence.clone()).await;
+    let raft = system.raft().unwrap().clone();
+    wait_until(TIMEOUT, || async {
+        raft.status().await.unwrap().role.is_leader()
+    })
+    .await
+    .expect("raft leader elected");
+
+    raft.set("key", b"value".to_vec()).await.unwrap();
+
+    let term = raft.status().await.unwrap().term;
+    system.actor_system().shutdown().await;
+
+    // the node remembers its term and log, so it's elected in a later term, and the entries
+    // are applied again once they've been committed
+    let system = single_node_raft(persistence).await;
+    let raft = system.raft().unwrap();
+    let status = raft.status().await.unwrap();
+    assert_eq!(status.term, term);
+    assert!(status.last_log_index >= 2);
+
+    wait_until(TIMEOUT, || async {
+        raft.metadata().get("key") == Some(b"value".to_vec())
+    })
+    .await
+    .expect("metadata recovered");
+
+    let status = raft.status().await.unwrap();
+    assert!(status.role.is_leader());
+    assert!(status.term > term);
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_not_started_when_state_cannot_be_recovered() {
+    let persistence = Persistence::from(UnavailableStorageProvider);
+
+    // the node still starts, but never takes part in the raft group
+    let system = single_node_raft(persistence).await;
+    let raft = system.raft().unwrap();
+    wait_until(TIMEOUT, || async { raft.status().await.is_err() })
+        .await
+        .expect("raft actor stopped");
+
+    assert_eq!(raft.set("key", vec![]).await, Err(RaftErr::Stopped));
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_raft_shard_allocation_rejected_when_not_committed() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .with_persistence(Persistence::from(InMemoryStorageProvider::new()))
+        .configure(|b| with_raft(with_test_actor(b), 3, RaftConfig::default()))
+        .build()
+        .await;
+
+    let leader = wait_for_raft_leader(&cluster, &[1, 2, 3]).await;
+    cluster
+        .wait_for_leader(leader, TIMEOUT)
+        .await
+        .expect("heartbeat leader follows the raft leader");
+
+    let mut shardings = vec![];
+    for node_id in 1..=3 {
+        shardings.push(
+            Sharding::<TestActorFactory>::builder(cluster.system(node_id).clone())
+                .with_entity_type("test")
+                .build()
+                .await,
+        );
+    }
+
+    let sharded_actor = shardings[0].get("leon".to_string(), Some(TestActorRecipe));
+    let res = sharded_actor
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+
+    let allocations = cluster
+        .system(leader)
+        .raft()
+        .unwrap()
+        .metadata()
+        .get_prefixed("sharding/test/shards/");
+
+    assert_eq!(allocations.len(), 1);
+
+    let coordinator = shardings[leader as usize - 1]
+        .shard_host()
+        .send(GetCoordinator)
+        .await
+        .unwrap();
+
+    // the leader can't commit anything once it's cut off from the other voters, so the
+    // allocation is rejected rather than recorded without being stored in the cluster metadata
+    cluster.split(&[leader]);
+
+    let shard_id = (0..100)
+        .find(|shard_id| !allocations[0].0.ends_with(&format!("/{}", shard_id)))
+        .unwrap();
+
+    let result = coordinator
+        .send(AllocateShard {
+            shard_id,
+            rebalancing: true,
+            datacenter: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result,
+        AllocateShardResult::Err(AllocateShardErr::Persistence)
+    );
+
+    let stats = coordinator.send(GetShardingStats).await.unwrap();
+    assert!(stats.shards.iter().all(|s| s.shard_id != shard_id));
+}
+
+async fn raft_cluster(nodes: usize, config: RaftConfig) -> TestCluster {
+    TestCluster::builder()
+        .nodes(nodes)
+        .with_persistence(Persistence::from(InMemoryStorageProvider::new()))
+        .configure(move |b| with_raft(b, nodes, config.clone()))
+        .build()
+        .await
+}
+
+fn with_raft(
+    builder: RemoteActorSystemBuilder,
+    nodes: usize,
+    config: RaftConfig,
+) -> RemoteActorSystemBuilder {
+    builder.configure(move |c| {
+        c.raft(RaftConfig {
+            voters: (1..=nodes as NodeId).collect(),
+            ..config
+        })
+    })
+}
+
+async fn single_node_raft(persistence: Persistence) -> RemoteActorSystem {
+    let builder = RemoteActorSystem::builder()
+        .with_id(1)
+        .with_actor_system(ActorSystem::new().to_persistent(persistence));
+
+    with_raft(builder, 1, RaftConfig::default()).build().await
+}
+
+async fn raft_status(cluster: &TestCluster, node_id: NodeId) -> RaftStatus {
+    cluster
+        .system(node_id)
+        .raft()
+        .expect("raft enabled")
+        .status()
+        .await
+        .unwrap()
+}
+
+/// Waits for every node in `nodes` to agree on the same raft leader, which must be one of `nodes`.
+async fn wait_for_raft_leader(cluster: &TestCluster, nodes: &[NodeId]) -> NodeId {
+    let leader = Cell::new(None);
+    wait_until(TIMEOUT, || async {
+        let mut leaders = vec![];
+        for node_id in nodes {
+            leaders.push(raft_status(cluster, *node_id).await.leader_id);
+        }
+
+        leader.set(
+            leaders[0].filter(|l| nodes.contains(l) && leaders.iter().all(|l2| *l2 == Some(*l))),
+        );
+        leader.get().is_some()
+    })
+    .await
+    .expect("raft leader elected");
+
+    leader.get().unwrap()
+}
+
+async fn wait_for_voters(cluster: &TestCluster, nodes: &[NodeId], voters: &[NodeId]) {
+    wait_until(TIMEOUT, || async {
+        for node_id in nodes {
+            if raft_status(cluster, *node_id).await.voters != voters {
+                return false;
+            }
+        }
+
+        true
+    })
+    .await
+    .expect("voters replicated");
+}
+
+async fn wait_for_metadata(
+    cluster: &TestCluster,
+    nodes: &[NodeId],
+    key: &str,
+    value: Option<Vec<u8>>,
+) {
+    wait_until(TIMEOUT, || async {
+        nodes.iter().all(|node_id| {
+            let raft = cluster.system(*node_id).raft().unwrap();
+            raft.metadata().get(key) == value
+        })
+    })
+    .await
+    .expect("metadata replicated");
+}
+
+struct UnavailableStorageProvider;
+
+struct UnavailableStorage;
+
+impl StorageProvider for UnavailableStorageProvider {
+    fn journal_storage(&self) -> Option<JournalStorageRef> {
+        Some(Arc::new(UnavailableStorage))
+    }
+}
+
+#[async_trait]
+impl JournalStorage for UnavailableStorage {
+    async fn write_snapshot(&self, _: &str, _: JournalEntry) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn write_message(&self, _: &str, _: JournalEntry) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn write_message_batch(&self, _: &str, _: Vec<JournalEntry>) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn read_latest_snapshot(&self, _: &str) -> anyhow::Result<Option<JournalEntry>> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn read_latest_messages(
+        &self,
+        _: &str,
+        _: i64,
+    ) -> anyhow::Result<Option<Vec<JournalEntry>>> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn read_message(&self, _: &str, _: i64) -> anyhow::Result<Option<JournalEntry>> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn read_messages(
+        &self,
+        _: &str,
+        _: i64,
+        _: i64,
+    ) -> anyhow::Result<Option<Vec<JournalEntry>>> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn delete_messages_to(&self, _: &str, _: i64) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+
+    async fn delete_all(&self, _: &str) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("storage unavailable"))
+    }
+}
@@ -0,0 +1,67 @@
+use crate::util::{
+    with_test_actor, GetStatusRequest, SetStatusRequest, SetStatusResponse, TestActorFactory,
+    TestActorRecipe, TestActorStatus,
+};
+use coerce::persistent::journal::provider::inmemory::InMemoryStorageProvider;
+use coerce::persistent::Persistence;
+use coerce::sharding::Sharding;
+use coerce_test::event::EventFilter;
+use coerce_test::TestCluster;
+use std::time::Duration;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_shard_rebalancing_upon_node_termination() {
+    let mut cluster = TestCluster::builder()
+        .nodes(2)
+        .with_persistence(Persistence::from(InMemoryStorageProvider::new()))
+        .configure(with_test_actor)
+        .build()
+        .await;
+
+    let sharding_1 = Sharding::<TestActorFactory>::builder(cluster.system(1).clone())
+        .build()
+        .await;
+
+    let sharding_2 = Sharding::<TestActorFactory>::builder(cluster.system(2).clone())
+        .build()
+        .await;
+
+    let sharded_actor = sharding_1.get("leon".to_string(), Some(TestActorRecipe));
+    let res = sharded_actor
+        .send(SetStatusRequest {
+            status: TestActorStatus::Active,
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), SetStatusResponse::Ok);
+
+    cluster.events().clear();
+    cluster.kill(1).await;
+
+    cluster
+        .wait_for(
+            &EventFilter::leader_changed().node(2).observed_by(2),
+            TIMEOUT,
+        )
+        .await
+        .expect("node 2 becomes leader");
+
+    // without a recipe, the entity is only reachable if its shard was reallocated to node 2,
+    // and the entity recovered from the shared persistence backplane
+    let sharded_actor = sharding_2.get("leon".to_string(), None);
+    let res = tokio::time::timeout(TIMEOUT, sharded_actor.send(GetStatusRequest))
+        .await
+        .expect("shard reallocated to node 2");
+
+    assert!(res.is_ok());
+}
@@ -0,0 +1,149 @@
+use crate::util::{GetStatusRequest, TestActor};
+use coerce::actor::IntoActor;
+use coerce::remote::cluster::split_brain::{SplitBrainResolverConfig, SplitBrainStrategy};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce_test::event::EventFilter;
+use coerce_test::time::wait_until;
+use coerce_test::TestCluster;
+use std::time::Duration;
+
+pub mod util;
+
+#[macro_use]
+extern crate serde;
+
+#[macro_use]
+extern crate async_trait;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const STABLE_AFTER: Duration = Duration::from_secs(5);
+
+#[tokio::test(start_paused = true)]
+pub async fn test_keep_majority_downs_minority() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .configure(|b| with_split_brain_resolver(b, SplitBrainStrategy::KeepMajority))
+        .build()
+        .await;
+
+    cluster.events().clear();
+    cluster.split(&[3]);
+
+    for node_id in [1, 2, 3] {
+        cluster
+            .wait_for(
+                &EventFilter::node_downed().node(3).observed_by(node_id),
+                TIMEOUT,
+            )
+            .await
+            .expect("minority node downed");
+    }
+
+    assert!(cluster
+        .events()
+        .matching(&EventFilter::node_downed().node(1))
+        .is_empty());
+
+    assert!(cluster
+        .events()
+        .matching(&EventFilter::node_downed().node(2))
+        .is_empty());
+
+    for node_id in [1, 2] {
+        let system = cluster.system(node_id);
+        assert_eq!(system.current_leader(), Some(1));
+        assert!(system.get_nodes().await.iter().all(|n| n.id != 3));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_downed_minority_stops_serving() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .configure(|b| with_split_brain_resolver(b, SplitBrainStrategy::KeepMajority))
+        .build()
+        .await;
+
+    let actor_ref = TestActor::default()
+        .into_actor(Some("test-actor"), cluster.system(3).actor_system())
+        .await
+        .unwrap();
+
+    assert!(actor_ref.send(GetStatusRequest).await.is_ok());
+
+    cluster.split(&[3]);
+
+    cluster
+        .wait_for(&EventFilter::node_downed().node(3).observed_by(3), TIMEOUT)
+        .await
+        .expect("minority node downed itself");
+
+    wait_until(TIMEOUT, || async {
+        cluster.system(3).actor_system().is_terminated()
+    })
+    .await
+    .expect("minority node left the cluster");
+
+    assert!(cluster.system(3).is_leaving());
+    assert!(actor_ref.send(GetStatusRequest).await.is_err());
+
+    for node_id in [1, 2] {
+        assert!(!cluster.system(node_id).actor_system().is_terminated());
+    }
+}
+
+#[tokio::test(start_paused = true)]
+pub async fn test_keep_oldest_downs_side_without_oldest() {
+    let cluster = TestCluster::builder()
+        .nodes(3)
+        .configure(|b| {
+            with_split_brain_resolver(
+                b,
+                SplitBrainStrategy::KeepOldest {
+                    down_if_alone: false,
+                },
+            )
+        })
+        .build()
+        .await;
+
+    cluster.events().clear();
+    cluster.split(&[1]);
+
+    for node_id in [2, 3] {
+        cluster
+            .wait_for(
+                &EventFilter::node_downed()
+                    .node(node_id)
+                    .observed_by(node_id),
+                TIMEOUT,
+            )
+            .await
+            .expect("node downed itself");
+
+        cluster
+            .wait_for(
+                &EventFilter::node_downed().node(node_id).observed_by(1),
+                TIMEOUT,
+            )
+            .await
+            .expect("oldest node downed unreachable node");
+    }
+
+    assert!(cluster
+        .events()
+        .matching(&EventFilter::node_downed().node(1))
+        .is_empty());
+}
+
+fn with_split_brain_resolver(
+    builder: RemoteActorSystemBuilder,
+    strategy: SplitBrainStrategy,
+) -> RemoteActorSystemBuilder {
+    builder.configure(move |c| {
+        c.split_brain_resolver(SplitBrainResolverConfig {
+            strategy,
+            stable_after: STABLE_AFTER,
+        })
+    })
+}
@@ -0,0 +1,96 @@
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::Handler;
+use coerce::actor::{Actor, ActorCreationErr, ActorFactory, ActorRecipe};
+use coerce::remote::system::builder::RemoteActorSystemBuilder;
+use coerce_macros::JsonMessage;
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TestActorStatus {
+    Inactive,
+    Active,
+}
+
+#[derive(JsonMessage, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[result("GetStatusResponse")]
+pub struct GetStatusRequest;
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub enum GetStatusResponse {
+    Ok(TestActorStatus),
+    None,
+}
+
+#[derive(JsonMessage, Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+#[result("SetStatusResponse")]
+pub struct SetStatusRequest {
+    pub status: TestActorStatus,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub enum SetStatusResponse {
+    Ok,
+}
+
+#[derive(Default)]
+pub struct TestActor {
+    pub status: Option<TestActorStatus>,
+}
+
+impl Actor for TestActor {}
+
+#[async_trait]
+impl Handler<GetStatusRequest> for TestActor {
+    async fn handle(
+        &mut self,
+        _message: GetStatusRequest,
+        _ctx: &mut ActorContext,
+    ) -> GetStatusResponse {
+        self.status
+            .map_or(GetStatusResponse::None, GetStatusResponse::Ok)
+    }
+}
+
+#[async_trait]
+impl Handler<SetStatusRequest> for TestActor {
+    async fn handle(
+        &mut self,
+        message: SetStatusRequest,
+        _ctx: &mut ActorContext,
+    ) -> SetStatusResponse {
+        self.status = Some(message.status);
+        SetStatusResponse::Ok
+    }
+}
+
+pub struct TestActorRecipe;
+
+impl ActorRecipe for TestActorRecipe {
+    fn read_from_bytes(_bytes: &Vec<u8>) -> Option<Self> {
+        Some(Self)
+    }
+
+    fn write_to_bytes(&self) -> Option<Vec<u8>> {
+        Some(vec![])
+    }
+}
+
+#[derive(Clone)]
+pub struct TestActorFactory;
+
+#[async_trait]
+impl ActorFactory for TestActorFactory {
+    type Actor = TestActor;
+    type Recipe = TestActorRecipe;
+
+    async fn create(&self, _recipe: TestActorRecipe) -> Result<TestActor, ActorCreationErr> {
+        Ok(TestActor::default())
+    }
+}
+
+pub fn with_test_actor(builder: RemoteActorSystemBuilder) -> RemoteActorSystemBuilder {
+    builder.with_actors(|a| {
+        a.with_actor(TestActorFactory)
+            .with_handler::<TestActor, GetStatusRequest>("GetStatusRequest")
+            .with_handler::<TestActor, SetStatusRequest>("SetStatusRequest")
+    })
+}
@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+#[derive(Clone)]
 pub enum Address {
     /// Uses the pod's IP address for coerce cluster communication
     PodIp,
@@ -6,14 +9,18 @@ pub enum Address {
     Hostname,
 }
 
+#[derive(Clone)]
 pub struct KubernetesDiscoveryConfig {
     /// Pod label used to discover active Coerce cluster nodes (environment variable: COERCE_K8S_POD_SELECTOR)
     pub pod_selection_label: Option<String>,
 
-    /// Name of the port, as defined in the kubernetes pod spec (
+    /// Name of the port, as defined in the kubernetes pod spec (environment variable: COERCE_K8S_PORT_NAME)
     pub coerce_remote_port_name: Option<String>,
 
     pub cluster_node_address: Address,
+
+    /// How often the pods are listed, in addition to watching them for changes
+    pub refresh_interval: Duration,
 }
 
 impl Default for KubernetesDiscoveryConfig {
@@ -35,6 +42,7 @@ impl Default for KubernetesDiscoveryConfig {
                     }
                 },
             ),
+            refresh_interval: Duration::from_secs(30),
         }
     }
 }
@@ -1,77 +1,147 @@
 use crate::config::{Address, KubernetesDiscoveryConfig};
-use coerce::remote::system::RemoteActorSystem;
+use crate::watch::PodWatch;
+use coerce::remote::cluster::discovery::provider::{
+    DiscoveryErr, DiscoveryProvider, DiscoveryWatch,
+};
 use k8s_openapi::api::core::v1::Pod;
 use kube::api::ListParams;
 use kube::{Api, Client};
+use std::time::Duration;
 
 #[macro_use]
 extern crate tracing;
+
+#[macro_use]
+extern crate async_trait;
+
 pub mod config;
+mod watch;
 
+/// Discovers the pods matching the configured label selector, watching them for changes so that
+/// pods are discovered as soon as they become ready.
+///
+/// Register with [`ClusterWorkerBuilder::with_discovery`].
+///
+/// [`ClusterWorkerBuilder::with_discovery`]: coerce::remote::cluster::builder::worker::ClusterWorkerBuilder::with_discovery
 pub struct KubernetesDiscovery {
-    system: RemoteActorSystem,
+    config: KubernetesDiscoveryConfig,
+    client: Option<Client>,
 }
 
 impl KubernetesDiscovery {
-    pub async fn discover(config: KubernetesDiscoveryConfig) -> Option<Vec<String>> {
-        let client = Client::try_default()
-            .await
-            .expect("failed to initialise k8s client");
-        let api = Api::<Pod>::default_namespaced(client);
-
-        let params = ListParams {
-            label_selector: config.pod_selection_label.clone(),
-            ..Default::default()
-        };
-
-        let pods = api.list(&params).await;
-        let mut cluster_nodes = vec![];
-
-        if let Ok(pods) = pods {
-            debug!("pods={:#?}", &pods.items);
-
-            let pods = pods.items;
-            for pod in pods {
-                let pod_spec = pod.spec.unwrap();
-                let pod_status = pod.status.unwrap();
-
-                debug!("pod_status={:?}", pod_status);
-
-                // TODO: Check that the pod is available before using it as a
-                //       seed node.
-
-                let addr = match &config.cluster_node_address {
-                    Address::PodIp => {
-                        let pod_ip = pod_status.pod_ip;
-                        if pod_ip.is_none() {
-                            continue;
-                        }
-
-                        pod_ip.unwrap()
-                    }
-
-                    Address::Hostname => {
-                        let hostname = pod_spec.hostname.unwrap();
-                        let subdomain = pod_spec.subdomain.unwrap();
-
-                        format!("{hostname}.{subdomain}")
-                    }
-                };
-
-                for container in pod_spec.containers {
-                    let port = container
-                        .ports
-                        .unwrap()
-                        .into_iter()
-                        .find(|p| p.name == config.coerce_remote_port_name);
-                    if let Some(port) = port {
-                        cluster_nodes.push(format!("{}:{}", addr, port.container_port));
-                    }
-                }
-            }
+    pub fn new(config: KubernetesDiscoveryConfig) -> KubernetesDiscovery {
+        KubernetesDiscovery {
+            config,
+            client: None,
         }
+    }
+
+    /// Overrides the client used to communicate with the Kubernetes API server, by default the
+    /// client is created from the pod's service account, or from the local kubeconfig.
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+}
+
+#[async_trait]
+impl DiscoveryProvider for KubernetesDiscovery {
+    fn name(&self) -> &str {
+        "kubernetes"
+    }
+
+    async fn discover(&mut self) -> Result<Vec<String>, DiscoveryErr> {
+        let api = pods_api(&mut self.client).await.map_err(discovery_err)?;
+        let pods = api
+            .list(&list_params(&self.config))
+            .await
+            .map_err(discovery_err)?;
+
+        let mut cluster_nodes: Vec<String> = pods
+            .items
+            .iter()
+            .flat_map(|pod| cluster_node_addrs(pod, &self.config))
+            .collect();
+
+        cluster_nodes.sort();
 
         debug!("discovered nodes: {:#?}", &cluster_nodes);
-        Some(cluster_nodes)
+        Ok(cluster_nodes)
     }
+
+    fn refresh_interval(&self) -> Duration {
+        self.config.refresh_interval
+    }
+
+    fn watch(&mut self) -> Option<DiscoveryWatch> {
+        Some(PodWatch::new(self.client.clone(), self.config.clone()).into_stream())
+    }
+}
+
+async fn pods_api(client: &mut Option<Client>) -> Result<Api<Pod>, kube::Error> {
+    let client = match client {
+        Some(client) => client.clone(),
+        None => client.insert(Client::try_default().await?).clone(),
+    };
+
+    Ok(Api::default_namespaced(client))
+}
+
+fn list_params(config: &KubernetesDiscoveryConfig) -> ListParams {
+    ListParams {
+        label_selector: config.pod_selection_label.clone(),
+        ..Default::default()
+    }
+}
+
+/// A pod is only used as a cluster node once it's ready, and until it starts terminating.
+fn is_ready(pod: &Pod) -> bool {
+    if pod.metadata.deletion_timestamp.is_some() {
+        return false;
+    }
+
+    pod.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|c| c.type_ == "Ready" && c.status == "True")
+        })
+}
+
+fn cluster_node_addrs(pod: &Pod, config: &KubernetesDiscoveryConfig) -> Vec<String> {
+    if !is_ready(pod) {
+        return vec![];
+    }
+
+    let pod_spec = match &pod.spec {
+        Some(pod_spec) => pod_spec,
+        None => return vec![],
+    };
+
+    let addr = match &config.cluster_node_address {
+        Address::PodIp => match pod.status.as_ref().and_then(|s| s.pod_ip.as_ref()) {
+            Some(pod_ip) => pod_ip.clone(),
+            None => return vec![],
+        },
+
+        Address::Hostname => match (&pod_spec.hostname, &pod_spec.subdomain) {
+            (Some(hostname), Some(subdomain)) => format!("{hostname}.{subdomain}"),
+            (Some(hostname), None) => hostname.clone(),
+            _ => return vec![],
+        },
+    };
+
+    pod_spec
+        .containers
+        .iter()
+        .flat_map(|container| container.ports.iter().flatten())
+        .filter(|port| port.name == config.coerce_remote_port_name)
+        .map(|port| format!("{}:{}", addr, port.container_port))
+        .collect()
+}
+
+fn discovery_err(e: kube::Error) -> DiscoveryErr {
+    DiscoveryErr::Err(e.to_string())
 }
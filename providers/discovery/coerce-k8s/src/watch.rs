@@ -0,0 +1,156 @@
+use crate::config::KubernetesDiscoveryConfig;
+use crate::{cluster_node_addrs, list_params, pods_api};
+use coerce::remote::cluster::discovery::provider::DiscoveryWatch;
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::WatchEvent;
+use kube::Client;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const WATCH_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Tracks the addresses of the ready pods matching the label selector, by listing the pods and
+/// then watching them for changes from the listed resource version.
+pub(crate) struct PodWatch {
+    client: Option<Client>,
+    config: KubernetesDiscoveryConfig,
+    pods: HashMap<String, Vec<String>>,
+    resource_version: Option<String>,
+    events: Option<BoxStream<'static, kube::Result<WatchEvent<Pod>>>>,
+    last_addrs: Option<Vec<String>>,
+}
+
+impl PodWatch {
+    pub fn new(client: Option<Client>, config: KubernetesDiscoveryConfig) -> PodWatch {
+        PodWatch {
+            client,
+            config,
+            pods: HashMap::new(),
+            resource_version: None,
+            events: None,
+            last_addrs: None,
+        }
+    }
+
+    pub fn into_stream(self) -> DiscoveryWatch {
+        stream::unfold(self, |mut watch| async move {
+            let addrs = watch.next_change().await;
+            Some((addrs, watch))
+        })
+        .boxed()
+    }
+
+    /// Waits until the addresses of the ready pods change.
+    async fn next_change(&mut self) -> Vec<String> {
+        loop {
+            if let Err(e) = self.poll().await {
+                warn!(
+                    "failed to watch pods, retrying in {}s, e={}",
+                    WATCH_RETRY_DELAY.as_secs(),
+                    e
+                );
+
+                self.events = None;
+                tokio::time::sleep(WATCH_RETRY_DELAY).await;
+                continue;
+            }
+
+            let addrs = self.addrs();
+            if self.last_addrs.as_ref() != Some(&addrs) {
+                debug!("pods changed, discovered nodes: {:#?}", &addrs);
+
+                self.last_addrs = Some(addrs.clone());
+                return addrs;
+            }
+        }
+    }
+
+    /// Lists the pods if they haven't yet been listed, otherwise applies the next watch event,
+    /// starting the watch from the last seen resource version if necessary.
+    async fn poll(&mut self) -> Result<(), kube::Error> {
+        let api = pods_api(&mut self.client).await?;
+        let params = list_params(&self.config);
+
+        let resource_version = match &self.resource_version {
+            Some(resource_version) => resource_version.clone(),
+            None => {
+                let pods = api.list(&params).await?;
+                self.pods = pods
+                    .items
+                    .iter()
+                    .filter_map(|pod| {
+                        let name = pod.metadata.name.clone()?;
+                        Some((name, cluster_node_addrs(pod, &self.config)))
+                    })
+                    .collect();
+
+                self.resource_version = Some(pods.metadata.resource_version.unwrap_or_default());
+                self.events = None;
+                return Ok(());
+            }
+        };
+
+        let events = match &mut self.events {
+            Some(events) => events,
+            None => self
+                .events
+                .insert(api.watch(&params, &resource_version).await?.boxed()),
+        };
+
+        match events.next().await {
+            Some(Ok(event)) => self.apply(event),
+
+            Some(Err(e)) => return Err(e),
+
+            // the watch timed out, it's restarted from the last seen resource version
+            None => self.events = None,
+        }
+
+        Ok(())
+    }
+
+    fn apply(&mut self, event: WatchEvent<Pod>) {
+        match event {
+            WatchEvent::Added(pod) | WatchEvent::Modified(pod) => {
+                self.observe_resource_version(&pod);
+                if let Some(name) = pod.metadata.name.clone() {
+                    self.pods
+                        .insert(name, cluster_node_addrs(&pod, &self.config));
+                }
+            }
+
+            WatchEvent::Deleted(pod) => {
+                self.observe_resource_version(&pod);
+                if let Some(name) = &pod.metadata.name {
+                    self.pods.remove(name);
+                }
+            }
+
+            WatchEvent::Bookmark(bookmark) => {
+                self.resource_version = Some(bookmark.metadata.resource_version);
+            }
+
+            WatchEvent::Error(e) => {
+                // typically the resource version is too old (410 Gone), so the pods are re-listed
+                debug!("pod watch error, re-listing pods, e={:?}", e);
+
+                self.resource_version = None;
+                self.events = None;
+            }
+        }
+    }
+
+    fn observe_resource_version(&mut self, pod: &Pod) {
+        if let Some(resource_version) = &pod.metadata.resource_version {
+            self.resource_version = Some(resource_version.clone());
+        }
+    }
+
+    fn addrs(&self) -> Vec<String> {
+        let mut addrs: Vec<String> = self.pods.values().flatten().cloned().collect();
+        addrs.sort();
+        addrs
+    }
+}
@@ -0,0 +1,215 @@
+use coerce::remote::cluster::discovery::provider::DiscoveryProvider;
+use coerce_k8s::config::{Address, KubernetesDiscoveryConfig};
+use coerce_k8s::KubernetesDiscovery;
+use futures::StreamExt;
+use hyper::body::Sender;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use kube::{Client, Config};
+use serde_json::{json, Value};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+pub async fn test_discover_only_ready_pods() {
+    let api_server = MockApiServer::start(vec![
+        pod("coerce-0", Some("10.0.0.1"), true),
+        pod("coerce-1", Some("10.0.0.2"), false),
+        pod("coerce-2", None, true),
+        terminating(pod("coerce-3", Some("10.0.0.4"), true)),
+    ])
+    .await;
+
+    let mut discovery = KubernetesDiscovery::new(config()).with_client(api_server.client());
+    let addrs = discovery.discover().await.unwrap();
+
+    assert_eq!(addrs, vec!["10.0.0.1:30101".to_string()]);
+}
+
+#[tokio::test]
+pub async fn test_watch_pod_changes() {
+    let api_server = MockApiServer::start(vec![
+        pod("coerce-0", Some("10.0.0.1"), true),
+        pod("coerce-1", Some("10.0.0.2"), false),
+    ])
+    .await;
+
+    let mut discovery = KubernetesDiscovery::new(config()).with_client(api_server.client());
+    let mut watch = discovery.watch().unwrap();
+
+    // the watch is driven continuously, as it would be by the discovery actor
+    let (tx, mut changes) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(addrs) = watch.next().await {
+            let _ = tx.send(addrs);
+        }
+    });
+
+    assert_eq!(next_addrs(&mut changes).await, vec!["10.0.0.1:30101"]);
+
+    api_server
+        .send_event("MODIFIED", pod("coerce-1", Some("10.0.0.2"), true))
+        .await;
+
+    assert_eq!(
+        next_addrs(&mut changes).await,
+        vec!["10.0.0.1:30101", "10.0.0.2:30101"]
+    );
+
+    api_server
+        .send_event(
+            "MODIFIED",
+            terminating(pod("coerce-0", Some("10.0.0.1"), true)),
+        )
+        .await;
+
+    assert_eq!(next_addrs(&mut changes).await, vec!["10.0.0.2:30101"]);
+
+    api_server
+        .send_event("ADDED", pod("coerce-2", Some("10.0.0.3"), true))
+        .await;
+
+    assert_eq!(
+        next_addrs(&mut changes).await,
+        vec!["10.0.0.2:30101", "10.0.0.3:30101"]
+    );
+
+    api_server
+        .send_event("DELETED", pod("coerce-1", Some("10.0.0.2"), true))
+        .await;
+
+    assert_eq!(next_addrs(&mut changes).await, vec!["10.0.0.3:30101"]);
+}
+
+async fn next_addrs(changes: &mut mpsc::UnboundedReceiver<Vec<String>>) -> Vec<String> {
+    tokio::time::timeout(TIMEOUT, changes.recv())
+        .await
+        .expect("pods changed")
+        .unwrap()
+}
+
+fn config() -> KubernetesDiscoveryConfig {
+    KubernetesDiscoveryConfig {
+        pod_selection_label: Some("app=coerce".to_string()),
+        coerce_remote_port_name: Some("coerce".to_string()),
+        cluster_node_address: Address::PodIp,
+        refresh_interval: Duration::from_secs(30),
+    }
+}
+
+fn pod(name: &str, pod_ip: Option<&str>, ready: bool) -> Value {
+    json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": name,
+            "namespace": "default",
+            "resourceVersion": "2",
+            "labels": { "app": "coerce" },
+        },
+        "spec": {
+            "containers": [{
+                "name": "coerce",
+                "ports": [{ "name": "coerce", "containerPort": 30101 }],
+            }],
+        },
+        "status": {
+            "podIP": pod_ip,
+            "conditions": [{
+                "type": "Ready",
+                "status": if ready { "True" } else { "False" },
+            }],
+        },
+    })
+}
+
+fn terminating(mut pod: Value) -> Value {
+    pod["metadata"]["deletionTimestamp"] = json!("2023-01-01T00:00:00Z");
+    pod
+}
+
+/// Serves the pod list and watch endpoints of the Kubernetes API. Events sent via
+/// [`MockApiServer::send_event`] are streamed to every open watch.
+struct MockApiServer {
+    addr: SocketAddr,
+    watches: Arc<Mutex<Vec<Sender>>>,
+}
+
+impl MockApiServer {
+    async fn start(pods: Vec<Value>) -> MockApiServer {
+        let watches: Arc<Mutex<Vec<Sender>>> = Arc::new(Mutex::new(vec![]));
+        let pods = Arc::new(pods);
+
+        let make_service = {
+            let watches = watches.clone();
+            make_service_fn(move |_conn| {
+                let watches = watches.clone();
+                let pods = pods.clone();
+                async move {
+                    Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                        let watches = watches.clone();
+                        let pods = pods.clone();
+                        async move { Ok::<_, Infallible>(handle(req, &pods, &watches).await) }
+                    }))
+                }
+            })
+        };
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        MockApiServer { addr, watches }
+    }
+
+    fn client(&self) -> Client {
+        let config = Config::new(format!("http://{}", self.addr).parse().unwrap());
+        Client::try_from(config).unwrap()
+    }
+
+    async fn send_event(&self, event_type: &str, pod: Value) {
+        let event = json!({ "type": event_type, "object": pod });
+        let line = format!("{}\n", event);
+
+        // the watch is started once the pods have been listed
+        let start = tokio::time::Instant::now();
+        while self.watches.lock().await.is_empty() {
+            assert!(start.elapsed() < TIMEOUT, "pods were not watched");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        for watch in self.watches.lock().await.iter_mut() {
+            let _ = watch.send_data(line.clone().into()).await;
+        }
+    }
+}
+
+async fn handle(
+    req: Request<Body>,
+    pods: &[Value],
+    watches: &Mutex<Vec<Sender>>,
+) -> Response<Body> {
+    let query = req.uri().query().unwrap_or_default();
+    assert!(req.uri().path().ends_with("/namespaces/default/pods"));
+    assert!(query.contains("labelSelector=app%3Dcoerce"));
+
+    if query.contains("watch=true") {
+        let (sender, body) = Body::channel();
+        watches.lock().await.push(sender);
+        return Response::new(body);
+    }
+
+    let pod_list = json!({
+        "apiVersion": "v1",
+        "kind": "PodList",
+        "metadata": { "resourceVersion": "1" },
+        "items": pods,
+    });
+
+    Response::new(Body::from(pod_list.to_string()))
+}
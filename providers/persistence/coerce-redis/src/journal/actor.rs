@@ -61,6 +61,15 @@ impl Message for Delete {
     type Result = anyhow::Result<()>;
 }
 
+pub(crate) struct ScanKeys {
+    pub pattern: String,
+    pub result_channel: Sender<anyhow::Result<Vec<String>>>,
+}
+
+impl Message for ScanKeys {
+    type Result = ();
+}
+
 pub(crate) struct DeleteRange {
     pub key: String,
     pub start_sequence: i64,
@@ -264,15 +273,80 @@ where
     C: Clone,
 {
     async fn handle(&mut self, message: Delete, _ctx: &mut ActorContext) -> anyhow::Result<()> {
-        let _ = redis::cmd("DEL")
+        redis::cmd("DEL")
             .arg(message.0)
-            .query_async(&mut self.0)
+            .query_async::<C, ()>(&mut self.0)
             .await?;
 
         Ok(())
     }
 }
 
+#[async_trait]
+impl<C: 'static + ConnectionLike + Send + Sync> Handler<ScanKeys> for RedisJournal<C>
+where
+    C: Clone,
+{
+    async fn handle(&mut self, message: ScanKeys, _ctx: &mut ActorContext) {
+        let connection = self.0.clone();
+        tokio::spawn(async move {
+            let mut connection = connection;
+
+            // `SCAN` only returns the keys held by the node it's sent to, so keys stored on the
+            // other nodes of a Redis Cluster would be silently missed
+            let info = redis::cmd("INFO")
+                .arg("cluster")
+                .query_async::<C, String>(&mut connection)
+                .await;
+
+            match info {
+                Ok(info) if info.lines().any(|line| line.trim() == "cluster_enabled:1") => {
+                    let _ = message.result_channel.send(Err(anyhow::anyhow!(
+                        "scanning keys is not supported when connected to a redis cluster"
+                    )));
+                    return;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    let _ = message.result_channel.send(Err(anyhow::Error::new(err)));
+                    return;
+                }
+            }
+
+            let mut cursor = 0u64;
+            let mut keys = vec![];
+
+            loop {
+                let data = redis::cmd("SCAN")
+                    .cursor_arg(cursor)
+                    .arg("MATCH")
+                    .arg(&message.pattern)
+                    .arg("COUNT")
+                    .arg(1000)
+                    .query_async::<C, (u64, Vec<String>)>(&mut connection)
+                    .await;
+
+                match data {
+                    Ok((next_cursor, mut batch)) => {
+                        keys.append(&mut batch);
+                        if next_cursor == 0 {
+                            break;
+                        }
+
+                        cursor = next_cursor;
+                    }
+                    Err(err) => {
+                        let _ = message.result_channel.send(Err(anyhow::Error::new(err)));
+                        return;
+                    }
+                }
+            }
+
+            let _ = message.result_channel.send(Ok(keys));
+        });
+    }
+}
+
 fn read_journal_entry(redis_value: Vec<u8>) -> Option<JournalEntry> {
     Some(JournalEntry::read_from_bytes(redis_value).unwrap())
 }
@@ -1,5 +1,6 @@
 use crate::journal::actor::{
-    Delete, DeleteRange, ReadMessage, ReadMessages, ReadSnapshot, RedisJournal, Write, WriteBatch,
+    Delete, DeleteRange, ReadMessage, ReadMessages, ReadSnapshot, RedisJournal, ScanKeys, Write,
+    WriteBatch,
 };
 
 use coerce::actor::system::ActorSystem;
@@ -9,6 +10,7 @@ use coerce::persistent::journal::storage::{JournalEntry, JournalStorage, Journal
 
 use redis::aio::ConnectionLike;
 
+use std::collections::BTreeSet;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
@@ -37,6 +39,27 @@ where
     key_provider_fn: fn(&str, &str, &RedisStorageConfig) -> String,
 }
 
+impl<C: 'static + ConnectionLike + Send + Sync> RedisJournalStorage<C>
+where
+    C: Clone,
+{
+    async fn scan_persistence_ids(&self, value_type: &str) -> anyhow::Result<Vec<String>> {
+        let (result_channel, rx) = oneshot::channel();
+        let pattern = (self.key_provider_fn)("*", value_type, self.config.as_ref());
+
+        self.redis_journal.notify(ScanKeys {
+            pattern,
+            result_channel,
+        })?;
+
+        let keys = rx.await??;
+        Ok(keys
+            .iter()
+            .filter_map(|key| get_persistence_id(key, value_type, self.config.as_ref()))
+            .collect())
+    }
+}
+
 impl RedisStorageProvider {
     pub async fn connect(config: RedisStorageConfig, system: &ActorSystem) -> Self {
         // #[cfg(feature = "cluster")]
@@ -91,7 +114,7 @@ async fn create_provider<C: 'static + ConnectionLike + Send + Sync>(
 where
     C: Clone,
 {
-    const REDIS_JOURNAL_COUNTER: AtomicU32 = AtomicU32::new(1);
+    static REDIS_JOURNAL_COUNTER: AtomicU32 = AtomicU32::new(1);
     let config = Arc::new(config);
 
     let redis_journal = RedisJournal(redis)
@@ -266,6 +289,22 @@ where
             .send(Delete(vec![journal_key, snapshot_key]))
             .await?
     }
+
+    /// Lists persistence IDs by scanning the keys of the connected node, which isn't supported
+    /// with Redis Cluster, since each node only holds a subset of the keys.
+    async fn read_persistence_ids(&self) -> anyhow::Result<Vec<String>> {
+        if self.config.cluster {
+            return Err(anyhow::anyhow!(
+                "listing persistence ids is not supported with redis cluster"
+            ));
+        }
+
+        let mut persistence_ids = BTreeSet::new();
+        persistence_ids.extend(self.scan_persistence_ids("journal").await?);
+        persistence_ids.extend(self.scan_persistence_ids("snapshot").await?);
+
+        Ok(persistence_ids.into_iter().collect())
+    }
 }
 
 fn get_clustered_redis_key(
@@ -289,3 +328,17 @@ fn get_redis_key(persistence_id: &str, value_type: &str, config: &RedisStorageCo
         value_type = value_type
     )
 }
+
+fn get_persistence_id(key: &str, value_type: &str, config: &RedisStorageConfig) -> Option<String> {
+    let key = if config.use_key_hashtags {
+        let hashtag_end = key.find("}:")?;
+        &key[hashtag_end + 2..]
+    } else {
+        key
+    };
+
+    key.strip_prefix(config.key_prefix.as_str())?
+        .strip_suffix(value_type)?
+        .strip_suffix(':')
+        .map(|persistence_id| persistence_id.to_string())
+}